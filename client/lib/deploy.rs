@@ -321,10 +321,22 @@ mod tests {
     #[test]
     fn should_create_deploy() {
         let deploy_params = deploy_params();
-        let payment_params =
-            PaymentStrParams::with_package_hash(PKG_HASH, VERSION, ENTRYPOINT, args_simple(), "");
-        let session_params =
-            SessionStrParams::with_package_hash(PKG_HASH, VERSION, ENTRYPOINT, args_simple(), "");
+        let payment_params = PaymentStrParams::with_package_hash(
+            PKG_HASH,
+            VERSION,
+            ENTRYPOINT,
+            args_simple(),
+            "",
+            "",
+        );
+        let session_params = SessionStrParams::with_package_hash(
+            PKG_HASH,
+            VERSION,
+            ENTRYPOINT,
+            args_simple(),
+            "",
+            "",
+        );
 
         let mut output = Vec::new();
 
@@ -357,8 +369,14 @@ mod tests {
     #[test]
     fn should_fail_to_create_large_deploy() {
         let deploy_params = deploy_params();
-        let payment_params =
-            PaymentStrParams::with_package_hash(PKG_HASH, VERSION, ENTRYPOINT, args_simple(), "");
+        let payment_params = PaymentStrParams::with_package_hash(
+            PKG_HASH,
+            VERSION,
+            ENTRYPOINT,
+            args_simple(),
+            "",
+            "",
+        );
         // Create a string arg of 1048576 letter 'a's to ensure the deploy is greater than 1048576
         // bytes.
         let large_args_simple = format!("name_01:string='{:a<1048576}'", "");
@@ -369,6 +387,7 @@ mod tests {
             ENTRYPOINT,
             vec![large_args_simple.as_str()],
             "",
+            "",
         );
 
         match Deploy::with_payment_and_session(
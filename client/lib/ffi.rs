@@ -354,6 +354,40 @@ pub extern "C" fn casper_send_deploy_file(
     })
 }
 
+/// Reads a previously-saved `Deploy` from a file and executes it on the node without committing
+/// the resulting effects to global state.
+///
+/// See [super::speculative_exec](super::speculative_exec) for more details.
+#[no_mangle]
+pub extern "C" fn casper_speculative_exec(
+    maybe_rpc_id: *const c_char,
+    node_address: *const c_char,
+    verbosity_level: u64,
+    input_path: *const c_char,
+    maybe_block_id: *const c_char,
+    response_buf: *mut c_uchar,
+    response_buf_len: usize,
+) -> casper_error_t {
+    let mut runtime = RUNTIME.lock().expect("should lock");
+    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
+    let node_address = try_unsafe_arg!(node_address);
+    let input_path = try_unsafe_arg!(input_path);
+    let maybe_block_id = try_unsafe_arg!(maybe_block_id);
+    runtime.block_on(async move {
+        let result = super::speculative_exec(
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+            input_path,
+            maybe_block_id,
+        );
+        let response = try_unwrap_rpc!(result);
+        copy_str_to_buf(&response, response_buf, response_buf_len);
+        casper_error_t::CASPER_SUCCESS
+    })
+}
+
 /// Transfers funds between purses.
 ///
 /// See [super::transfer](super::transfer) for more details
@@ -421,6 +455,42 @@ pub extern "C" fn casper_get_deploy(
     })
 }
 
+/// Retrieves a page of the `Deploy`s sent by an account from the network.
+///
+/// See [super::get_account_deploys](super::get_account_deploys) for more details.
+#[no_mangle]
+pub extern "C" fn casper_get_account_deploys(
+    maybe_rpc_id: *const c_char,
+    node_address: *const c_char,
+    verbosity_level: u64,
+    public_key: *const c_char,
+    maybe_offset: *const c_char,
+    maybe_limit: *const c_char,
+    response_buf: *mut c_uchar,
+    response_buf_len: usize,
+) -> casper_error_t {
+    let mut runtime = RUNTIME.lock().expect("should lock");
+    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
+    let node_address = try_unsafe_arg!(node_address);
+    let public_key = try_unsafe_arg!(public_key);
+    let maybe_offset = try_unsafe_arg!(maybe_offset);
+    let maybe_limit = try_unsafe_arg!(maybe_limit);
+    runtime.block_on(async move {
+        let result = super::get_account_deploys(
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+            public_key,
+            maybe_offset,
+            maybe_limit,
+        );
+        let response = try_unwrap_rpc!(result);
+        copy_str_to_buf(&response, response_buf, response_buf_len);
+        casper_error_t::CASPER_SUCCESS
+    })
+}
+
 /// Retrieves a `Block` from the network.
 ///
 /// See [super::get_block](super::get_block) for more details.
@@ -509,6 +579,7 @@ pub extern "C" fn casper_get_item(
     state_root_hash: *const c_char,
     key: *const c_char,
     path: *const c_char,
+    maybe_block_hash: *const c_char,
     response_buf: *mut c_uchar,
     response_buf_len: usize,
 ) -> casper_error_t {
@@ -519,6 +590,7 @@ pub extern "C" fn casper_get_item(
     let state_root_hash = try_unsafe_arg!(state_root_hash);
     let key = try_unsafe_arg!(key);
     let path = try_unsafe_arg!(path);
+    let maybe_block_hash = try_unsafe_arg!(maybe_block_hash);
     runtime.block_on(async move {
         let result = super::get_item(
             maybe_rpc_id,
@@ -527,6 +599,43 @@ pub extern "C" fn casper_get_item(
             state_root_hash,
             key,
             path,
+            maybe_block_hash,
+        );
+        let response = try_unwrap_rpc!(result);
+        copy_str_to_buf(&response, response_buf, response_buf_len);
+        casper_error_t::CASPER_SUCCESS
+    })
+}
+
+/// Retrieves an item stored in a dictionary from the network.
+///
+/// See [super::get_dictionary_item](super::get_dictionary_item) for more details.
+#[no_mangle]
+pub extern "C" fn casper_get_dictionary_item(
+    maybe_rpc_id: *const c_char,
+    node_address: *const c_char,
+    verbosity_level: u64,
+    state_root_hash: *const c_char,
+    seed_uref: *const c_char,
+    dictionary_item_key: *const c_char,
+    response_buf: *mut c_uchar,
+    response_buf_len: usize,
+) -> casper_error_t {
+    let mut runtime = RUNTIME.lock().expect("should lock");
+    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
+    let node_address = try_unsafe_arg!(node_address);
+    let state_root_hash = try_unsafe_arg!(state_root_hash);
+    let seed_uref = try_unsafe_arg!(seed_uref);
+    let dictionary_item_key = try_unsafe_arg!(dictionary_item_key);
+    runtime.block_on(async move {
+        let result = super::get_dictionary_item(
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+            state_root_hash,
+            seed_uref,
+            dictionary_item_key,
         );
         let response = try_unwrap_rpc!(result);
         copy_str_to_buf(&response, response_buf, response_buf_len);
@@ -544,6 +653,7 @@ pub extern "C" fn casper_get_balance(
     verbosity_level: u64,
     state_root_hash: *const c_char,
     purse: *const c_char,
+    maybe_block_hash: *const c_char,
     response_buf: *mut c_uchar,
     response_buf_len: usize,
 ) -> casper_error_t {
@@ -553,6 +663,7 @@ pub extern "C" fn casper_get_balance(
     let node_address = try_unsafe_arg!(node_address);
     let state_root_hash = try_unsafe_arg!(state_root_hash);
     let purse = try_unsafe_arg!(purse);
+    let maybe_block_hash = try_unsafe_arg!(maybe_block_hash);
     runtime.block_on(async move {
         let result = super::get_balance(
             maybe_rpc_id,
@@ -560,6 +671,7 @@ pub extern "C" fn casper_get_balance(
             verbosity_level,
             state_root_hash,
             purse,
+            maybe_block_hash,
         );
         let response = try_unwrap_rpc!(result);
         copy_str_to_buf(&response, response_buf, response_buf_len);
@@ -677,6 +789,7 @@ pub struct casper_payment_params_t {
     payment_args_simple: *const *const c_char,
     payment_args_simple_len: usize,
     payment_args_complex: *const c_char,
+    payment_args_json: *const c_char,
     payment_version: *const c_char,
     payment_entry_point: *const c_char,
 }
@@ -712,6 +825,10 @@ impl TryInto<super::PaymentStrParams<'static>> for casper_payment_params_t {
             self.payment_args_complex,
             "casper_payment_params_t.payment_args_complex",
         )?;
+        let payment_args_json = unsafe_str_arg(
+            self.payment_args_json,
+            "casper_payment_params_t.payment_args_json",
+        )?;
         let payment_version = unsafe_str_arg(
             self.payment_version,
             "casper_payment_params_t.payment_version",
@@ -729,6 +846,7 @@ impl TryInto<super::PaymentStrParams<'static>> for casper_payment_params_t {
             payment_path,
             payment_args_simple,
             payment_args_complex,
+            payment_args_json,
             payment_version,
             payment_entry_point,
         })
@@ -750,6 +868,7 @@ pub struct casper_session_params_t {
     session_args_simple: *const *const c_char,
     session_args_simple_len: usize,
     session_args_complex: *const c_char,
+    session_args_json: *const c_char,
     session_version: *const c_char,
     session_entry_point: *const c_char,
 }
@@ -781,6 +900,10 @@ impl TryInto<super::SessionStrParams<'static>> for casper_session_params_t {
             self.session_args_complex,
             "casper_session_params_t.session_args_complex",
         )?;
+        let session_args_json = unsafe_str_arg(
+            self.session_args_json,
+            "casper_session_params_t.session_args_json",
+        )?;
         let session_version = unsafe_str_arg(
             self.session_version,
             "casper_session_params_t.session_version",
@@ -797,6 +920,7 @@ impl TryInto<super::SessionStrParams<'static>> for casper_session_params_t {
             session_path,
             session_args_simple,
             session_args_complex,
+            session_args_json,
             session_version,
             session_entry_point,
         })
@@ -25,6 +25,7 @@ mod validation;
 use std::{convert::TryInto, fs::File};
 
 use jsonrpc_lite::JsonRpc;
+use num_rational::Ratio;
 use serde::Serialize;
 
 use casper_execution_engine::core::engine_state::ExecutableDeployItem;
@@ -38,7 +39,7 @@ pub use error::Error;
 use error::Result;
 use parsing::none_if_empty;
 use rpc::{RpcCall, TransferTarget};
-pub use validation::ValidateResponseError;
+pub use validation::{TrustedValidators, ValidateResponseError};
 
 /// Creates a `Deploy` and sends it to the network for execution.
 ///
@@ -162,6 +163,34 @@ pub fn send_deploy_file(
     RpcCall::new(maybe_rpc_id, node_address, verbosity_level).send_deploy_file(input_path)
 }
 
+/// Reads a previously-saved `Deploy` from a file and executes it on the node without committing
+/// the resulting effects to global state.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+/// * `input_path` specifies the path to the previously-saved `Deploy` file.
+/// * `maybe_block_id` must be a hex-encoded, 32-byte hash digest or a `u64` representing the
+///   `Block` height or empty. The `Deploy` is executed on top of the global state of that `Block`,
+///   or of the latest `Block` if empty.
+pub fn speculative_exec(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    input_path: &str,
+    maybe_block_id: &str,
+) -> Result<JsonRpc> {
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+        .speculative_exec(input_path, maybe_block_id)
+}
+
 /// Transfers funds between purses.
 ///
 /// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
@@ -242,6 +271,37 @@ pub fn get_deploy(
     RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_deploy(deploy_hash)
 }
 
+/// Retrieves a page of the `Deploy`s sent by an account from the network.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+/// * `public_key` is the hex-encoded public key of the account which sent the `Deploy`s.
+/// * `maybe_offset` is the number of entries to skip, or empty to start from the first entry.
+/// * `maybe_limit` is the maximum number of entries to return, or empty to use the node's maximum
+///   page size.
+pub fn get_account_deploys(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    public_key: &str,
+    maybe_offset: &str,
+    maybe_limit: &str,
+) -> Result<JsonRpc> {
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_account_deploys(
+        public_key,
+        maybe_offset,
+        maybe_limit,
+    )
+}
+
 /// Retrieves a `Block` from the network.
 ///
 /// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
@@ -336,6 +396,10 @@ pub fn get_state_root_hash(
 /// deploy-0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20      # Key::DeployInfo
 /// ```
 /// * `path` is comprised of components starting from the `key`, separated by `/`s.
+/// * `maybe_block_hash` must be a hex-encoded, 32-byte hash digest or empty.  If provided, it must
+///   be the hash of the block whose state root hash is `state_root_hash`, and the response will
+///   include that block's header and finality signatures.  These can be verified against a trusted
+///   set of validators via [`verify_finality_proof`](fn.verify_finality_proof.html).
 pub fn get_item(
     maybe_rpc_id: &str,
     node_address: &str,
@@ -343,8 +407,47 @@ pub fn get_item(
     state_root_hash: &str,
     key: &str,
     path: &str,
+    maybe_block_hash: &str,
+) -> Result<JsonRpc> {
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_item(
+        state_root_hash,
+        key,
+        path,
+        maybe_block_hash,
+    )
+}
+
+/// Retrieves an item stored in a dictionary from the network.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+/// * `state_root_hash` must be a hex-encoded, 32-byte hash digest.
+/// * `seed_uref` is the dictionary's seed URef, formatted as e.g.
+/// ```text
+/// uref-0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20-007
+/// ```
+/// * `dictionary_item_key` is the key of the item within the dictionary.
+pub fn get_dictionary_item(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    state_root_hash: &str,
+    seed_uref: &str,
+    dictionary_item_key: &str,
 ) -> Result<JsonRpc> {
-    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_item(state_root_hash, key, path)
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_dictionary_item(
+        state_root_hash,
+        seed_uref,
+        dictionary_item_key,
+    )
 }
 
 /// Retrieves a purse's balance from the network.
@@ -364,14 +467,60 @@ pub fn get_item(
 /// ```text
 /// uref-0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20-007
 /// ```
+/// * `maybe_block_hash` must be a hex-encoded, 32-byte hash digest or empty.  If provided, it must
+///   be the hash of the block whose state root hash is `state_root_hash`, and the response will
+///   include that block's header and finality signatures.  These can be verified against a trusted
+///   set of validators via [`verify_finality_proof`](fn.verify_finality_proof.html).
 pub fn get_balance(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
     state_root_hash: &str,
     purse: &str,
+    maybe_block_hash: &str,
 ) -> Result<JsonRpc> {
-    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_balance(state_root_hash, purse)
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_balance(
+        state_root_hash,
+        purse,
+        maybe_block_hash,
+    )
+}
+
+/// Verifies a response from [`get_item`](fn.get_item.html) or
+/// [`get_balance`](fn.get_balance.html) made with a block hash, tying the queried value to a
+/// finalized block.
+///
+/// * `key` and `path` must be those passed to `get_item`.  For a `get_balance` response, `key` must
+///   be the `purse` passed to `get_balance` and `path` must be empty.
+/// * `trusted_validators` must be the validators of the block's era.
+/// * `finality_threshold_fraction` is the fraction of the total weight of `trusted_validators`
+///   which the validators who signed the block must exceed.  It should be the network's
+///   `finality_threshold_fraction` given in the `[highway]` section of its chainspec.
+///
+/// The response's merkle proof must prove that its value is the one under `key` and `path` in the
+/// global state of the included block header, and the block must have been signed by validators
+/// holding more than `finality_threshold_fraction` of the total weight of `trusted_validators`.
+///
+/// Starting from a trusted set of validators, the validators of subsequent eras can be established
+/// from verified switch block headers via
+/// [`TrustedValidators::from_switch_block_header`](struct.TrustedValidators.html).
+pub fn verify_finality_proof(
+    response: &JsonRpc,
+    key: &str,
+    path: &str,
+    trusted_validators: &TrustedValidators,
+    finality_threshold_fraction: Ratio<u64>,
+) -> Result<()> {
+    let key = parsing::key(key)?;
+    let path = parsing::path(path);
+    validation::verify_finality_proof(
+        response,
+        &key,
+        &path,
+        trusted_validators,
+        finality_threshold_fraction,
+    )
+    .map_err(Error::from)
 }
 
 /// Retrieves era information from the network.
@@ -548,10 +697,30 @@ impl<'a> TryInto<DeployParams> for DeployStrParams<'a> {
 /// For methods taking `payment_args_complex`, this parameter is the payment contract arguments, in
 /// the form of a `ToBytes`-encoded file.
 ///
+/// ## `payment_args_json`
+///
+/// For methods taking `payment_args_json`, this parameter is the payment contract arguments, as a
+/// JSON array of objects each with a `name`, a `type` and a `value`.  The type is the JSON form of
+/// a `CLType`, and the value is parsed according to the type, using the same representation as the
+/// `parsed` field of a `CLValue` in JSON output.  Unlike `payment_args_simple`, this supports every
+/// `CLType` other than `Any`, e.g.
+///
+/// ```text
+/// [
+///   {"name": "amount", "type": "U512", "value": "1000000"},
+///   {"name": "ids", "type": {"List": "U64"}, "value": [1, 2, 3]},
+///   {"name": "owner", "type": {"Option": "Key"}, "value": null},
+///   {"name": "weights", "type": {"Map": {"key": "String", "value": "U8"}},
+///    "value": [{"key": "a", "value": 1}]},
+///   {"name": "pair", "type": {"Tuple2": ["Bool", "String"]}, "value": [true, "b"]},
+///   {"name": "outcome", "type": {"Result": {"ok": "U32", "err": "String"}}, "value": {"Ok": 7}}
+/// ]
+/// ```
+///
 /// ---
 ///
 /// **Note** while multiple payment args can be specified for a single payment code instance, only
-/// one of `payment_args_simple` and `payment_args_complex` may be used.
+/// one of `payment_args_simple`, `payment_args_complex` and `payment_args_json` may be used.
 #[derive(Default)]
 pub struct PaymentStrParams<'a> {
     payment_amount: &'a str,
@@ -562,6 +731,7 @@ pub struct PaymentStrParams<'a> {
     payment_path: &'a str,
     payment_args_simple: Vec<&'a str>,
     payment_args_complex: &'a str,
+    payment_args_json: &'a str,
     payment_version: &'a str,
     payment_entry_point: &'a str,
 }
@@ -579,6 +749,7 @@ impl<'a> TryInto<ExecutableDeployItem> for PaymentStrParams<'a> {
             payment_path,
             payment_args_simple,
             payment_args_complex,
+            payment_args_json,
             payment_version,
             payment_entry_point,
         } = self;
@@ -592,6 +763,7 @@ impl<'a> TryInto<ExecutableDeployItem> for PaymentStrParams<'a> {
             payment_path,
             &payment_args_simple,
             payment_args_complex,
+            payment_args_json,
             payment_version,
             payment_entry_point,
         )
@@ -725,6 +897,16 @@ impl<'a> PaymentStrParams<'a> {
             ..Default::default()
         }
     }
+
+    /// Returns `self` with the payment contract arguments given as JSON.
+    ///
+    /// Use with any of the constructors, passing empty `payment_args_simple` and
+    /// `payment_args_complex`.  See the struct docs for a description of
+    /// [`payment_args_json`](#payment_args_json).
+    pub fn with_payment_args_json(mut self, payment_args_json: &'a str) -> Self {
+        self.payment_args_json = payment_args_json;
+        self
+    }
 }
 
 impl<'a> TryInto<ExecutableDeployItem> for SessionStrParams<'a> {
@@ -739,6 +921,7 @@ impl<'a> TryInto<ExecutableDeployItem> for SessionStrParams<'a> {
             session_path,
             session_args_simple,
             session_args_complex,
+            session_args_json,
             session_version,
             session_entry_point,
         } = self;
@@ -751,6 +934,7 @@ impl<'a> TryInto<ExecutableDeployItem> for SessionStrParams<'a> {
             session_path,
             &session_args_simple,
             session_args_complex,
+            session_args_json,
             session_version,
             session_entry_point,
         )
@@ -773,10 +957,19 @@ impl<'a> TryInto<ExecutableDeployItem> for SessionStrParams<'a> {
 /// For methods taking `session_args_complex`, this parameter is the session contract arguments, in
 /// the form of a `ToBytes`-encoded file.
 ///
+/// ## `session_args_json`
+///
+/// For methods taking `session_args_json`, this parameter is the session contract arguments, as a
+/// JSON array of objects each with a `name`, a `type` and a `value`.
+///
+/// There are further details in
+/// [the docs for the equivalent
+/// `payment_args_json`](struct.PaymentStrParams.html#payment_args_json).
+///
 /// ---
 ///
 /// **Note** while multiple payment args can be specified for a single session code instance, only
-/// one of `session_args_simple` and `session_args_complex` may be used.
+/// one of `session_args_simple`, `session_args_complex` and `session_args_json` may be used.
 #[derive(Default)]
 pub struct SessionStrParams<'a> {
     session_hash: &'a str,
@@ -786,6 +979,7 @@ pub struct SessionStrParams<'a> {
     session_path: &'a str,
     session_args_simple: Vec<&'a str>,
     session_args_complex: &'a str,
+    session_args_json: &'a str,
     session_version: &'a str,
     session_entry_point: &'a str,
 }
@@ -906,6 +1100,16 @@ impl<'a> SessionStrParams<'a> {
             ..Default::default()
         }
     }
+
+    /// Returns `self` with the session contract arguments given as JSON.
+    ///
+    /// Use with any of the constructors, passing empty `session_args_simple` and
+    /// `session_args_complex`.  See the struct docs for a description of
+    /// [`session_args_json`](#session_args_json).
+    pub fn with_session_args_json(mut self, session_args_json: &'a str) -> Self {
+        self.session_args_json = session_args_json;
+        self
+    }
 }
 
 /// When `verbosity_level` is `1`, the value will be printed to `stdout` with long string fields
@@ -1044,6 +1248,24 @@ mod param_tests {
                 other => panic!("incorrect type parsed {:?}", other),
             }
         }
+
+        #[test]
+        pub fn with_session_args_json() {
+            let args_json = r#"[{"name": "ids", "type": {"List": "U64"}, "value": [1, 2]}]"#;
+            let params: Result<ExecutableDeployItem> =
+                SessionStrParams::with_hash(HASH, ENTRYPOINT, vec![], "")
+                    .with_session_args_json(args_json)
+                    .try_into();
+            match params {
+                Ok(item @ ExecutableDeployItem::StoredContractByHash { .. }) => {
+                    let actual: BTreeMap<String, CLValue> = item.args().clone().into();
+                    let mut expected = BTreeMap::new();
+                    expected.insert("ids".to_owned(), CLValue::from_t(vec![1u64, 2]).unwrap());
+                    assert_eq!(actual, expected);
+                }
+                other => panic!("incorrect type parsed {:?}", other),
+            }
+        }
     }
 
     /// Sample data creation methods for PaymentStrParams
@@ -4,6 +4,7 @@
 use std::{convert::TryInto, fs, io, path::PathBuf, str::FromStr};
 
 use serde::{self, Deserialize};
+use serde_json::Value;
 
 use casper_execution_engine::core::engine_state::executable_deploy_item::ExecutableDeployItem;
 use casper_node::{
@@ -11,8 +12,8 @@ use casper_node::{
     types::{DeployHash, TimeDiff, Timestamp},
 };
 use casper_types::{
-    bytesrepr, AsymmetricType, CLType, CLValue, HashAddr, Key, NamedArg, PublicKey, RuntimeArgs,
-    SecretKey, UIntParseError, U512,
+    bytesrepr, cl_value_from_json, AsymmetricType, CLType, CLValue, HashAddr, Key, NamedArg,
+    PublicKey, RuntimeArgs, SecretKey, UIntParseError, U512,
 };
 
 use crate::{
@@ -209,6 +210,61 @@ mod args_complex {
     }
 }
 
+/// Handles retrieval of session and payment args given as a JSON array of objects, each with a
+/// `name`, a `type` in the JSON form of `CLType` and a `value` in the JSON form of a value of that
+/// type, e.g. `[{"name":"amount","type":"U512","value":"100"}]`.
+mod args_json {
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct JsonArg {
+        name: String,
+        #[serde(rename = "type")]
+        cl_type: CLType,
+        value: Value,
+    }
+
+    pub mod session {
+        use super::*;
+
+        pub fn parse(json_str: &str) -> Result<Option<RuntimeArgs>> {
+            super::parse("session_args_json", json_str)
+        }
+    }
+
+    pub mod payment {
+        use super::*;
+
+        pub fn parse(json_str: &str) -> Result<Option<RuntimeArgs>> {
+            super::parse("payment_args_json", json_str)
+        }
+    }
+
+    fn parse(context: &'static str, json_str: &str) -> Result<Option<RuntimeArgs>> {
+        if json_str.is_empty() {
+            return Ok(None);
+        }
+        let args: Vec<JsonArg> = serde_json::from_str(json_str).map_err(|error| {
+            Error::InvalidArgument(context, format!("failed to parse JSON args: {}", error))
+        })?;
+        let mut runtime_args = RuntimeArgs::new();
+        for arg in args {
+            let cl_value = cl_value_from_json(&arg.cl_type, &arg.value).ok_or_else(|| {
+                Error::InvalidArgument(
+                    context,
+                    format!(
+                        "failed to parse {} as {:?} for arg '{}'",
+                        arg.value, arg.cl_type, arg.name
+                    ),
+                )
+            })?;
+            runtime_args.insert_cl_value(arg.name, cl_value);
+        }
+        Ok(Some(runtime_args))
+    }
+}
+
 const STANDARD_PAYMENT_ARG_NAME: &str = "amount";
 fn standard_payment(value: &str) -> Result<RuntimeArgs> {
     if value.is_empty() {
@@ -229,16 +285,33 @@ pub(crate) fn secret_key(value: &str) -> Result<SecretKey> {
     })
 }
 
-fn args_from_simple_or_complex(
+fn args_from_simple_complex_or_json(
     simple: Option<RuntimeArgs>,
     complex: Option<RuntimeArgs>,
+    json: Option<RuntimeArgs>,
 ) -> RuntimeArgs {
-    // We can have exactly zero or one of the two as `Some`.
-    match (simple, complex) {
-        (Some(args), None) | (None, Some(args)) => args,
-        (None, None) => RuntimeArgs::new(),
-        (Some(_), Some(_)) => unreachable!("should not have both simple and complex args"),
+    // We can have exactly zero or one of the three as `Some`.
+    match (simple, complex, json) {
+        (Some(args), None, None) | (None, Some(args), None) | (None, None, Some(args)) => args,
+        (None, None, None) => RuntimeArgs::new(),
+        _ => unreachable!("should not have more than one of simple, complex and json args"),
+    }
+}
+
+/// Returns an error if more than one of the given styles of args is non-empty.
+fn check_at_most_one_args_style(context: &'static str, styles: &[(&str, bool)]) -> Result<()> {
+    let provided: Vec<String> = styles
+        .iter()
+        .filter(|(_, is_empty)| !*is_empty)
+        .map(|(name, _)| name.to_string())
+        .collect();
+    if provided.len() > 1 {
+        return Err(Error::ConflictingArguments {
+            context,
+            args: provided,
+        });
     }
+    Ok(())
 }
 
 /// Private macro for enforcing parameter validity.
@@ -366,6 +439,7 @@ pub(super) fn parse_session_info(
     session_path: &str,
     session_args: &[&str],
     session_args_complex: &str,
+    session_args_json: &str,
     session_version: &str,
     session_entry_point: &str,
 ) -> Result<ExecutableDeployItem> {
@@ -382,16 +456,19 @@ pub(super) fn parse_session_info(
         (session_path)
             requires[] requires_empty[session_entry_point, session_version],
     );
-    if !session_args.is_empty() && !session_args_complex.is_empty() {
-        return Err(Error::ConflictingArguments {
-            context: "parse_session_info",
-            args: vec!["session_args".to_owned(), "session_args_complex".to_owned()],
-        });
-    }
-
-    let session_args = args_from_simple_or_complex(
+    check_at_most_one_args_style(
+        "parse_session_info",
+        &[
+            ("session_args", session_args.is_empty()),
+            ("session_args_complex", session_args_complex.is_empty()),
+            ("session_args_json", session_args_json.is_empty()),
+        ],
+    )?;
+
+    let session_args = args_from_simple_complex_or_json(
         arg_simple::session::parse(session_args)?,
         args_complex::session::parse(session_args_complex).ok(),
+        args_json::session::parse(session_args_json)?,
     );
     let invalid_entry_point =
         || Error::InvalidArgument("session_entry_point", session_entry_point.to_string());
@@ -450,6 +527,7 @@ pub(super) fn parse_payment_info(
     payment_path: &str,
     payment_args: &[&str],
     payment_args_complex: &str,
+    payment_args_json: &str,
     payment_version: &str,
     payment_entry_point: &str,
 ) -> Result<ExecutableDeployItem> {
@@ -467,12 +545,14 @@ pub(super) fn parse_payment_info(
             requires[payment_entry_point] requires_empty[],
         (payment_path) requires[] requires_empty[payment_entry_point, payment_version],
     );
-    if !payment_args.is_empty() && !payment_args_complex.is_empty() {
-        return Err(Error::ConflictingArguments {
-            context: "parse_payment_info",
-            args: vec!["payment_args".to_owned(), "payment_args_complex".to_owned()],
-        });
-    }
+    check_at_most_one_args_style(
+        "parse_payment_info",
+        &[
+            ("payment_args", payment_args.is_empty()),
+            ("payment_args_complex", payment_args_complex.is_empty()),
+            ("payment_args_json", payment_args_json.is_empty()),
+        ],
+    )?;
 
     if let Ok(payment_args) = standard_payment(payment_amount) {
         return Ok(ExecutableDeployItem::ModuleBytes {
@@ -484,9 +564,10 @@ pub(super) fn parse_payment_info(
     let invalid_entry_point =
         || Error::InvalidArgument("payment_entry_point", payment_entry_point.to_string());
 
-    let payment_args = args_from_simple_or_complex(
+    let payment_args = args_from_simple_complex_or_json(
         arg_simple::payment::parse(payment_args)?,
         args_complex::payment::parse(payment_args_complex).ok(),
+        args_json::payment::parse(payment_args_json)?,
     );
 
     if let Some(payment_name) = name(payment_name) {
@@ -587,6 +668,44 @@ fn account(value: &str) -> Result<PublicKey> {
     })
 }
 
+/// Parses a formatted `Key`, or a hex-encoded `PublicKey` as the key of its account.
+pub(crate) fn key(value: &str) -> Result<Key> {
+    if let Ok(key) = Key::from_formatted_str(value) {
+        Ok(key)
+    } else if let Ok(public_key) = PublicKey::from_hex(value) {
+        Ok(Key::Account(public_key.to_account_hash()))
+    } else {
+        Err(Error::FailedToParseKey)
+    }
+}
+
+/// Parses a path of named keys separated by `/`, which is empty if `value` is empty.
+pub(crate) fn path(value: &str) -> Vec<String> {
+    if value.is_empty() {
+        vec![]
+    } else {
+        value.split('/').map(ToString::to_string).collect()
+    }
+}
+
+pub(crate) fn public_key(value: &str) -> Result<PublicKey> {
+    PublicKey::from_hex(value).map_err(|error| Error::CryptoError {
+        context: "public_key",
+        error: error.into(),
+    })
+}
+
+/// Parses an optional `u64` which is `None` if `value` is empty.
+pub(crate) fn optional_u64(context: &'static str, value: &str) -> Result<Option<u64>> {
+    if str::is_empty(value) {
+        return Ok(None);
+    }
+    let value = value
+        .parse::<u64>()
+        .map_err(|error| Error::FailedToParseInt(context, error))?;
+    Ok(Some(value))
+}
+
 pub(crate) fn transfer_id(value: &str) -> Result<Option<u64>> {
     if str::is_empty(value) {
         return Ok(None);
@@ -599,7 +718,7 @@ pub(crate) fn transfer_id(value: &str) -> Result<Option<u64>> {
 
 #[cfg(test)]
 mod tests {
-    use std::{convert::TryFrom, result::Result as StdResult};
+    use std::{collections::BTreeMap, convert::TryFrom, result::Result as StdResult};
 
     use casper_types::{
         account::AccountHash, bytesrepr::ToBytes, AccessRights, CLTyped, CLValue, NamedArg,
@@ -882,6 +1001,7 @@ mod tests {
                 &["something:u32='0'"],
                 "path_to/file",
                 "",
+                "",
                 "entrypoint",
             )
             .map(|_| ())
@@ -903,6 +1023,7 @@ mod tests {
                 &["something:u32='0'"],
                 "path_to/file",
                 "",
+                "",
                 "entrypoint",
             )
             .map(|_| ())
@@ -915,6 +1036,78 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_parse_args_json() {
+        let args_json = r#"[
+            {"name": "list", "type": {"List": "U64"}, "value": [1, 2]},
+            {"name": "map", "type": {"Map": {"key": "String", "value": "U512"}},
+                "value": [{"key": "a", "value": "1"}]},
+            {"name": "option", "type": {"Option": "Bool"}, "value": null}
+        ]"#;
+        let item = parse_session_info(
+            "",
+            happy::NAME,
+            "",
+            "",
+            "",
+            &[],
+            "",
+            args_json,
+            "",
+            happy::ENTRY_POINT,
+        )
+        .expect("should parse args json");
+
+        let mut map = BTreeMap::new();
+        map.insert(String::from("a"), U512::one());
+        let mut expected = RuntimeArgs::new();
+        expected.insert("list", vec![1u64, 2]).unwrap();
+        expected.insert("map", map).unwrap();
+        expected.insert("option", Option::<bool>::None).unwrap();
+        assert_eq!(item.args(), &expected);
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_args_json() {
+        for args_json in &[
+            "not json",
+            r#"[{"name": "a", "type": "U8", "value": 256}]"#,
+            r#"[{"name": "a", "type": "U8"}]"#,
+            r#"[{"name": "a", "type": "U8", "value": 1, "extra": 2}]"#,
+        ] {
+            assert!(
+                args_json::payment::parse(args_json).is_err(),
+                "{} should be an error",
+                args_json
+            );
+        }
+    }
+
+    #[test]
+    fn should_fail_to_parse_conflicting_args_json() {
+        assert_eq!(
+            parse_session_info(
+                "",
+                "name",
+                "",
+                "",
+                "",
+                &["something:u32='0'"],
+                "",
+                "[]",
+                "",
+                "entrypoint",
+            )
+            .map(|_| ())
+            .map_err(ErrWrapper),
+            Err(Error::ConflictingArguments {
+                context: "parse_session_info",
+                args: vec!["session_args".to_owned(), "session_args_json".to_owned()]
+            }
+            .into())
+        );
+    }
+
     #[test]
     fn should_fail_to_parse_conflicting_session_parameters() {
         assert_eq!(
@@ -928,6 +1121,7 @@ mod tests {
                 "",
                 "",
                 "",
+                "",
             )
             .map(|_| ())
             .map_err(ErrWrapper),
@@ -948,7 +1142,7 @@ mod tests {
     #[test]
     fn should_fail_to_parse_conflicting_payment_parameters() {
         assert_eq!(
-            parse_payment_info("12345", happy::HASH, happy::NAME, happy::PACKAGE_HASH, happy::PACKAGE_NAME, happy::PATH, &[], "", "", "",)
+            parse_payment_info("12345", happy::HASH, happy::NAME, happy::PACKAGE_HASH, happy::PACKAGE_NAME, happy::PATH, &[], "", "", "", "",)
                 .map(|_| ())
                 .map_err(ErrWrapper),
             Err(Error::ConflictingArguments {
@@ -11,24 +11,28 @@ use casper_execution_engine::core::engine_state::ExecutableDeployItem;
 use casper_node::{
     crypto::hash::Digest,
     rpcs::{
-        account::{PutDeploy, PutDeployParams},
+        account::{GetAccountDeploys, GetAccountDeploysParams, PutDeploy, PutDeployParams},
         chain::{
             BlockIdentifier, GetBlock, GetBlockParams, GetBlockTransfers, GetBlockTransfersParams,
             GetEraInfoBySwitchBlock, GetEraInfoParams, GetStateRootHash, GetStateRootHashParams,
         },
         docs::ListRpcs,
         info::{GetDeploy, GetDeployParams},
-        state::{GetAuctionInfo, GetBalance, GetBalanceParams, GetItem, GetItemParams},
+        speculative_exec::{SpeculativeExec, SpeculativeExecParams},
+        state::{
+            GetAuctionInfo, GetBalance, GetBalanceParams, GetDictionaryItem,
+            GetDictionaryItemParams, GetItem, GetItemParams,
+        },
         RpcWithOptionalParams, RpcWithParams, RpcWithoutParams, RPC_API_PATH,
     },
     types::{BlockHash, Deploy, DeployHash},
 };
-use casper_types::{AsymmetricType, Key, PublicKey, RuntimeArgs, URef, U512};
+use casper_types::{Key, PublicKey, RuntimeArgs, URef, U512};
 
 use crate::{
     deploy::{DeployExt, DeployParams, SendDeploy, Transfer},
     error::{Error, Result},
-    validation,
+    parsing, validation,
 };
 
 /// Target for a given transfer.
@@ -87,36 +91,48 @@ impl RpcCall {
         GetDeploy::request_with_map_params(self, params)
     }
 
-    pub(crate) fn get_item(self, state_root_hash: &str, key: &str, path: &str) -> Result<JsonRpc> {
+    pub(crate) fn get_account_deploys(
+        self,
+        public_key: &str,
+        maybe_offset: &str,
+        maybe_limit: &str,
+    ) -> Result<JsonRpc> {
+        let params = GetAccountDeploysParams {
+            public_key: parsing::public_key(public_key)?,
+            offset: parsing::optional_u64("offset", maybe_offset)?,
+            limit: parsing::optional_u64("limit", maybe_limit)?,
+        };
+        GetAccountDeploys::request_with_map_params(self, params)
+    }
+
+    pub(crate) fn get_item(
+        self,
+        state_root_hash: &str,
+        key: &str,
+        path: &str,
+        maybe_block_hash: &str,
+    ) -> Result<JsonRpc> {
         let state_root_hash =
             Digest::from_hex(state_root_hash).map_err(|error| Error::CryptoError {
                 context: "state_root_hash",
                 error,
             })?;
+        let maybe_block_hash = Self::optional_block_hash(maybe_block_hash)?;
 
-        let key = {
-            if let Ok(key) = Key::from_formatted_str(key) {
-                key
-            } else if let Ok(public_key) = PublicKey::from_hex(key) {
-                Key::Account(public_key.to_account_hash())
-            } else {
-                return Err(Error::FailedToParseKey);
-            }
-        };
-
-        let path = if path.is_empty() {
-            vec![]
-        } else {
-            path.split('/').map(ToString::to_string).collect()
-        };
+        let key = parsing::key(key)?;
+        let path = parsing::path(path);
 
         let params = GetItemParams {
             state_root_hash,
             key: key.to_formatted_string(),
             path: path.clone(),
+            block_hash: maybe_block_hash,
         };
         let response = GetItem::request_with_map_params(self, params)?;
         validation::validate_query_response(&response, &state_root_hash, &key, &path)?;
+        if let Some(block_hash) = maybe_block_hash {
+            validation::validate_signed_block_header(&response, &state_root_hash, &block_hash)?;
+        }
         Ok(response)
     }
 
@@ -130,12 +146,18 @@ impl RpcCall {
         }
     }
 
-    pub(crate) fn get_balance(self, state_root_hash: &str, purse_uref: &str) -> Result<JsonRpc> {
+    pub(crate) fn get_balance(
+        self,
+        state_root_hash: &str,
+        purse_uref: &str,
+        maybe_block_hash: &str,
+    ) -> Result<JsonRpc> {
         let state_root_hash =
             Digest::from_hex(state_root_hash).map_err(|error| Error::CryptoError {
                 context: "state_root_hash",
                 error,
             })?;
+        let maybe_block_hash = Self::optional_block_hash(maybe_block_hash)?;
         let uref = URef::from_formatted_str(purse_uref)
             .map_err(|error| Error::FailedToParseURef("purse_uref", error))?;
         let key = Key::from(uref);
@@ -143,9 +165,38 @@ impl RpcCall {
         let params = GetBalanceParams {
             state_root_hash,
             purse_uref: purse_uref.to_string(),
+            block_hash: maybe_block_hash,
         };
         let response = GetBalance::request_with_map_params(self, params)?;
         validation::validate_get_balance_response(&response, &state_root_hash, &key)?;
+        if let Some(block_hash) = maybe_block_hash {
+            validation::validate_signed_block_header(&response, &state_root_hash, &block_hash)?;
+        }
+        Ok(response)
+    }
+
+    pub(crate) fn get_dictionary_item(
+        self,
+        state_root_hash: &str,
+        seed_uref: &str,
+        dictionary_item_key: &str,
+    ) -> Result<JsonRpc> {
+        let state_root_hash =
+            Digest::from_hex(state_root_hash).map_err(|error| Error::CryptoError {
+                context: "state_root_hash",
+                error,
+            })?;
+        let uref = URef::from_formatted_str(seed_uref)
+            .map_err(|error| Error::FailedToParseURef("seed_uref", error))?;
+        let key = Key::dictionary(uref, dictionary_item_key.as_bytes());
+
+        let params = GetDictionaryItemParams {
+            state_root_hash,
+            dictionary_seed_uref: seed_uref.to_string(),
+            dictionary_item_key: dictionary_item_key.to_string(),
+        };
+        let response = GetDictionaryItem::request_with_map_params(self, params)?;
+        validation::validate_query_response(&response, &state_root_hash, &key, &[])?;
         Ok(response)
     }
 
@@ -216,6 +267,23 @@ impl RpcCall {
         SendDeploy::request_with_map_params(self, params)
     }
 
+    pub(crate) fn speculative_exec(
+        self,
+        input_path: &str,
+        maybe_block_identifier: &str,
+    ) -> Result<JsonRpc> {
+        let input = File::open(input_path).map_err(|error| Error::IoError {
+            context: format!("unable to read input file '{}'", input_path),
+            error,
+        })?;
+        let deploy = Deploy::read_deploy(input)?;
+        let params = SpeculativeExecParams {
+            deploy,
+            block_identifier: Self::block_identifier(maybe_block_identifier)?,
+        };
+        SpeculativeExec::request_with_map_params(self, params)
+    }
+
     pub(crate) fn put_deploy(self, deploy: Deploy) -> Result<JsonRpc> {
         let params = PutDeployParams { deploy };
         PutDeploy::request_with_map_params(self, params)
@@ -266,6 +334,18 @@ impl RpcCall {
         }
     }
 
+    fn optional_block_hash(maybe_block_hash: &str) -> Result<Option<BlockHash>> {
+        if maybe_block_hash.is_empty() {
+            return Ok(None);
+        }
+
+        let hash = Digest::from_hex(maybe_block_hash).map_err(|error| Error::CryptoError {
+            context: "block_hash",
+            error,
+        })?;
+        Ok(Some(BlockHash::new(hash)))
+    }
+
     async fn request(self, method: &str, params: Params) -> Result<JsonRpc> {
         let url = format!("{}/{}", self.node_address, RPC_API_PATH);
         let rpc_req = JsonRpc::request_with_params(self.rpc_id, method, params);
@@ -338,6 +418,10 @@ pub(crate) trait RpcClient {
     }
 }
 
+impl RpcClient for GetAccountDeploys {
+    const RPC_METHOD: &'static str = Self::METHOD;
+}
+
 impl RpcClient for GetBalance {
     const RPC_METHOD: &'static str = Self::METHOD;
 }
@@ -354,6 +438,10 @@ impl RpcClient for GetStateRootHash {
     const RPC_METHOD: &'static str = Self::METHOD;
 }
 
+impl RpcClient for GetDictionaryItem {
+    const RPC_METHOD: &'static str = Self::METHOD;
+}
+
 impl RpcClient for GetItem {
     const RPC_METHOD: &'static str = <Self as RpcWithParams>::METHOD;
 }
@@ -366,6 +454,10 @@ impl RpcClient for GetAuctionInfo {
     const RPC_METHOD: &'static str = Self::METHOD;
 }
 
+impl RpcClient for SpeculativeExec {
+    const RPC_METHOD: &'static str = Self::METHOD;
+}
+
 impl RpcClient for ListRpcs {
     const RPC_METHOD: &'static str = Self::METHOD;
 }
@@ -387,7 +479,10 @@ impl IntoJsonMap for GetBlockParams {}
 impl IntoJsonMap for GetBlockTransfersParams {}
 impl IntoJsonMap for GetStateRootHashParams {}
 impl IntoJsonMap for GetDeployParams {}
+impl IntoJsonMap for GetAccountDeploysParams {}
 impl IntoJsonMap for GetBalanceParams {}
 impl IntoJsonMap for GetItemParams {}
+impl IntoJsonMap for GetDictionaryItemParams {}
 impl IntoJsonMap for GetEraInfoParams {}
+impl IntoJsonMap for SpeculativeExecParams {}
 impl IntoJsonMap for ListRpcs {}
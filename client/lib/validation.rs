@@ -1,6 +1,10 @@
-use std::convert::TryFrom;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    convert::TryFrom,
+};
 
 use jsonrpc_lite::JsonRpc;
+use num_rational::Ratio;
 use thiserror::Error;
 
 use casper_execution_engine::{
@@ -8,15 +12,19 @@ use casper_execution_engine::{
     storage::trie::merkle_proof::TrieMerkleProof,
 };
 use casper_node::{
-    crypto::hash::Digest,
+    crypto::{self, hash::Digest},
     rpcs::chain::{BlockIdentifier, EraSummary, GetEraInfoResult},
-    types::{json_compatibility, Block, BlockValidationError, JsonBlock},
+    types::{
+        json_compatibility, Block, BlockHash, BlockHeader, BlockValidationError, JsonBlock,
+        JsonSignedBlockHeader,
+    },
 };
-use casper_types::{bytesrepr, Key, U512};
+use casper_types::{bytesrepr, Key, PublicKey, U512};
 
 const GET_ITEM_RESULT_BALANCE_VALUE: &str = "balance_value";
 const GET_ITEM_RESULT_STORED_VALUE: &str = "stored_value";
 const GET_ITEM_RESULT_MERKLE_PROOF: &str = "merkle_proof";
+const GET_ITEM_RESULT_SIGNED_BLOCK_HEADER: &str = "signed_block_header";
 
 /// Error that can be returned when validating a block returned from a JSON-RPC method.
 #[derive(Error, Debug)]
@@ -56,6 +64,26 @@ pub enum ValidateResponseError {
     /// Block height was not as requested.
     #[error("block height was not as requested")]
     UnexpectedBlockHeight,
+
+    /// No signed block header in response.
+    #[error("no signed block header in response")]
+    NoSignedBlockHeaderInResponse,
+
+    /// State root hash of the block header does not correspond to the request or the proof.
+    #[error("state root hash of block header does not correspond to request or proof")]
+    UnexpectedStateRootHash,
+
+    /// Block was not created in the era of the trusted validators.
+    #[error("block was not created in the era of the trusted validators")]
+    UnexpectedEraId,
+
+    /// A finality signature failed to verify.
+    #[error("invalid finality signature: {0}")]
+    InvalidFinalitySignature(crypto::Error),
+
+    /// Finality signatures from trusted validators do not reach the finality threshold.
+    #[error("finality signatures from trusted validators do not reach the finality threshold")]
+    InsufficientFinalitySignatures,
 }
 
 impl From<bytesrepr::Error> for ValidateResponseError {
@@ -70,6 +98,32 @@ impl From<BlockValidationError> for ValidateResponseError {
     }
 }
 
+/// The validators of an era, trusted by a light client to attest to the blocks of that era.
+#[derive(Clone, Debug)]
+pub struct TrustedValidators {
+    era_id: u64,
+    weights: BTreeMap<PublicKey, U512>,
+}
+
+impl TrustedValidators {
+    /// Creates a new set of trusted validators for the given era.
+    pub fn new(era_id: u64, weights: BTreeMap<PublicKey, U512>) -> Self {
+        TrustedValidators { era_id, weights }
+    }
+
+    /// Returns the validators of the era following the given switch block, or `None` if the header
+    /// is not that of a switch block.
+    ///
+    /// The header must itself have been verified, e.g. via `verify_finality_proof`.
+    pub fn from_switch_block_header(header: &BlockHeader) -> Option<Self> {
+        let weights = header.next_era_validator_weights()?.clone();
+        Some(TrustedValidators::new(
+            u64::from(header.era_id()) + 1,
+            weights,
+        ))
+    }
+}
+
 pub(crate) fn validate_get_era_info_response(
     response: &JsonRpc,
 ) -> Result<(), ValidateResponseError> {
@@ -219,6 +273,91 @@ pub(crate) fn validate_get_balance_response(
     .map_err(Into::into)
 }
 
+pub(crate) fn validate_signed_block_header(
+    response: &JsonRpc,
+    state_root_hash: &Digest,
+    block_hash: &BlockHash,
+) -> Result<(), ValidateResponseError> {
+    let signed_block_header = get_signed_block_header(response)?;
+    let header = signed_block_header.header();
+    if header.hash() != *block_hash {
+        return Err(ValidateResponseError::UnexpectedBlockHash);
+    }
+    if header.state_root_hash() != state_root_hash {
+        return Err(ValidateResponseError::UnexpectedStateRootHash);
+    }
+    for finality_signature in signed_block_header.finality_signatures() {
+        finality_signature
+            .verify()
+            .map_err(ValidateResponseError::InvalidFinalitySignature)?;
+    }
+    Ok(())
+}
+
+/// Verifies that a "state_get_item" or "state_get_balance" response holds the value under `key`
+/// and `path` in the global state of the included block header, and that the block has been
+/// finalized according to the given trusted validators, i.e. signed by validators holding more than
+/// `finality_threshold_fraction` of their total weight.
+pub(crate) fn verify_finality_proof(
+    response: &JsonRpc,
+    key: &Key,
+    path: &[String],
+    trusted_validators: &TrustedValidators,
+    finality_threshold_fraction: Ratio<u64>,
+) -> Result<(), ValidateResponseError> {
+    let value = response
+        .get_result()
+        .ok_or(ValidateResponseError::ValidateResponseFailedToParse)?;
+
+    let signed_block_header = get_signed_block_header(response)?;
+    let header = signed_block_header.header();
+
+    // The returned value must be proven to be that of the requested key in the block's state.
+    if value.get(GET_ITEM_RESULT_BALANCE_VALUE).is_some() {
+        validate_get_balance_response(response, header.state_root_hash(), key)?;
+    } else {
+        validate_query_response(response, header.state_root_hash(), key, path)?;
+    }
+
+    // The block must have been signed by enough of the trusted validators.
+    if u64::from(header.era_id()) != trusted_validators.era_id {
+        return Err(ValidateResponseError::UnexpectedEraId);
+    }
+    let mut signers = BTreeSet::new();
+    for finality_signature in signed_block_header.finality_signatures() {
+        finality_signature
+            .verify()
+            .map_err(ValidateResponseError::InvalidFinalitySignature)?;
+        signers.insert(finality_signature.public_key);
+    }
+    let signed_weight = signers
+        .iter()
+        .filter_map(|public_key| trusted_validators.weights.get(public_key))
+        .fold(U512::zero(), |sum, weight| sum + *weight);
+    let total_weight = trusted_validators
+        .weights
+        .values()
+        .fold(U512::zero(), |sum, weight| sum + *weight);
+    if signed_weight * *finality_threshold_fraction.denom()
+        <= total_weight * *finality_threshold_fraction.numer()
+    {
+        return Err(ValidateResponseError::InsufficientFinalitySignatures);
+    }
+    Ok(())
+}
+
+fn get_signed_block_header(
+    response: &JsonRpc,
+) -> Result<JsonSignedBlockHeader, ValidateResponseError> {
+    let value = response
+        .get_result()
+        .and_then(|value| value.get(GET_ITEM_RESULT_SIGNED_BLOCK_HEADER))
+        .ok_or(ValidateResponseError::NoSignedBlockHeaderInResponse)?;
+    let maybe_signed_block_header: Option<JsonSignedBlockHeader> =
+        serde_json::from_value(value.to_owned())?;
+    maybe_signed_block_header.ok_or(ValidateResponseError::NoSignedBlockHeaderInResponse)
+}
+
 pub(crate) fn validate_get_block_response(
     response: &JsonRpc,
     maybe_block_identifier: &Option<BlockIdentifier>,
@@ -254,3 +393,112 @@ pub(crate) fn validate_get_block_response(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use serde_json::json;
+
+    use casper_node::{rpcs::docs::DocExample, types::FinalitySignature};
+    use casper_types::{CLValue, SecretKey};
+
+    use super::*;
+
+    const KEY: Key = Key::Hash([1; 32]);
+    const PATH: &[String] = &[];
+
+    fn stored_value(value: u64) -> StoredValue {
+        StoredValue::CLValue(CLValue::from_t(value).unwrap())
+    }
+
+    /// Returns a "state_get_item" response for `KEY` whose merkle proof proves `proven_value`, but
+    /// which returns `returned_value`, along with the trusted validators of the block's era.  Of
+    /// the two equally weighted validators, only one signed the block.
+    fn get_item_response(
+        proven_value: StoredValue,
+        returned_value: StoredValue,
+    ) -> (JsonRpc, TrustedValidators) {
+        let proof = TrieMerkleProof::new(KEY.normalize(), proven_value, VecDeque::new());
+        let state_root_hash = Digest::from(proof.compute_state_hash().unwrap());
+
+        let mut signed_block_header =
+            serde_json::to_value(JsonSignedBlockHeader::doc_example()).unwrap();
+        signed_block_header["header"]["state_root_hash"] = json!(state_root_hash);
+        signed_block_header["proofs"] = json!([]);
+        let header = serde_json::from_value::<JsonSignedBlockHeader>(signed_block_header.clone())
+            .unwrap()
+            .header();
+
+        let signer_secret_key = SecretKey::ed25519([2; SecretKey::ED25519_LENGTH]);
+        let signer = PublicKey::from(&signer_secret_key);
+        let non_signer = PublicKey::from(&SecretKey::ed25519([3; SecretKey::ED25519_LENGTH]));
+        let finality_signature =
+            FinalitySignature::new(header.hash(), header.era_id(), &signer_secret_key, signer);
+        signed_block_header["proofs"] = json!([{
+            "public_key": signer,
+            "signature": finality_signature.signature,
+        }]);
+
+        let response = serde_json::from_value(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "api_version": "1.0.0",
+                "stored_value": json_compatibility::StoredValue::try_from(&returned_value).unwrap(),
+                "merkle_proof": hex::encode(bytesrepr::serialize(vec![proof]).unwrap()),
+                "signed_block_header": signed_block_header,
+            }
+        }))
+        .unwrap();
+
+        let mut weights = BTreeMap::new();
+        weights.insert(signer, U512::from(100));
+        weights.insert(non_signer, U512::from(100));
+        let trusted_validators = TrustedValidators::new(u64::from(header.era_id()), weights);
+
+        (response, trusted_validators)
+    }
+
+    #[test]
+    fn should_verify_finality_proof() {
+        let (response, trusted_validators) = get_item_response(stored_value(1), stored_value(1));
+        verify_finality_proof(&response, &KEY, PATH, &trusted_validators, Ratio::new(1, 3))
+            .unwrap();
+    }
+
+    #[test]
+    fn should_reject_finality_proof_of_other_value() {
+        let (response, trusted_validators) = get_item_response(stored_value(1), stored_value(2));
+        assert!(matches!(
+            verify_finality_proof(&response, &KEY, PATH, &trusted_validators, Ratio::new(1, 3)),
+            Err(ValidateResponseError::SerializedValueNotContainedInProof)
+        ));
+    }
+
+    #[test]
+    fn should_reject_finality_proof_of_other_key() {
+        let (response, trusted_validators) = get_item_response(stored_value(1), stored_value(1));
+        assert!(matches!(
+            verify_finality_proof(
+                &response,
+                &Key::Hash([4; 32]),
+                PATH,
+                &trusted_validators,
+                Ratio::new(1, 3)
+            ),
+            Err(ValidateResponseError::ValidationError(
+                ValidationError::UnexpectedKey
+            ))
+        ));
+    }
+
+    #[test]
+    fn should_reject_finality_proof_below_finality_threshold() {
+        let (response, trusted_validators) = get_item_response(stored_value(1), stored_value(1));
+        assert!(matches!(
+            verify_finality_proof(&response, &KEY, PATH, &trusted_validators, Ratio::new(1, 2)),
+            Err(ValidateResponseError::InsufficientFinalitySignatures)
+        ));
+    }
+}
@@ -5,6 +5,7 @@ mod make;
 mod put;
 mod send;
 mod sign;
+mod speculative_exec;
 mod transfer;
 
 pub use transfer::Transfer;
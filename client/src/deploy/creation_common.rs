@@ -18,6 +18,7 @@ pub(super) enum DisplayOrder {
     SecretKey,
     Input,
     Output,
+    BlockIdentifier,
     TransferAmount,
     TransferTargetAccount,
     TransferId,
@@ -29,6 +30,7 @@ pub(super) enum DisplayOrder {
     SessionCode,
     SessionArgSimple,
     SessionArgsComplex,
+    SessionArgsJson,
     SessionHash,
     SessionName,
     SessionPackageHash,
@@ -39,6 +41,7 @@ pub(super) enum DisplayOrder {
     PaymentCode,
     PaymentArgSimple,
     PaymentArgsComplex,
+    PaymentArgsJson,
     PaymentHash,
     PaymentName,
     PaymentPackageHash,
@@ -81,12 +84,14 @@ pub(super) mod show_arg_examples {
 pub(super) fn session_str_params<'a>(matches: &'a ArgMatches) -> SessionStrParams<'a> {
     let session_args_simple = arg_simple::session::get(matches);
     let session_args_complex = args_complex::session::get(matches);
+    let session_args_json = args_json::session::get(matches);
     if let Some(session_path) = session_path::get(matches) {
         return SessionStrParams::with_path(
             session_path,
             session_args_simple,
             session_args_complex,
-        );
+        )
+        .with_session_args_json(session_args_json);
     }
     let session_entry_point = session_entry_point::get(matches);
     if let Some(session_hash) = session_hash::get(matches) {
@@ -95,7 +100,8 @@ pub(super) fn session_str_params<'a>(matches: &'a ArgMatches) -> SessionStrParam
             session_entry_point,
             session_args_simple,
             session_args_complex,
-        );
+        )
+        .with_session_args_json(session_args_json);
     }
     if let Some(session_name) = session_name::get(matches) {
         return SessionStrParams::with_name(
@@ -103,7 +109,8 @@ pub(super) fn session_str_params<'a>(matches: &'a ArgMatches) -> SessionStrParam
             session_entry_point,
             session_args_simple,
             session_args_complex,
-        );
+        )
+        .with_session_args_json(session_args_json);
     }
     let session_version = session_version::get(matches);
     if let Some(session_package_hash) = session_package_hash::get(matches) {
@@ -113,7 +120,8 @@ pub(super) fn session_str_params<'a>(matches: &'a ArgMatches) -> SessionStrParam
             session_entry_point,
             session_args_simple,
             session_args_complex,
-        );
+        )
+        .with_session_args_json(session_args_json);
     }
     if let Some(session_package_name) = session_package_name::get(matches) {
         return SessionStrParams::with_package_name(
@@ -122,7 +130,8 @@ pub(super) fn session_str_params<'a>(matches: &'a ArgMatches) -> SessionStrParam
             session_entry_point,
             session_args_simple,
             session_args_complex,
-        );
+        )
+        .with_session_args_json(session_args_json);
     }
     unreachable!("clap arg groups and parsing should prevent this")
 }
@@ -133,12 +142,14 @@ pub(super) fn payment_str_params<'a>(matches: &'a ArgMatches) -> PaymentStrParam
     }
     let payment_args_simple = arg_simple::payment::get(matches);
     let payment_args_complex = args_complex::payment::get(matches);
+    let payment_args_json = args_json::payment::get(matches);
     if let Some(payment_path) = payment_path::get(matches) {
         return PaymentStrParams::with_path(
             payment_path,
             payment_args_simple,
             payment_args_complex,
-        );
+        )
+        .with_payment_args_json(payment_args_json);
     }
     let payment_entry_point = payment_entry_point::get(matches);
     if let Some(payment_hash) = payment_hash::get(matches) {
@@ -147,7 +158,8 @@ pub(super) fn payment_str_params<'a>(matches: &'a ArgMatches) -> PaymentStrParam
             payment_entry_point,
             payment_args_simple,
             payment_args_complex,
-        );
+        )
+        .with_payment_args_json(payment_args_json);
     }
     if let Some(payment_name) = payment_name::get(matches) {
         return PaymentStrParams::with_name(
@@ -155,7 +167,8 @@ pub(super) fn payment_str_params<'a>(matches: &'a ArgMatches) -> PaymentStrParam
             payment_entry_point,
             payment_args_simple,
             payment_args_complex,
-        );
+        )
+        .with_payment_args_json(payment_args_json);
     }
     let payment_version = payment_version::get(matches);
     if let Some(payment_package_hash) = payment_package_hash::get(matches) {
@@ -165,7 +178,8 @@ pub(super) fn payment_str_params<'a>(matches: &'a ArgMatches) -> PaymentStrParam
             payment_entry_point,
             payment_args_simple,
             payment_args_complex,
-        );
+        )
+        .with_payment_args_json(payment_args_json);
     }
     if let Some(payment_package_name) = payment_package_name::get(matches) {
         return PaymentStrParams::with_package_name(
@@ -174,7 +188,8 @@ pub(super) fn payment_str_params<'a>(matches: &'a ArgMatches) -> PaymentStrParam
             payment_entry_point,
             payment_args_simple,
             payment_args_complex,
-        );
+        )
+        .with_payment_args_json(payment_args_json);
     }
     unreachable!("clap arg groups and parsing should prevent this")
 }
@@ -458,6 +473,58 @@ pub(super) mod args_complex {
     }
 }
 
+/// Handles providing the arg for and retrieval of session and payment args given as JSON.
+pub(super) mod args_json {
+    use super::*;
+
+    const ARG_VALUE_NAME: &str = "JSON";
+    const ARG_HELP: &str =
+        "JSON-encoded named and typed args for passing to the Wasm code, as an array of objects \
+        each with a 'name', a 'type' and a 'value', e.g. \
+        '[{\"name\":\"ids\",\"type\":{\"List\":\"U64\"},\"value\":[1,2]}]'. Unlike \
+        --session-arg/--payment-arg, this supports all CLTypes including lists, maps, tuples, \
+        options and results";
+
+    pub(in crate::deploy) mod session {
+        use super::*;
+
+        pub const ARG_NAME: &str = "session-args-json";
+
+        pub fn arg() -> Arg<'static, 'static> {
+            super::arg(ARG_NAME, DisplayOrder::SessionArgsJson as usize)
+                .requires(super::session::ARG_NAME)
+        }
+
+        pub fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+            matches.value_of(ARG_NAME).unwrap_or_default()
+        }
+    }
+
+    pub(in crate::deploy) mod payment {
+        use super::*;
+
+        pub const ARG_NAME: &str = "payment-args-json";
+
+        pub fn arg() -> Arg<'static, 'static> {
+            super::arg(ARG_NAME, DisplayOrder::PaymentArgsJson as usize)
+                .requires(super::payment::ARG_NAME)
+        }
+
+        pub fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+            matches.value_of(ARG_NAME).unwrap_or_default()
+        }
+    }
+
+    fn arg(name: &'static str, order: usize) -> Arg<'static, 'static> {
+        Arg::with_name(name)
+            .long(name)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(order)
+    }
+}
+
 /// Handles providing the arg for and retrieval of the payment code bytes.
 pub(super) mod payment_path {
     use super::*;
@@ -544,11 +611,13 @@ pub(super) fn apply_common_session_options<'a, 'b>(subcommand: App<'a, 'b>) -> A
         .arg(session_name::arg())
         .arg(arg_simple::session::arg())
         .arg(args_complex::session::arg())
+        .arg(args_json::session::arg())
         // Group the session-arg args so only one style is used to ensure consistent ordering.
         .group(
             ArgGroup::with_name("session-args")
                 .arg(arg_simple::session::ARG_NAME)
                 .arg(args_complex::session::ARG_NAME)
+                .arg(args_json::session::ARG_NAME)
                 .required(false),
         )
         .arg(session_entry_point::arg())
@@ -577,11 +646,13 @@ pub(crate) fn apply_common_payment_options(
         .arg(payment_name::arg())
         .arg(arg_simple::payment::arg())
         .arg(args_complex::payment::arg())
+        .arg(args_json::payment::arg())
         // Group the payment-arg args so only one style is used to ensure consistent ordering.
         .group(
             ArgGroup::with_name("payment-args")
                 .arg(arg_simple::payment::ARG_NAME)
                 .arg(args_complex::payment::ARG_NAME)
+                .arg(args_json::payment::ARG_NAME)
                 .required(false),
         )
         .arg(payment_entry_point::arg())
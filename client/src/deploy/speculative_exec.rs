@@ -0,0 +1,46 @@
+use clap::{App, ArgMatches, SubCommand};
+
+use casper_client::Error;
+use casper_node::rpcs::speculative_exec::SpeculativeExec;
+
+use super::creation_common::{self, DisplayOrder};
+use crate::{command::ClientCommand, common, Success};
+
+impl<'a, 'b> ClientCommand<'a, 'b> for SpeculativeExec {
+    const NAME: &'static str = "speculative-exec";
+    const ABOUT: &'static str =
+        "Reads a previously-saved deploy from a file and executes it on the node without \
+        committing its effects";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(common::node_address::arg(
+                DisplayOrder::NodeAddress as usize,
+            ))
+            .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(creation_common::input::arg())
+            .arg(common::block_identifier::arg(
+                DisplayOrder::BlockIdentifier as usize,
+            ))
+    }
+
+    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
+        let maybe_rpc_id = common::rpc_id::get(matches);
+        let node_address = common::node_address::get(matches);
+        let verbosity_level = common::verbose::get(matches);
+        let input_path = creation_common::input::get(matches);
+        let maybe_block_id = common::block_identifier::get(matches);
+
+        casper_client::speculative_exec(
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+            input_path,
+            maybe_block_id,
+        )
+        .map(Success::from)
+    }
+}
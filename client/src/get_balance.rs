@@ -14,6 +14,7 @@ enum DisplayOrder {
     RpcId,
     StateRootHash,
     PurseURef,
+    BlockHash,
 }
 
 /// Handles providing the arg for and retrieval of the purse URef.
@@ -61,6 +62,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetBalance {
                 DisplayOrder::StateRootHash as usize,
             ))
             .arg(purse_uref::arg())
+            .arg(common::block_hash::arg(DisplayOrder::BlockHash as usize))
     }
 
     fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
@@ -69,6 +71,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetBalance {
         let verbosity_level = common::verbose::get(matches);
         let state_root_hash = common::state_root_hash::get(&matches);
         let purse_uref = purse_uref::get(&matches);
+        let maybe_block_hash = common::block_hash::get(&matches);
 
         casper_client::get_balance(
             maybe_rpc_id,
@@ -76,6 +79,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetBalance {
             verbosity_level,
             state_root_hash,
             purse_uref,
+            maybe_block_hash,
         )
         .map(Success::from)
     }
@@ -0,0 +1,112 @@
+use std::str;
+
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use casper_client::Error;
+use casper_node::rpcs::state::GetDictionaryItem;
+
+use crate::{command::ClientCommand, common, Success};
+
+/// This struct defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Verbose,
+    NodeAddress,
+    RpcId,
+    StateRootHash,
+    SeedURef,
+    DictionaryItemKey,
+}
+
+/// Handles providing the arg for and retrieval of the dictionary's seed URef.
+mod seed_uref {
+    use super::*;
+
+    const ARG_NAME: &str = "seed-uref";
+    const ARG_SHORT: &str = "u";
+    const ARG_VALUE_NAME: &str = "FORMATTED STRING";
+    const ARG_HELP: &str =
+        "The seed URef of the dictionary. This must be a properly formatted URef \
+        \"uref-<HEX STRING>-<THREE DIGIT INTEGER>\"";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(true)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::SeedURef as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches
+            .value_of(ARG_NAME)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+    }
+}
+
+/// Handles providing the arg for and retrieval of the dictionary item key.
+mod dictionary_item_key {
+    use super::*;
+
+    const ARG_NAME: &str = "dictionary-item-key";
+    const ARG_SHORT: &str = "d";
+    const ARG_VALUE_NAME: &str = "STRING";
+    const ARG_HELP: &str = "The key of the item within the dictionary";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(true)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::DictionaryItemKey as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches
+            .value_of(ARG_NAME)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+    }
+}
+
+impl<'a, 'b> ClientCommand<'a, 'b> for GetDictionaryItem {
+    const NAME: &'static str = "get-dictionary-item";
+    const ABOUT: &'static str = "Retrieves an item stored in a dictionary from the network";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(common::node_address::arg(
+                DisplayOrder::NodeAddress as usize,
+            ))
+            .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(common::state_root_hash::arg(
+                DisplayOrder::StateRootHash as usize,
+            ))
+            .arg(seed_uref::arg())
+            .arg(dictionary_item_key::arg())
+    }
+
+    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
+        let maybe_rpc_id = common::rpc_id::get(matches);
+        let node_address = common::node_address::get(matches);
+        let verbosity_level = common::verbose::get(matches);
+        let state_root_hash = common::state_root_hash::get(&matches);
+        let seed_uref = seed_uref::get(&matches);
+        let dictionary_item_key = dictionary_item_key::get(&matches);
+
+        casper_client::get_dictionary_item(
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+            state_root_hash,
+            seed_uref,
+            dictionary_item_key,
+        )
+        .map(Success::from)
+    }
+}
@@ -5,8 +5,10 @@ mod common;
 mod deploy;
 mod docs;
 mod generate_completion;
+mod get_account_deploys;
 mod get_auction_info;
 mod get_balance;
+mod get_dictionary_item;
 mod get_era_info_by_switch_block;
 mod get_state_hash;
 mod keygen;
@@ -18,11 +20,12 @@ use clap::{crate_description, crate_version, App};
 
 use casper_client::Error;
 use casper_node::rpcs::{
-    account::PutDeploy,
+    account::{GetAccountDeploys, PutDeploy},
     chain::{GetBlock, GetBlockTransfers, GetEraInfoBySwitchBlock, GetStateRootHash},
     docs::ListRpcs,
     info::GetDeploy,
-    state::{GetAuctionInfo, GetBalance, GetItem as QueryState},
+    speculative_exec::SpeculativeExec,
+    state::{GetAuctionInfo, GetBalance, GetDictionaryItem, GetItem as QueryState},
 };
 
 use deploy::{ListDeploys, MakeDeploy, SendDeploy, SignDeploy};
@@ -41,14 +44,17 @@ enum DisplayOrder {
     MakeDeploy,
     SignDeploy,
     SendDeploy,
+    SpeculativeExec,
     Transfer,
     GetDeploy,
+    GetAccountDeploys,
     GetBlock,
     GetBlockTransfers,
     ListDeploys,
     GetStateRootHash,
     QueryState,
     GetBalance,
+    GetDictionaryItem,
     GetEraInfo,
     GetAuctionInfo,
     Keygen,
@@ -65,8 +71,14 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
         .subcommand(MakeDeploy::build(DisplayOrder::MakeDeploy as usize))
         .subcommand(SignDeploy::build(DisplayOrder::SignDeploy as usize))
         .subcommand(SendDeploy::build(DisplayOrder::SendDeploy as usize))
+        .subcommand(SpeculativeExec::build(
+            DisplayOrder::SpeculativeExec as usize,
+        ))
         .subcommand(Transfer::build(DisplayOrder::Transfer as usize))
         .subcommand(GetDeploy::build(DisplayOrder::GetDeploy as usize))
+        .subcommand(GetAccountDeploys::build(
+            DisplayOrder::GetAccountDeploys as usize,
+        ))
         .subcommand(GetBlock::build(DisplayOrder::GetBlock as usize))
         .subcommand(GetBlockTransfers::build(
             DisplayOrder::GetBlockTransfers as usize,
@@ -77,6 +89,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             DisplayOrder::GetStateRootHash as usize,
         ))
         .subcommand(QueryState::build(DisplayOrder::QueryState as usize))
+        .subcommand(GetDictionaryItem::build(
+            DisplayOrder::GetDictionaryItem as usize,
+        ))
         .subcommand(GetEraInfoBySwitchBlock::build(
             DisplayOrder::GetEraInfo as usize,
         ))
@@ -97,14 +112,17 @@ async fn main() {
         (MakeDeploy::NAME, Some(matches)) => (MakeDeploy::run(matches), matches),
         (SignDeploy::NAME, Some(matches)) => (SignDeploy::run(matches), matches),
         (SendDeploy::NAME, Some(matches)) => (SendDeploy::run(matches), matches),
+        (SpeculativeExec::NAME, Some(matches)) => (SpeculativeExec::run(matches), matches),
         (Transfer::NAME, Some(matches)) => (Transfer::run(matches), matches),
         (GetDeploy::NAME, Some(matches)) => (GetDeploy::run(matches), matches),
+        (GetAccountDeploys::NAME, Some(matches)) => (GetAccountDeploys::run(matches), matches),
         (GetBlock::NAME, Some(matches)) => (GetBlock::run(matches), matches),
         (GetBlockTransfers::NAME, Some(matches)) => (GetBlockTransfers::run(matches), matches),
         (ListDeploys::NAME, Some(matches)) => (ListDeploys::run(matches), matches),
         (GetBalance::NAME, Some(matches)) => (GetBalance::run(matches), matches),
         (GetStateRootHash::NAME, Some(matches)) => (GetStateRootHash::run(matches), matches),
         (QueryState::NAME, Some(matches)) => (QueryState::run(matches), matches),
+        (GetDictionaryItem::NAME, Some(matches)) => (GetDictionaryItem::run(matches), matches),
         (GetEraInfoBySwitchBlock::NAME, Some(matches)) => {
             (GetEraInfoBySwitchBlock::run(matches), matches)
         }
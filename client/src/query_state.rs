@@ -16,6 +16,7 @@ enum DisplayOrder {
     StateRootHash,
     Key,
     Path,
+    BlockHash,
 }
 
 /// Handles providing the arg for and retrieval of the key.
@@ -116,6 +117,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetItem {
             ))
             .arg(key::arg())
             .arg(path::arg())
+            .arg(common::block_hash::arg(DisplayOrder::BlockHash as usize))
     }
 
     fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
@@ -125,6 +127,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetItem {
         let state_root_hash = common::state_root_hash::get(matches);
         let key = key::get(matches)?;
         let path = path::get(matches);
+        let maybe_block_hash = common::block_hash::get(matches);
 
         casper_client::get_item(
             maybe_rpc_id,
@@ -133,6 +136,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetItem {
             state_root_hash,
             &key,
             path,
+            maybe_block_hash,
         )
         .map(Success::from)
     }
@@ -14,10 +14,11 @@ use hex::FromHexError;
 
 use casper_client::{DeployStrParams, Error, PaymentStrParams, SessionStrParams};
 use casper_node::rpcs::{
-    account::{PutDeploy, PutDeployParams},
+    account::{GetAccountDeploys, GetAccountDeploysParams, PutDeploy, PutDeployParams},
     chain::{GetStateRootHash, GetStateRootHashParams},
     info::{GetDeploy, GetDeployParams},
-    state::{GetBalance, GetBalanceParams},
+    speculative_exec::{SpeculativeExec, SpeculativeExecParams},
+    state::{GetBalance, GetBalanceParams, GetDictionaryItem, GetDictionaryItemParams},
     RpcWithOptionalParams, RpcWithParams,
 };
 
@@ -119,10 +120,40 @@ impl MockServerHandle {
         }
     }
 
-    fn get_balance(&self, state_root_hash: &str, purse_uref: &str) -> Result<(), ErrWrapper> {
-        casper_client::get_balance("1", &self.url(), 0, state_root_hash, purse_uref)
-            .map(|_| ())
-            .map_err(ErrWrapper)
+    fn get_balance(
+        &self,
+        state_root_hash: &str,
+        purse_uref: &str,
+        maybe_block_hash: &str,
+    ) -> Result<(), ErrWrapper> {
+        casper_client::get_balance(
+            "1",
+            &self.url(),
+            0,
+            state_root_hash,
+            purse_uref,
+            maybe_block_hash,
+        )
+        .map(|_| ())
+        .map_err(ErrWrapper)
+    }
+
+    fn get_dictionary_item(
+        &self,
+        state_root_hash: &str,
+        seed_uref: &str,
+        dictionary_item_key: &str,
+    ) -> Result<(), ErrWrapper> {
+        casper_client::get_dictionary_item(
+            "1",
+            &self.url(),
+            0,
+            state_root_hash,
+            seed_uref,
+            dictionary_item_key,
+        )
+        .map(|_| ())
+        .map_err(ErrWrapper)
     }
 
     fn get_deploy(&self, deploy_hash: &str) -> Result<(), ErrWrapper> {
@@ -131,6 +162,24 @@ impl MockServerHandle {
             .map_err(ErrWrapper)
     }
 
+    fn get_account_deploys(
+        &self,
+        public_key: &str,
+        maybe_offset: &str,
+        maybe_limit: &str,
+    ) -> Result<(), ErrWrapper> {
+        casper_client::get_account_deploys(
+            "1",
+            &self.url(),
+            0,
+            public_key,
+            maybe_offset,
+            maybe_limit,
+        )
+        .map(|_| ())
+        .map_err(ErrWrapper)
+    }
+
     fn get_state_root_hash(&self, maybe_block_id: &str) -> Result<(), ErrWrapper> {
         casper_client::get_state_root_hash("1", &self.url(), 0, maybe_block_id)
             .map(|_| ())
@@ -143,10 +192,24 @@ impl MockServerHandle {
             .map_err(ErrWrapper)
     }
 
-    fn get_item(&self, state_root_hash: &str, key: &str, path: &str) -> Result<(), ErrWrapper> {
-        casper_client::get_item("1", &self.url(), 0, state_root_hash, key, path)
-            .map(|_| ())
-            .map_err(ErrWrapper)
+    fn get_item(
+        &self,
+        state_root_hash: &str,
+        key: &str,
+        path: &str,
+        maybe_block_hash: &str,
+    ) -> Result<(), ErrWrapper> {
+        casper_client::get_item(
+            "1",
+            &self.url(),
+            0,
+            state_root_hash,
+            key,
+            path,
+            maybe_block_hash,
+        )
+        .map(|_| ())
+        .map_err(ErrWrapper)
     }
 
     fn transfer(
@@ -194,6 +257,12 @@ impl MockServerHandle {
             .map_err(ErrWrapper)
     }
 
+    fn speculative_exec(&self, input_path: &str, maybe_block_id: &str) -> Result<(), ErrWrapper> {
+        casper_client::speculative_exec("1", &self.url(), 0, input_path, maybe_block_id)
+            .map(|_| ())
+            .map_err(ErrWrapper)
+    }
+
     fn get_auction_info(&self) -> Result<(), ErrWrapper> {
         casper_client::get_auction_info("1", &self.url(), 0)
             .map(|_| ())
@@ -284,7 +353,7 @@ mod get_balance {
     async fn should_succeed_with_valid_arguments() {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
-            server_handle.get_balance(VALID_STATE_ROOT_HASH, VALID_PURSE_UREF),
+            server_handle.get_balance(VALID_STATE_ROOT_HASH, VALID_PURSE_UREF, ""),
             // NOTE: this "success" means that we then fail to validate the response, but that
             // is outside the scope of this test.
             // The MockServerHandle could support a pre-baked response, which should successfully
@@ -299,7 +368,7 @@ mod get_balance {
     async fn should_fail_with_empty_arguments() {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
-            server_handle.get_balance("", ""),
+            server_handle.get_balance("", "", ""),
             Err(Error::CryptoError {
                 context: "state_root_hash",
                 error: CryptoError::FromHex(FromHexError::InvalidStringLength)
@@ -312,7 +381,7 @@ mod get_balance {
     async fn should_fail_with_empty_state_root_hash() {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
-            server_handle.get_balance("", VALID_PURSE_UREF),
+            server_handle.get_balance("", VALID_PURSE_UREF, ""),
             Err(Error::CryptoError {
                 context: "state_root_hash",
                 error: CryptoError::FromHex(FromHexError::InvalidStringLength)
@@ -325,16 +394,29 @@ mod get_balance {
     async fn should_fail_with_empty_purse_uref() {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
-            server_handle.get_balance(VALID_STATE_ROOT_HASH, ""),
+            server_handle.get_balance(VALID_STATE_ROOT_HASH, "", ""),
             Err(Error::FailedToParseURef("purse_uref", URefFromStrError::InvalidPrefix).into())
         );
     }
 
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_with_bad_block_hash() {
+        let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
+        assert_eq!(
+            server_handle.get_balance(VALID_STATE_ROOT_HASH, VALID_PURSE_UREF, "deadbeef"),
+            Err(Error::CryptoError {
+                context: "block_hash",
+                error: CryptoError::FromHex(FromHexError::InvalidStringLength)
+            }
+            .into())
+        );
+    }
+
     #[tokio::test(threaded_scheduler)]
     async fn should_fail_with_bad_state_root_hash() {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
-            server_handle.get_balance("deadbeef", VALID_PURSE_UREF),
+            server_handle.get_balance("deadbeef", VALID_PURSE_UREF, ""),
             Err(Error::CryptoError {
                 context: "state_root_hash",
                 error: CryptoError::FromHex(FromHexError::InvalidStringLength)
@@ -344,6 +426,56 @@ mod get_balance {
     }
 }
 
+mod get_dictionary_item {
+    use super::*;
+
+    use casper_client::ValidateResponseError;
+    use casper_types::URefFromStrError;
+
+    const DICTIONARY_ITEM_KEY: &str = "item";
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_succeed_with_valid_arguments() {
+        let server_handle =
+            MockServerHandle::spawn::<GetDictionaryItemParams>(GetDictionaryItem::METHOD);
+        assert_eq!(
+            server_handle.get_dictionary_item(
+                VALID_STATE_ROOT_HASH,
+                VALID_PURSE_UREF,
+                DICTIONARY_ITEM_KEY
+            ),
+            // NOTE: as with `get_balance`, the mock server's response fails validation.
+            Err(
+                Error::InvalidResponse(ValidateResponseError::ValidateResponseFailedToParse).into()
+            )
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_with_empty_state_root_hash() {
+        let server_handle =
+            MockServerHandle::spawn::<GetDictionaryItemParams>(GetDictionaryItem::METHOD);
+        assert_eq!(
+            server_handle.get_dictionary_item("", VALID_PURSE_UREF, DICTIONARY_ITEM_KEY),
+            Err(Error::CryptoError {
+                context: "state_root_hash",
+                error: CryptoError::FromHex(FromHexError::InvalidStringLength)
+            }
+            .into())
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_with_empty_seed_uref() {
+        let server_handle =
+            MockServerHandle::spawn::<GetDictionaryItemParams>(GetDictionaryItem::METHOD);
+        assert_eq!(
+            server_handle.get_dictionary_item(VALID_STATE_ROOT_HASH, "", DICTIONARY_ITEM_KEY),
+            Err(Error::FailedToParseURef("seed_uref", URefFromStrError::InvalidPrefix).into())
+        );
+    }
+}
+
 mod get_state_root_hash {
     use super::*;
 
@@ -449,7 +581,7 @@ mod get_item {
         // mock implementation fails to validate
 
         assert_eq!(
-            server_handle.get_item(VALID_STATE_ROOT_HASH, VALID_PURSE_UREF, ""),
+            server_handle.get_item(VALID_STATE_ROOT_HASH, VALID_PURSE_UREF, "", ""),
             Err(
                 Error::InvalidResponse(ValidateResponseError::ValidateResponseFailedToParse).into()
             )
@@ -460,7 +592,7 @@ mod get_item {
     async fn should_fail_with_invalid_state_root_hash() {
         let server_handle = MockServerHandle::spawn::<GetItemParams>(GetItem::METHOD);
         assert_eq!(
-            server_handle.get_item("<invalid state root hash>", VALID_PURSE_UREF, ""),
+            server_handle.get_item("<invalid state root hash>", VALID_PURSE_UREF, "", ""),
             Err(Error::CryptoError {
                 context: "state_root_hash",
                 error: CryptoError::FromHex(FromHexError::OddLength)
@@ -473,16 +605,34 @@ mod get_item {
     async fn should_fail_with_invalid_key() {
         let server_handle = MockServerHandle::spawn::<GetItemParams>(GetItem::METHOD);
         assert_eq!(
-            server_handle.get_item(VALID_STATE_ROOT_HASH, "invalid key", ""),
+            server_handle.get_item(VALID_STATE_ROOT_HASH, "invalid key", "", ""),
             Err(Error::FailedToParseKey.into())
         );
     }
 
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_with_invalid_block_hash() {
+        let server_handle = MockServerHandle::spawn::<GetItemParams>(GetItem::METHOD);
+        assert_eq!(
+            server_handle.get_item(
+                VALID_STATE_ROOT_HASH,
+                VALID_PURSE_UREF,
+                "",
+                "<invalid block hash>"
+            ),
+            Err(Error::CryptoError {
+                context: "block_hash",
+                error: CryptoError::FromHex(FromHexError::OddLength)
+            }
+            .into())
+        );
+    }
+
     #[tokio::test(threaded_scheduler)]
     async fn should_fail_with_empty_key() {
         let server_handle = MockServerHandle::spawn::<GetItemParams>(GetItem::METHOD);
         assert_eq!(
-            server_handle.get_item("<invalid state root hash>", "", ""),
+            server_handle.get_item("<invalid state root hash>", "", "", ""),
             Err(Error::CryptoError {
                 context: "state_root_hash",
                 error: CryptoError::FromHex(FromHexError::OddLength)
@@ -519,6 +669,50 @@ mod get_deploy {
     }
 }
 
+mod get_account_deploys {
+    use super::*;
+
+    const VALID_PUBLIC_KEY: &str =
+        "01522ef6c89038019cb7af05c340623804392dd2bb1f4dab5e4a9c3ab752fc0179";
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_succeed_with_valid_arguments() {
+        let server_handle =
+            MockServerHandle::spawn::<GetAccountDeploysParams>(GetAccountDeploys::METHOD);
+        assert_eq!(
+            server_handle.get_account_deploys(VALID_PUBLIC_KEY, "10", "5"),
+            Ok(())
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_succeed_with_empty_offset_and_limit() {
+        let server_handle =
+            MockServerHandle::spawn::<GetAccountDeploysParams>(GetAccountDeploys::METHOD);
+        assert_eq!(
+            server_handle.get_account_deploys(VALID_PUBLIC_KEY, "", ""),
+            Ok(())
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_with_invalid_public_key() {
+        let server_handle =
+            MockServerHandle::spawn::<GetAccountDeploysParams>(GetAccountDeploys::METHOD);
+        assert!(server_handle.get_account_deploys("012345", "", "").is_err());
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_with_invalid_offset() {
+        let server_handle =
+            MockServerHandle::spawn::<GetAccountDeploysParams>(GetAccountDeploys::METHOD);
+        assert_eq!(
+            server_handle.get_account_deploys(VALID_PUBLIC_KEY, "ten", ""),
+            Err(Error::FailedToParseInt("offset", "ten".parse::<u64>().unwrap_err()).into())
+        );
+    }
+}
+
 mod get_auction_info {
     use super::*;
 
@@ -602,6 +796,73 @@ mod send_deploy {
     }
 }
 
+mod speculative_exec {
+    use super::*;
+
+    fn make_deploy_file(temp_dir: &TempDir) -> String {
+        let file_path = temp_dir.path().join("test_speculative_exec.json");
+        assert_eq!(
+            casper_client::make_deploy(
+                file_path.to_str().unwrap(),
+                deploy_params::test_data_valid(),
+                session_params::test_data_with_package_hash(),
+                payment_params::test_data_with_name()
+            )
+            .map_err(ErrWrapper),
+            Ok(())
+        );
+        file_path.to_str().unwrap().to_string()
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_with_bad_deploy_file_path() {
+        let server_handle =
+            MockServerHandle::spawn::<SpeculativeExecParams>(SpeculativeExec::METHOD);
+        if let Err(ErrWrapper(Error::IoError { context, .. })) =
+            server_handle.speculative_exec("<not a valid path>", "")
+        {
+            assert_eq!(context, "unable to read input file \'<not a valid path>\'")
+        }
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_succeed_without_block_identifier() {
+        let temp_dir = TempDir::new()
+            .unwrap_or_else(|err| panic!("Failed to create temp dir with error: {}", err));
+        let file_path = make_deploy_file(&temp_dir);
+        let server_handle =
+            MockServerHandle::spawn::<SpeculativeExecParams>(SpeculativeExec::METHOD);
+        assert_eq!(server_handle.speculative_exec(&file_path, ""), Ok(()));
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_succeed_with_block_identifier() {
+        let temp_dir = TempDir::new()
+            .unwrap_or_else(|err| panic!("Failed to create temp dir with error: {}", err));
+        let file_path = make_deploy_file(&temp_dir);
+        let server_handle =
+            MockServerHandle::spawn::<SpeculativeExecParams>(SpeculativeExec::METHOD);
+        assert_eq!(server_handle.speculative_exec(&file_path, "1"), Ok(()));
+        assert_eq!(
+            server_handle.speculative_exec(&file_path, VALID_STATE_ROOT_HASH),
+            Ok(())
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_with_bad_block_identifier() {
+        let temp_dir = TempDir::new()
+            .unwrap_or_else(|err| panic!("Failed to create temp dir with error: {}", err));
+        let file_path = make_deploy_file(&temp_dir);
+        let server_handle =
+            MockServerHandle::spawn::<SpeculativeExecParams>(SpeculativeExec::METHOD);
+        match server_handle.speculative_exec(&file_path, "<not a block identifier>") {
+            Err(ErrWrapper(Error::FailedToParseInt("block_identifier", _))) => {}
+            other => panic!("incorrect error returned from client {:?}", other),
+        }
+    }
+}
+
 mod sign_deploy {
     use super::*;
 
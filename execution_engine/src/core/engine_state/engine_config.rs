@@ -1,22 +1,49 @@
-const DEFAULT_MAX_QUERY_DEPTH: u64 = 5;
+use casper_types::{Contract, ProtocolVersion};
+
+/// The default maximum depth of a query, i.e. the default value of `max_query_depth`.
+pub const DEFAULT_MAX_QUERY_DEPTH: u64 = 5;
 
 /// The runtime configuration of the execution engine
 #[derive(Debug, Copy, Clone)]
 pub struct EngineConfig {
     pub(crate) max_query_depth: u64,
+    /// The protocol version from which installed contracts have their call args resolved by name
+    /// against their entry points' parameters, or `None` if no contracts do.
+    ///
+    /// This is consensus-breaking, so it must be the same for every node of a network.  Contracts
+    /// installed under an earlier protocol version keep the original behavior, where args are
+    /// passed through unchanged and only calls to versioned contracts are type checked by
+    /// position.
+    pub(crate) strict_entry_point_args_protocol_version: Option<ProtocolVersion>,
 }
 
 impl Default for EngineConfig {
     fn default() -> Self {
         EngineConfig {
             max_query_depth: DEFAULT_MAX_QUERY_DEPTH,
+            strict_entry_point_args_protocol_version: None,
         }
     }
 }
 
 impl EngineConfig {
     /// Creates a new engine configuration with provided parameters.
-    pub fn new(max_query_depth: u64) -> EngineConfig {
-        EngineConfig { max_query_depth }
+    pub fn new(
+        max_query_depth: u64,
+        strict_entry_point_args_protocol_version: Option<ProtocolVersion>,
+    ) -> EngineConfig {
+        EngineConfig {
+            max_query_depth,
+            strict_entry_point_args_protocol_version,
+        }
+    }
+
+    /// Returns `true` if calls to `contract` have their args resolved via
+    /// [`resolve_entry_point_args`](crate::core::runtime::resolve_entry_point_args).
+    pub fn has_strict_entry_point_args(&self, contract: &Contract) -> bool {
+        self.strict_entry_point_args_protocol_version
+            .map_or(false, |protocol_version| {
+                contract.protocol_version() >= protocol_version
+            })
     }
 }
@@ -29,8 +29,8 @@ use casper_types::{
 use super::error;
 use crate::{
     core::{
-        engine_state::{Error, ExecError, MAX_PAYMENT_AMOUNT},
-        execution,
+        engine_state::{EngineConfig, Error, ExecError, MAX_PAYMENT_AMOUNT},
+        execution, runtime,
         tracking_copy::{TrackingCopy, TrackingCopyExt},
     },
     shared::{
@@ -149,6 +149,7 @@ impl ExecutableDeployItem {
         preprocessor: &Preprocessor,
         protocol_version: &ProtocolVersion,
         protocol_data: &ProtocolData,
+        engine_config: &EngineConfig,
         phase: Phase,
     ) -> Result<DeployMetadata, Error>
     where
@@ -164,6 +165,7 @@ impl ExecutableDeployItem {
                         contract: Contract::default(),
                         contract_package: ContractPackage::default(),
                         entry_point: EntryPoint::default(),
+                        args: self.args().clone(),
                     });
                 }
 
@@ -172,6 +174,7 @@ impl ExecutableDeployItem {
                     module,
                     contract_package: ContractPackage::default(),
                     entry_point: EntryPoint::default(),
+                    args: self.args().clone(),
                 });
             }
             ExecutableDeployItem::StoredContractByHash { .. }
@@ -279,9 +282,17 @@ impl ExecutableDeployItem {
                 contract,
                 contract_package,
                 entry_point,
+                args: self.args().clone(),
             });
         }
 
+        let args = if engine_config.has_strict_entry_point_args(&contract) {
+            runtime::resolve_entry_point_args(&entry_point, self.args().clone())
+                .map_err(error::Error::Exec)?
+        } else {
+            self.args().clone()
+        };
+
         let contract_wasm = tracking_copy
             .borrow_mut()
             .get_contract_wasm(correlation_id, contract.contract_wasm_hash())?;
@@ -293,6 +304,7 @@ impl ExecutableDeployItem {
                 module,
                 contract_package,
                 entry_point,
+                args,
             }),
             EntryPointType::Contract => Ok(DeployMetadata::Contract {
                 module,
@@ -300,6 +312,7 @@ impl ExecutableDeployItem {
                 contract,
                 contract_package,
                 entry_point,
+                args,
             }),
         }
     }
@@ -683,6 +696,7 @@ pub enum DeployMetadata {
         module: Module,
         contract_package: ContractPackage,
         entry_point: EntryPoint,
+        args: RuntimeArgs,
     },
     Contract {
         // Contract hash
@@ -691,12 +705,14 @@ pub enum DeployMetadata {
         contract: Contract,
         contract_package: ContractPackage,
         entry_point: EntryPoint,
+        args: RuntimeArgs,
     },
     System {
         base_key: Key,
         contract: Contract,
         contract_package: ContractPackage,
         entry_point: EntryPoint,
+        args: RuntimeArgs,
     },
 }
 
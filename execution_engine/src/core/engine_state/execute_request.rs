@@ -12,6 +12,9 @@ pub struct ExecuteRequest {
     pub deploys: Vec<Result<DeployItem, ExecutionResult>>,
     pub protocol_version: ProtocolVersion,
     pub proposer: PublicKey,
+    /// The accumulated seed of the block being executed, used to seed the random bytes available
+    /// to contracts.
+    pub accumulated_seed: Blake2bHash,
 }
 
 impl ExecuteRequest {
@@ -21,6 +24,7 @@ impl ExecuteRequest {
         deploys: Vec<Result<DeployItem, ExecutionResult>>,
         protocol_version: ProtocolVersion,
         proposer: PublicKey,
+        accumulated_seed: Blake2bHash,
     ) -> Self {
         Self {
             parent_state_hash,
@@ -28,6 +32,7 @@ impl ExecuteRequest {
             deploys,
             protocol_version,
             proposer,
+            accumulated_seed,
         }
     }
 
@@ -49,6 +54,7 @@ impl Default for ExecuteRequest {
             deploys: vec![],
             protocol_version: Default::default(),
             proposer,
+            accumulated_seed: Blake2bHash::new(&[]),
         }
     }
 }
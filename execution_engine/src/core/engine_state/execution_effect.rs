@@ -1,4 +1,4 @@
-use casper_types::Key;
+use casper_types::{ContractEvent, Key};
 
 use super::op::Op;
 use crate::shared::{additive_map::AdditiveMap, transform::Transform};
@@ -7,11 +7,21 @@ use crate::shared::{additive_map::AdditiveMap, transform::Transform};
 pub struct ExecutionEffect {
     pub ops: AdditiveMap<Key, Op>,
     pub transforms: AdditiveMap<Key, Transform>,
+    pub events: Vec<ContractEvent>,
 }
 
 impl ExecutionEffect {
     pub fn new(ops: AdditiveMap<Key, Op>, transforms: AdditiveMap<Key, Transform>) -> Self {
-        ExecutionEffect { ops, transforms }
+        ExecutionEffect {
+            ops,
+            transforms,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn with_events(mut self, events: Vec<ContractEvent>) -> Self {
+        self.events = events;
+        self
     }
 }
 
@@ -1,6 +1,8 @@
 use std::collections::VecDeque;
 
-use casper_types::{bytesrepr::FromBytes, CLTyped, CLValue, CLValueError, Key, TransferAddr};
+use casper_types::{
+    bytesrepr::FromBytes, CLTyped, CLValue, CLValueError, ContractEvent, Key, TransferAddr, U512,
+};
 
 use super::{error, execution_effect::ExecutionEffect, op::Op};
 use crate::{
@@ -11,11 +13,24 @@ use crate::{
     storage::global_state::StateReader,
 };
 
+/// The destination of the fee charged for a deploy, as determined by the fee handling configured
+/// in the handle payment contract.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeTarget {
+    /// The fee is added to the purse with the given balance key.
+    Purse(Key),
+    /// The fee is burned, reducing the total supply stored under `total_supply_key`.
+    Burn {
+        total_supply_key: Key,
+        total_supply: U512,
+    },
+}
+
 fn make_payment_error_effects(
     max_payment_cost: Motes,
     account_main_purse_balance: Motes,
     account_main_purse_balance_key: Key,
-    proposer_main_purse_balance_key: Key,
+    fee_target: FeeTarget,
 ) -> Result<ExecutionEffect, CLValueError> {
     let mut ops = AdditiveMap::new();
     let mut transforms = AdditiveMap::new();
@@ -26,7 +41,6 @@ fn make_payment_error_effects(
     let new_balance_value = StoredValue::CLValue(new_balance_clvalue);
 
     let account_main_purse_balance_normalize = account_main_purse_balance_key.normalize();
-    let proposer_main_purse_balance_normalize = proposer_main_purse_balance_key.normalize();
 
     ops.insert(account_main_purse_balance_normalize, Op::Write);
     transforms.insert(
@@ -34,11 +48,29 @@ fn make_payment_error_effects(
         Transform::Write(new_balance_value),
     );
 
-    ops.insert(proposer_main_purse_balance_normalize, Op::Add);
-    transforms.insert(
-        proposer_main_purse_balance_normalize,
-        Transform::AddUInt512(max_payment_cost.value()),
-    );
+    match fee_target {
+        FeeTarget::Purse(balance_key) => {
+            let balance_normalize = balance_key.normalize();
+            ops.insert(balance_normalize, Op::Add);
+            transforms.insert(
+                balance_normalize,
+                Transform::AddUInt512(max_payment_cost.value()),
+            );
+        }
+        FeeTarget::Burn {
+            total_supply_key,
+            total_supply,
+        } => {
+            let new_total_supply = total_supply.saturating_sub(max_payment_cost.value());
+            let new_total_supply_value = StoredValue::CLValue(CLValue::from_t(new_total_supply)?);
+            let total_supply_normalize = total_supply_key.normalize();
+            ops.insert(total_supply_normalize, Op::Write);
+            transforms.insert(
+                total_supply_normalize,
+                Transform::Write(new_total_supply_value),
+            );
+        }
+    }
 
     Ok(ExecutionEffect::new(ops, transforms))
 }
@@ -257,13 +289,13 @@ impl ExecutionResult {
         account_main_purse_balance: Motes,
         gas_cost: Gas,
         account_main_purse_balance_key: Key,
-        proposer_main_purse_balance_key: Key,
+        fee_target: FeeTarget,
     ) -> Result<ExecutionResult, CLValueError> {
         let effect = make_payment_error_effects(
             max_payment_cost,
             account_main_purse_balance,
             account_main_purse_balance_key,
-            proposer_main_purse_balance_key,
+            fee_target,
         )?;
         let transfers = Vec::default();
         Ok(ExecutionResult::Failure {
@@ -294,6 +326,7 @@ impl From<&ExecutionResult> for casper_types::ExecutionResult {
                 effect: effect.into(),
                 transfers: transfers.clone(),
                 cost: cost.value(),
+                events: effect.events.clone(),
             },
             ExecutionResult::Failure {
                 error,
@@ -305,6 +338,7 @@ impl From<&ExecutionResult> for casper_types::ExecutionResult {
                 transfers: transfers.clone(),
                 cost: cost.value(),
                 error_message: error.to_string(),
+                events: effect.events.clone(),
             },
         }
     }
@@ -390,6 +424,7 @@ impl ExecutionResultBuilder {
         let cost = self.total_cost();
         let mut ops = AdditiveMap::new();
         let mut transforms = AdditiveMap::new();
+        let mut events = Vec::new();
 
         let mut ret: ExecutionResult = ExecutionResult::Success {
             effect: Default::default(),
@@ -402,7 +437,7 @@ impl ExecutionResultBuilder {
                 if result.is_failure() {
                     return Ok(result);
                 } else {
-                    Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    Self::add_effects(&mut ops, &mut transforms, &mut events, result.effect());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingPaymentExecutionResult),
@@ -415,7 +450,7 @@ impl ExecutionResultBuilder {
                 if result.is_failure() {
                     ret = result.with_cost(cost);
                 } else {
-                    Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    Self::add_effects(&mut ops, &mut transforms, &mut events, result.effect());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingSessionExecutionResult),
@@ -429,7 +464,7 @@ impl ExecutionResultBuilder {
                         error::Error::Finalization,
                     ));
                 } else {
-                    Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    Self::add_effects(&mut ops, &mut transforms, &mut events, result.effect());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingFinalizeExecutionResult),
@@ -438,12 +473,13 @@ impl ExecutionResultBuilder {
         // Remove redundant writes to allow more opportunity to commute
         let reduced_effect = Self::reduce_identity_writes(ops, transforms, reader, correlation_id);
 
-        Ok(ret.with_effect(reduced_effect))
+        Ok(ret.with_effect(reduced_effect.with_events(events)))
     }
 
     fn add_effects(
         ops: &mut AdditiveMap<Key, Op>,
         transforms: &mut AdditiveMap<Key, Transform>,
+        events: &mut Vec<ContractEvent>,
         effect: &ExecutionEffect,
     ) {
         for (k, op) in effect.ops.iter() {
@@ -452,6 +488,7 @@ impl ExecutionResultBuilder {
         for (k, t) in effect.transforms.iter() {
             transforms.insert_add(*k, t.clone())
         }
+        events.extend(effect.events.iter().cloned());
     }
 
     /// In the case we are writing the same value as was there originally,
@@ -1,9 +1,14 @@
-use std::{cell::RefCell, collections::BTreeMap, fmt, iter, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    fmt, iter,
+    rc::Rc,
+};
 
 use datasize::DataSize;
 use num_rational::Ratio;
 use num_traits::Zero;
-use parity_wasm::elements::Module;
+use parity_wasm::elements::{Internal, Module};
 use rand::{
     distributions::{Distribution, Standard},
     Rng,
@@ -11,7 +16,7 @@ use rand::{
 use serde::{Deserialize, Serialize};
 
 use casper_types::{
-    account::AccountHash,
+    account::{AccountHash, Weight},
     bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
     contracts::{
         ContractPackageStatus, ContractVersions, DisabledVersions, Groups, NamedKeys, Parameters,
@@ -30,8 +35,9 @@ use casper_types::{
             SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
         },
         handle_payment::{
-            self, ARG_ACCOUNT, METHOD_FINALIZE_PAYMENT, METHOD_GET_PAYMENT_PURSE,
-            METHOD_GET_REFUND_PURSE, METHOD_SET_REFUND_PURSE,
+            self, FeeHandling, ARG_ACCOUNT, ARG_VALIDATOR_PUBLIC_KEYS, FEE_HANDLING_KEY,
+            METHOD_DISTRIBUTE_ACCUMULATED_FEES, METHOD_FINALIZE_PAYMENT, METHOD_GET_PAYMENT_PURSE,
+            METHOD_GET_REFUND_PURSE, METHOD_SET_REFUND_PURSE, REFUND_RATIO_KEY,
         },
         mint::{
             self, ARG_AMOUNT, ARG_ID, ARG_PURSE, ARG_ROUND_SEIGNIORAGE_RATE, ARG_SOURCE,
@@ -55,13 +61,14 @@ use crate::{
         tracking_copy::TrackingCopy,
     },
     shared::{
-        account::Account,
+        account::{Account, ActionThresholds, AssociatedKeys},
         gas::Gas,
         motes::Motes,
         newtypes::{Blake2bHash, CorrelationId},
         stored_value::StoredValue,
         system_config::SystemConfig,
         wasm_config::WasmConfig,
+        wasm_prep::Preprocessor,
         TypeMismatch,
     },
     storage::{
@@ -463,6 +470,127 @@ impl FromBytes for GenesisAccount {
     }
 }
 
+/// The value of a named key set up at genesis.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenesisNamedKeyValue {
+    /// An existing key.
+    Key(Key),
+    /// A value to be stored under a newly created `URef`.
+    Value(CLValue),
+}
+
+/// Additional setup of an account created at genesis.
+///
+/// The account's own key is always associated with a weight of 1 unless it is given a different
+/// weight in `associated_keys`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisAccountSetup {
+    public_key: PublicKey,
+    associated_keys: BTreeMap<AccountHash, Weight>,
+    deployment_threshold: Weight,
+    key_management_threshold: Weight,
+    named_keys: BTreeMap<String, GenesisNamedKeyValue>,
+}
+
+impl GenesisAccountSetup {
+    pub fn new(
+        public_key: PublicKey,
+        associated_keys: BTreeMap<AccountHash, Weight>,
+        deployment_threshold: Weight,
+        key_management_threshold: Weight,
+        named_keys: BTreeMap<String, GenesisNamedKeyValue>,
+    ) -> Self {
+        GenesisAccountSetup {
+            public_key,
+            associated_keys,
+            deployment_threshold,
+            key_management_threshold,
+            named_keys,
+        }
+    }
+
+    /// The public key of the account to be set up.
+    pub fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    /// The keys associated with the account, along with their weights.
+    pub fn associated_keys(&self) -> &BTreeMap<AccountHash, Weight> {
+        &self.associated_keys
+    }
+
+    /// The weight required to send deploys from the account.
+    pub fn deployment_threshold(&self) -> Weight {
+        self.deployment_threshold
+    }
+
+    /// The weight required to manage the account's associated keys.
+    pub fn key_management_threshold(&self) -> Weight {
+        self.key_management_threshold
+    }
+
+    /// The named keys of the account.
+    pub fn named_keys(&self) -> &BTreeMap<String, GenesisNamedKeyValue> {
+        &self.named_keys
+    }
+}
+
+/// A Wasm contract to be installed at genesis.
+///
+/// The contract hash, contract package hash and package access `URef` are stored under the
+/// owner's named keys `<name>`, `<name>_package` and `<name>_access` respectively.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisContract {
+    name: String,
+    owner: PublicKey,
+    module_bytes: Vec<u8>,
+    entry_points: EntryPoints,
+    named_keys: BTreeMap<String, GenesisNamedKeyValue>,
+}
+
+impl GenesisContract {
+    pub fn new(
+        name: String,
+        owner: PublicKey,
+        module_bytes: Vec<u8>,
+        entry_points: EntryPoints,
+        named_keys: BTreeMap<String, GenesisNamedKeyValue>,
+    ) -> Self {
+        GenesisContract {
+            name,
+            owner,
+            module_bytes,
+            entry_points,
+            named_keys,
+        }
+    }
+
+    /// The name under which the contract is stored in its owner's named keys.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The public key of the genesis account owning the contract.
+    pub fn owner(&self) -> PublicKey {
+        self.owner
+    }
+
+    /// The contract's Wasm module.
+    pub fn module_bytes(&self) -> &[u8] {
+        &self.module_bytes
+    }
+
+    /// The contract's entry points.
+    pub fn entry_points(&self) -> &EntryPoints {
+        &self.entry_points
+    }
+
+    /// The initial named keys of the contract.
+    pub fn named_keys(&self) -> &BTreeMap<String, GenesisNamedKeyValue> {
+        &self.named_keys
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GenesisConfig {
     name: String,
@@ -537,6 +665,8 @@ impl Distribution<GenesisConfig> for Standard {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ExecConfig {
     accounts: Vec<GenesisAccount>,
+    account_setups: Vec<GenesisAccountSetup>,
+    contracts: Vec<GenesisContract>,
     wasm_config: WasmConfig,
     system_config: SystemConfig,
     validator_slots: u32,
@@ -544,6 +674,8 @@ pub struct ExecConfig {
     locked_funds_period_millis: u64,
     round_seigniorage_rate: Ratio<u64>,
     unbonding_delay: u64,
+    refund_ratio: Ratio<u64>,
+    fee_handling: FeeHandling,
     genesis_timestamp_millis: u64,
 }
 
@@ -558,10 +690,14 @@ impl ExecConfig {
         locked_funds_period_millis: u64,
         round_seigniorage_rate: Ratio<u64>,
         unbonding_delay: u64,
+        refund_ratio: Ratio<u64>,
+        fee_handling: FeeHandling,
         genesis_timestamp_millis: u64,
     ) -> ExecConfig {
         ExecConfig {
             accounts,
+            account_setups: Vec::new(),
+            contracts: Vec::new(),
             wasm_config,
             system_config,
             validator_slots,
@@ -569,6 +705,8 @@ impl ExecConfig {
             locked_funds_period_millis,
             round_seigniorage_rate,
             unbonding_delay,
+            refund_ratio,
+            fee_handling,
             genesis_timestamp_millis,
         }
     }
@@ -603,6 +741,22 @@ impl ExecConfig {
         self.accounts.push(account)
     }
 
+    pub fn account_setups(&self) -> &[GenesisAccountSetup] {
+        self.account_setups.as_slice()
+    }
+
+    pub fn push_account_setup(&mut self, account_setup: GenesisAccountSetup) {
+        self.account_setups.push(account_setup)
+    }
+
+    pub fn contracts(&self) -> &[GenesisContract] {
+        self.contracts.as_slice()
+    }
+
+    pub fn push_contract(&mut self, contract: GenesisContract) {
+        self.contracts.push(contract)
+    }
+
     pub fn validator_slots(&self) -> u32 {
         self.validator_slots
     }
@@ -623,6 +777,14 @@ impl ExecConfig {
         self.unbonding_delay
     }
 
+    pub fn refund_ratio(&self) -> Ratio<u64> {
+        self.refund_ratio
+    }
+
+    pub fn fee_handling(&self) -> FeeHandling {
+        self.fee_handling
+    }
+
     pub fn genesis_timestamp_millis(&self) -> u64 {
         self.genesis_timestamp_millis
     }
@@ -651,10 +813,23 @@ impl Distribution<ExecConfig> for Standard {
 
         let unbonding_delay = rng.gen();
 
+        let refund_ratio = {
+            let denom = rng.gen_range(1..1_000_000_000);
+            Ratio::new(rng.gen_range(0..=denom), denom)
+        };
+
+        let fee_handling = match rng.gen_range(0..3) {
+            0 => FeeHandling::PayToProposer,
+            1 => FeeHandling::Accumulate,
+            _ => FeeHandling::Burn,
+        };
+
         let genesis_timestamp_millis = rng.gen();
 
         ExecConfig {
             accounts,
+            account_setups: Vec::new(),
+            contracts: Vec::new(),
             wasm_config,
             system_config,
             validator_slots,
@@ -662,6 +837,8 @@ impl Distribution<ExecConfig> for Standard {
             locked_funds_period_millis,
             round_seigniorage_rate,
             unbonding_delay,
+            refund_ratio,
+            fee_handling,
             genesis_timestamp_millis,
         }
     }
@@ -695,6 +872,20 @@ pub enum GenesisError {
     InvalidDelegatedAmount {
         public_key: PublicKey,
     },
+    MissingGenesisAccount {
+        public_key: PublicKey,
+    },
+    InvalidActionThresholds {
+        public_key: PublicKey,
+    },
+    InvalidContractWasm {
+        name: String,
+        error: String,
+    },
+    MissingContractEntryPoint {
+        name: String,
+        entry_point: String,
+    },
 }
 
 pub(crate) struct GenesisInstaller<S>
@@ -710,6 +901,7 @@ where
     uref_address_generator: Rc<RefCell<AddressGenerator>>,
     hash_address_generator: Rc<RefCell<AddressGenerator>>,
     transfer_address_generator: Rc<RefCell<AddressGenerator>>,
+    random_bytes_generator: Rc<RefCell<AddressGenerator>>,
     executor: Executor,
     tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
     protocol_data: ProtocolData,
@@ -746,6 +938,10 @@ where
             let generator = AddressGenerator::new(genesis_config_hash_bytes, phase);
             Rc::new(RefCell::new(generator))
         };
+        let random_bytes_generator = {
+            let generator = AddressGenerator::new(genesis_config_hash_bytes, phase);
+            Rc::new(RefCell::new(generator))
+        };
 
         let protocol_data = ProtocolData::default();
 
@@ -771,6 +967,7 @@ where
             uref_address_generator,
             hash_address_generator,
             transfer_address_generator,
+            random_bytes_generator,
             executor,
             tracking_copy,
             protocol_data,
@@ -839,7 +1036,7 @@ where
             .borrow_mut()
             .new_uref(AccessRights::READ_ADD_WRITE);
 
-        let (_, mint_hash) = self.store_contract(access_key, named_keys, entry_points);
+        let (_, mint_hash) = self.store_contract(access_key, named_keys, entry_points, vec![]);
 
         self.protocol_data = ProtocolData::partial_with_mint(mint_hash);
 
@@ -852,10 +1049,57 @@ where
             DeployHash::new(self.genesis_config_hash.value()),
         )?;
 
+        let accumulation_purse = self.create_purse(
+            U512::zero(),
+            DeployHash::new(self.genesis_config_hash.value()),
+        )?;
+
+        let refund_ratio_uref = {
+            let refund_ratio_uref = self
+                .uref_address_generator
+                .borrow_mut()
+                .new_uref(AccessRights::READ_ADD_WRITE);
+
+            let (refund_ratio_numer, refund_ratio_denom) = self.exec_config.refund_ratio().into();
+            let refund_ratio: Ratio<U512> =
+                Ratio::new(refund_ratio_numer.into(), refund_ratio_denom.into());
+
+            self.tracking_copy.borrow_mut().write(
+                refund_ratio_uref.into(),
+                StoredValue::CLValue(
+                    CLValue::from_t(refund_ratio)
+                        .map_err(|_| GenesisError::CLValue(REFUND_RATIO_KEY.to_string()))?,
+                ),
+            );
+            refund_ratio_uref
+        };
+
+        let fee_handling_uref = {
+            let fee_handling_uref = self
+                .uref_address_generator
+                .borrow_mut()
+                .new_uref(AccessRights::READ_ADD_WRITE);
+
+            self.tracking_copy.borrow_mut().write(
+                fee_handling_uref.into(),
+                StoredValue::CLValue(
+                    CLValue::from_t(self.exec_config.fee_handling())
+                        .map_err(|_| GenesisError::CLValue(FEE_HANDLING_KEY.to_string()))?,
+                ),
+            );
+            fee_handling_uref
+        };
+
         let named_keys = {
             let mut named_keys = NamedKeys::new();
             let named_key = Key::URef(handle_payment_payment_purse);
             named_keys.insert(handle_payment::PAYMENT_PURSE_KEY.to_string(), named_key);
+            named_keys.insert(
+                handle_payment::ACCUMULATION_PURSE_KEY.to_string(),
+                accumulation_purse.into(),
+            );
+            named_keys.insert(REFUND_RATIO_KEY.to_string(), refund_ratio_uref.into());
+            named_keys.insert(FEE_HANDLING_KEY.to_string(), fee_handling_uref.into());
             named_keys
         };
 
@@ -866,7 +1110,8 @@ where
             .borrow_mut()
             .new_uref(AccessRights::READ_ADD_WRITE);
 
-        let (_, handle_payment_hash) = self.store_contract(access_key, named_keys, entry_points);
+        let (_, handle_payment_hash) =
+            self.store_contract(access_key, named_keys, entry_points, vec![]);
 
         Ok(handle_payment_hash)
     }
@@ -1101,7 +1346,7 @@ where
             .borrow_mut()
             .new_uref(AccessRights::READ_ADD_WRITE);
 
-        let (_, auction_hash) = self.store_contract(access_key, named_keys, entry_points);
+        let (_, auction_hash) = self.store_contract(access_key, named_keys, entry_points, vec![]);
 
         Ok(auction_hash)
     }
@@ -1116,7 +1361,8 @@ where
             .borrow_mut()
             .new_uref(AccessRights::READ_ADD_WRITE);
 
-        let (_, standard_payment_hash) = self.store_contract(access_key, named_keys, entry_points);
+        let (_, standard_payment_hash) =
+            self.store_contract(access_key, named_keys, entry_points, vec![]);
 
         standard_payment_hash
     }
@@ -1150,6 +1396,154 @@ where
         Ok(())
     }
 
+    pub(crate) fn setup_accounts(&self) -> Result<(), GenesisError> {
+        for account_setup in self.exec_config.account_setups() {
+            let public_key = account_setup.public_key();
+            let account = self.read_account(public_key)?;
+            let account_hash = account.account_hash();
+
+            let associated_keys = {
+                let mut associated_keys = BTreeMap::new();
+                associated_keys.insert(account_hash, Weight::new(1));
+                associated_keys.extend(account_setup.associated_keys().clone());
+                AssociatedKeys::from(associated_keys)
+            };
+
+            let action_thresholds = ActionThresholds::new(
+                account_setup.deployment_threshold(),
+                account_setup.key_management_threshold(),
+            )
+            .map_err(|_| GenesisError::InvalidActionThresholds { public_key })?;
+
+            // The account would be locked if its keys could never meet the key management
+            // threshold.
+            if associated_keys.total_keys_weight() < *action_thresholds.key_management() {
+                return Err(GenesisError::InvalidActionThresholds { public_key });
+            }
+
+            let mut named_keys = account.named_keys().clone();
+            named_keys.append(&mut self.create_named_keys(account_setup.named_keys()));
+
+            let account = Account::new(
+                account_hash,
+                named_keys,
+                account.main_purse(),
+                associated_keys,
+                action_thresholds,
+            );
+
+            self.tracking_copy
+                .borrow_mut()
+                .write(Key::Account(account_hash), StoredValue::Account(account));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn install_contracts(&self) -> Result<(), GenesisError> {
+        let preprocessor = Preprocessor::new(*self.exec_config.wasm_config());
+
+        for genesis_contract in self.exec_config.contracts() {
+            let name = genesis_contract.name();
+
+            let module = preprocessor
+                .preprocess(genesis_contract.module_bytes())
+                .map_err(|error| GenesisError::InvalidContractWasm {
+                    name: name.to_string(),
+                    error: error.to_string(),
+                })?;
+
+            let exported_functions: BTreeSet<&str> = module
+                .export_section()
+                .map(|export_section| {
+                    export_section
+                        .entries()
+                        .iter()
+                        .filter(|export| matches!(export.internal(), Internal::Function(_)))
+                        .map(|export| export.field())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            if let Some(entry_point) = genesis_contract
+                .entry_points()
+                .keys()
+                .find(|entry_point| !exported_functions.contains(entry_point.as_str()))
+            {
+                return Err(GenesisError::MissingContractEntryPoint {
+                    name: name.to_string(),
+                    entry_point: entry_point.clone(),
+                });
+            }
+
+            let mut owner = self.read_account(genesis_contract.owner())?;
+
+            let named_keys = self.create_named_keys(genesis_contract.named_keys());
+
+            let access_key = self
+                .uref_address_generator
+                .borrow_mut()
+                .new_uref(AccessRights::READ_ADD_WRITE);
+
+            let (contract_package_hash, contract_hash) = self.store_contract(
+                access_key,
+                named_keys,
+                genesis_contract.entry_points().clone(),
+                genesis_contract.module_bytes().to_vec(),
+            );
+
+            let owner_named_keys = owner.named_keys_mut();
+            owner_named_keys.insert(name.to_string(), contract_hash.into());
+            owner_named_keys.insert(format!("{}_package", name), contract_package_hash.into());
+            owner_named_keys.insert(format!("{}_access", name), access_key.into());
+
+            self.tracking_copy.borrow_mut().write(
+                Key::Account(owner.account_hash()),
+                StoredValue::Account(owner),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn read_account(&self, public_key: PublicKey) -> Result<Account, GenesisError> {
+        let key = Key::Account(public_key.to_account_hash());
+        match self
+            .tracking_copy
+            .borrow_mut()
+            .read(self.correlation_id, &key)
+            .map_err(|_| GenesisError::MissingGenesisAccount { public_key })?
+        {
+            Some(StoredValue::Account(account)) => Ok(account),
+            Some(_) => Err(GenesisError::UnexpectedStoredValue),
+            None => Err(GenesisError::MissingGenesisAccount { public_key }),
+        }
+    }
+
+    fn create_named_keys(
+        &self,
+        genesis_named_keys: &BTreeMap<String, GenesisNamedKeyValue>,
+    ) -> NamedKeys {
+        let mut named_keys = NamedKeys::new();
+        for (name, value) in genesis_named_keys {
+            let key = match value {
+                GenesisNamedKeyValue::Key(key) => *key,
+                GenesisNamedKeyValue::Value(cl_value) => {
+                    let uref = self
+                        .uref_address_generator
+                        .borrow_mut()
+                        .new_uref(AccessRights::READ_ADD_WRITE);
+                    self.tracking_copy
+                        .borrow_mut()
+                        .write(uref.into(), StoredValue::CLValue(cl_value.clone()));
+                    uref.into()
+                }
+            };
+            named_keys.insert(name.clone(), key);
+        }
+        named_keys
+    }
+
     fn initial_seigniorage_recipients(
         &self,
         validators: &BTreeMap<PublicKey, Bid>,
@@ -1211,6 +1605,7 @@ where
                 Rc::clone(&self.hash_address_generator),
                 Rc::clone(&self.uref_address_generator),
                 Rc::clone(&self.transfer_address_generator),
+                Rc::clone(&self.random_bytes_generator),
                 self.protocol_version,
                 self.correlation_id,
                 Rc::clone(&self.tracking_copy),
@@ -1234,6 +1629,7 @@ where
         access_key: URef,
         named_keys: NamedKeys,
         entry_points: EntryPoints,
+        module_bytes: Vec<u8>,
     ) -> (ContractPackageHash, ContractHash) {
         let protocol_version = self.protocol_version;
         let contract_wasm_hash =
@@ -1243,7 +1639,7 @@ where
         let contract_package_hash =
             ContractPackageHash::new(self.hash_address_generator.borrow_mut().new_hash_address());
 
-        let contract_wasm = ContractWasm::new(vec![]);
+        let contract_wasm = ContractWasm::new(module_bytes);
         let contract = Contract::new(
             contract_package_hash,
             contract_wasm_hash,
@@ -1396,6 +1792,18 @@ where
         );
         entry_points.add_entry_point(finalize_payment);
 
+        let distribute_accumulated_fees = EntryPoint::new(
+            METHOD_DISTRIBUTE_ACCUMULATED_FEES,
+            vec![Parameter::new(
+                ARG_VALIDATOR_PUBLIC_KEYS,
+                CLType::List(Box::new(CLType::PublicKey)),
+            )],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(distribute_accumulated_fees);
+
         entry_points
     }
 
@@ -38,7 +38,7 @@ use casper_types::{
             ARG_REWARD_FACTORS, ARG_VALIDATOR_PUBLIC_KEYS, AUCTION_DELAY_KEY,
             LOCKED_FUNDS_PERIOD_KEY, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
         },
-        handle_payment,
+        handle_payment::{self, FeeHandling},
         mint::{self, ROUND_SEIGNIORAGE_RATE_KEY},
     },
     AccessRights, ApiError, BlockTime, CLValue, Contract, DeployHash, DeployInfo, Key, KeyTag,
@@ -54,8 +54,11 @@ pub use self::{
     executable_deploy_item::ExecutableDeployItem,
     execute_request::ExecuteRequest,
     execution::Error as ExecError,
-    execution_result::{ExecutionResult, ExecutionResults, ForcedTransferResult},
-    genesis::{ExecConfig, GenesisAccount, GenesisResult},
+    execution_result::{ExecutionResult, ExecutionResults, FeeTarget, ForcedTransferResult},
+    genesis::{
+        ExecConfig, GenesisAccount, GenesisAccountSetup, GenesisContract, GenesisNamedKeyValue,
+        GenesisResult,
+    },
     query::{GetBidsRequest, GetBidsResult, QueryRequest, QueryResult},
     step::{RewardItem, SlashItem, StepRequest, StepResult},
     system_contract_cache::SystemContractCache,
@@ -68,7 +71,7 @@ use crate::{
             executable_deploy_item::DeployMetadata, execution_result::ExecutionResultBuilder,
             genesis::GenesisInstaller, upgrade::SystemUpgrader,
         },
-        execution::{self, DirectSystemContractCall, Executor},
+        execution::{self, AddressGenerator, DirectSystemContractCall, Executor},
         tracking_copy::{TrackingCopy, TrackingCopyExt},
     },
     shared::{
@@ -82,9 +85,10 @@ use crate::{
         wasm_prep::Preprocessor,
     },
     storage::{
-        global_state::{CommitResult, StateProvider},
+        global_state::{CommitResult, DiffResult, StateProvider},
         protocol_data::ProtocolData,
         trie::Trie,
+        trie_store::PruneResult,
     },
 };
 
@@ -182,6 +186,12 @@ where
         // Create standard payment
         let standard_payment_hash = genesis_installer.create_standard_payment();
 
+        // Set up associated keys, action thresholds and named keys of accounts
+        genesis_installer.setup_accounts()?;
+
+        // Install contracts
+        genesis_installer.install_contracts()?;
+
         // Associate given CostTable with given ProtocolVersion.
         {
             let protocol_data = ProtocolData::new(
@@ -369,6 +379,78 @@ where
                 .write(locked_funds_period_key, value);
         }
 
+        if upgrade_config.new_refund_ratio().is_some()
+            || upgrade_config.new_fee_handling().is_some()
+        {
+            let mut handle_payment_contract = tracking_copy
+                .borrow_mut()
+                .get_contract(correlation_id, new_protocol_data.handle_payment())?;
+
+            // Networks installed by a protocol version predating these settings lack the
+            // corresponding named keys of the handle payment contract, so they're created here.
+            let mut address_generator =
+                AddressGenerator::new(&pre_state_hash.value(), Phase::System);
+            let mut new_named_keys = NamedKeys::new();
+            let mut write_named_value =
+                |name: &str, value: StoredValue| match handle_payment_contract
+                    .named_keys()
+                    .get(name)
+                {
+                    Some(key) => tracking_copy.borrow_mut().write(*key, value),
+                    None => {
+                        let uref = address_generator.new_uref(AccessRights::READ_ADD_WRITE);
+                        tracking_copy.borrow_mut().write(uref.into(), value);
+                        new_named_keys.insert(name.to_string(), uref.into());
+                    }
+                };
+
+            if let Some(new_refund_ratio) = upgrade_config.new_refund_ratio() {
+                let new_refund_ratio: Ratio<U512> = {
+                    let (numer, denom) = new_refund_ratio.into();
+                    Ratio::new(numer.into(), denom.into())
+                };
+                let value = StoredValue::CLValue(
+                    CLValue::from_t(new_refund_ratio)
+                        .map_err(|_| Error::Bytesrepr("new_refund_ratio".to_string()))?,
+                );
+                write_named_value(handle_payment::REFUND_RATIO_KEY, value);
+            }
+
+            if let Some(new_fee_handling) = upgrade_config.new_fee_handling() {
+                let value = StoredValue::CLValue(
+                    CLValue::from_t(new_fee_handling)
+                        .map_err(|_| Error::Bytesrepr("new_fee_handling".to_string()))?,
+                );
+                write_named_value(handle_payment::FEE_HANDLING_KEY, value);
+
+                let has_accumulation_purse = handle_payment_contract
+                    .named_keys()
+                    .contains_key(handle_payment::ACCUMULATION_PURSE_KEY);
+                if new_fee_handling == FeeHandling::Accumulate && !has_accumulation_purse {
+                    // Purses are created the same way as by the mint: a unit value under the
+                    // purse's URef and a zero balance under its balance key.
+                    let purse = address_generator.new_uref(AccessRights::READ_ADD_WRITE);
+                    let balance = CLValue::from_t(U512::zero())
+                        .map_err(|_| Error::Bytesrepr("accumulation_purse".to_string()))?;
+                    let mut tracking_copy = tracking_copy.borrow_mut();
+                    tracking_copy.write(purse.into(), StoredValue::CLValue(CLValue::unit()));
+                    tracking_copy.write(Key::Balance(purse.addr()), StoredValue::CLValue(balance));
+                    new_named_keys.insert(
+                        handle_payment::ACCUMULATION_PURSE_KEY.to_string(),
+                        purse.into(),
+                    );
+                }
+            }
+
+            if !new_named_keys.is_empty() {
+                handle_payment_contract.named_keys_append(&mut new_named_keys);
+                tracking_copy.borrow_mut().write(
+                    new_protocol_data.handle_payment().into(),
+                    StoredValue::Contract(handle_payment_contract),
+                );
+            }
+        }
+
         // apply the arbitrary modifications
         for (key, value) in upgrade_config.global_state_update() {
             tracking_copy.borrow_mut().write(*key, value.clone());
@@ -452,6 +534,7 @@ where
                         exec_request.protocol_version,
                         exec_request.parent_state_hash,
                         BlockTime::new(exec_request.block_time),
+                        exec_request.accumulated_seed,
                         deploy_item,
                         exec_request.proposer,
                     ),
@@ -498,6 +581,76 @@ where
         Ok(account)
     }
 
+    /// Returns where the fee for a deploy is sent according to the fee handling configured in the
+    /// handle payment contract, defaulting to the proposer's main purse.
+    fn get_fee_target(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
+        protocol_data: &ProtocolData,
+        handle_payment_contract: &Contract,
+        proposer_main_purse_balance_key: Key,
+    ) -> Result<FeeTarget, Error> {
+        let read_cl_value = |key: Key| -> Result<CLValue, Error> {
+            match tracking_copy
+                .borrow_mut()
+                .read(correlation_id, &key)
+                .map_err(Into::<execution::Error>::into)?
+            {
+                Some(StoredValue::CLValue(cl_value)) => Ok(cl_value),
+                Some(_) | None => Err(Error::Exec(execution::Error::KeyNotFound(key))),
+            }
+        };
+
+        let fee_handling: FeeHandling = match handle_payment_contract
+            .named_keys()
+            .get(handle_payment::FEE_HANDLING_KEY)
+        {
+            Some(key) => read_cl_value(*key)?
+                .into_t()
+                .map_err(execution::Error::from)?,
+            None => FeeHandling::PayToProposer,
+        };
+
+        match fee_handling {
+            FeeHandling::PayToProposer => Ok(FeeTarget::Purse(proposer_main_purse_balance_key)),
+            FeeHandling::Accumulate => {
+                let accumulation_purse = handle_payment_contract
+                    .named_keys()
+                    .get(handle_payment::ACCUMULATION_PURSE_KEY)
+                    .copied()
+                    .ok_or_else(|| {
+                        Error::MissingSystemContract(
+                            handle_payment::ACCUMULATION_PURSE_KEY.to_string(),
+                        )
+                    })?;
+                let balance_key = tracking_copy
+                    .borrow_mut()
+                    .get_purse_balance_key(correlation_id, accumulation_purse)?;
+                Ok(FeeTarget::Purse(balance_key))
+            }
+            FeeHandling::Burn => {
+                let mint_contract = tracking_copy
+                    .borrow_mut()
+                    .get_contract(correlation_id, protocol_data.mint())?;
+                let total_supply_key = mint_contract
+                    .named_keys()
+                    .get(mint::TOTAL_SUPPLY_KEY)
+                    .copied()
+                    .ok_or_else(|| {
+                        Error::MissingSystemContract(mint::TOTAL_SUPPLY_KEY.to_string())
+                    })?;
+                let total_supply: U512 = read_cl_value(total_supply_key)?
+                    .into_t()
+                    .map_err(execution::Error::from)?;
+                Ok(FeeTarget::Burn {
+                    total_supply_key,
+                    total_supply,
+                })
+            }
+        }
+    }
+
     pub fn get_purse_balance(
         &self,
         correlation_id: CorrelationId,
@@ -679,6 +832,17 @@ where
             ));
         }
 
+        let fee_target = match self.get_fee_target(
+            correlation_id,
+            Rc::clone(&tracking_copy),
+            &protocol_data,
+            &handle_payment_contract,
+            proposer_main_purse_balance_key,
+        ) {
+            Ok(fee_target) => fee_target,
+            Err(error) => return Ok(ExecutionResult::precondition_failure(error)),
+        };
+
         // Function below creates an ExecutionResult with precomputed effects of "finalize_payment".
         let make_charged_execution_failure = |error| match ExecutionResult::new_payment_code_error(
             error,
@@ -686,7 +850,7 @@ where
             account_main_purse_balance,
             wasmless_transfer_gas_cost,
             account_main_purse_balance_key,
-            proposer_main_purse_balance_key,
+            fee_target,
         ) {
             Ok(execution_result) => execution_result,
             Err(error) => {
@@ -1055,6 +1219,7 @@ where
         protocol_version: ProtocolVersion,
         prestate_hash: Blake2bHash,
         blocktime: BlockTime,
+        accumulated_seed: Blake2bHash,
         deploy_item: DeployItem,
         proposer: PublicKey,
     ) -> Result<ExecutionResult, RootNotFound> {
@@ -1143,6 +1308,7 @@ where
             &preprocessor,
             &protocol_version,
             &protocol_data,
+            &self.config,
             Phase::Session,
         ) {
             Ok(metadata) => metadata,
@@ -1222,6 +1388,7 @@ where
                 &preprocessor,
                 &protocol_version,
                 &protocol_data,
+                &self.config,
                 phase,
             ) {
                 Ok(metadata) => metadata,
@@ -1237,11 +1404,13 @@ where
                 mut payment_named_keys,
                 payment_package,
                 payment_entry_point,
+                payment_args,
                 is_standard_payment,
             ) = match payment_metadata {
                 DeployMetadata::System {
                     contract_package,
                     entry_point,
+                    args,
                     ..
                 } => (
                     system_module.clone(),
@@ -1249,18 +1418,21 @@ where
                     account.named_keys().clone(), // standard payment uses account keys
                     contract_package,
                     entry_point,
+                    args,
                     true,
                 ),
                 DeployMetadata::Session {
                     module,
                     contract_package,
                     entry_point,
+                    args,
                 } => (
                     module,
                     base_key, // this is account key
                     account.named_keys().clone(),
                     contract_package,
                     entry_point,
+                    args,
                     false,
                 ),
                 DeployMetadata::Contract {
@@ -1269,17 +1441,18 @@ where
                     contract,
                     contract_package,
                     entry_point,
+                    args,
                 } => (
                     module,
                     base_key, // this is contract key
                     contract.named_keys().clone(),
                     contract_package,
                     entry_point,
+                    args,
                     false,
                 ),
             };
 
-            let payment_args = payment.args().clone();
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
             if is_standard_payment {
@@ -1311,6 +1484,7 @@ where
                     authorization_keys.clone(),
                     blocktime,
                     deploy_hash,
+                    accumulated_seed,
                     payment_gas_limit,
                     protocol_version,
                     correlation_id,
@@ -1405,6 +1579,27 @@ where
                 }
             };
 
+            let handle_payment_contract = match tracking_copy
+                .borrow_mut()
+                .get_contract(correlation_id, protocol_data.handle_payment())
+            {
+                Ok(contract) => contract,
+                Err(error) => {
+                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                }
+            };
+
+            let fee_target = match self.get_fee_target(
+                correlation_id,
+                Rc::clone(&tracking_copy),
+                &protocol_data,
+                &handle_payment_contract,
+                proposer_main_purse_balance_key,
+            ) {
+                Ok(fee_target) => fee_target,
+                Err(error) => return Ok(ExecutionResult::precondition_failure(error)),
+            };
+
             let error = match forced_transfer {
                 ForcedTransferResult::InsufficientPayment => Error::InsufficientPayment,
                 ForcedTransferResult::GasConversionOverflow => Error::GasConversionOverflow,
@@ -1428,7 +1623,7 @@ where
                 account_main_purse_balance,
                 gas_cost,
                 account_main_purse_balance_key,
-                proposer_main_purse_balance_key,
+                fee_target,
             ) {
                 Ok(execution_result) => return Ok(execution_result),
                 Err(error) => {
@@ -1452,12 +1647,14 @@ where
             mut session_named_keys,
             session_package,
             session_entry_point,
+            session_args,
         ) = match session_metadata {
             DeployMetadata::System {
                 base_key,
                 contract,
                 contract_package,
                 entry_point,
+                args,
             } => {
                 (
                     system_module.clone(),
@@ -1465,18 +1662,21 @@ where
                     contract.named_keys().clone(),
                     contract_package,
                     entry_point,
+                    args,
                 )
             }
             DeployMetadata::Session {
                 module,
                 contract_package,
                 entry_point,
+                args,
             } => (
                 module,
                 base_key,
                 account.named_keys().clone(),
                 contract_package,
                 entry_point,
+                args,
             ),
             DeployMetadata::Contract {
                 module,
@@ -1484,16 +1684,17 @@ where
                 contract,
                 contract_package,
                 entry_point,
+                args,
             } => (
                 module,
                 base_key,
                 contract.named_keys().clone(),
                 contract_package,
                 entry_point,
+                args,
             ),
         };
 
-        let session_args = session.args().clone();
         let mut session_result = {
             // payment_code_spec_3_b_i: if (balance of handle payment pay purse) >= (gas spent
             // during payment code execution) * gas_price, yes session
@@ -1523,6 +1724,7 @@ where
                 authorization_keys.clone(),
                 blocktime,
                 deploy_hash,
+                accumulated_seed,
                 session_gas_limit,
                 protocol_version,
                 correlation_id,
@@ -1699,6 +1901,45 @@ where
             .map_err(Error::from)
     }
 
+    /// Performs a step of removing global state which is unreachable from the retained roots.
+    pub fn prune_tries(
+        &self,
+        correlation_id: CorrelationId,
+        retained_roots: &[Blake2bHash],
+        batch_size: usize,
+    ) -> Result<PruneResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        self.state
+            .prune_tries(correlation_id, retained_roots, batch_size)
+            .map_err(Error::from)
+    }
+
+    /// Returns up to `max_entries` values which were added, removed or modified between two state
+    /// roots, ordered by their serialized keys and starting after the key `start_after` if given.
+    pub fn get_trie_diff(
+        &self,
+        correlation_id: CorrelationId,
+        left_root: Blake2bHash,
+        right_root: Blake2bHash,
+        start_after: Option<Key>,
+        max_entries: usize,
+    ) -> Result<DiffResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        self.state
+            .diff(
+                correlation_id,
+                left_root,
+                right_root,
+                start_after,
+                max_entries,
+            )
+            .map_err(Error::from)
+    }
+
     /// Obtains validator weights for given era.
     pub fn get_era_validators(
         &self,
@@ -1897,6 +2138,56 @@ where
             }
         };
 
+        let handle_payment_contract = match tracking_copy
+            .borrow_mut()
+            .get_contract(correlation_id, protocol_data.handle_payment())
+        {
+            Ok(contract) => contract,
+            Err(error) => {
+                return Ok(StepResult::GetContractError(error.into()));
+            }
+        };
+
+        let distribute_fees_args = {
+            let validator_public_keys: Vec<PublicKey> = reward_factors.keys().cloned().collect();
+            let maybe_runtime_args = RuntimeArgs::try_new(|args| {
+                args.insert(
+                    handle_payment::ARG_VALIDATOR_PUBLIC_KEYS,
+                    validator_public_keys,
+                )?;
+                Ok(())
+            });
+
+            match maybe_runtime_args {
+                Ok(runtime_args) => runtime_args,
+                Err(error) => return Ok(StepResult::CLValueError(error)),
+            }
+        };
+
+        let (_, execution_result): (Option<()>, ExecutionResult) = executor.exec_system_contract(
+            DirectSystemContractCall::DistributeAccumulatedFees,
+            system_module.clone(),
+            distribute_fees_args,
+            &mut handle_payment_contract.named_keys().to_owned(),
+            Default::default(),
+            Key::from(protocol_data.handle_payment()),
+            &virtual_system_account,
+            authorization_keys.clone(),
+            BlockTime::default(),
+            deploy_hash,
+            gas_limit,
+            step_request.protocol_version,
+            correlation_id,
+            Rc::clone(&tracking_copy),
+            Phase::Session,
+            protocol_data,
+            SystemContractCache::clone(&self.system_contract_cache),
+        );
+
+        if let Some(exec_error) = execution_result.take_error() {
+            return Ok(StepResult::DistributeAccumulatedFeesError(exec_error));
+        }
+
         let reward_args = {
             let maybe_runtime_args = RuntimeArgs::try_new(|args| {
                 args.insert(ARG_REWARD_FACTORS, reward_factors)?;
@@ -115,6 +115,7 @@ pub enum StepResult {
     SlashingError(Error),
     AuctionError(Error),
     DistributeError(Error),
+    DistributeAccumulatedFeesError(Error),
     InvalidProtocolVersion,
     KeyNotFound(Key),
     TypeMismatch(TypeMismatch),
@@ -5,7 +5,7 @@ use thiserror::Error;
 
 use casper_types::{
     bytesrepr,
-    system::{AUCTION, HANDLE_PAYMENT, MINT, STANDARD_PAYMENT},
+    system::{handle_payment::FeeHandling, AUCTION, HANDLE_PAYMENT, MINT, STANDARD_PAYMENT},
     ContractHash, Key, ProtocolVersion,
 };
 
@@ -87,6 +87,8 @@ pub struct UpgradeConfig {
     new_locked_funds_period_millis: Option<u64>,
     new_round_seigniorage_rate: Option<Ratio<u64>>,
     new_unbonding_delay: Option<u64>,
+    new_refund_ratio: Option<Ratio<u64>>,
+    new_fee_handling: Option<FeeHandling>,
     global_state_update: BTreeMap<Key, StoredValue>,
 }
 
@@ -104,6 +106,8 @@ impl UpgradeConfig {
         new_locked_funds_period_millis: Option<u64>,
         new_round_seigniorage_rate: Option<Ratio<u64>>,
         new_unbonding_delay: Option<u64>,
+        new_refund_ratio: Option<Ratio<u64>>,
+        new_fee_handling: Option<FeeHandling>,
         global_state_update: BTreeMap<Key, StoredValue>,
     ) -> Self {
         UpgradeConfig {
@@ -118,6 +122,8 @@ impl UpgradeConfig {
             new_locked_funds_period_millis,
             new_round_seigniorage_rate,
             new_unbonding_delay,
+            new_refund_ratio,
+            new_fee_handling,
             global_state_update,
         }
     }
@@ -166,6 +172,14 @@ impl UpgradeConfig {
         self.new_unbonding_delay
     }
 
+    pub fn new_refund_ratio(&self) -> Option<Ratio<u64>> {
+        self.new_refund_ratio
+    }
+
+    pub fn new_fee_handling(&self) -> Option<FeeHandling> {
+        self.new_fee_handling
+    }
+
     pub fn global_state_update(&self) -> &BTreeMap<Key, StoredValue> {
         &self.global_state_update
     }
@@ -84,6 +84,15 @@ pub enum Error {
     InvalidContractVersion(ContractVersionKey),
     #[error("No such method: {}", _0)]
     NoSuchMethod(String),
+    #[error("Missing argument: {}", _0)]
+    MissingArgument(String),
+    #[error("Unexpected argument: {}", _0)]
+    UnexpectedArgument(String),
+    #[error("Invalid argument {name}: {mismatch}")]
+    InvalidArgumentType {
+        name: String,
+        mismatch: TypeMismatch,
+    },
     #[error("Wasm preprocessing error: {}", _0)]
     WasmPreprocessing(wasm_prep::PreprocessingError),
     #[error("Unexpected Key length. Expected length {expected} but actual length is {actual}")]
@@ -19,12 +19,20 @@ use crate::{
             execution_effect::ExecutionEffect, execution_result::ExecutionResult,
             system_contract_cache::SystemContractCache, EngineConfig,
         },
-        execution::{address_generator::AddressGenerator, Error},
-        runtime::{extract_access_rights_from_keys, instance_and_memory, Runtime},
+        execution::{
+            address_generator::{AddressGenerator, AddressGeneratorBuilder},
+            Error,
+        },
+        runtime::{extract_access_rights_from_keys, gas_profiler, instance_and_memory, Runtime},
         runtime_context::{self, RuntimeContext},
         tracking_copy::TrackingCopy,
     },
-    shared::{account::Account, gas::Gas, newtypes::CorrelationId, stored_value::StoredValue},
+    shared::{
+        account::Account,
+        gas::Gas,
+        newtypes::{Blake2bHash, CorrelationId},
+        stored_value::StoredValue,
+    },
     storage::{global_state::StateReader, protocol_data::ProtocolData},
 };
 
@@ -96,6 +104,7 @@ impl Executor {
         authorization_keys: BTreeSet<AccountHash>,
         blocktime: BlockTime,
         deploy_hash: DeployHash,
+        accumulated_seed: Blake2bHash,
         gas_limit: Gas,
         protocol_version: ProtocolVersion,
         correlation_id: CorrelationId,
@@ -113,6 +122,10 @@ impl Executor {
         let entry_point_type = entry_point.entry_point_type();
         let entry_point_access = entry_point.access();
 
+        let _phase_scope = gas_profiler::phase(phase);
+        let _entry_point_scope =
+            gas_profiler::wasm_entry_point(|| entry_point_name.to_string(), &module);
+
         let (instance, memory) = on_fail_charge!(instance_and_memory(
             module.clone(),
             protocol_version,
@@ -136,6 +149,14 @@ impl Executor {
             let generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
             Rc::new(RefCell::new(generator))
         };
+        let random_bytes_generator = {
+            let generator = AddressGeneratorBuilder::new()
+                .seed_with(accumulated_seed.as_ref())
+                .seed_with(deploy_hash.as_bytes())
+                .seed_with(&[phase as u8])
+                .build();
+            Rc::new(RefCell::new(generator))
+        };
         let gas_counter: Gas = Gas::default();
         let transfers = Vec::default();
 
@@ -159,6 +180,7 @@ impl Executor {
             hash_address_generator,
             uref_address_generator,
             target_address_generator,
+            random_bytes_generator,
             protocol_version,
             correlation_id,
             phase,
@@ -301,6 +323,12 @@ impl Executor {
             let generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
             Rc::new(RefCell::new(generator))
         };
+        // System contracts are executed natively and never request random bytes, so this generator
+        // doesn't need to be seeded from the block.
+        let random_bytes_generator = {
+            let generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
+            Rc::new(RefCell::new(generator))
+        };
 
         let mut runtime = match self.create_runtime(
             system_module,
@@ -317,6 +345,7 @@ impl Executor {
             hash_address_generator,
             uref_address_generator,
             transfer_address_generator,
+            random_bytes_generator,
             protocol_version,
             correlation_id,
             Rc::clone(&tracking_copy),
@@ -377,6 +406,8 @@ impl Executor {
         R::Error: Into<Error>,
         T: FromBytes + CLTyped,
     {
+        let _phase_scope = gas_profiler::phase(phase);
+
         match direct_system_contract_call {
             DirectSystemContractCall::Slash
             | DirectSystemContractCall::RunAuction
@@ -389,6 +420,7 @@ impl Executor {
                 }
             }
             DirectSystemContractCall::FinalizePayment
+            | DirectSystemContractCall::DistributeAccumulatedFees
             | DirectSystemContractCall::GetPaymentPurse => {
                 if Some(protocol_data.handle_payment().value()) != base_key.into_hash() {
                     panic!(
@@ -427,6 +459,12 @@ impl Executor {
             let generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
             Rc::new(RefCell::new(generator))
         };
+        // System contracts are executed natively and never request random bytes, so this generator
+        // doesn't need to be seeded from the block.
+        let random_bytes_generator = {
+            let generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
+            Rc::new(RefCell::new(generator))
+        };
         let gas_counter = Gas::default(); // maybe const?
 
         // Snapshot of effects before execution, so in case of error only nonce update
@@ -450,6 +488,7 @@ impl Executor {
             hash_address_generator,
             uref_address_generator,
             transfer_address_generator,
+            random_bytes_generator,
             protocol_version,
             correlation_id,
             tracking_copy,
@@ -497,6 +536,7 @@ impl Executor {
         hash_address_generator: Rc<RefCell<AddressGenerator>>,
         uref_address_generator: Rc<RefCell<AddressGenerator>>,
         transfer_address_generator: Rc<RefCell<AddressGenerator>>,
+        random_bytes_generator: Rc<RefCell<AddressGenerator>>,
         protocol_version: ProtocolVersion,
         correlation_id: CorrelationId,
         tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
@@ -527,6 +567,7 @@ impl Executor {
             hash_address_generator,
             uref_address_generator,
             transfer_address_generator,
+            random_bytes_generator,
             protocol_version,
             correlation_id,
             tracking_copy,
@@ -584,6 +625,7 @@ impl Executor {
         hash_address_generator: Rc<RefCell<AddressGenerator>>,
         uref_address_generator: Rc<RefCell<AddressGenerator>>,
         transfer_address_generator: Rc<RefCell<AddressGenerator>>,
+        random_bytes_generator: Rc<RefCell<AddressGenerator>>,
         protocol_version: ProtocolVersion,
         correlation_id: CorrelationId,
         tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
@@ -620,6 +662,7 @@ impl Executor {
             hash_address_generator,
             uref_address_generator,
             transfer_address_generator,
+            random_bytes_generator,
             protocol_version,
             correlation_id,
             phase,
@@ -650,6 +693,7 @@ pub enum DirectSystemContractCall {
     RunAuction,
     DistributeRewards,
     FinalizePayment,
+    DistributeAccumulatedFees,
     CreatePurse,
     Transfer,
     GetEraValidators,
@@ -663,6 +707,9 @@ impl DirectSystemContractCall {
             DirectSystemContractCall::RunAuction => auction::METHOD_RUN_AUCTION,
             DirectSystemContractCall::DistributeRewards => auction::METHOD_DISTRIBUTE,
             DirectSystemContractCall::FinalizePayment => handle_payment::METHOD_FINALIZE_PAYMENT,
+            DirectSystemContractCall::DistributeAccumulatedFees => {
+                handle_payment::METHOD_DISTRIBUTE_ACCUMULATED_FEES
+            }
             DirectSystemContractCall::CreatePurse => mint::METHOD_CREATE,
             DirectSystemContractCall::Transfer => mint::METHOD_TRANSFER,
             DirectSystemContractCall::GetEraValidators => auction::METHOD_GET_ERA_VALIDATORS,
@@ -695,13 +742,15 @@ impl DirectSystemContractCall {
                 runtime_args,
                 extra_keys,
             ),
-            DirectSystemContractCall::FinalizePayment => runtime.call_host_handle_payment(
-                protocol_version,
-                entry_point_name,
-                named_keys,
-                runtime_args,
-                extra_keys,
-            ),
+            DirectSystemContractCall::FinalizePayment
+            | DirectSystemContractCall::DistributeAccumulatedFees => runtime
+                .call_host_handle_payment(
+                    protocol_version,
+                    entry_point_name,
+                    named_keys,
+                    runtime_args,
+                    extra_keys,
+                ),
             DirectSystemContractCall::CreatePurse | DirectSystemContractCall::Transfer => runtime
                 .call_host_mint(
                     protocol_version,
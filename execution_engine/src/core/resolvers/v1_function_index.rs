@@ -50,6 +50,14 @@ pub enum FunctionIndex {
     Blake2b,
     RecordTransfer,
     RecordEraInfo,
+    NewDictionaryFuncIndex,
+    DictionaryGetFuncIndex,
+    DictionaryPutFuncIndex,
+    EmitEventFuncIndex,
+    Sha256FuncIndex,
+    Keccak256FuncIndex,
+    VerifySignatureFuncIndex,
+    RandomBytesFuncIndex,
 }
 
 impl Into<usize> for FunctionIndex {
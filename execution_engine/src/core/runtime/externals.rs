@@ -1,5 +1,7 @@
 use std::{collections::BTreeSet, convert::TryFrom};
 
+use sha2::Sha256;
+use sha3::Keccak256;
 use wasmi::{Externals, RuntimeArgs, RuntimeValue, Trap};
 
 use casper_types::{
@@ -12,7 +14,7 @@ use casper_types::{
     ContractHash, ContractPackageHash, ContractVersion, Group, Key, URef, U512,
 };
 
-use super::{args::Args, scoped_instrumenter::ScopedInstrumenter, Error, Runtime};
+use super::{args::Args, gas_profiler, scoped_instrumenter::ScopedInstrumenter, Error, Runtime};
 use crate::{
     core::resolvers::v1_function_index::FunctionIndex,
     shared::{gas::Gas, host_function_costs::Cost, stored_value::StoredValue},
@@ -31,6 +33,7 @@ where
     ) -> Result<Option<RuntimeValue>, Trap> {
         let func = FunctionIndex::try_from(index).expect("unknown function index");
         let mut scoped_instrumenter = ScopedInstrumenter::new(func);
+        let _profiler_scope = gas_profiler::host_function(func);
 
         let host_function_costs = self
             .protocol_data()
@@ -201,6 +204,10 @@ where
 
             FunctionIndex::GasFuncIndex => {
                 let gas_arg: u32 = Args::parse(args)?;
+                // Profiling markers are passed through the gas import and are never charged.
+                if gas_profiler::handle_marker(gas_arg) {
+                    return Ok(None);
+                }
                 // Gas is special cased internal host function and for accounting purposes it isn't
                 // represented in protocol data.
                 self.gas(Gas::new(gas_arg.into()))?;
@@ -968,6 +975,177 @@ where
                 self.record_era_info(era_id, era_info)?;
                 Ok(Some(RuntimeValue::I32(0)))
             }
+
+            FunctionIndex::NewDictionaryFuncIndex => {
+                // args(0) = pointer to output size (output param)
+                let output_size_ptr: u32 = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.new_dictionary,
+                    [output_size_ptr],
+                )?;
+                let ret = self.new_dictionary(output_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::DictionaryGetFuncIndex => {
+                // args(0) = pointer to seed uref in Wasm memory
+                // args(1) = size of seed uref in Wasm memory
+                // args(2) = pointer to dictionary item key in Wasm memory
+                // args(3) = size of dictionary item key in Wasm memory
+                // args(4) = pointer to output size (output param)
+                let (uref_ptr, uref_size, key_bytes_ptr, key_bytes_size, output_size_ptr) =
+                    Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.dictionary_get,
+                    [
+                        uref_ptr,
+                        uref_size,
+                        key_bytes_ptr,
+                        key_bytes_size,
+                        output_size_ptr,
+                    ],
+                )?;
+                scoped_instrumenter.add_property("key_bytes_size", key_bytes_size);
+                let ret = self.dictionary_get(
+                    uref_ptr,
+                    uref_size,
+                    key_bytes_ptr,
+                    key_bytes_size,
+                    output_size_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::DictionaryPutFuncIndex => {
+                // args(0) = pointer to seed uref in Wasm memory
+                // args(1) = size of seed uref in Wasm memory
+                // args(2) = pointer to dictionary item key in Wasm memory
+                // args(3) = size of dictionary item key in Wasm memory
+                // args(4) = pointer to value in Wasm memory
+                // args(5) = size of value in Wasm memory
+                let (uref_ptr, uref_size, key_bytes_ptr, key_bytes_size, value_ptr, value_size) =
+                    Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.dictionary_put,
+                    [
+                        uref_ptr,
+                        uref_size,
+                        key_bytes_ptr,
+                        key_bytes_size,
+                        value_ptr,
+                        value_size,
+                    ],
+                )?;
+                scoped_instrumenter.add_property("key_bytes_size", key_bytes_size);
+                scoped_instrumenter.add_property("value_size", value_size);
+                let ret = self.dictionary_put(
+                    uref_ptr,
+                    uref_size,
+                    key_bytes_ptr,
+                    key_bytes_size,
+                    value_ptr,
+                    value_size,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::EmitEventFuncIndex => {
+                // args(0) = pointer to event name in Wasm memory
+                // args(1) = size of event name in Wasm memory
+                // args(2) = pointer to event payload in Wasm memory
+                // args(3) = size of event payload in Wasm memory
+                let (name_ptr, name_size, payload_ptr, payload_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.emit_event,
+                    [name_ptr, name_size, payload_ptr, payload_size],
+                )?;
+                scoped_instrumenter.add_property("name_size", name_size);
+                scoped_instrumenter.add_property("payload_size", payload_size);
+                let ret = self.emit_event(name_ptr, name_size, payload_ptr, payload_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::Sha256FuncIndex => {
+                // args(0) = pointer to input bytes in Wasm memory
+                // args(1) = size of input bytes in Wasm memory
+                // args(2) = pointer to output buffer in Wasm memory
+                // args(3) = size of output buffer in Wasm memory
+                let (in_ptr, in_size, out_ptr, out_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.sha256,
+                    [in_ptr, in_size, out_ptr, out_size],
+                )?;
+                scoped_instrumenter.add_property("in_size", in_size);
+                scoped_instrumenter.add_property("out_size", out_size);
+                let ret = self.digest::<Sha256>(in_ptr, in_size, out_ptr, out_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::Keccak256FuncIndex => {
+                // args(0) = pointer to input bytes in Wasm memory
+                // args(1) = size of input bytes in Wasm memory
+                // args(2) = pointer to output buffer in Wasm memory
+                // args(3) = size of output buffer in Wasm memory
+                let (in_ptr, in_size, out_ptr, out_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.keccak256,
+                    [in_ptr, in_size, out_ptr, out_size],
+                )?;
+                scoped_instrumenter.add_property("in_size", in_size);
+                scoped_instrumenter.add_property("out_size", out_size);
+                let ret = self.digest::<Keccak256>(in_ptr, in_size, out_ptr, out_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::VerifySignatureFuncIndex => {
+                // args(0) = pointer to message in Wasm memory
+                // args(1) = size of message in Wasm memory
+                // args(2) = pointer to serialized signature in Wasm memory
+                // args(3) = size of serialized signature in Wasm memory
+                // args(4) = pointer to serialized public key in Wasm memory
+                // args(5) = size of serialized public key in Wasm memory
+                let (
+                    message_ptr,
+                    message_size,
+                    signature_ptr,
+                    signature_size,
+                    public_key_ptr,
+                    public_key_size,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.verify_signature,
+                    [
+                        message_ptr,
+                        message_size,
+                        signature_ptr,
+                        signature_size,
+                        public_key_ptr,
+                        public_key_size,
+                    ],
+                )?;
+                scoped_instrumenter.add_property("message_size", message_size);
+                let is_valid = self.verify_signature(
+                    message_ptr,
+                    message_size,
+                    signature_ptr,
+                    signature_size,
+                    public_key_ptr,
+                    public_key_size,
+                )?;
+                Ok(Some(RuntimeValue::I32(i32::from(is_valid))))
+            }
+
+            FunctionIndex::RandomBytesFuncIndex => {
+                // args(0) = pointer to output buffer in Wasm memory
+                // args(1) = size of output buffer in Wasm memory
+                let (out_ptr, out_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.random_bytes,
+                    [out_ptr, out_size],
+                )?;
+                let ret = self.random_bytes(out_ptr, out_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
         }
     }
 }
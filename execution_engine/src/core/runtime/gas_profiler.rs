@@ -0,0 +1,632 @@
+//! Opt-in attribution of gas costs to the code which incurred them.
+//!
+//! While profiling is active on the current thread (see [`start`] and [`finish`]) every gas charge
+//! made through the runtime is recorded against the current call stack.  The stack is built from
+//! the executed entry points, the Wasm functions of the executing module, the host functions
+//! called by those and a final `storage` frame for charges based on the number of bytes written
+//! to global state.
+//!
+//! Wasm functions are tracked by instrumenting each module as it is instantiated: every function
+//! body is prefixed with a call to the `gas` import carrying an "entered function" marker and
+//! every call to another defined function is followed by a "resumed function" marker.  The
+//! markers are never charged and are only injected while profiling is active, so neither the
+//! stored contract bytes nor the results of ordinary execution are affected.  Function names are
+//! taken from the module's name section where present, and rendered as `func[<index>]` otherwise.
+//!
+//! Profiling should only be used in tests: the injected markers slow execution down considerably.
+
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+    rc::Rc,
+};
+
+use parity_wasm::elements::{External, Instruction, Module, Section};
+
+use casper_types::Phase;
+
+use crate::{core::resolvers::v1_function_index::FunctionIndex, shared::gas::Gas};
+
+/// Name of the custom section holding debug names of a Wasm module.
+const NAME_SECTION: &str = "name";
+/// Id of the name section subsection holding function names.
+const FUNCTION_NAMES_SUBSECTION: u8 = 1;
+/// Name of the frame used for gas charged for bytes written to global state.
+const STORAGE_FRAME: &str = "storage";
+/// Name of the frame used for gas charged outside of any tracked entry point.
+const UNATTRIBUTED_FRAME: &str = "[unattributed]";
+
+/// Set on arguments to the `gas` import which are profiling markers rather than gas amounts.
+const MARKER_FLAG: u32 = 1 << 31;
+/// Set on markers emitted after a call returns to the calling function.
+const RESUME_FLAG: u32 = 1 << 30;
+/// Mask for the index of the defined function emitting a marker.
+const FUNCTION_MASK: u32 = RESUME_FLAG - 1;
+
+thread_local! {
+    static PROFILER: RefCell<Option<Profiler>> = RefCell::new(None);
+}
+
+/// Starts profiling gas usage on the current thread, discarding any profile still in progress.
+pub fn start() {
+    PROFILER.with(|profiler| *profiler.borrow_mut() = Some(Profiler::default()));
+}
+
+/// Stops profiling gas usage on the current thread, returning the collected profile.
+///
+/// Returns `None` if profiling was not started.
+pub fn finish() -> Option<GasProfile> {
+    PROFILER.with(|profiler| {
+        profiler.borrow_mut().take().map(|profiler| GasProfile {
+            samples: profiler.samples,
+        })
+    })
+}
+
+/// Returns `true` if gas is being profiled on the current thread.
+pub fn is_active() -> bool {
+    PROFILER.with(|profiler| profiler.borrow().is_some())
+}
+
+/// Gas usage broken down by the call stack which incurred it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GasProfile {
+    samples: BTreeMap<Vec<String>, Gas>,
+}
+
+impl GasProfile {
+    /// Returns the total gas recorded in this profile.
+    pub fn total(&self) -> Gas {
+        self.samples.values().copied().sum()
+    }
+
+    /// Returns the gas charged directly in each distinct call stack, outermost frame first.
+    pub fn samples(&self) -> impl Iterator<Item = (&[String], Gas)> {
+        self.samples
+            .iter()
+            .map(|(stack, gas)| (stack.as_slice(), *gas))
+    }
+
+    /// Returns the gas charged directly in each frame, excluding the frames it called.
+    pub fn self_costs(&self) -> BTreeMap<&str, Gas> {
+        let mut self_costs = BTreeMap::new();
+        for (stack, gas) in self.samples() {
+            if let Some(frame) = stack.last() {
+                *self_costs
+                    .entry(frame.as_str())
+                    .or_insert_with(Gas::default) += gas;
+            }
+        }
+        self_costs
+    }
+
+    /// Returns the gas charged in each frame including the frames it called.
+    ///
+    /// Recursive frames are only counted once per call stack.
+    pub fn total_costs(&self) -> BTreeMap<&str, Gas> {
+        let mut total_costs = BTreeMap::new();
+        for (stack, gas) in self.samples() {
+            let mut frames: Vec<&str> = stack.iter().map(String::as_str).collect();
+            frames.sort_unstable();
+            frames.dedup();
+            for frame in frames {
+                *total_costs.entry(frame).or_insert_with(Gas::default) += gas;
+            }
+        }
+        total_costs
+    }
+
+    /// Renders the profile in the "folded stacks" format understood by flamegraph tools: one line
+    /// per call stack with frames separated by `;`, followed by a space and the gas charged.
+    pub fn to_folded_stacks(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for GasProfile {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        for (stack, gas) in self.samples() {
+            let frames: Vec<String> = stack.iter().map(|frame| frame.replace(';', ":")).collect();
+            writeln!(formatter, "{} {}", frames.join(";"), gas)?;
+        }
+        Ok(())
+    }
+}
+
+/// Pops the frames pushed since its creation, or resumes recording, when dropped.
+#[must_use]
+pub(crate) struct Scope(Option<ScopeKind>);
+
+enum ScopeKind {
+    Frame { depth: usize },
+    Suspension,
+}
+
+impl Scope {
+    fn inactive() -> Self {
+        Scope(None)
+    }
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        let kind = match self.0.take() {
+            Some(kind) => kind,
+            None => return,
+        };
+        with_profiler(|profiler| match kind {
+            ScopeKind::Frame { depth } => profiler.stack.truncate(depth),
+            ScopeKind::Suspension => profiler.suspended = profiler.suspended.saturating_sub(1),
+        });
+    }
+}
+
+/// Pushes a frame for a Wasm entry point of `module`, the name of which is lazily produced by
+/// `name`.
+pub(crate) fn wasm_entry_point<F: FnOnce() -> String>(name: F, module: &Module) -> Scope {
+    push_frame(|| Frame {
+        name: name(),
+        kind: FrameKind::EntryPoint {
+            function_names: Rc::new(function_names(module)),
+        },
+    })
+}
+
+/// Pushes a frame for an entry point of a system contract implemented by the host.
+pub(crate) fn system_entry_point(contract_name: &str, entry_point_name: &str) -> Scope {
+    push_frame(|| Frame {
+        name: format!("{}::{}", contract_name, entry_point_name),
+        kind: FrameKind::EntryPoint {
+            function_names: Rc::default(),
+        },
+    })
+}
+
+/// Pushes a frame for the given execution phase.
+pub(crate) fn phase(phase: Phase) -> Scope {
+    let name = match phase {
+        Phase::System => "system",
+        Phase::Payment => "payment",
+        Phase::Session => "session",
+        Phase::FinalizePayment => "finalize_payment",
+    };
+    push_frame(|| Frame::other(name))
+}
+
+/// Pushes a frame for a host function called from Wasm.
+pub(crate) fn host_function(function_index: FunctionIndex) -> Scope {
+    if function_index == FunctionIndex::GasFuncIndex {
+        return Scope::inactive();
+    }
+    push_frame(|| Frame::other(host_function_name(function_index)))
+}
+
+/// Pushes a frame for gas charged for bytes written to global state.
+pub(crate) fn storage() -> Scope {
+    push_frame(|| Frame::other(STORAGE_FRAME))
+}
+
+/// Stops recording gas charges until dropped.
+///
+/// Used where gas is charged twice (e.g. once in a nested runtime and again in its parent) or
+/// where the charged gas is subsequently discarded, so that the profile only reflects the gas
+/// actually paid.
+pub(crate) fn suspend() -> Scope {
+    with_profiler(|profiler| {
+        profiler.suspended += 1;
+        Scope(Some(ScopeKind::Suspension))
+    })
+    .unwrap_or_else(Scope::inactive)
+}
+
+/// Records `amount` of gas against the current call stack.
+pub(crate) fn record(amount: Gas) {
+    if amount == Gas::default() {
+        return;
+    }
+    with_profiler(|profiler| {
+        if profiler.suspended > 0 {
+            return;
+        }
+        let mut stack: Vec<String> = profiler
+            .stack
+            .iter()
+            .map(|frame| frame.name.clone())
+            .collect();
+        if stack.is_empty() {
+            stack.push(UNATTRIBUTED_FRAME.to_string());
+        }
+        *profiler.samples.entry(stack).or_insert_with(Gas::default) += amount;
+    });
+}
+
+/// Handles an argument passed to the `gas` import, returning `true` if it was a profiling marker
+/// rather than an amount of gas to charge.
+pub(crate) fn handle_marker(gas_arg: u32) -> bool {
+    if gas_arg & MARKER_FLAG == 0 {
+        return false;
+    }
+    with_profiler(|profiler| {
+        let function = gas_arg & FUNCTION_MASK;
+        if gas_arg & RESUME_FLAG == 0 {
+            profiler.enter_wasm_function(function);
+        } else {
+            profiler.resume_wasm_function(function);
+        }
+    })
+    .is_some()
+}
+
+/// Injects profiling markers into `module` if profiling is active, returning it unchanged
+/// otherwise.
+pub(crate) fn instrument(mut module: Module) -> Module {
+    if !is_active() {
+        return module;
+    }
+
+    let mut imported_functions = 0;
+    let mut gas_function = None;
+    if let Some(import_section) = module.import_section() {
+        for entry in import_section.entries() {
+            if let External::Function(_) = entry.external() {
+                if entry.module() == "env" && entry.field() == "gas" {
+                    gas_function = Some(imported_functions);
+                }
+                imported_functions += 1;
+            }
+        }
+    }
+    let gas_function = match gas_function {
+        Some(gas_function) => gas_function,
+        None => return module,
+    };
+
+    if let Some(code_section) = module.code_section_mut() {
+        for (index, body) in code_section.bodies_mut().iter_mut().enumerate() {
+            let index = index as u32 & FUNCTION_MASK;
+            let enter = marker(index);
+            let resume = marker(index | RESUME_FLAG);
+            let instructions = body.code_mut().elements_mut();
+            let mut instrumented = Vec::with_capacity(instructions.len() + 2);
+            instrumented.push(enter.clone());
+            instrumented.push(Instruction::Call(gas_function));
+            for instruction in instructions.drain(..) {
+                let calls_defined_function = match instruction {
+                    Instruction::Call(callee) => callee >= imported_functions,
+                    Instruction::CallIndirect(..) => true,
+                    _ => false,
+                };
+                instrumented.push(instruction);
+                if calls_defined_function {
+                    instrumented.push(resume.clone());
+                    instrumented.push(Instruction::Call(gas_function));
+                }
+            }
+            *instructions = instrumented;
+        }
+    }
+
+    module
+}
+
+fn marker(value: u32) -> Instruction {
+    Instruction::I32Const((MARKER_FLAG | value) as i32)
+}
+
+fn push_frame<F: FnOnce() -> Frame>(frame: F) -> Scope {
+    with_profiler(|profiler| {
+        let depth = profiler.stack.len();
+        profiler.stack.push(frame());
+        Scope(Some(ScopeKind::Frame { depth }))
+    })
+    .unwrap_or_else(Scope::inactive)
+}
+
+fn with_profiler<T, F: FnOnce(&mut Profiler) -> T>(f: F) -> Option<T> {
+    PROFILER.with(|profiler| profiler.borrow_mut().as_mut().map(f))
+}
+
+/// Returns the names of the defined functions of a preprocessed `module`, keyed by their index in
+/// the code section.
+fn function_names(module: &Module) -> BTreeMap<u32, String> {
+    let payload = module.sections().iter().find_map(|section| match section {
+        Section::Custom(custom) if custom.name() == NAME_SECTION => Some(custom.payload()),
+        _ => None,
+    });
+    let names = match payload.and_then(parse_function_names) {
+        Some(names) => names,
+        None => return BTreeMap::new(),
+    };
+
+    // The name section is left untouched by preprocessing, so its indices predate the `gas`
+    // import, which is appended to the imported functions.
+    let imported_functions = module
+        .import_section()
+        .map(|import_section| {
+            import_section
+                .entries()
+                .iter()
+                .filter(|entry| matches!(entry.external(), External::Function(_)))
+                .count() as u32
+        })
+        .unwrap_or_default();
+    let original_imported_functions = imported_functions.saturating_sub(1);
+
+    names
+        .into_iter()
+        .filter_map(|(index, name)| {
+            index
+                .checked_sub(original_imported_functions)
+                .map(|defined_index| (defined_index, name))
+        })
+        .collect()
+}
+
+/// Parses the function names subsection of a name section's payload.
+fn parse_function_names(payload: &[u8]) -> Option<BTreeMap<u32, String>> {
+    let mut position = 0;
+    while position < payload.len() {
+        let id = *payload.get(position)?;
+        position += 1;
+        let size = read_var_u32(payload, &mut position)? as usize;
+        let end = position.checked_add(size)?;
+        if id != FUNCTION_NAMES_SUBSECTION {
+            position = end;
+            continue;
+        }
+
+        let subsection = payload.get(position..end)?;
+        let mut position = 0;
+        let count = read_var_u32(subsection, &mut position)?;
+        let mut names = BTreeMap::new();
+        for _ in 0..count {
+            let index = read_var_u32(subsection, &mut position)?;
+            let length = read_var_u32(subsection, &mut position)? as usize;
+            let name = subsection.get(position..position.checked_add(length)?)?;
+            position += length;
+            names.insert(index, String::from_utf8_lossy(name).into_owned());
+        }
+        return Some(names);
+    }
+    None
+}
+
+/// Reads an unsigned LEB128-encoded `u32`, advancing `position` past it.
+fn read_var_u32(bytes: &[u8], position: &mut usize) -> Option<u32> {
+    let mut result = 0u32;
+    let mut shift = 0;
+    loop {
+        let byte = *bytes.get(*position)?;
+        *position += 1;
+        result |= u32::from(byte & 0x7f).checked_shl(shift)?;
+        if byte & 0x80 == 0 {
+            return Some(result);
+        }
+        shift += 7;
+        if shift > 28 {
+            return None;
+        }
+    }
+}
+
+fn host_function_name(function_index: FunctionIndex) -> &'static str {
+    match function_index {
+        FunctionIndex::WriteFuncIndex => "casper_write",
+        FunctionIndex::ReadFuncIndex => "casper_read_value",
+        FunctionIndex::AddFuncIndex => "casper_add",
+        FunctionIndex::NewFuncIndex => "casper_new_uref",
+        FunctionIndex::RetFuncIndex => "casper_ret",
+        FunctionIndex::CallContractFuncIndex => "casper_call_contract",
+        FunctionIndex::GetKeyFuncIndex => "casper_get_key",
+        FunctionIndex::GasFuncIndex => "gas",
+        FunctionIndex::HasKeyFuncIndex => "casper_has_key",
+        FunctionIndex::PutKeyFuncIndex => "casper_put_key",
+        FunctionIndex::IsValidURefFnIndex => "casper_is_valid_uref",
+        FunctionIndex::RevertFuncIndex => "casper_revert",
+        FunctionIndex::AddAssociatedKeyFuncIndex => "casper_add_associated_key",
+        FunctionIndex::RemoveAssociatedKeyFuncIndex => "casper_remove_associated_key",
+        FunctionIndex::UpdateAssociatedKeyFuncIndex => "casper_update_associated_key",
+        FunctionIndex::SetActionThresholdFuncIndex => "casper_set_action_threshold",
+        FunctionIndex::LoadNamedKeysFuncIndex => "casper_load_named_keys",
+        FunctionIndex::RemoveKeyFuncIndex => "casper_remove_key",
+        FunctionIndex::GetCallerIndex => "casper_get_caller",
+        FunctionIndex::GetBlocktimeIndex => "casper_get_blocktime",
+        FunctionIndex::CreatePurseIndex => "casper_create_purse",
+        FunctionIndex::TransferToAccountIndex => "casper_transfer_to_account",
+        FunctionIndex::TransferFromPurseToAccountIndex => "casper_transfer_from_purse_to_account",
+        FunctionIndex::TransferFromPurseToPurseIndex => "casper_transfer_from_purse_to_purse",
+        FunctionIndex::GetBalanceIndex => "casper_get_balance",
+        FunctionIndex::GetPhaseIndex => "casper_get_phase",
+        FunctionIndex::GetSystemContractIndex => "casper_get_system_contract",
+        FunctionIndex::GetMainPurseIndex => "casper_get_main_purse",
+        FunctionIndex::ReadHostBufferIndex => "casper_read_host_buffer",
+        FunctionIndex::CreateContractPackageAtHash => "casper_create_contract_package_at_hash",
+        FunctionIndex::AddContractVersion => "casper_add_contract_version",
+        FunctionIndex::DisableContractVersion => "casper_disable_contract_version",
+        FunctionIndex::CallVersionedContract => "casper_call_versioned_contract",
+        FunctionIndex::CreateContractUserGroup => "casper_create_contract_user_group",
+        #[cfg(feature = "test-support")]
+        FunctionIndex::PrintIndex => "casper_print",
+        FunctionIndex::GetRuntimeArgsizeIndex => "casper_get_named_arg_size",
+        FunctionIndex::GetRuntimeArgIndex => "casper_get_named_arg",
+        FunctionIndex::RemoveContractUserGroupIndex => "casper_remove_contract_user_group",
+        FunctionIndex::ExtendContractUserGroupURefsIndex => {
+            "casper_provision_contract_user_group_uref"
+        }
+        FunctionIndex::RemoveContractUserGroupURefsIndex => {
+            "casper_remove_contract_user_group_urefs"
+        }
+        FunctionIndex::Blake2b => "casper_blake2b",
+        FunctionIndex::RecordTransfer => "casper_record_transfer",
+        FunctionIndex::RecordEraInfo => "casper_record_era_info",
+        FunctionIndex::NewDictionaryFuncIndex => "casper_new_dictionary",
+        FunctionIndex::DictionaryGetFuncIndex => "casper_dictionary_get",
+        FunctionIndex::DictionaryPutFuncIndex => "casper_dictionary_put",
+        FunctionIndex::EmitEventFuncIndex => "casper_emit_event",
+        FunctionIndex::Sha256FuncIndex => "casper_sha256",
+        FunctionIndex::Keccak256FuncIndex => "casper_keccak256",
+        FunctionIndex::VerifySignatureFuncIndex => "casper_verify_signature",
+        FunctionIndex::RandomBytesFuncIndex => "casper_random_bytes",
+    }
+}
+
+#[derive(Default)]
+struct Profiler {
+    stack: Vec<Frame>,
+    suspended: usize,
+    samples: BTreeMap<Vec<String>, Gas>,
+}
+
+impl Profiler {
+    fn enter_wasm_function(&mut self, function: u32) {
+        let name = self
+            .stack
+            .iter()
+            .rev()
+            .find_map(|frame| match &frame.kind {
+                FrameKind::EntryPoint { function_names } => function_names.get(&function).cloned(),
+                _ => None,
+            })
+            .unwrap_or_else(|| format!("func[{}]", function));
+        self.stack.push(Frame {
+            name,
+            kind: FrameKind::WasmFunction(function),
+        });
+    }
+
+    /// Pops the frames of the Wasm functions called by `function` once control returns to it.
+    fn resume_wasm_function(&mut self, function: u32) {
+        // The top frame is the function which just returned, so start looking for the caller
+        // below it, without leaving the Wasm functions of the current entry point.
+        let caller_depth = self
+            .stack
+            .iter()
+            .enumerate()
+            .rev()
+            .skip(1)
+            .take_while(|(_, frame)| matches!(frame.kind, FrameKind::WasmFunction(_)))
+            .find_map(|(depth, frame)| match frame.kind {
+                FrameKind::WasmFunction(caller) if caller == function => Some(depth),
+                _ => None,
+            });
+        if let Some(depth) = caller_depth {
+            self.stack.truncate(depth + 1);
+        }
+    }
+}
+
+struct Frame {
+    name: String,
+    kind: FrameKind,
+}
+
+impl Frame {
+    fn other(name: &str) -> Self {
+        Frame {
+            name: name.to_string(),
+            kind: FrameKind::Other,
+        }
+    }
+}
+
+enum FrameKind {
+    EntryPoint {
+        function_names: Rc<BTreeMap<u32, String>>,
+    },
+    WasmFunction(u32),
+    Other,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_profiling<F: FnOnce()>(f: F) -> GasProfile {
+        start();
+        f();
+        finish().expect("should be profiling")
+    }
+
+    #[test]
+    fn should_not_record_when_inactive() {
+        assert!(!is_active());
+        let _scope = phase(Phase::Session);
+        record(Gas::from(1u32));
+        assert!(!handle_marker(MARKER_FLAG));
+        assert!(finish().is_none());
+    }
+
+    #[test]
+    fn should_attribute_gas_to_call_stacks() {
+        let profile = with_profiling(|| {
+            let _phase = phase(Phase::Session);
+            record(Gas::from(1u32));
+            {
+                let _host_function = host_function(FunctionIndex::WriteFuncIndex);
+                let _storage = storage();
+                record(Gas::from(10u32));
+            }
+            {
+                let _suspended = suspend();
+                record(Gas::from(100u32));
+            }
+            record(Gas::from(2u32));
+        });
+
+        assert_eq!(profile.total(), Gas::from(13u32));
+        assert_eq!(
+            profile.to_folded_stacks(),
+            "session 3\nsession;casper_write;storage 10\n"
+        );
+        assert_eq!(profile.self_costs()["storage"], Gas::from(10u32));
+        assert_eq!(profile.total_costs()["session"], Gas::from(13u32));
+    }
+
+    #[test]
+    fn should_track_wasm_functions_through_markers() {
+        let profile = with_profiling(|| {
+            let _entry_point = system_entry_point("contract", "call");
+            assert!(handle_marker(marker_value(0)));
+            record(Gas::from(1u32));
+            // Recursive call into function 1, which then calls function 2.
+            assert!(handle_marker(marker_value(1)));
+            assert!(handle_marker(marker_value(1)));
+            assert!(handle_marker(marker_value(2)));
+            record(Gas::from(2u32));
+            assert!(handle_marker(marker_value(1 | RESUME_FLAG)));
+            assert!(handle_marker(marker_value(1 | RESUME_FLAG)));
+            record(Gas::from(3u32));
+            assert!(handle_marker(marker_value(RESUME_FLAG)));
+            record(Gas::from(4u32));
+            // Not a marker.
+            assert!(!handle_marker(5));
+        });
+
+        assert_eq!(
+            profile.to_folded_stacks(),
+            "contract::call;func[0] 5\n\
+             contract::call;func[0];func[1] 3\n\
+             contract::call;func[0];func[1];func[1];func[2] 2\n"
+        );
+    }
+
+    #[test]
+    fn should_parse_function_names() {
+        // Module name subsection followed by function names for indices 0 and 3.
+        let payload = [
+            0, 2, 1, b'm', //
+            1, 10, 2, 0, 2, b'f', b'0', 3, 3, b'f', b'o', b'o',
+        ];
+        let names = parse_function_names(&payload).expect("should parse names");
+        assert_eq!(names.len(), 2);
+        assert_eq!(names[&0], "f0");
+        assert_eq!(names[&3], "foo");
+
+        assert!(parse_function_names(&payload[..8]).is_none());
+    }
+
+    fn marker_value(value: u32) -> u32 {
+        MARKER_FLAG | value
+    }
+}
@@ -1,7 +1,10 @@
 use casper_types::{
     account::AccountHash,
-    system::handle_payment::{Error, HandlePayment, MintProvider, RuntimeProvider},
-    BlockTime, Key, Phase, TransferredTo, URef, U512,
+    bytesrepr::FromBytes,
+    system::handle_payment::{
+        Error, HandlePayment, MintProvider, RuntimeProvider, StorageProvider,
+    },
+    BlockTime, CLTyped, CLValue, Key, Phase, TransferredTo, URef, U512,
 };
 
 use crate::{
@@ -60,6 +63,42 @@ where
         self.get_balance(purse)
             .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::GetBalance))
     }
+
+    fn burn(&mut self, purse: URef, amount: U512) -> Result<(), Error> {
+        let balance = match MintProvider::balance(self, purse)? {
+            Some(balance) if balance >= amount => balance,
+            _ => return Err(Error::FailedBurn),
+        };
+        let cl_value = CLValue::from_t(balance - amount).map_err(|_| Error::FailedBurn)?;
+        self.context
+            .metered_write_gs_unsafe(Key::Balance(purse.addr()), StoredValue::CLValue(cl_value))
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::FailedBurn))?;
+
+        let mint_contract = self.get_mint_contract();
+        self.mint_reduce_total_supply(mint_contract, amount)
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::FailedBurn))
+    }
+}
+
+impl<'a, R> StorageProvider for Runtime<'a, R>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    fn read<T: CLTyped + FromBytes>(&mut self, uref: URef) -> Result<Option<T>, Error> {
+        let maybe_value = self
+            .context
+            .read_gs(&Key::URef(uref))
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))?;
+        match maybe_value {
+            Some(StoredValue::CLValue(value)) => {
+                let value = CLValue::into_t(value).map_err(|_| Error::Storage)?;
+                Ok(Some(value))
+            }
+            Some(_cl_value) => Err(Error::Storage),
+            None => Ok(None),
+        }
+    }
 }
 
 // TODO: Update RuntimeProvider to better handle errors
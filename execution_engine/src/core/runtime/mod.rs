@@ -1,6 +1,7 @@
 mod args;
 mod auction_internal;
 mod externals;
+pub mod gas_profiler;
 mod handle_payment_internal;
 mod mint_internal;
 mod scoped_instrumenter;
@@ -15,6 +16,7 @@ use std::{
 
 use itertools::Itertools;
 use parity_wasm::elements::Module;
+use sha2::Digest;
 use wasmi::{ImportsBuilder, MemoryRef, ModuleInstance, ModuleRef, Trap, TrapKind};
 
 use casper_types::{
@@ -24,6 +26,7 @@ use casper_types::{
         self, Contract, ContractPackage, ContractPackageStatus, ContractVersion, ContractVersions,
         DisabledVersions, EntryPoint, EntryPointAccess, EntryPoints, Group, Groups, NamedKeys,
     },
+    crypto,
     system::{
         self,
         auction::{self, Auction, EraId, EraInfo},
@@ -34,7 +37,8 @@ use casper_types::{
     },
     AccessRights, ApiError, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash,
     ContractVersionKey, ContractWasm, DeployHash, EntryPointType, Key, Phase, ProtocolVersion,
-    PublicKey, RuntimeArgs, Transfer, TransferResult, TransferredTo, URef, U128, U256, U512,
+    PublicKey, RuntimeArgs, Signature, Transfer, TransferResult, TransferredTo, URef,
+    RANDOM_BYTES_COUNT, U128, U256, U512,
 };
 
 use crate::{
@@ -52,6 +56,7 @@ use crate::{
         host_function_costs::{Cost, HostFunction},
         stored_value::StoredValue,
         wasm_config::WasmConfig,
+        TypeMismatch,
     },
     storage::{global_state::StateReader, protocol_data::ProtocolData},
 };
@@ -70,6 +75,7 @@ pub fn instance_and_memory(
     protocol_version: ProtocolVersion,
     wasm_config: &WasmConfig,
 ) -> Result<(ModuleRef, MemoryRef), Error> {
+    let parity_module = gas_profiler::instrument(parity_module);
     let module = wasmi::Module::from_parity_wasm_module(parity_module)?;
     let resolver = create_module_resolver(protocol_version, wasm_config)?;
     let mut imports = ImportsBuilder::new();
@@ -98,6 +104,7 @@ pub fn key_to_tuple(key: Key) -> Option<([u8; 32], AccessRights)> {
         Key::Balance(_) => None,
         Key::Bid(_) => None,
         Key::Withdraw(_) => None,
+        Key::Dictionary(_) => None,
     }
 }
 
@@ -960,6 +967,48 @@ fn extract_urefs(cl_value: &CLValue) -> Result<Vec<URef>, Error> {
     }
 }
 
+/// Checks `args` by name against the parameters of `entry_point`, returning the args in the order
+/// the parameters are declared, with the default values of any omitted optional parameters filled
+/// in.
+///
+/// Parameters of type `CLType::Any` accept an argument of any type.
+pub fn resolve_entry_point_args(
+    entry_point: &EntryPoint,
+    args: RuntimeArgs,
+) -> Result<RuntimeArgs, Error> {
+    let parameters = entry_point.args();
+
+    if let Some(unexpected) = args.named_args().find(|named_arg| {
+        !parameters
+            .iter()
+            .any(|param| param.name() == named_arg.name())
+    }) {
+        return Err(Error::UnexpectedArgument(unexpected.name().to_string()));
+    }
+
+    let mut resolved_args = RuntimeArgs::new();
+    for parameter in parameters {
+        let value = match args.get(parameter.name()) {
+            Some(value) => value.clone(),
+            None => parameter
+                .default_value()
+                .cloned()
+                .ok_or_else(|| Error::MissingArgument(parameter.name().to_string()))?,
+        };
+        if *parameter.cl_type() != CLType::Any && value.cl_type() != parameter.cl_type() {
+            return Err(Error::InvalidArgumentType {
+                name: parameter.name().to_string(),
+                mismatch: TypeMismatch::new(
+                    format!("{:?}", parameter.cl_type()),
+                    format!("{:?}", value.cl_type()),
+                ),
+            });
+        }
+        resolved_args.insert_cl_value(parameter.name(), value);
+    }
+    Ok(resolved_args)
+}
+
 impl<'a, R> Runtime<'a, R>
 where
     R: StateReader<Key, StoredValue>,
@@ -1320,6 +1369,7 @@ where
         let hash_address_generator = self.context.hash_address_generator();
         let uref_address_generator = self.context.uref_address_generator();
         let transfer_address_generator = self.context.transfer_address_generator();
+        let random_bytes_generator = self.context.random_bytes_generator();
         let correlation_id = self.context.correlation_id();
         let phase = self.context.phase();
         let protocol_data = self.context.protocol_data();
@@ -1341,6 +1391,7 @@ where
             hash_address_generator,
             uref_address_generator,
             transfer_address_generator,
+            random_bytes_generator,
             protocol_version,
             correlation_id,
             phase,
@@ -1359,6 +1410,8 @@ where
         let system_config = protocol_data.system_config();
         let mint_costs = system_config.mint_costs();
 
+        let profiler_scope = gas_profiler::system_entry_point("mint", entry_point_name);
+
         let result = match entry_point_name {
             // Type: `fn mint(amount: U512) -> Result<URef, Error>`
             mint::METHOD_MINT => (|| {
@@ -1422,10 +1475,16 @@ where
             _ => CLValue::from_t(()).map_err(Self::reverter),
         };
 
+        drop(profiler_scope);
+
         // Charge just for the amount that particular entry point cost - using gas cost from the
         // isolated runtime might have a recursive costs whenever system contract calls other system
         // contract.
-        self.gas(mint_runtime.gas_counter() - gas_counter)?;
+        {
+            // The gas charged by the isolated runtime has already been profiled.
+            let _suspended = gas_profiler::suspend();
+            self.gas(mint_runtime.gas_counter() - gas_counter)?;
+        }
 
         // Result still contains a result, but the entrypoints logic does not exit early on errors.
         let ret = result?;
@@ -1465,6 +1524,7 @@ where
         let fn_store_id = self.context.hash_address_generator();
         let address_generator = self.context.uref_address_generator();
         let transfer_address_generator = self.context.transfer_address_generator();
+        let random_bytes_generator = self.context.random_bytes_generator();
         let correlation_id = self.context.correlation_id();
         let phase = self.context.phase();
         let protocol_data = self.context.protocol_data();
@@ -1486,6 +1546,7 @@ where
             fn_store_id,
             address_generator,
             transfer_address_generator,
+            random_bytes_generator,
             protocol_version,
             correlation_id,
             phase,
@@ -1504,6 +1565,8 @@ where
         let system_config = protocol_data.system_config();
         let handle_payment_costs = system_config.handle_payment_costs();
 
+        let profiler_scope = gas_profiler::system_entry_point("handle_payment", entry_point_name);
+
         let result = match entry_point_name {
             handle_payment::METHOD_GET_PAYMENT_PURSE => (|| {
                 runtime.charge_system_contract_call(handle_payment_costs.get_payment_purse)?;
@@ -1540,10 +1603,30 @@ where
                     .map_err(Self::reverter)?;
                 CLValue::from_t(()).map_err(Self::reverter)
             })(),
+            handle_payment::METHOD_DISTRIBUTE_ACCUMULATED_FEES => (|| {
+                // Distribution only happens at the end of an era, and is priced like finalizing a
+                // payment.
+                runtime.charge_system_contract_call(handle_payment_costs.finalize_payment)?;
+
+                let validators: Vec<PublicKey> = Self::get_named_argument(
+                    &runtime_args,
+                    handle_payment::ARG_VALIDATOR_PUBLIC_KEYS,
+                )?;
+                runtime
+                    .distribute_accumulated_fees(validators)
+                    .map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)
+            })(),
             _ => CLValue::from_t(()).map_err(Self::reverter),
         };
 
-        self.gas(runtime.gas_counter() - gas_counter)?;
+        drop(profiler_scope);
+
+        {
+            // The gas charged by the isolated runtime has already been profiled.
+            let _suspended = gas_profiler::suspend();
+            self.gas(runtime.gas_counter() - gas_counter)?;
+        }
 
         let ret = result?;
         let urefs = extract_urefs(&ret)?;
@@ -1562,6 +1645,7 @@ where
         let gas_counter = self.gas_counter();
         let amount: U512 =
             Self::get_named_argument(&self.context.args(), standard_payment::ARG_AMOUNT)?;
+        let _suspended = gas_profiler::suspend();
         let result = self.pay(amount).map_err(Self::reverter);
         self.set_gas_counter(gas_counter);
         result
@@ -1592,6 +1676,7 @@ where
         let fn_store_id = self.context.hash_address_generator();
         let address_generator = self.context.uref_address_generator();
         let transfer_address_generator = self.context.transfer_address_generator();
+        let random_bytes_generator = self.context.random_bytes_generator();
         let correlation_id = self.context.correlation_id();
         let phase = self.context.phase();
         let protocol_data = self.context.protocol_data();
@@ -1613,6 +1698,7 @@ where
             fn_store_id,
             address_generator,
             transfer_address_generator,
+            random_bytes_generator,
             protocol_version,
             correlation_id,
             phase,
@@ -1631,6 +1717,8 @@ where
         let system_config = protocol_data.system_config();
         let auction_costs = system_config.auction_costs();
 
+        let profiler_scope = gas_profiler::system_entry_point("auction", entry_point_name);
+
         let result = match entry_point_name {
             auction::METHOD_GET_ERA_VALIDATORS => (|| {
                 runtime.charge_system_contract_call(auction_costs.get_era_validators)?;
@@ -1758,8 +1846,14 @@ where
             _ => CLValue::from_t(()).map_err(Self::reverter),
         };
 
+        drop(profiler_scope);
+
         // Charge for the gas spent during execution in an isolated runtime.
-        self.gas(runtime.gas_counter() - gas_counter)?;
+        {
+            // The gas charged by the isolated runtime has already been profiled.
+            let _suspended = gas_profiler::suspend();
+            self.gas(runtime.gas_counter() - gas_counter)?;
+        }
 
         // Result still contains a result, but the entrypoints logic does not exit early on errors.
         let ret = result?;
@@ -1812,8 +1906,8 @@ where
     }
 
     /// Calls `version` of the contract living at `key`, invoking `method` with
-    /// supplied `args`. This function also checks the args conform with the
-    /// types given in the contract header.
+    /// supplied `args`. Unless the engine config resolves the contract's args by name, this
+    /// function also checks the args conform with the types given in the contract header.
     pub fn call_versioned_contract(
         &mut self,
         contract_package_hash: ContractPackageHash,
@@ -1869,15 +1963,17 @@ where
 
         self.validate_entry_point_access(&contract_package, entry_point.access())?;
 
-        for (expected, found) in entry_point
-            .args()
-            .iter()
-            .map(|a| a.cl_type())
-            .cloned()
-            .zip(args.to_values().into_iter().map(|v| v.cl_type()).cloned())
-        {
-            if expected != found {
-                return Err(Error::type_mismatch(expected, found));
+        if !self.config.has_strict_entry_point_args(&contract) {
+            for (expected, found) in entry_point
+                .args()
+                .iter()
+                .map(|a| a.cl_type())
+                .cloned()
+                .zip(args.to_values().into_iter().map(|v| v.cl_type()).cloned())
+            {
+                if expected != found {
+                    return Err(Error::type_mismatch(expected, found));
+                }
             }
         }
 
@@ -1931,6 +2027,19 @@ where
             });
         }
 
+        // System contracts parse their own args, so only Wasm contracts installed from the
+        // configured protocol version have their args checked against the entry point's
+        // parameters.
+        let args = if self.is_mint(key)
+            || self.is_handle_payment(key)
+            || self.is_auction(key)
+            || !self.config.has_strict_entry_point_args(&contract)
+        {
+            args
+        } else {
+            resolve_entry_point_args(&entry_point, args)?
+        };
+
         // TODO: should we be using named_keys_mut() instead?
         let mut named_keys = match entry_point.entry_point_type() {
             EntryPointType::Session => self.context.account().named_keys().clone(),
@@ -2010,6 +2119,11 @@ where
 
         let entry_point_name = entry_point.name();
 
+        let _profiler_scope = gas_profiler::wasm_entry_point(
+            || format!("{}::{}", key.to_formatted_string(), entry_point_name),
+            &module,
+        );
+
         let (instance, memory) = instance_and_memory(
             module.clone(),
             protocol_version,
@@ -2046,6 +2160,7 @@ where
             self.context.hash_address_generator(),
             self.context.uref_address_generator(),
             self.context.transfer_address_generator(),
+            self.context.random_bytes_generator(),
             protocol_version,
             self.context.correlation_id(),
             self.context.phase(),
@@ -2594,6 +2709,152 @@ where
         Ok(Ok(()))
     }
 
+    /// Creates a new dictionary and writes its seed URef to the host buffer.
+    fn new_dictionary(&mut self, output_size_ptr: u32) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let seed_uref = self.context.new_dictionary()?;
+        let cl_value = CLValue::from_t(seed_uref).map_err(Error::CLValue)?;
+
+        let value_size = cl_value.inner_bytes().len() as u32;
+        if let Err(error) = self.write_host_buffer(cl_value) {
+            return Ok(Err(error));
+        }
+
+        let value_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size_ptr, &value_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Reads the dictionary item stored under the item key specified by `key_bytes_ptr` and
+    /// `key_bytes_size` in the dictionary seeded by the URef specified by `uref_ptr` and
+    /// `uref_size`, and writes it to the host buffer.
+    fn dictionary_get(
+        &mut self,
+        uref_ptr: u32,
+        uref_size: u32,
+        key_bytes_ptr: u32,
+        key_bytes_size: u32,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let seed_uref: URef = self.t_from_mem(uref_ptr, uref_size)?;
+        let dictionary_item_key = self.bytes_from_mem(key_bytes_ptr, key_bytes_size as usize)?;
+
+        let cl_value = match self
+            .context
+            .dictionary_read(seed_uref, &dictionary_item_key)?
+        {
+            Some(stored_value) => CLValue::try_from(stored_value).map_err(Error::TypeMismatch)?,
+            None => return Ok(Err(ApiError::ValueNotFound)),
+        };
+
+        let value_size = cl_value.inner_bytes().len() as u32;
+        if let Err(error) = self.write_host_buffer(cl_value) {
+            return Ok(Err(error));
+        }
+
+        let value_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size_ptr, &value_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Writes the value specified by `value_ptr` and `value_size` under the item key specified by
+    /// `key_bytes_ptr` and `key_bytes_size` in the dictionary seeded by the URef specified by
+    /// `uref_ptr` and `uref_size`.
+    fn dictionary_put(
+        &mut self,
+        uref_ptr: u32,
+        uref_size: u32,
+        key_bytes_ptr: u32,
+        key_bytes_size: u32,
+        value_ptr: u32,
+        value_size: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let seed_uref: URef = self.t_from_mem(uref_ptr, uref_size)?;
+        let dictionary_item_key = self.bytes_from_mem(key_bytes_ptr, key_bytes_size as usize)?;
+        let cl_value = self.cl_value_from_mem(value_ptr, value_size)?;
+        self.context
+            .dictionary_write(seed_uref, &dictionary_item_key, cl_value)?;
+        Ok(Ok(()))
+    }
+
+    /// Emits an event with the name specified by `name_ptr` and `name_size` and the payload
+    /// specified by `payload_ptr` and `payload_size`.
+    fn emit_event(
+        &mut self,
+        name_ptr: u32,
+        name_size: u32,
+        payload_ptr: u32,
+        payload_size: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let name = self.string_from_mem(name_ptr, name_size)?;
+        let payload = self.cl_value_from_mem(payload_ptr, payload_size)?;
+        self.context.emit_event(name, payload)?;
+        Ok(Ok(()))
+    }
+
+    /// Hashes the input bytes with `D`, writing the digest to the output buffer.
+    fn digest<D: Digest>(
+        &mut self,
+        in_ptr: u32,
+        in_size: u32,
+        out_ptr: u32,
+        out_size: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let input = self.bytes_from_mem(in_ptr, in_size as usize)?;
+        let digest = D::digest(&input);
+        if digest.len() != out_size as usize {
+            return Ok(Err(ApiError::BufferTooSmall));
+        }
+        self.memory
+            .set(out_ptr, &digest)
+            .map_err(|error| Error::Interpreter(error.into()))?;
+        Ok(Ok(()))
+    }
+
+    /// Returns `true` if the signature is a valid signature of the message by the holder of the
+    /// secret key corresponding to the public key.
+    fn verify_signature(
+        &self,
+        message_ptr: u32,
+        message_size: u32,
+        signature_ptr: u32,
+        signature_size: u32,
+        public_key_ptr: u32,
+        public_key_size: u32,
+    ) -> Result<bool, Trap> {
+        let message = self.bytes_from_mem(message_ptr, message_size as usize)?;
+        let signature: Signature = self.t_from_mem(signature_ptr, signature_size)?;
+        let public_key: PublicKey = self.t_from_mem(public_key_ptr, public_key_size)?;
+        Ok(crypto::verify(message, &signature, &public_key).is_ok())
+    }
+
+    /// Writes the next random bytes of the currently executing deploy to the output buffer.
+    fn random_bytes(&mut self, out_ptr: u32, out_size: u32) -> Result<Result<(), ApiError>, Trap> {
+        if out_size as usize != RANDOM_BYTES_COUNT {
+            return Ok(Err(ApiError::BufferTooSmall));
+        }
+        let random_bytes = self.context.random_bytes();
+        self.memory
+            .set(out_ptr, &random_bytes)
+            .map_err(|error| Error::Interpreter(error.into()))?;
+        Ok(Ok(()))
+    }
+
     /// Reverts contract execution with a status specified.
     fn revert(&mut self, status: u32) -> Trap {
         Error::Revert(status.into()).into()
@@ -2728,6 +2989,7 @@ where
         mint_contract_hash: ContractHash,
     ) -> Result<U512, Error> {
         let gas_counter = self.gas_counter();
+        let _suspended = gas_profiler::suspend();
         let call_result = self.call_contract(
             mint_contract_hash,
             mint::METHOD_READ_BASE_ROUND_REWARD,
@@ -2743,6 +3005,7 @@ where
     /// contract key
     fn mint_mint(&mut self, mint_contract_hash: ContractHash, amount: U512) -> Result<URef, Error> {
         let gas_counter = self.gas_counter();
+        let _suspended = gas_profiler::suspend();
         let runtime_args = {
             let mut runtime_args = RuntimeArgs::new();
             runtime_args.insert(mint::ARG_AMOUNT, amount)?;
@@ -2763,6 +3026,7 @@ where
         amount: U512,
     ) -> Result<(), Error> {
         let gas_counter = self.gas_counter();
+        let _suspended = gas_profiler::suspend();
         let runtime_args = {
             let mut runtime_args = RuntimeArgs::new();
             runtime_args.insert(mint::ARG_AMOUNT, amount)?;
@@ -2783,6 +3047,7 @@ where
     /// contract key
     fn mint_create(&mut self, mint_contract_hash: ContractHash) -> Result<URef, Error> {
         let gas_counter = self.gas_counter();
+        let _suspended = gas_profiler::suspend();
         let result =
             self.call_contract(mint_contract_hash, mint::METHOD_CREATE, RuntimeArgs::new());
         self.set_gas_counter(gas_counter);
@@ -2817,6 +3082,7 @@ where
         };
 
         let gas_counter = self.gas_counter();
+        let _suspended = gas_profiler::suspend();
         let call_result =
             self.call_contract(mint_contract_hash, mint::METHOD_TRANSFER, args_values);
         self.set_gas_counter(gas_counter);
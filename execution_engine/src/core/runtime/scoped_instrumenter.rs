@@ -143,6 +143,14 @@ impl Drop for ScopedInstrumenter {
             FunctionIndex::Blake2b => "host_blake2b",
             FunctionIndex::RecordTransfer => "host_record_transfer",
             FunctionIndex::RecordEraInfo => "host_record_era_info",
+            FunctionIndex::NewDictionaryFuncIndex => "host_function_new_dictionary",
+            FunctionIndex::DictionaryGetFuncIndex => "host_function_dictionary_get",
+            FunctionIndex::DictionaryPutFuncIndex => "host_function_dictionary_put",
+            FunctionIndex::EmitEventFuncIndex => "host_function_emit_event",
+            FunctionIndex::Sha256FuncIndex => "host_function_sha256",
+            FunctionIndex::Keccak256FuncIndex => "host_function_keccak256",
+            FunctionIndex::VerifySignatureFuncIndex => "host_function_verify_signature",
+            FunctionIndex::RandomBytesFuncIndex => "host_function_random_bytes",
         };
 
         let mut properties = mem::take(&mut self.properties);
@@ -15,15 +15,17 @@ use casper_types::{
     bytesrepr::ToBytes,
     contracts::NamedKeys,
     system::auction::EraInfo,
-    AccessRights, BlockTime, CLType, CLValue, Contract, ContractPackage, ContractPackageHash,
-    DeployHash, DeployInfo, EntryPointAccess, EntryPointType, Key, KeyTag, Phase, ProtocolVersion,
-    PublicKey, RuntimeArgs, Transfer, TransferAddr, URef, KEY_HASH_LENGTH,
+    AccessRights, BlockTime, CLType, CLValue, Contract, ContractEvent, ContractHash,
+    ContractPackage, ContractPackageHash, DeployHash, DeployInfo, EntryPointAccess, EntryPointType,
+    Key, KeyTag, Phase, ProtocolVersion, PublicKey, RuntimeArgs, Transfer, TransferAddr, URef,
+    KEY_HASH_LENGTH, RANDOM_BYTES_COUNT,
 };
 
 use crate::{
     core::{
         engine_state::execution_effect::ExecutionEffect,
         execution::{AddressGenerator, Error},
+        runtime::gas_profiler,
         tracking_copy::{AddResult, TrackingCopy},
         Address,
     },
@@ -99,6 +101,7 @@ pub struct RuntimeContext<'a, R> {
     hash_address_generator: Rc<RefCell<AddressGenerator>>,
     uref_address_generator: Rc<RefCell<AddressGenerator>>,
     transfer_address_generator: Rc<RefCell<AddressGenerator>>,
+    random_bytes_generator: Rc<RefCell<AddressGenerator>>,
     protocol_version: ProtocolVersion,
     correlation_id: CorrelationId,
     phase: Phase,
@@ -129,6 +132,7 @@ where
         hash_address_generator: Rc<RefCell<AddressGenerator>>,
         uref_address_generator: Rc<RefCell<AddressGenerator>>,
         transfer_address_generator: Rc<RefCell<AddressGenerator>>,
+        random_bytes_generator: Rc<RefCell<AddressGenerator>>,
         protocol_version: ProtocolVersion,
         correlation_id: CorrelationId,
         phase: Phase,
@@ -151,6 +155,7 @@ where
             hash_address_generator,
             uref_address_generator,
             transfer_address_generator,
+            random_bytes_generator,
             protocol_version,
             correlation_id,
             phase,
@@ -260,6 +265,10 @@ where
                 self.named_keys.remove(name);
                 Ok(())
             }
+            Key::Dictionary(_) => {
+                self.named_keys.remove(name);
+                Ok(())
+            }
         }
     }
 
@@ -303,6 +312,10 @@ where
         Rc::clone(&self.transfer_address_generator)
     }
 
+    pub fn random_bytes_generator(&self) -> Rc<RefCell<AddressGenerator>> {
+        Rc::clone(&self.random_bytes_generator)
+    }
+
     pub(super) fn state(&self) -> Rc<RefCell<TrackingCopy<R>>> {
         Rc::clone(&self.tracking_copy)
     }
@@ -355,6 +368,66 @@ where
         self.new_uref(StoredValue::CLValue(CLValue::unit()))
     }
 
+    /// Creates a new dictionary seed URef. Items of the dictionary are stored under
+    /// [`Key::Dictionary`]s derived from this URef.
+    pub fn new_dictionary(&mut self) -> Result<URef, Error> {
+        self.new_unit_uref()
+    }
+
+    /// Reads the dictionary item stored under `dictionary_item_key` in the dictionary seeded by
+    /// `seed_uref`. The seed URef must be valid in this context and readable.
+    pub fn dictionary_read(
+        &mut self,
+        seed_uref: URef,
+        dictionary_item_key: &[u8],
+    ) -> Result<Option<StoredValue>, Error> {
+        self.validate_readable(&Key::URef(seed_uref))?;
+        self.validate_uref(&seed_uref)?;
+
+        let dictionary_key = Key::dictionary(seed_uref, dictionary_item_key);
+        self.tracking_copy
+            .borrow_mut()
+            .read(self.correlation_id, &dictionary_key)
+            .map_err(Into::into)
+    }
+
+    /// Writes `cl_value` under `dictionary_item_key` in the dictionary seeded by `seed_uref`. The
+    /// seed URef must be valid in this context and writeable.
+    pub fn dictionary_write(
+        &mut self,
+        seed_uref: URef,
+        dictionary_item_key: &[u8],
+        cl_value: CLValue,
+    ) -> Result<(), Error> {
+        self.validate_writeable(&Key::URef(seed_uref))?;
+        self.validate_uref(&seed_uref)?;
+
+        let stored_value = StoredValue::CLValue(cl_value);
+        self.validate_value(&stored_value)?;
+
+        let dictionary_key = Key::dictionary(seed_uref, dictionary_item_key);
+        self.metered_write_gs_unsafe(dictionary_key, stored_value)
+    }
+
+    /// Records a named event with the given payload, attributed to the currently executing
+    /// contract (if any).
+    pub fn emit_event(&mut self, name: String, payload: CLValue) -> Result<(), Error> {
+        self.validate_value(&StoredValue::CLValue(payload.clone()))?;
+
+        let event = ContractEvent {
+            contract_hash: self.base_key().into_hash().map(ContractHash::new),
+            name,
+            payload,
+        };
+
+        // Events are not persisted in global state, but are still charged as storage since they
+        // are recorded in the execution results of every node.
+        self.charge_gas_storage(event.serialized_length())?;
+
+        self.tracking_copy.borrow_mut().emit_event(event);
+        Ok(())
+    }
+
     pub fn new_transfer_addr(&mut self) -> Result<TransferAddr, Error> {
         let transfer_addr = self
             .transfer_address_generator
@@ -363,6 +436,15 @@ where
         Ok(TransferAddr::new(transfer_addr))
     }
 
+    /// Generates the next random bytes for the currently executing deploy.
+    ///
+    /// The generator is seeded from the accumulated seed of the block being executed, the deploy
+    /// hash and the execution phase, and is shared by all contracts called while executing the
+    /// deploy, so every call yields different bytes.
+    pub fn random_bytes(&mut self) -> [u8; RANDOM_BYTES_COUNT] {
+        self.random_bytes_generator.borrow_mut().create_address()
+    }
+
     /// Puts `key` to the map of named keys of current context.
     pub fn put_key(&mut self, name: String, key: Key) -> Result<(), Error> {
         // No need to perform actual validation on the base key because an account or contract (i.e.
@@ -673,6 +755,8 @@ where
             Key::Balance(_) => false,
             Key::Bid(_) => true,
             Key::Withdraw(_) => true,
+            // Dictionary items are only accessible via their seed URef.
+            Key::Dictionary(_) => false,
         }
     }
 
@@ -687,6 +771,7 @@ where
             Key::Balance(_) => false,
             Key::Bid(_) => false,
             Key::Withdraw(_) => false,
+            Key::Dictionary(_) => false,
         }
     }
 
@@ -701,6 +786,7 @@ where
             Key::Balance(_) => false,
             Key::Bid(_) => false,
             Key::Withdraw(_) => false,
+            Key::Dictionary(_) => false,
         }
     }
 
@@ -713,7 +799,7 @@ where
         let prev = self.gas_counter();
         let gas_limit = self.gas_limit();
         // gas charge overflow protection
-        match prev.checked_add(amount) {
+        let result = match prev.checked_add(amount) {
             None => {
                 self.set_gas_counter(gas_limit);
                 Err(Error::GasLimit)
@@ -726,7 +812,11 @@ where
                 self.set_gas_counter(val);
                 Ok(())
             }
+        };
+        if let Some(charged) = self.gas_counter().checked_sub(prev) {
+            gas_profiler::record(charged);
         }
+        result
     }
 
     /// Checks if we are calling a system contract.
@@ -751,6 +841,7 @@ where
 
         let gas_cost = storage_costs.calculate_gas_cost(bytes_count);
 
+        let _profiler_scope = gas_profiler::storage();
         self.charge_gas(gas_cost)
     }
 
@@ -145,6 +145,10 @@ fn mock_runtime_context<'a>(
         Rc::new(RefCell::new(hash_address_generator)),
         Rc::new(RefCell::new(uref_address_generator)),
         Rc::new(RefCell::new(transfer_address_generator)),
+        Rc::new(RefCell::new(AddressGenerator::new(
+            &[1u8; 32],
+            Phase::Session,
+        ))),
         ProtocolVersion::V1_0_0,
         CorrelationId::new(),
         Phase::Session,
@@ -379,6 +383,7 @@ fn contract_key_addable_valid() {
         Rc::new(RefCell::new(hash_address_generator)),
         Rc::new(RefCell::new(uref_address_generator)),
         Rc::new(RefCell::new(transfer_address_generator)),
+        Rc::new(RefCell::new(AddressGenerator::new(&DEPLOY_HASH, PHASE))),
         ProtocolVersion::V1_0_0,
         CorrelationId::new(),
         PHASE,
@@ -452,6 +457,7 @@ fn contract_key_addable_invalid() {
         Rc::new(RefCell::new(hash_address_generator)),
         Rc::new(RefCell::new(uref_address_generator)),
         Rc::new(RefCell::new(transfer_address_generator)),
+        Rc::new(RefCell::new(AddressGenerator::new(&DEPLOY_HASH, PHASE))),
         ProtocolVersion::V1_0_0,
         CorrelationId::new(),
         PHASE,
@@ -573,6 +579,85 @@ fn hash_key_addable_invalid() {
     assert!(query_result.is_err())
 }
 
+#[test]
+fn dictionary_round_trip() {
+    let query_result = test(HashMap::new(), |mut rc| {
+        let seed_uref = rc.new_dictionary().expect("should create dictionary");
+        let cl_value = CLValue::from_t(42_i32).unwrap();
+        rc.dictionary_write(seed_uref, b"item", cl_value.clone())?;
+        let stored_value = rc.dictionary_read(seed_uref.into_read(), b"item")?;
+        assert_eq!(stored_value, Some(StoredValue::CLValue(cl_value)));
+        assert_eq!(rc.dictionary_read(seed_uref, b"other item")?, None);
+        Ok(())
+    });
+    assert!(query_result.is_ok());
+}
+
+#[test]
+fn dictionary_write_requires_writeable_seed_uref() {
+    let query_result = test(HashMap::new(), |mut rc| {
+        let seed_uref = rc.new_dictionary().expect("should create dictionary");
+        rc.dictionary_write(
+            seed_uref.into_read(),
+            b"item",
+            CLValue::from_t(42_i32).unwrap(),
+        )
+    });
+    assert_invalid_access(query_result, AccessRights::WRITE);
+}
+
+#[test]
+fn dictionary_read_requires_known_seed_uref() {
+    let mut rng = AddressGenerator::new(&DEPLOY_HASH, PHASE);
+    let seed_uref = create_uref(&mut rng, AccessRights::READ_ADD_WRITE)
+        .into_uref()
+        .unwrap();
+    let query_result = test(HashMap::new(), |mut rc| {
+        rc.dictionary_read(seed_uref, b"item")
+    });
+    assert_forged_reference(query_result);
+}
+
+#[test]
+fn dictionary_key_not_directly_accessible() {
+    let query = |runtime_context: RuntimeContext<InMemoryGlobalStateView>| {
+        let key = Key::Dictionary([42; KEY_HASH_LENGTH]);
+        assert!(!runtime_context.is_readable(&key));
+        assert!(!runtime_context.is_addable(&key));
+        assert!(!runtime_context.is_writeable(&key));
+        Ok(())
+    };
+    let query_result = test(HashMap::new(), query);
+    assert!(query_result.is_ok())
+}
+
+#[test]
+fn emit_event_recorded_in_effect() {
+    let query_result = test(HashMap::new(), |mut rc| {
+        let payload = CLValue::from_t(42_u64).unwrap();
+        rc.emit_event("token_minted".to_string(), payload.clone())?;
+
+        let events = rc.effect().events;
+        assert_eq!(events.len(), 1);
+        // Session code runs in the context of an account, so there is no contract hash.
+        assert_eq!(events[0].contract_hash, None);
+        assert_eq!(events[0].name, "token_minted");
+        assert_eq!(events[0].payload, payload);
+        Ok(())
+    });
+    assert!(query_result.is_ok());
+}
+
+#[test]
+fn emit_event_rejects_forged_uref_in_payload() {
+    let mut rng = AddressGenerator::new(&DEPLOY_HASH, PHASE);
+    let forged_key = create_uref(&mut rng, AccessRights::READ_ADD_WRITE);
+    let query_result = test(HashMap::new(), |mut rc| {
+        rc.emit_event("forged".to_string(), CLValue::from_t(forged_key).unwrap())
+    });
+    assert_forged_reference(query_result);
+}
+
 #[test]
 fn manage_associated_keys() {
     // Testing a valid case only - successfuly added a key, and successfuly removed,
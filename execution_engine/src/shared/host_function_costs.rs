@@ -17,6 +17,15 @@ const NOT_USED: Cost = 0;
 /// An arbitrary default fixed cost for host functions that were not researched yet.
 const DEFAULT_FIXED_COST: Cost = 200;
 
+/// Prefix of the serialized form of [`HostFunctionCosts`], which includes the costs of the host
+/// functions added after protocol version 1.0.0.
+///
+/// Costs serialized under 1.0.0, such as those in stored protocol data, start with the cost of
+/// `read_value` instead and are given the default costs for the newer host functions when parsed.
+/// A `read_value` cost of `u32::MAX` would make every read unaffordable, so it is not a value those
+/// costs can hold.
+const EXTENDED_HOST_FUNCTION_COSTS_MARKER: u32 = u32::MAX;
+
 const DEFAULT_ADD_ASSOCIATED_KEY_COST: u32 = 9_000;
 const DEFAULT_ADD_COST: u32 = 5_800;
 
@@ -24,6 +33,18 @@ const DEFAULT_CALL_CONTRACT_COST: u32 = 4_500;
 const DEFAULT_CALL_CONTRACT_ARGS_SIZE_WEIGHT: u32 = 420;
 
 const DEFAULT_CREATE_PURSE_COST: u32 = 170_000;
+
+const DEFAULT_DICTIONARY_GET_COST: u32 = 5_500;
+const DEFAULT_DICTIONARY_GET_KEY_SIZE_WEIGHT: u32 = 590;
+
+const DEFAULT_DICTIONARY_PUT_COST: u32 = 9_500;
+const DEFAULT_DICTIONARY_PUT_KEY_BYTES_SIZE_WEIGHT: u32 = 1_800;
+const DEFAULT_DICTIONARY_PUT_VALUE_SIZE_WEIGHT: u32 = 520;
+
+const DEFAULT_EMIT_EVENT_COST: u32 = 4_500;
+const DEFAULT_EMIT_EVENT_NAME_SIZE_WEIGHT: u32 = 440;
+const DEFAULT_EMIT_EVENT_PAYLOAD_SIZE_WEIGHT: u32 = 520;
+
 const DEFAULT_GET_BALANCE_COST: u32 = 3_800;
 const DEFAULT_GET_BLOCKTIME_COST: u32 = 330;
 const DEFAULT_GET_CALLER_COST: u32 = 380;
@@ -35,9 +56,14 @@ const DEFAULT_GET_SYSTEM_CONTRACT_COST: u32 = 1_100;
 const DEFAULT_HAS_KEY_COST: u32 = 1_500;
 const DEFAULT_HAS_KEY_NAME_SIZE_WEIGHT: u32 = 840;
 const DEFAULT_IS_VALID_UREF_COST: u32 = 760;
+
+const DEFAULT_KECCAK256_COST: u32 = 1_000;
+const DEFAULT_KECCAK256_INPUT_SIZE_WEIGHT: u32 = 50;
+
 const DEFAULT_LOAD_NAMED_KEYS_COST: u32 = 42_000;
 const DEFAULT_NEW_UREF_COST: u32 = 17_000;
 const DEFAULT_NEW_UREF_VALUE_SIZE_WEIGHT: u32 = 590;
+const DEFAULT_NEW_DICTIONARY_COST: u32 = DEFAULT_NEW_UREF_COST;
 
 const DEFAULT_PRINT_COST: u32 = 20_000;
 const DEFAULT_PRINT_TEXT_SIZE_WEIGHT: u32 = 4_600;
@@ -45,6 +71,8 @@ const DEFAULT_PRINT_TEXT_SIZE_WEIGHT: u32 = 4_600;
 const DEFAULT_PUT_KEY_COST: u32 = 38_000;
 const DEFAULT_PUT_KEY_NAME_SIZE_WEIGHT: u32 = 1_100;
 
+const DEFAULT_RANDOM_BYTES_COST: u32 = 1_000;
+
 const DEFAULT_READ_HOST_BUFFER_COST: u32 = 3_500;
 const DEFAULT_READ_HOST_BUFFER_DEST_SIZE_WEIGHT: u32 = 310;
 
@@ -62,11 +90,18 @@ const DEFAULT_RET_VALUE_SIZE_WEIGHT: u32 = 420;
 
 const DEFAULT_REVERT_COST: u32 = 500;
 const DEFAULT_SET_ACTION_THRESHOLD_COST: u32 = 74_000;
+
+const DEFAULT_SHA256_COST: u32 = 1_000;
+const DEFAULT_SHA256_INPUT_SIZE_WEIGHT: u32 = 50;
+
 const DEFAULT_TRANSFER_FROM_PURSE_TO_ACCOUNT_COST: u32 = 160_000;
 const DEFAULT_TRANSFER_FROM_PURSE_TO_PURSE_COST: u32 = 82_000;
 const DEFAULT_TRANSFER_TO_ACCOUNT_COST: u32 = 24_000;
 const DEFAULT_UPDATE_ASSOCIATED_KEY_COST: u32 = 4_200;
 
+const DEFAULT_VERIFY_SIGNATURE_COST: u32 = 60_000;
+const DEFAULT_VERIFY_SIGNATURE_MESSAGE_SIZE_WEIGHT: u32 = 50;
+
 const DEFAULT_WRITE_COST: u32 = 14_000;
 const DEFAULT_WRITE_VALUE_SIZE_WEIGHT: u32 = 980;
 
@@ -227,6 +262,22 @@ pub struct HostFunctionCosts {
     pub remove_contract_user_group_urefs: HostFunction<[Cost; 6]>,
     pub print: HostFunction<[Cost; 2]>,
     pub blake2b: HostFunction<[Cost; 4]>,
+    #[serde(default = "default_new_dictionary")]
+    pub new_dictionary: HostFunction<[Cost; 1]>,
+    #[serde(default = "default_dictionary_get")]
+    pub dictionary_get: HostFunction<[Cost; 5]>,
+    #[serde(default = "default_dictionary_put")]
+    pub dictionary_put: HostFunction<[Cost; 6]>,
+    #[serde(default = "default_emit_event")]
+    pub emit_event: HostFunction<[Cost; 4]>,
+    #[serde(default = "default_sha256")]
+    pub sha256: HostFunction<[Cost; 4]>,
+    #[serde(default = "default_keccak256")]
+    pub keccak256: HostFunction<[Cost; 4]>,
+    #[serde(default = "default_verify_signature")]
+    pub verify_signature: HostFunction<[Cost; 6]>,
+    #[serde(default = "default_random_bytes")]
+    pub random_bytes: HostFunction<[Cost; 2]>,
 }
 
 impl Default for HostFunctionCosts {
@@ -344,13 +395,107 @@ impl Default for HostFunctionCosts {
                 [NOT_USED, DEFAULT_PRINT_TEXT_SIZE_WEIGHT],
             ),
             blake2b: HostFunction::default(),
+            new_dictionary: default_new_dictionary(),
+            dictionary_get: default_dictionary_get(),
+            dictionary_put: default_dictionary_put(),
+            emit_event: default_emit_event(),
+            sha256: default_sha256(),
+            keccak256: default_keccak256(),
+            verify_signature: default_verify_signature(),
+            random_bytes: default_random_bytes(),
         }
     }
 }
 
+fn default_new_dictionary() -> HostFunction<[Cost; 1]> {
+    HostFunction::new(DEFAULT_NEW_DICTIONARY_COST, [NOT_USED])
+}
+
+fn default_dictionary_get() -> HostFunction<[Cost; 5]> {
+    HostFunction::new(
+        DEFAULT_DICTIONARY_GET_COST,
+        [
+            NOT_USED,
+            NOT_USED,
+            NOT_USED,
+            DEFAULT_DICTIONARY_GET_KEY_SIZE_WEIGHT,
+            NOT_USED,
+        ],
+    )
+}
+
+fn default_dictionary_put() -> HostFunction<[Cost; 6]> {
+    HostFunction::new(
+        DEFAULT_DICTIONARY_PUT_COST,
+        [
+            NOT_USED,
+            NOT_USED,
+            NOT_USED,
+            DEFAULT_DICTIONARY_PUT_KEY_BYTES_SIZE_WEIGHT,
+            NOT_USED,
+            DEFAULT_DICTIONARY_PUT_VALUE_SIZE_WEIGHT,
+        ],
+    )
+}
+
+fn default_sha256() -> HostFunction<[Cost; 4]> {
+    HostFunction::new(
+        DEFAULT_SHA256_COST,
+        [
+            NOT_USED,
+            DEFAULT_SHA256_INPUT_SIZE_WEIGHT,
+            NOT_USED,
+            NOT_USED,
+        ],
+    )
+}
+
+fn default_keccak256() -> HostFunction<[Cost; 4]> {
+    HostFunction::new(
+        DEFAULT_KECCAK256_COST,
+        [
+            NOT_USED,
+            DEFAULT_KECCAK256_INPUT_SIZE_WEIGHT,
+            NOT_USED,
+            NOT_USED,
+        ],
+    )
+}
+
+fn default_verify_signature() -> HostFunction<[Cost; 6]> {
+    HostFunction::new(
+        DEFAULT_VERIFY_SIGNATURE_COST,
+        [
+            NOT_USED,
+            DEFAULT_VERIFY_SIGNATURE_MESSAGE_SIZE_WEIGHT,
+            NOT_USED,
+            NOT_USED,
+            NOT_USED,
+            NOT_USED,
+        ],
+    )
+}
+
+fn default_random_bytes() -> HostFunction<[Cost; 2]> {
+    HostFunction::fixed(DEFAULT_RANDOM_BYTES_COST)
+}
+
+fn default_emit_event() -> HostFunction<[Cost; 4]> {
+    HostFunction::new(
+        DEFAULT_EMIT_EVENT_COST,
+        [
+            NOT_USED,
+            DEFAULT_EMIT_EVENT_NAME_SIZE_WEIGHT,
+            NOT_USED,
+            DEFAULT_EMIT_EVENT_PAYLOAD_SIZE_WEIGHT,
+        ],
+    )
+}
+
 impl ToBytes for HostFunctionCosts {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+        ret.append(&mut EXTENDED_HOST_FUNCTION_COSTS_MARKER.to_bytes()?);
         ret.append(&mut self.read_value.to_bytes()?);
         ret.append(&mut self.read_value_local.to_bytes()?);
         ret.append(&mut self.write.to_bytes()?);
@@ -393,11 +538,20 @@ impl ToBytes for HostFunctionCosts {
         ret.append(&mut self.remove_contract_user_group_urefs.to_bytes()?);
         ret.append(&mut self.print.to_bytes()?);
         ret.append(&mut self.blake2b.to_bytes()?);
+        ret.append(&mut self.new_dictionary.to_bytes()?);
+        ret.append(&mut self.dictionary_get.to_bytes()?);
+        ret.append(&mut self.dictionary_put.to_bytes()?);
+        ret.append(&mut self.emit_event.to_bytes()?);
+        ret.append(&mut self.sha256.to_bytes()?);
+        ret.append(&mut self.keccak256.to_bytes()?);
+        ret.append(&mut self.verify_signature.to_bytes()?);
+        ret.append(&mut self.random_bytes.to_bytes()?);
         Ok(ret)
     }
 
     fn serialized_length(&self) -> usize {
-        self.read_value.serialized_length()
+        EXTENDED_HOST_FUNCTION_COSTS_MARKER.serialized_length()
+            + self.read_value.serialized_length()
             + self.read_value_local.serialized_length()
             + self.write.serialized_length()
             + self.write_local.serialized_length()
@@ -439,11 +593,25 @@ impl ToBytes for HostFunctionCosts {
             + self.remove_contract_user_group_urefs.serialized_length()
             + self.print.serialized_length()
             + self.blake2b.serialized_length()
+            + self.new_dictionary.serialized_length()
+            + self.dictionary_get.serialized_length()
+            + self.dictionary_put.serialized_length()
+            + self.emit_event.serialized_length()
+            + self.sha256.serialized_length()
+            + self.keccak256.serialized_length()
+            + self.verify_signature.serialized_length()
+            + self.random_bytes.serialized_length()
     }
 }
 
 impl FromBytes for HostFunctionCosts {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (maybe_marker, remainder) = u32::from_bytes(bytes)?;
+        let (is_extended, bytes) = if maybe_marker == EXTENDED_HOST_FUNCTION_COSTS_MARKER {
+            (true, remainder)
+        } else {
+            (false, bytes)
+        };
         let (read_value, rem) = FromBytes::from_bytes(bytes)?;
         let (read_value_local, rem) = FromBytes::from_bytes(rem)?;
         let (write, rem) = FromBytes::from_bytes(rem)?;
@@ -486,53 +654,72 @@ impl FromBytes for HostFunctionCosts {
         let (remove_contract_user_group_urefs, rem) = FromBytes::from_bytes(rem)?;
         let (print, rem) = FromBytes::from_bytes(rem)?;
         let (blake2b, rem) = FromBytes::from_bytes(rem)?;
-        Ok((
-            HostFunctionCosts {
-                read_value,
-                read_value_local,
-                write,
-                write_local,
-                add,
-                new_uref,
-                load_named_keys,
-                ret,
-                get_key,
-                has_key,
-                put_key,
-                remove_key,
-                revert,
-                is_valid_uref,
-                add_associated_key,
-                remove_associated_key,
-                update_associated_key,
-                set_action_threshold,
-                get_caller,
-                get_blocktime,
-                create_purse,
-                transfer_to_account,
-                transfer_from_purse_to_account,
-                transfer_from_purse_to_purse,
-                get_balance,
-                get_phase,
-                get_system_contract,
-                get_main_purse,
-                read_host_buffer,
-                create_contract_package_at_hash,
-                create_contract_user_group,
-                add_contract_version,
-                disable_contract_version,
-                call_contract,
-                call_versioned_contract,
-                get_named_arg_size,
-                get_named_arg,
-                remove_contract_user_group,
-                provision_contract_user_group_uref,
-                remove_contract_user_group_urefs,
-                print,
-                blake2b,
-            },
-            rem,
-        ))
+        let mut host_function_costs = HostFunctionCosts {
+            read_value,
+            read_value_local,
+            write,
+            write_local,
+            add,
+            new_uref,
+            load_named_keys,
+            ret,
+            get_key,
+            has_key,
+            put_key,
+            remove_key,
+            revert,
+            is_valid_uref,
+            add_associated_key,
+            remove_associated_key,
+            update_associated_key,
+            set_action_threshold,
+            get_caller,
+            get_blocktime,
+            create_purse,
+            transfer_to_account,
+            transfer_from_purse_to_account,
+            transfer_from_purse_to_purse,
+            get_balance,
+            get_phase,
+            get_system_contract,
+            get_main_purse,
+            read_host_buffer,
+            create_contract_package_at_hash,
+            create_contract_user_group,
+            add_contract_version,
+            disable_contract_version,
+            call_contract,
+            call_versioned_contract,
+            get_named_arg_size,
+            get_named_arg,
+            remove_contract_user_group,
+            provision_contract_user_group_uref,
+            remove_contract_user_group_urefs,
+            print,
+            blake2b,
+            ..HostFunctionCosts::default()
+        };
+        if !is_extended {
+            // Costs serialized under protocol version 1.0.0 end here.
+            return Ok((host_function_costs, rem));
+        }
+        let (new_dictionary, rem) = FromBytes::from_bytes(rem)?;
+        host_function_costs.new_dictionary = new_dictionary;
+        let (dictionary_get, rem) = FromBytes::from_bytes(rem)?;
+        host_function_costs.dictionary_get = dictionary_get;
+        let (dictionary_put, rem) = FromBytes::from_bytes(rem)?;
+        host_function_costs.dictionary_put = dictionary_put;
+        let (emit_event, rem) = FromBytes::from_bytes(rem)?;
+        host_function_costs.emit_event = emit_event;
+        let (sha256, rem) = FromBytes::from_bytes(rem)?;
+        host_function_costs.sha256 = sha256;
+        let (keccak256, rem) = FromBytes::from_bytes(rem)?;
+        host_function_costs.keccak256 = keccak256;
+        let (verify_signature, rem) = FromBytes::from_bytes(rem)?;
+        host_function_costs.verify_signature = verify_signature;
+        let (random_bytes, rem) = FromBytes::from_bytes(rem)?;
+        host_function_costs.random_bytes = random_bytes;
+        Ok((host_function_costs, rem))
     }
 }
 
@@ -581,6 +768,14 @@ impl Distribution<HostFunctionCosts> for Standard {
             remove_contract_user_group_urefs: rng.gen(),
             print: rng.gen(),
             blake2b: rng.gen(),
+            new_dictionary: rng.gen(),
+            dictionary_get: rng.gen(),
+            dictionary_put: rng.gen(),
+            emit_event: rng.gen(),
+            sha256: rng.gen(),
+            keccak256: rng.gen(),
+            verify_signature: rng.gen(),
+            random_bytes: rng.gen(),
         }
     }
 }
@@ -639,6 +834,14 @@ pub mod gens {
             remove_contract_user_group_urefs in host_function_cost_arb(),
             print in host_function_cost_arb(),
             blake2b in host_function_cost_arb(),
+            new_dictionary in host_function_cost_arb(),
+            dictionary_get in host_function_cost_arb(),
+            dictionary_put in host_function_cost_arb(),
+            emit_event in host_function_cost_arb(),
+            sha256 in host_function_cost_arb(),
+            keccak256 in host_function_cost_arb(),
+            verify_signature in host_function_cost_arb(),
+            random_bytes in host_function_cost_arb(),
         ) -> HostFunctionCosts {
             HostFunctionCosts {
                 read_value,
@@ -683,6 +886,14 @@ pub mod gens {
                 remove_contract_user_group_urefs,
                 print,
                 blake2b,
+                new_dictionary,
+                dictionary_get,
+                dictionary_put,
+                emit_event,
+                sha256,
+                keccak256,
+                verify_signature,
+                random_bytes,
             }
         }
     }
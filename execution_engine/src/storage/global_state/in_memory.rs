@@ -1,4 +1,7 @@
-use std::{ops::Deref, sync::Arc};
+use std::{
+    ops::Deref,
+    sync::{Arc, Mutex},
+};
 
 use crate::shared::{
     additive_map::AdditiveMap,
@@ -10,7 +13,7 @@ use casper_types::{Key, ProtocolVersion};
 
 use crate::storage::{
     error::{self, in_memory},
-    global_state::{commit, CommitResult, StateProvider, StateReader},
+    global_state::{commit, diff, CommitResult, DiffResult, StateProvider, StateReader},
     protocol_data::ProtocolData,
     protocol_data_store::in_memory::InMemoryProtocolDataStore,
     store::Store,
@@ -22,8 +25,8 @@ use crate::storage::{
     trie_store::{
         in_memory::InMemoryTrieStore,
         operations::{
-            self, keys_with_prefix, missing_trie_keys, put_trie, read, read_with_proof, ReadResult,
-            WriteResult,
+            self, keys_with_prefix, missing_trie_keys, put_trie, read, read_with_proof,
+            PruneResult, ReadResult, TriePruner, WriteResult,
         },
     },
 };
@@ -33,6 +36,7 @@ pub struct InMemoryGlobalState {
     pub trie_store: Arc<InMemoryTrieStore>,
     pub protocol_data_store: Arc<InMemoryProtocolDataStore>,
     pub empty_root_hash: Blake2bHash,
+    trie_pruner: Mutex<TriePruner>,
 }
 
 /// Represents a "view" of global state at a particular root hash.
@@ -76,6 +80,7 @@ impl InMemoryGlobalState {
             trie_store,
             protocol_data_store,
             empty_root_hash,
+            trie_pruner: Mutex::new(TriePruner::new()),
         }
     }
 
@@ -217,6 +222,9 @@ impl StateProvider for InMemoryGlobalState {
         prestate_hash: Blake2bHash,
         effects: AdditiveMap<Key, Transform>,
     ) -> Result<CommitResult, Self::Error> {
+        // Hold the lock for the whole commit, so that no pruning step can run before the new root
+        // has been recorded.
+        let mut trie_pruner = self.trie_pruner.lock()?;
         let commit_result = commit::<InMemoryEnvironment, InMemoryTrieStore, _, Self::Error>(
             &self.environment,
             &self.trie_store,
@@ -224,6 +232,9 @@ impl StateProvider for InMemoryGlobalState {
             prestate_hash,
             effects,
         )?;
+        if let CommitResult::Success { state_root } = commit_result {
+            trie_pruner.record_root(state_root);
+        }
         Ok(commit_result)
     }
 
@@ -268,6 +279,7 @@ impl StateProvider for InMemoryGlobalState {
         correlation_id: CorrelationId,
         trie: &Trie<Key, StoredValue>,
     ) -> Result<Blake2bHash, Self::Error> {
+        let mut trie_pruner = self.trie_pruner.lock()?;
         let mut txn = self.environment.create_read_write_txn()?;
         let trie_hash = put_trie::<
             Key,
@@ -277,6 +289,7 @@ impl StateProvider for InMemoryGlobalState {
             Self::Error,
         >(correlation_id, &mut txn, &self.trie_store, trie)?;
         txn.commit()?;
+        trie_pruner.record_root(trie_hash);
         Ok(trie_hash)
     }
 
@@ -298,6 +311,46 @@ impl StateProvider for InMemoryGlobalState {
         txn.commit()?;
         Ok(missing_descendants)
     }
+
+    fn prune_tries(
+        &self,
+        correlation_id: CorrelationId,
+        retained_roots: &[Blake2bHash],
+        batch_size: usize,
+    ) -> Result<PruneResult, Self::Error> {
+        let mut trie_pruner = self.trie_pruner.lock()?;
+        let mut txn = self.environment.create_read_write_txn()?;
+        let mut retained_roots = retained_roots.to_vec();
+        retained_roots.push(self.empty_root_hash);
+        let prune_result = trie_pruner.step::<Key, StoredValue, _, _, Self::Error>(
+            correlation_id,
+            &mut txn,
+            self.trie_store.deref(),
+            &retained_roots,
+            batch_size,
+        )?;
+        txn.commit()?;
+        Ok(prune_result)
+    }
+
+    fn diff(
+        &self,
+        correlation_id: CorrelationId,
+        left_root: Blake2bHash,
+        right_root: Blake2bHash,
+        start_after: Option<Key>,
+        max_entries: usize,
+    ) -> Result<DiffResult, Self::Error> {
+        diff::<InMemoryEnvironment, InMemoryTrieStore, Self::Error>(
+            &self.environment,
+            &self.trie_store,
+            correlation_id,
+            left_root,
+            right_root,
+            start_after,
+            max_entries,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,7 @@
-use std::{ops::Deref, sync::Arc};
+use std::{
+    ops::Deref,
+    sync::{Arc, Mutex},
+};
 
 use crate::shared::{
     additive_map::AdditiveMap,
@@ -10,7 +13,7 @@ use casper_types::{Key, ProtocolVersion};
 
 use crate::storage::{
     error,
-    global_state::{commit, CommitResult, StateProvider, StateReader},
+    global_state::{commit, diff, CommitResult, DiffResult, StateProvider, StateReader},
     protocol_data::ProtocolData,
     protocol_data_store::lmdb::LmdbProtocolDataStore,
     store::Store,
@@ -19,7 +22,8 @@ use crate::storage::{
     trie_store::{
         lmdb::LmdbTrieStore,
         operations::{
-            keys_with_prefix, missing_trie_keys, put_trie, read, read_with_proof, ReadResult,
+            keys_with_prefix, missing_trie_keys, put_trie, read, read_with_proof, PruneResult,
+            ReadResult, TriePruner,
         },
     },
 };
@@ -29,6 +33,7 @@ pub struct LmdbGlobalState {
     pub trie_store: Arc<LmdbTrieStore>,
     pub protocol_data_store: Arc<LmdbProtocolDataStore>,
     pub empty_root_hash: Blake2bHash,
+    trie_pruner: Mutex<TriePruner>,
 }
 
 /// Represents a "view" of global state at a particular root hash.
@@ -73,6 +78,7 @@ impl LmdbGlobalState {
             trie_store,
             protocol_data_store,
             empty_root_hash,
+            trie_pruner: Mutex::new(TriePruner::new()),
         }
     }
 }
@@ -176,6 +182,9 @@ impl StateProvider for LmdbGlobalState {
         prestate_hash: Blake2bHash,
         effects: AdditiveMap<Key, Transform>,
     ) -> Result<CommitResult, Self::Error> {
+        // Hold the lock for the whole commit, so that no pruning step can run before the new root
+        // has been recorded.
+        let mut trie_pruner = self.trie_pruner.lock()?;
         let commit_result = commit::<LmdbEnvironment, LmdbTrieStore, _, Self::Error>(
             &self.environment,
             &self.trie_store,
@@ -183,6 +192,9 @@ impl StateProvider for LmdbGlobalState {
             prestate_hash,
             effects,
         )?;
+        if let CommitResult::Success { state_root } = commit_result {
+            trie_pruner.record_root(state_root);
+        }
         Ok(commit_result)
     }
 
@@ -227,6 +239,7 @@ impl StateProvider for LmdbGlobalState {
         correlation_id: CorrelationId,
         trie: &Trie<Key, StoredValue>,
     ) -> Result<Blake2bHash, Self::Error> {
+        let mut trie_pruner = self.trie_pruner.lock()?;
         let mut txn = self.environment.create_read_write_txn()?;
         let trie_hash = put_trie::<
             Key,
@@ -236,6 +249,7 @@ impl StateProvider for LmdbGlobalState {
             Self::Error,
         >(correlation_id, &mut txn, &self.trie_store, trie)?;
         txn.commit()?;
+        trie_pruner.record_root(trie_hash);
         Ok(trie_hash)
     }
 
@@ -256,6 +270,46 @@ impl StateProvider for LmdbGlobalState {
         txn.commit()?;
         Ok(missing_descendants)
     }
+
+    fn prune_tries(
+        &self,
+        correlation_id: CorrelationId,
+        retained_roots: &[Blake2bHash],
+        batch_size: usize,
+    ) -> Result<PruneResult, Self::Error> {
+        let mut trie_pruner = self.trie_pruner.lock()?;
+        let mut txn = self.environment.create_read_write_txn()?;
+        let mut retained_roots = retained_roots.to_vec();
+        retained_roots.push(self.empty_root_hash);
+        let prune_result = trie_pruner.step::<Key, StoredValue, _, _, Self::Error>(
+            correlation_id,
+            &mut txn,
+            self.trie_store.deref(),
+            &retained_roots,
+            batch_size,
+        )?;
+        txn.commit()?;
+        Ok(prune_result)
+    }
+
+    fn diff(
+        &self,
+        correlation_id: CorrelationId,
+        left_root: Blake2bHash,
+        right_root: Blake2bHash,
+        start_after: Option<Key>,
+        max_entries: usize,
+    ) -> Result<DiffResult, Self::Error> {
+        diff::<LmdbEnvironment, LmdbTrieStore, Self::Error>(
+            &self.environment,
+            &self.trie_store,
+            correlation_id,
+            left_root,
+            right_root,
+            start_after,
+            max_entries,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -443,4 +497,52 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn diff_returns_pages_in_key_order() {
+        let correlation_id = CorrelationId::new();
+        let test_pairs_updated = create_test_pairs_updated();
+
+        let (state, root_hash) = create_test_state();
+
+        let effects: AdditiveMap<Key, Transform> = {
+            let mut tmp = AdditiveMap::new();
+            for TestPair { key, value } in &test_pairs_updated {
+                tmp.insert(*key, Transform::Write(value.to_owned()));
+            }
+            tmp
+        };
+
+        let updated_hash = match state.commit(correlation_id, root_hash, effects).unwrap() {
+            CommitResult::Success { state_root, .. } => state_root,
+            _ => panic!("commit failed"),
+        };
+
+        let get_page = |start_after: Option<Key>, max_entries: usize| match state
+            .diff(
+                correlation_id,
+                root_hash,
+                updated_hash,
+                start_after,
+                max_entries,
+            )
+            .unwrap()
+        {
+            DiffResult::Success { entries, has_more } => (entries, has_more),
+            DiffResult::RootNotFound(root) => panic!("root {} not found", root),
+        };
+
+        let (all_entries, has_more) = get_page(None, usize::MAX);
+        assert!(!has_more);
+        let keys: Vec<Key> = all_entries.iter().map(|entry| *entry.key()).collect();
+        let expected_keys: Vec<Key> = test_pairs_updated.iter().map(|pair| pair.key).collect();
+        assert_eq!(keys, expected_keys);
+
+        let (first_page, has_more) = get_page(None, 2);
+        assert_eq!(first_page, all_entries[..2]);
+        assert!(has_more);
+        let (second_page, has_more) = get_page(Some(*first_page[1].key()), 2);
+        assert_eq!(second_page, all_entries[2..]);
+        assert!(!has_more);
+    }
 }
@@ -10,15 +10,18 @@ use crate::shared::{
     transform::{self, Transform},
     TypeMismatch,
 };
-use casper_types::{bytesrepr, Key, ProtocolVersion};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    Key, ProtocolVersion,
+};
 
 use crate::storage::{
     protocol_data::ProtocolData,
     transaction_source::{Transaction, TransactionSource},
     trie::{merkle_proof::TrieMerkleProof, Trie},
     trie_store::{
-        operations::{read, write, ReadResult, WriteResult},
-        TrieStore,
+        operations::{self, read, write, DiffEntry, ReadResult, WriteResult},
+        PruneResult, TrieStore,
     },
 };
 
@@ -70,6 +73,20 @@ impl fmt::Display for CommitResult {
     }
 }
 
+/// The result of comparing the global state under two state roots.
+#[derive(Debug)]
+pub enum DiffResult {
+    /// The given state root was not found.
+    RootNotFound(Blake2bHash),
+    /// The differences between the two states.
+    Success {
+        /// The requested page of differences, ordered by their serialized keys.
+        entries: Vec<DiffEntry<Key, StoredValue>>,
+        /// Whether there are further differences after the last of `entries`.
+        has_more: bool,
+    },
+}
+
 impl From<transform::Error> for CommitResult {
     fn from(error: transform::Error) -> Self {
         match error {
@@ -130,6 +147,31 @@ pub trait StateProvider {
         correlation_id: CorrelationId,
         trie_keys: Vec<Blake2bHash>,
     ) -> Result<Vec<Blake2bHash>, Self::Error>;
+
+    /// Performs a step of removing the trie nodes which are unreachable from `retained_roots`,
+    /// visiting at most `batch_size` nodes.
+    ///
+    /// Roots committed while pruning is in progress are retained as well.
+    fn prune_tries(
+        &self,
+        correlation_id: CorrelationId,
+        retained_roots: &[Blake2bHash],
+        batch_size: usize,
+    ) -> Result<PruneResult, Self::Error>;
+
+    /// Returns the values which were added, removed or modified between the states under
+    /// `left_root` and `right_root`.
+    ///
+    /// At most `max_entries` entries are returned, in the order of their serialized keys, starting
+    /// after the key `start_after` if given.
+    fn diff(
+        &self,
+        correlation_id: CorrelationId,
+        left_root: Blake2bHash,
+        right_root: Blake2bHash,
+        start_after: Option<Key>,
+        max_entries: usize,
+    ) -> Result<DiffResult, Self::Error>;
 }
 
 pub fn commit<'a, R, S, H, E>(
@@ -186,3 +228,60 @@ where
 
     Ok(CommitResult::Success { state_root })
 }
+
+pub fn diff<'a, R, S, E>(
+    environment: &'a R,
+    store: &S,
+    correlation_id: CorrelationId,
+    left_root: Blake2bHash,
+    right_root: Blake2bHash,
+    start_after: Option<Key>,
+    max_entries: usize,
+) -> Result<DiffResult, E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<Key, StoredValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error>,
+{
+    let txn = environment.create_read_txn()?;
+
+    for root in &[left_root, right_root] {
+        let maybe_root: Option<Trie<Key, StoredValue>> = store.get(&txn, root)?;
+        if maybe_root.is_none() {
+            return Ok(DiffResult::RootNotFound(*root));
+        }
+    }
+
+    let start_after = start_after
+        .as_ref()
+        .map(ToBytes::to_bytes)
+        .transpose()
+        .map_err(S::Error::from)?;
+    let diff_entries = operations::diff::<Key, StoredValue, _, _>(
+        correlation_id,
+        &txn,
+        store,
+        &left_root,
+        &right_root,
+    );
+    let mut entries = Vec::new();
+    let mut has_more = false;
+    for entry in diff_entries {
+        let entry = entry?;
+        if let Some(start_after) = &start_after {
+            if entry.key().to_bytes().map_err(S::Error::from)? <= *start_after {
+                continue;
+            }
+        }
+        if entries.len() == max_entries {
+            has_more = true;
+            break;
+        }
+        entries.push(entry);
+    }
+
+    txn.commit()?;
+
+    Ok(DiffResult::Success { entries, has_more })
+}
@@ -229,9 +229,17 @@ pub(crate) mod gens {
 #[cfg(test)]
 mod tests {
     use proptest::proptest;
+    use rand::Rng;
 
-    use crate::shared::{system_config::SystemConfig, wasm_config::WasmConfig};
-    use casper_types::{bytesrepr, ContractHash};
+    use crate::shared::{
+        host_function_costs::{HostFunction, HostFunctionCosts},
+        system_config::SystemConfig,
+        wasm_config::WasmConfig,
+    };
+    use casper_types::{
+        bytesrepr::{self, FromBytes, ToBytes},
+        ContractHash,
+    };
 
     use super::{gens, ProtocolData};
 
@@ -301,6 +309,139 @@ mod tests {
         assert_eq!(actual[2], auction_reference);
     }
 
+    /// Serializes `protocol_data` as protocol version 1.0.0 did, before host functions were added.
+    fn to_1_0_0_bytes(protocol_data: &ProtocolData) -> Vec<u8> {
+        let wasm_config = protocol_data.wasm_config();
+        let host_function_costs = wasm_config.take_host_function_costs();
+        let legacy_host_function_costs: [&dyn ToBytes; 42] = [
+            &host_function_costs.read_value,
+            &host_function_costs.read_value_local,
+            &host_function_costs.write,
+            &host_function_costs.write_local,
+            &host_function_costs.add,
+            &host_function_costs.new_uref,
+            &host_function_costs.load_named_keys,
+            &host_function_costs.ret,
+            &host_function_costs.get_key,
+            &host_function_costs.has_key,
+            &host_function_costs.put_key,
+            &host_function_costs.remove_key,
+            &host_function_costs.revert,
+            &host_function_costs.is_valid_uref,
+            &host_function_costs.add_associated_key,
+            &host_function_costs.remove_associated_key,
+            &host_function_costs.update_associated_key,
+            &host_function_costs.set_action_threshold,
+            &host_function_costs.get_caller,
+            &host_function_costs.get_blocktime,
+            &host_function_costs.create_purse,
+            &host_function_costs.transfer_to_account,
+            &host_function_costs.transfer_from_purse_to_account,
+            &host_function_costs.transfer_from_purse_to_purse,
+            &host_function_costs.get_balance,
+            &host_function_costs.get_phase,
+            &host_function_costs.get_system_contract,
+            &host_function_costs.get_main_purse,
+            &host_function_costs.read_host_buffer,
+            &host_function_costs.create_contract_package_at_hash,
+            &host_function_costs.create_contract_user_group,
+            &host_function_costs.add_contract_version,
+            &host_function_costs.disable_contract_version,
+            &host_function_costs.call_contract,
+            &host_function_costs.call_versioned_contract,
+            &host_function_costs.get_named_arg_size,
+            &host_function_costs.get_named_arg,
+            &host_function_costs.remove_contract_user_group,
+            &host_function_costs.provision_contract_user_group_uref,
+            &host_function_costs.remove_contract_user_group_urefs,
+            &host_function_costs.print,
+            &host_function_costs.blake2b,
+        ];
+
+        let mut bytes = vec![];
+        bytes.append(&mut wasm_config.max_memory.to_bytes().unwrap());
+        bytes.append(&mut wasm_config.max_stack_height.to_bytes().unwrap());
+        bytes.append(&mut wasm_config.opcode_costs().to_bytes().unwrap());
+        bytes.append(&mut wasm_config.storage_costs().to_bytes().unwrap());
+        for host_function in legacy_host_function_costs.iter() {
+            bytes.append(&mut host_function.to_bytes().unwrap());
+        }
+        bytes.append(&mut protocol_data.system_config().to_bytes().unwrap());
+        bytes.append(&mut protocol_data.mint().to_bytes().unwrap());
+        bytes.append(&mut protocol_data.handle_payment().to_bytes().unwrap());
+        bytes.append(&mut protocol_data.standard_payment().to_bytes().unwrap());
+        bytes.append(&mut protocol_data.auction().to_bytes().unwrap());
+        bytes
+    }
+
+    #[test]
+    fn should_deserialize_1_0_0_protocol_data() {
+        let protocol_data = ProtocolData::new(
+            WasmConfig::default(),
+            SystemConfig::default(),
+            [1u8; 32].into(),
+            [2u8; 32].into(),
+            [3u8; 32].into(),
+            [4u8; 32].into(),
+        );
+        let bytes = to_1_0_0_bytes(&protocol_data);
+
+        let (deserialized, rem) =
+            ProtocolData::from_bytes(&bytes).expect("should deserialize 1.0.0 protocol data");
+        assert!(rem.is_empty());
+        assert_eq!(deserialized, protocol_data);
+    }
+
+    #[test]
+    fn should_deserialize_1_0_0_protocol_data_with_custom_costs() {
+        let mut rng = rand::thread_rng();
+        let protocol_data = ProtocolData::new(
+            WasmConfig::new(
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                HostFunctionCosts {
+                    // A `read_value` cost of `u32::MAX` can't be represented in 1.0.0 encoding.
+                    read_value: HostFunction::new(rng.gen_range(0..u32::MAX), rng.gen()),
+                    ..rng.gen()
+                },
+            ),
+            rng.gen(),
+            rng.gen::<[u8; 32]>().into(),
+            rng.gen::<[u8; 32]>().into(),
+            rng.gen::<[u8; 32]>().into(),
+            rng.gen::<[u8; 32]>().into(),
+        );
+        let bytes = to_1_0_0_bytes(&protocol_data);
+
+        let (deserialized, rem) =
+            ProtocolData::from_bytes(&bytes).expect("should deserialize 1.0.0 protocol data");
+        assert!(rem.is_empty());
+        // Costs of host functions added after 1.0.0 are given their defaults.
+        let default_costs = HostFunctionCosts::default();
+        let expected_host_function_costs = HostFunctionCosts {
+            new_dictionary: default_costs.new_dictionary,
+            dictionary_get: default_costs.dictionary_get,
+            dictionary_put: default_costs.dictionary_put,
+            emit_event: default_costs.emit_event,
+            sha256: default_costs.sha256,
+            keccak256: default_costs.keccak256,
+            verify_signature: default_costs.verify_signature,
+            random_bytes: default_costs.random_bytes,
+            ..protocol_data.wasm_config().take_host_function_costs()
+        };
+        assert_eq!(
+            deserialized.wasm_config().take_host_function_costs(),
+            expected_host_function_costs
+        );
+        assert_eq!(deserialized.system_config(), protocol_data.system_config());
+        assert_eq!(
+            deserialized.system_contracts(),
+            protocol_data.system_contracts()
+        );
+    }
+
     proptest! {
         #[test]
         fn should_serialize_and_deserialize_with_arbitrary_values(
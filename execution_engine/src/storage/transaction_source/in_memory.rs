@@ -7,7 +7,7 @@ use casper_types::bytesrepr::Bytes;
 
 use crate::storage::{
     error::in_memory::Error,
-    transaction_source::{Readable, Transaction, TransactionSource, Writable},
+    transaction_source::{Readable, Scannable, Transaction, TransactionSource, Writable},
 };
 
 /// A marker for use in a mutex which represents the capability to perform a
@@ -56,6 +56,17 @@ impl Readable for InMemoryReadTransaction {
     }
 }
 
+impl Scannable for InMemoryReadTransaction {
+    fn scan_keys(
+        &self,
+        handle: Self::Handle,
+        start_after: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<Vec<Bytes>, Self::Error> {
+        Ok(scan_keys(self.view.get(&handle), start_after, limit))
+    }
+}
+
 /// A read-write transaction for the in-memory trie store.
 pub struct InMemoryReadWriteTransaction<'a> {
     view: HashMap<Option<String>, BytesMap>,
@@ -107,6 +118,38 @@ impl<'a> Writable for InMemoryReadWriteTransaction<'a> {
         sub_view.insert(Bytes::from(key), Bytes::from(value));
         Ok(())
     }
+
+    fn delete(&mut self, handle: Self::Handle, key: &[u8]) -> Result<bool, Self::Error> {
+        let removed = self
+            .view
+            .get_mut(&handle)
+            .and_then(|sub_view| sub_view.remove(&Bytes::from(key)));
+        Ok(removed.is_some())
+    }
+}
+
+impl<'a> Scannable for InMemoryReadWriteTransaction<'a> {
+    fn scan_keys(
+        &self,
+        handle: Self::Handle,
+        start_after: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<Vec<Bytes>, Self::Error> {
+        Ok(scan_keys(self.view.get(&handle), start_after, limit))
+    }
+}
+
+/// Returns up to `limit` keys of the given map in ascending order, starting after `start_after`.
+fn scan_keys(maybe_map: Option<&BytesMap>, start_after: Option<&[u8]>, limit: usize) -> Vec<Bytes> {
+    let mut keys: Vec<&Bytes> = match maybe_map {
+        Some(map) => map
+            .keys()
+            .filter(|key| start_after.map_or(true, |start_after| key.as_slice() > start_after))
+            .collect(),
+        None => return Vec::new(),
+    };
+    keys.sort();
+    keys.into_iter().take(limit).cloned().collect()
 }
 
 /// An environment for the in-memory trie store.
@@ -2,12 +2,13 @@ use std::path::Path;
 
 use casper_types::bytesrepr::Bytes;
 use lmdb::{
-    self, Database, Environment, EnvironmentFlags, RoTransaction, RwTransaction, WriteFlags,
+    self, Cursor, Database, Environment, EnvironmentFlags, RoTransaction, RwTransaction,
+    WriteFlags,
 };
 
 use crate::storage::{
     error,
-    transaction_source::{Readable, Transaction, TransactionSource, Writable},
+    transaction_source::{Readable, Scannable, Transaction, TransactionSource, Writable},
     MAX_DBS,
 };
 
@@ -34,6 +35,17 @@ impl<'a> Readable for RoTransaction<'a> {
     }
 }
 
+impl<'a> Scannable for RoTransaction<'a> {
+    fn scan_keys(
+        &self,
+        handle: Self::Handle,
+        start_after: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<Vec<Bytes>, Self::Error> {
+        scan_keys(self, handle, start_after, limit)
+    }
+}
+
 impl<'a> Transaction for RwTransaction<'a> {
     type Error = lmdb::Error;
 
@@ -59,6 +71,53 @@ impl<'a> Writable for RwTransaction<'a> {
         self.put(handle, &key, &value, WriteFlags::empty())
             .map_err(Into::into)
     }
+
+    fn delete(&mut self, handle: Self::Handle, key: &[u8]) -> Result<bool, Self::Error> {
+        match self.del(handle, &key, None) {
+            Ok(()) => Ok(true),
+            Err(lmdb::Error::NotFound) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl<'a> Scannable for RwTransaction<'a> {
+    fn scan_keys(
+        &self,
+        handle: Self::Handle,
+        start_after: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<Vec<Bytes>, Self::Error> {
+        scan_keys(self, handle, start_after, limit)
+    }
+}
+
+fn scan_keys<T: lmdb::Transaction>(
+    txn: &T,
+    handle: Database,
+    start_after: Option<&[u8]>,
+    limit: usize,
+) -> Result<Vec<Bytes>, lmdb::Error> {
+    let mut cursor = txn.open_ro_cursor(handle)?;
+    let keys = match start_after {
+        None => cursor
+            .iter()
+            .take(limit)
+            .map(|(key, _)| Bytes::from(key))
+            .collect(),
+        Some(start_after) => {
+            // `iter_from` panics if there is no key at or after the given one, so make sure it
+            // exists.
+            txn.get(handle, &start_after)?;
+            cursor
+                .iter_from(start_after)
+                .skip(1)
+                .take(limit)
+                .map(|(key, _)| Bytes::from(key))
+                .collect()
+        }
+    };
+    Ok(keys)
 }
 
 /// The environment for an LMDB-backed trie store.
@@ -33,6 +33,26 @@ pub trait Readable: Transaction {
 pub trait Writable: Transaction {
     /// Inserts a key-value pair into a given [`Transaction::Handle`].
     fn write(&mut self, handle: Self::Handle, key: &[u8], value: &[u8]) -> Result<(), Self::Error>;
+
+    /// Removes the value under the given key from a given [`Transaction::Handle`].
+    ///
+    /// Returns `true` if a value was removed, or `false` if there was none.
+    fn delete(&mut self, handle: Self::Handle, key: &[u8]) -> Result<bool, Self::Error>;
+}
+
+/// A transaction with the capability to list the keys stored under a given
+/// [`Handle`](Transaction::Handle).
+pub trait Scannable: Transaction {
+    /// Returns up to `limit` keys from a given [`Transaction::Handle`] in ascending order.
+    ///
+    /// If `start_after` is given, only the keys following it are returned.  In that case it has to
+    /// be an existing key.
+    fn scan_keys(
+        &self,
+        handle: Self::Handle,
+        start_after: Option<&[u8]>,
+        limit: usize,
+    ) -> Result<Vec<Bytes>, Self::Error>;
 }
 
 /// A source of transactions e.g. values that implement [`Readable`]
@@ -46,11 +66,13 @@ pub trait TransactionSource<'a> {
     type Handle;
 
     /// Represents the type of read transactions.
-    type ReadTransaction: Readable<Error = Self::Error, Handle = Self::Handle>;
+    type ReadTransaction: Readable<Error = Self::Error, Handle = Self::Handle>
+        + Scannable<Error = Self::Error, Handle = Self::Handle>;
 
     /// Represents the type of read-write transactions.
     type ReadWriteTransaction: Readable<Error = Self::Error, Handle = Self::Handle>
-        + Writable<Error = Self::Error, Handle = Self::Handle>;
+        + Writable<Error = Self::Error, Handle = Self::Handle>
+        + Scannable<Error = Self::Error, Handle = Self::Handle>;
 
     /// Creates a read transaction.
     fn create_read_txn(&'a self) -> Result<Self::ReadTransaction, Self::Error>;
@@ -8,6 +8,7 @@ pub(crate) mod operations;
 #[cfg(test)]
 mod tests;
 
+pub use self::operations::{DiffEntry, PruneResult, TriePruner};
 use crate::shared::newtypes::Blake2bHash;
 
 use crate::storage::{store::Store, trie::Trie};
@@ -3,7 +3,7 @@ mod tests;
 
 use std::{
     cmp,
-    collections::{HashSet, VecDeque},
+    collections::{BTreeMap, HashSet, VecDeque},
     convert::TryInto,
     mem,
 };
@@ -15,10 +15,10 @@ use casper_types::bytesrepr::{self, FromBytes, ToBytes};
 use crate::{
     shared::newtypes::{Blake2bHash, CorrelationId},
     storage::{
-        transaction_source::{Readable, Writable},
+        transaction_source::{Readable, Scannable, Writable},
         trie::{
             merkle_proof::{TrieMerkleProof, TrieMerkleProofStep},
-            Parents, Pointer, Trie, RADIX, USIZE_EXCEEDS_U8,
+            Parents, Pointer, PointerBlock, Trie, RADIX, USIZE_EXCEEDS_U8,
         },
         trie_store::TrieStore,
     },
@@ -753,6 +753,216 @@ where
     Ok(trie_hash)
 }
 
+/// The outcome of a single step of [`TriePruner::step`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruneResult {
+    /// The pruning pass is still in progress and requires further steps.
+    InProgress,
+    /// The pruning pass has finished.
+    Finished {
+        /// The number of trie nodes deleted during the pass.
+        deleted_count: u64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PrunePhase {
+    /// Visiting all trie nodes reachable from the retained roots.
+    Mark,
+    /// Deleting all trie nodes which have not been marked.
+    Sweep,
+}
+
+/// A single mark-and-sweep pass over a trie store.
+#[derive(Debug)]
+struct PrunePass {
+    phase: PrunePhase,
+    /// Marked trie nodes whose children have not been visited yet.
+    to_visit: Vec<Blake2bHash>,
+    /// Trie nodes reachable from the retained roots.
+    marked: HashSet<Blake2bHash>,
+    /// The last marked trie node encountered while sweeping, from which the sweep resumes.
+    resume_after: Option<Blake2bHash>,
+    deleted_count: u64,
+}
+
+impl PrunePass {
+    fn new() -> Self {
+        PrunePass {
+            phase: PrunePhase::Mark,
+            to_visit: Vec::new(),
+            marked: HashSet::new(),
+            resume_after: None,
+            deleted_count: 0,
+        }
+    }
+
+    fn retain(&mut self, trie_key: Blake2bHash) {
+        if self.marked.insert(trie_key) {
+            self.to_visit.push(trie_key);
+        }
+    }
+
+    fn extend(&mut self, trie_keys: impl IntoIterator<Item = Blake2bHash>) {
+        for trie_key in trie_keys {
+            self.retain(trie_key);
+        }
+    }
+
+    /// Marks the children of up to `limit` marked trie nodes.
+    fn mark<K, V, T, S, E>(&mut self, txn: &T, store: &S, limit: usize) -> Result<(), E>
+    where
+        K: FromBytes,
+        V: FromBytes,
+        T: Readable<Handle = S::Handle>,
+        S: TrieStore<K, V>,
+        S::Error: From<T::Error>,
+        E: From<S::Error>,
+    {
+        for _ in 0..limit {
+            let trie_key = match self.to_visit.pop() {
+                Some(trie_key) => trie_key,
+                None => break,
+            };
+            match store.get(txn, &trie_key)? {
+                // Missing nodes have nothing to retain.
+                None | Some(Trie::Leaf { .. }) => (),
+                Some(Trie::Node { pointer_block }) => {
+                    for (_, pointer) in pointer_block.to_indexed_pointers() {
+                        self.retain(pointer.into_hash());
+                    }
+                }
+                Some(Trie::Extension { pointer, .. }) => self.retain(pointer.into_hash()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Deletes unmarked trie nodes among the next `limit` keys of the store.
+    ///
+    /// Returns `true` if the end of the store has been reached.
+    fn sweep<K, V, T, S, E>(&mut self, txn: &mut T, store: &S, limit: usize) -> Result<bool, E>
+    where
+        T: Writable<Handle = S::Handle> + Scannable<Handle = S::Handle>,
+        S: TrieStore<K, V>,
+        S::Error: From<T::Error>,
+        E: From<S::Error> + From<bytesrepr::Error>,
+    {
+        // Marked nodes are never deleted, so the one we resume after is guaranteed to exist.
+        let start_after = self
+            .resume_after
+            .as_ref()
+            .map(ToBytes::to_bytes)
+            .transpose()?;
+        let keys = txn
+            .scan_keys(store.handle(), start_after.as_deref(), limit)
+            .map_err(S::Error::from)?;
+        let reached_end = keys.len() < limit;
+        for key in keys {
+            let trie_key: Blake2bHash = bytesrepr::deserialize(key.into())?;
+            if self.marked.contains(&trie_key) {
+                self.resume_after = Some(trie_key);
+            } else if store.delete(txn, &trie_key)? {
+                self.deleted_count += 1;
+            }
+        }
+        Ok(reached_end)
+    }
+}
+
+/// Removes the trie nodes which are unreachable from a set of retained state roots.
+///
+/// Pruning is done in passes, each consisting of a mark phase, which visits all trie nodes
+/// reachable from the retained roots, followed by a sweep phase, which deletes all other trie nodes
+/// in the store.  Both phases proceed in steps of bounded size, so that pruning can be interleaved
+/// with other use of the store.
+///
+/// Any root written to the store while a pass is in progress must be reported via
+/// [`TriePruner::record_root`] before the next step, otherwise its nodes may be deleted.
+#[derive(Debug, Default)]
+pub struct TriePruner {
+    pass: Option<PrunePass>,
+    latest_root: Option<Blake2bHash>,
+}
+
+impl TriePruner {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Records a trie node which has been written to the store, e.g. the state root resulting
+    /// from a commit.
+    ///
+    /// The node and its descendants are retained by the pass in progress, if any.  The most
+    /// recently recorded node is also retained by the next pass, as it may still be in use as a
+    /// prestate.
+    pub fn record_root(&mut self, root: Blake2bHash) {
+        if let Some(pass) = self.pass.as_mut() {
+            pass.retain(root);
+        }
+        self.latest_root = Some(root);
+    }
+
+    /// Returns `true` if a pruning pass has been started but not finished yet.
+    pub fn is_in_progress(&self) -> bool {
+        self.pass.is_some()
+    }
+
+    /// Performs a step of the current pruning pass, starting a new pass if none is in progress.
+    ///
+    /// All trie nodes reachable from `retained_roots` are kept.  Each step visits at most
+    /// `batch_size` trie nodes, except when roots recorded during the sweep phase need to be
+    /// marked.
+    pub fn step<K, V, T, S, E>(
+        &mut self,
+        _correlation_id: CorrelationId,
+        txn: &mut T,
+        store: &S,
+        retained_roots: &[Blake2bHash],
+        batch_size: usize,
+    ) -> Result<PruneResult, E>
+    where
+        K: FromBytes,
+        V: FromBytes,
+        T: Readable<Handle = S::Handle>
+            + Writable<Handle = S::Handle>
+            + Scannable<Handle = S::Handle>,
+        S: TrieStore<K, V>,
+        S::Error: From<T::Error>,
+        E: From<S::Error> + From<bytesrepr::Error>,
+    {
+        // A sweep over zero keys would finish the pass without deleting anything.
+        let batch_size = batch_size.max(1);
+        let latest_root = self.latest_root;
+        let pass = self.pass.get_or_insert_with(|| {
+            let mut pass = PrunePass::new();
+            pass.extend(latest_root);
+            pass
+        });
+        pass.extend(retained_roots.iter().copied());
+
+        match pass.phase {
+            PrunePhase::Mark => {
+                pass.mark::<K, V, T, S, E>(txn, store, batch_size)?;
+                if pass.to_visit.is_empty() {
+                    pass.phase = PrunePhase::Sweep;
+                }
+                Ok(PruneResult::InProgress)
+            }
+            PrunePhase::Sweep => {
+                // Anything recorded since the mark phase needs to be marked before deleting.
+                pass.mark::<K, V, T, S, E>(txn, store, usize::MAX)?;
+                if !pass.sweep::<K, V, T, S, E>(txn, store, batch_size)? {
+                    return Ok(PruneResult::InProgress);
+                }
+                let deleted_count = pass.deleted_count;
+                self.pass = None;
+                Ok(PruneResult::Finished { deleted_count })
+            }
+        }
+    }
+}
+
 enum KeysIteratorState<K, V, S: TrieStore<K, V>> {
     /// Iterate normally
     Ok,
@@ -955,3 +1165,277 @@ where
         state: init_state,
     }
 }
+
+/// A difference between two tries under a single key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffEntry<K, V> {
+    /// The key is only present in the second trie.
+    Added { key: K, value: V },
+    /// The key is only present in the first trie.
+    Removed { key: K, value: V },
+    /// The key is present in both tries with different values.
+    Modified { key: K, old_value: V, new_value: V },
+}
+
+impl<K, V> DiffEntry<K, V> {
+    /// Returns the key under which the difference was found.
+    pub fn key(&self) -> &K {
+        match self {
+            DiffEntry::Added { key, .. }
+            | DiffEntry::Removed { key, .. }
+            | DiffEntry::Modified { key, .. } => key,
+        }
+    }
+}
+
+/// A position in one of the two tries being compared, relative to the current path.
+enum DiffCursor<K, V> {
+    /// No subtrie at this path.
+    Empty,
+    /// A subtrie which has not been read yet.
+    Hash(Blake2bHash),
+    Leaf {
+        key: K,
+        value: V,
+    },
+    Node(Box<PointerBlock>),
+    /// The remainder of an extension which has already been partially descended.
+    Extension {
+        affix: Vec<u8>,
+        pointer: Blake2bHash,
+    },
+}
+
+impl<K: Eq, V: Eq> DiffCursor<K, V> {
+    /// Returns `true` if both cursors are known to point to identical subtries.
+    fn is_same_as(&self, other: &Self) -> bool {
+        match (self, other) {
+            (DiffCursor::Empty, DiffCursor::Empty) => true,
+            (DiffCursor::Hash(left), DiffCursor::Hash(right)) => left == right,
+            (
+                DiffCursor::Leaf {
+                    key: left_key,
+                    value: left_value,
+                },
+                DiffCursor::Leaf {
+                    key: right_key,
+                    value: right_value,
+                },
+            ) => left_key == right_key && left_value == right_value,
+            (
+                DiffCursor::Extension {
+                    affix: left_affix,
+                    pointer: left_pointer,
+                },
+                DiffCursor::Extension {
+                    affix: right_affix,
+                    pointer: right_pointer,
+                },
+            ) => left_affix == right_affix && left_pointer == right_pointer,
+            _ => false,
+        }
+    }
+
+    fn is_branch(&self) -> bool {
+        matches!(self, DiffCursor::Node(_) | DiffCursor::Extension { .. })
+    }
+
+    /// Splits a branch into the cursors of its children, indexed by the next byte of the path.
+    fn into_children(self) -> Vec<DiffCursor<K, V>> {
+        let mut children: Vec<DiffCursor<K, V>> = (0..RADIX).map(|_| DiffCursor::Empty).collect();
+        match self {
+            DiffCursor::Node(pointer_block) => {
+                for (index, pointer) in pointer_block.to_indexed_pointers() {
+                    children[index as usize] = DiffCursor::Hash(pointer.into_hash());
+                }
+            }
+            DiffCursor::Extension { affix, pointer } => {
+                let child = if affix.len() == 1 {
+                    DiffCursor::Hash(pointer)
+                } else {
+                    DiffCursor::Extension {
+                        affix: affix[1..].to_vec(),
+                        pointer,
+                    }
+                };
+                children[affix[0] as usize] = child;
+            }
+            DiffCursor::Empty | DiffCursor::Hash(_) | DiffCursor::Leaf { .. } => {
+                unreachable!("should only split branches")
+            }
+        }
+        children
+    }
+}
+
+/// An iterator over the differences between two tries.
+pub struct DiffIterator<'a, 'b, K, V, T, S: TrieStore<K, V>> {
+    to_compare: Vec<(DiffCursor<K, V>, DiffCursor<K, V>)>,
+    pending: VecDeque<DiffEntry<K, V>>,
+    store: &'a S,
+    txn: &'b T,
+    failed: bool,
+}
+
+impl<'a, 'b, K, V, T, S> DiffIterator<'a, 'b, K, V, T, S>
+where
+    K: ToBytes + FromBytes + Clone + Eq,
+    V: FromBytes + Clone + Eq,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+{
+    /// Reads the trie a cursor points to, if it has not been read yet.
+    fn load(&self, cursor: DiffCursor<K, V>) -> Result<DiffCursor<K, V>, S::Error> {
+        let trie_key = match cursor {
+            DiffCursor::Hash(trie_key) => trie_key,
+            cursor => return Ok(cursor),
+        };
+        let maybe_trie = self.store.get(self.txn, &trie_key)?;
+        debug_assert!(maybe_trie.is_some());
+        Ok(match maybe_trie {
+            None => DiffCursor::Empty,
+            Some(Trie::Leaf { key, value }) => DiffCursor::Leaf { key, value },
+            Some(Trie::Node { pointer_block }) => DiffCursor::Node(pointer_block),
+            Some(Trie::Extension { affix, pointer }) => DiffCursor::Extension {
+                affix: affix.to_vec(),
+                pointer: pointer.into_hash(),
+            },
+        })
+    }
+
+    /// Collects all leaves of the subtrie a cursor points to, keyed by their serialized keys.
+    fn leaves(&self, cursor: DiffCursor<K, V>) -> Result<BTreeMap<Vec<u8>, (K, V)>, S::Error> {
+        let mut leaves = BTreeMap::new();
+        let mut to_visit = vec![cursor];
+        while let Some(cursor) = to_visit.pop() {
+            match self.load(cursor)? {
+                DiffCursor::Empty | DiffCursor::Hash(_) => (),
+                DiffCursor::Leaf { key, value } => {
+                    leaves.insert(key.to_bytes()?, (key, value));
+                }
+                DiffCursor::Node(pointer_block) => to_visit.extend(
+                    pointer_block
+                        .to_indexed_pointers()
+                        .map(|(_, pointer)| DiffCursor::Hash(pointer.into_hash())),
+                ),
+                DiffCursor::Extension { pointer, .. } => to_visit.push(DiffCursor::Hash(pointer)),
+            }
+        }
+        Ok(leaves)
+    }
+
+    /// Compares the subtries at the same path in both tries.
+    ///
+    /// Branches are split into their children to be compared later, while anything else is
+    /// resolved into diff entries by comparing the leaves on both sides.
+    fn compare(&mut self, left: DiffCursor<K, V>, right: DiffCursor<K, V>) -> Result<(), S::Error> {
+        if left.is_same_as(&right) {
+            return Ok(());
+        }
+        let left = self.load(left)?;
+        let right = self.load(right)?;
+        if left.is_same_as(&right) {
+            return Ok(());
+        }
+
+        if left.is_branch() && right.is_branch() {
+            let children = left.into_children().into_iter().zip(right.into_children());
+            // Pushed in reverse, so that children are compared in order of their index.
+            for (left_child, right_child) in children.rev() {
+                if !left_child.is_same_as(&right_child) {
+                    self.to_compare.push((left_child, right_child));
+                }
+            }
+            return Ok(());
+        }
+
+        let mut left_leaves = self.leaves(left)?;
+        let mut entries = Vec::new();
+        for (key_bytes, (key, new_value)) in self.leaves(right)? {
+            match left_leaves.remove(&key_bytes) {
+                None => entries.push((
+                    key_bytes,
+                    DiffEntry::Added {
+                        key,
+                        value: new_value,
+                    },
+                )),
+                Some((_, old_value)) if old_value != new_value => entries.push((
+                    key_bytes,
+                    DiffEntry::Modified {
+                        key,
+                        old_value,
+                        new_value,
+                    },
+                )),
+                Some(_) => (),
+            }
+        }
+        entries.extend(
+            left_leaves
+                .into_iter()
+                .map(|(key_bytes, (key, value))| (key_bytes, DiffEntry::Removed { key, value })),
+        );
+        // The serialized key is the leaf's path, so sorting by it keeps the overall output in
+        // path order.
+        entries.sort_by(|(left_bytes, _), (right_bytes, _)| left_bytes.cmp(right_bytes));
+        self.pending
+            .extend(entries.into_iter().map(|(_, entry)| entry));
+        Ok(())
+    }
+}
+
+impl<'a, 'b, K, V, T, S> Iterator for DiffIterator<'a, 'b, K, V, T, S>
+where
+    K: ToBytes + FromBytes + Clone + Eq,
+    V: FromBytes + Clone + Eq,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+{
+    type Item = Result<DiffEntry<K, V>, S::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.pending.pop_front() {
+                return Some(Ok(entry));
+            }
+            if self.failed {
+                return None;
+            }
+            let (left, right) = self.to_compare.pop()?;
+            if let Err(error) = self.compare(left, right) {
+                self.failed = true;
+                return Some(Err(error));
+            }
+        }
+    }
+}
+
+/// Returns the iterator over the differences between the tries at `left_root` and `right_root`.
+///
+/// Subtries which are identical in both tries are skipped without being read.  Entries are yielded
+/// in the order of their serialized keys.  Both roots should be present in the store.
+pub fn diff<'a, 'b, K, V, T, S>(
+    _correlation_id: CorrelationId,
+    txn: &'b T,
+    store: &'a S,
+    left_root: &Blake2bHash,
+    right_root: &Blake2bHash,
+) -> DiffIterator<'a, 'b, K, V, T, S>
+where
+    K: ToBytes + FromBytes + Clone + Eq,
+    V: FromBytes + Clone + Eq,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+{
+    DiffIterator {
+        to_compare: vec![(DiffCursor::Hash(*left_root), DiffCursor::Hash(*right_root))],
+        pending: VecDeque::new(),
+        store,
+        txn,
+        failed: false,
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+
+use super::*;
+use crate::storage::trie_store::operations::{diff, DiffEntry};
+
+type TestDiffEntry = DiffEntry<TestKey, TestValue>;
+
+fn entry_key(entry: &TestDiffEntry) -> TestKey {
+    match entry {
+        DiffEntry::Added { key, .. }
+        | DiffEntry::Removed { key, .. }
+        | DiffEntry::Modified { key, .. } => *key,
+    }
+}
+
+/// Returns the leaves as a map, with later leaves overriding earlier ones under the same key.
+fn leaves_to_map(leaves: &[TestTrie]) -> BTreeMap<TestKey, TestValue> {
+    leaves
+        .iter()
+        .map(|leaf| match leaf {
+            Trie::Leaf { key, value } => (*key, *value),
+            _ => panic!("leaves should contain only leaves"),
+        })
+        .collect()
+}
+
+fn expected_diff(
+    left: &BTreeMap<TestKey, TestValue>,
+    right: &BTreeMap<TestKey, TestValue>,
+) -> Vec<TestDiffEntry> {
+    let mut expected = Vec::new();
+    for (key, value) in left {
+        match right.get(key) {
+            None => expected.push(DiffEntry::Removed {
+                key: *key,
+                value: *value,
+            }),
+            Some(new_value) if new_value != value => expected.push(DiffEntry::Modified {
+                key: *key,
+                old_value: *value,
+                new_value: *new_value,
+            }),
+            Some(_) => (),
+        }
+    }
+    for (key, value) in right {
+        if !left.contains_key(key) {
+            expected.push(DiffEntry::Added {
+                key: *key,
+                value: *value,
+            });
+        }
+    }
+    expected.sort_by_key(entry_key);
+    expected
+}
+
+fn diff_between_all_states_has_expected_results<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    empty_root: &Blake2bHash,
+) -> Result<(), E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<bytesrepr::Error>,
+{
+    let mut states = vec![(*empty_root, BTreeMap::new())];
+
+    let roots =
+        write_roots::<_, _, _, _, E>(correlation_id, environment, store, empty_root, &TEST_LEAVES)?;
+    for (n, root) in roots.iter().enumerate() {
+        states.push((*root, leaves_to_map(&TEST_LEAVES[..=n])));
+    }
+
+    let last_root = *roots.last().expect("should have written roots");
+    let roots = write_roots::<_, _, _, _, E>(
+        correlation_id,
+        environment,
+        store,
+        &last_root,
+        &TEST_LEAVES_UPDATED,
+    )?;
+    for (n, root) in roots.iter().enumerate() {
+        let leaves: Vec<TestTrie> = TEST_LEAVES
+            .iter()
+            .chain(&TEST_LEAVES_UPDATED[..=n])
+            .cloned()
+            .collect();
+        states.push((*root, leaves_to_map(&leaves)));
+    }
+
+    let txn = environment.create_read_txn()?;
+    for (left_root, left_leaves) in &states {
+        for (right_root, right_leaves) in &states {
+            // The serialized test keys sort like the keys themselves, so no sorting is needed.
+            let actual = diff::<TestKey, TestValue, _, _>(
+                correlation_id,
+                &txn,
+                store,
+                left_root,
+                right_root,
+            )
+            .collect::<Result<Vec<_>, S::Error>>()?;
+            assert_eq!(actual, expected_diff(left_leaves, right_leaves));
+        }
+    }
+    txn.commit()?;
+    Ok(())
+}
+
+#[test]
+fn lmdb_diff_between_all_states_has_expected_results() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+    let context = LmdbTestContext::new(&tries).unwrap();
+
+    diff_between_all_states_has_expected_results::<_, _, error::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+    )
+    .unwrap();
+}
+
+#[test]
+fn in_memory_diff_between_all_states_has_expected_results() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+
+    diff_between_all_states_has_expected_results::<_, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+    )
+    .unwrap();
+}
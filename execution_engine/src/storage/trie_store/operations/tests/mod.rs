@@ -1,5 +1,7 @@
+mod diff;
 mod keys;
 mod proptests;
+mod prune;
 mod read;
 mod scan;
 mod synchronize;
@@ -825,6 +827,33 @@ where
     Ok(results)
 }
 
+/// Writes the given leaves one at a time, returning the root hash after each write.
+fn write_roots<'a, K, V, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    root_hash: &Blake2bHash,
+    leaves: &[Trie<K, V>],
+) -> Result<Vec<Blake2bHash>, E>
+where
+    K: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    V: ToBytes + FromBytes + Clone + Eq,
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<bytesrepr::Error>,
+{
+    let results =
+        write_leaves::<_, _, _, _, E>(correlation_id, environment, store, root_hash, leaves)?;
+    Ok(results
+        .into_iter()
+        .filter_map(|result| match result {
+            WriteResult::Written(hash) => Some(hash),
+            _ => None,
+        })
+        .collect())
+}
+
 fn check_pairs_proofs<'a, K, V, R, S, E>(
     correlation_id: CorrelationId,
     environment: &'a R,
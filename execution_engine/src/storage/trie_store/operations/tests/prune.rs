@@ -0,0 +1,279 @@
+use std::collections::HashSet;
+
+use super::*;
+use crate::storage::{
+    transaction_source::Scannable,
+    trie_store::operations::{PruneResult, TriePruner},
+};
+
+const PRUNE_BATCH_SIZE: usize = 2;
+
+/// Runs the pruner until its pass has finished, returning the number of deleted trie nodes.
+fn prune_until_finished<'a, K, V, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    pruner: &mut TriePruner,
+    retained_roots: &[Blake2bHash],
+) -> Result<u64, E>
+where
+    K: FromBytes,
+    V: FromBytes,
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<bytesrepr::Error>,
+{
+    loop {
+        let mut txn = environment.create_read_write_txn()?;
+        let result = pruner.step::<K, V, _, _, E>(
+            correlation_id,
+            &mut txn,
+            store,
+            retained_roots,
+            PRUNE_BATCH_SIZE,
+        )?;
+        txn.commit()?;
+        if let PruneResult::Finished { deleted_count } = result {
+            return Ok(deleted_count);
+        }
+    }
+}
+
+/// Returns the hashes of all trie nodes reachable from `root`.
+fn reachable_tries<K, V, T, S, E>(
+    txn: &T,
+    store: &S,
+    root: &Blake2bHash,
+) -> Result<HashSet<Blake2bHash>, E>
+where
+    K: FromBytes,
+    V: FromBytes,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error>,
+{
+    let mut reachable = HashSet::new();
+    let mut to_visit = vec![*root];
+    while let Some(trie_key) = to_visit.pop() {
+        if !reachable.insert(trie_key) {
+            continue;
+        }
+        match store.get(txn, &trie_key)? {
+            None | Some(Trie::Leaf { .. }) => (),
+            Some(Trie::Node { pointer_block }) => to_visit.extend(
+                pointer_block
+                    .to_indexed_pointers()
+                    .map(|(_, pointer)| pointer.into_hash()),
+            ),
+            Some(Trie::Extension { pointer, .. }) => to_visit.push(pointer.into_hash()),
+        }
+    }
+    Ok(reachable)
+}
+
+/// Asserts that the store holds exactly the trie nodes reachable from `retained_roots`.
+fn check_only_reachable_remain<'a, K, V, R, S, E>(
+    environment: &'a R,
+    store: &S,
+    retained_roots: &[Blake2bHash],
+) -> Result<(), E>
+where
+    K: FromBytes,
+    V: FromBytes,
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<bytesrepr::Error>,
+{
+    let txn = environment.create_read_txn()?;
+    let mut expected = HashSet::new();
+    for root in retained_roots {
+        expected.extend(reachable_tries::<K, V, _, _, E>(&txn, store, root)?);
+    }
+    let mut actual = HashSet::new();
+    for key_bytes in txn.scan_keys(store.handle(), None, usize::MAX)? {
+        let (trie_key, _) = Blake2bHash::from_bytes(&key_bytes)?;
+        actual.insert(trie_key);
+    }
+    assert_eq!(expected, actual);
+    txn.commit()?;
+    Ok(())
+}
+
+fn prune_keeps_only_retained_roots<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    empty_root: &Blake2bHash,
+) -> Result<(), E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<bytesrepr::Error>,
+{
+    let roots =
+        write_roots::<_, _, _, _, E>(correlation_id, environment, store, empty_root, &TEST_LEAVES)?;
+    let last_root = *roots.last().expect("should have written roots");
+
+    let mut pruner = TriePruner::new();
+    let retained_roots = [last_root, *empty_root];
+    let deleted_count = prune_until_finished::<TestKey, TestValue, _, _, E>(
+        correlation_id,
+        environment,
+        store,
+        &mut pruner,
+        &retained_roots,
+    )?;
+    assert!(deleted_count > 0);
+    assert!(!pruner.is_in_progress());
+
+    check_only_reachable_remain::<TestKey, TestValue, _, _, E>(
+        environment,
+        store,
+        &retained_roots,
+    )?;
+    check_leaves::<_, _, _, _, E>(
+        correlation_id,
+        environment,
+        store,
+        &last_root,
+        &TEST_LEAVES,
+        &[],
+    )?;
+
+    // A second pass has nothing left to delete.
+    let deleted_count = prune_until_finished::<TestKey, TestValue, _, _, E>(
+        correlation_id,
+        environment,
+        store,
+        &mut pruner,
+        &retained_roots,
+    )?;
+    assert_eq!(deleted_count, 0);
+    Ok(())
+}
+
+fn prune_keeps_roots_recorded_during_pass<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    empty_root: &Blake2bHash,
+) -> Result<(), E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<bytesrepr::Error>,
+{
+    let (old_leaves, new_leaves) = TEST_LEAVES.split_at(TEST_LEAVES_LENGTH / 2);
+    let roots =
+        write_roots::<_, _, _, _, E>(correlation_id, environment, store, empty_root, old_leaves)?;
+    let old_root = *roots.last().expect("should have written roots");
+
+    let mut pruner = TriePruner::new();
+    pruner.record_root(old_root);
+    {
+        let mut txn = environment.create_read_write_txn()?;
+        let result = pruner.step::<TestKey, TestValue, _, _, E>(
+            correlation_id,
+            &mut txn,
+            store,
+            &[*empty_root],
+            1,
+        )?;
+        txn.commit()?;
+        assert!(matches!(result, PruneResult::InProgress));
+    }
+
+    // Commit a new root on top of the old one while the pass is still running.
+    let roots =
+        write_roots::<_, _, _, _, E>(correlation_id, environment, store, &old_root, new_leaves)?;
+    let new_root = *roots.last().expect("should have written roots");
+    pruner.record_root(new_root);
+
+    prune_until_finished::<TestKey, TestValue, _, _, E>(
+        correlation_id,
+        environment,
+        store,
+        &mut pruner,
+        &[*empty_root],
+    )?;
+
+    check_only_reachable_remain::<TestKey, TestValue, _, _, E>(
+        environment,
+        store,
+        &[old_root, new_root, *empty_root],
+    )?;
+    check_leaves::<_, _, _, _, E>(
+        correlation_id,
+        environment,
+        store,
+        &new_root,
+        &TEST_LEAVES,
+        &[],
+    )?;
+    Ok(())
+}
+
+#[test]
+fn lmdb_prune_keeps_only_retained_roots() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+    let context = LmdbTestContext::new(&tries).unwrap();
+
+    prune_keeps_only_retained_roots::<_, _, error::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+    )
+    .unwrap();
+}
+
+#[test]
+fn in_memory_prune_keeps_only_retained_roots() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+
+    prune_keeps_only_retained_roots::<_, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+    )
+    .unwrap();
+}
+
+#[test]
+fn lmdb_prune_keeps_roots_recorded_during_pass() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+    let context = LmdbTestContext::new(&tries).unwrap();
+
+    prune_keeps_roots_recorded_during_pass::<_, _, error::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+    )
+    .unwrap();
+}
+
+#[test]
+fn in_memory_prune_keeps_roots_recorded_during_pass() {
+    let correlation_id = CorrelationId::new();
+    let (root_hash, tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+
+    prune_keeps_roots_recorded_during_pass::<_, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &root_hash,
+    )
+    .unwrap();
+}
@@ -0,0 +1,155 @@
+use casper_execution_engine::{
+    core::{engine_state, execution},
+    shared::stored_value::StoredValue,
+    storage::global_state::{in_memory::InMemoryGlobalState, StateProvider},
+};
+use casper_types::{
+    account::AccountHash, bytesrepr::FromBytes, CLType, CLTyped, ContractHash, ContractPackageHash,
+    EntryPoint, EntryPoints, Key, RuntimeArgs,
+};
+
+use crate::{
+    harness::{CallResult, TestHarness},
+    internal::{DeployItemBuilder, DEFAULT_ACCOUNT_ADDR},
+    Error, Result,
+};
+
+/// A handle to a contract stored in a [`TestHarness`], obtained via [`TestHarness::contract`] or
+/// [`TestHarness::contract_by_name`].
+///
+/// Calls are made to the contract version which was current when the handle was created, and the
+/// supplied arguments are checked against the entry point's parameters before being sent.
+pub struct Contract<'a, S = InMemoryGlobalState> {
+    harness: &'a mut TestHarness<S>,
+    package_hash: ContractPackageHash,
+    contract_hash: ContractHash,
+    entry_points: EntryPoints,
+    caller: AccountHash,
+}
+
+impl<'a, S> Contract<'a, S>
+where
+    S: StateProvider,
+    engine_state::Error: From<S::Error>,
+    S::Error: Into<execution::Error>,
+{
+    pub(crate) fn new(
+        harness: &'a mut TestHarness<S>,
+        package_hash: ContractPackageHash,
+    ) -> Result<Self> {
+        let contract_hash = match harness.builder().query(None, package_hash.into(), &[])? {
+            StoredValue::ContractPackage(package) => package.current_contract_hash(),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            Error::from(format!(
+                "{} is not a contract package with an enabled version",
+                Key::from(package_hash)
+            ))
+        })?;
+        let entry_points = match harness.builder().query(None, contract_hash.into(), &[])? {
+            StoredValue::Contract(contract) => contract.entry_points().clone(),
+            _ => {
+                return Err(Error::from(format!(
+                    "{} is not a contract",
+                    Key::from(contract_hash)
+                )))
+            }
+        };
+        Ok(Contract {
+            harness,
+            package_hash,
+            contract_hash,
+            entry_points,
+            caller: *DEFAULT_ACCOUNT_ADDR,
+        })
+    }
+
+    /// Returns `self` calling the contract as `caller`.
+    pub fn as_account(mut self, caller: AccountHash) -> Self {
+        self.caller = caller;
+        self
+    }
+
+    /// Returns the hash of the contract package.
+    pub fn package_hash(&self) -> ContractPackageHash {
+        self.package_hash
+    }
+
+    /// Returns the hash of the called contract version.
+    pub fn contract_hash(&self) -> ContractHash {
+        self.contract_hash
+    }
+
+    /// Returns the contract's entry points.
+    pub fn entry_points(&self) -> impl Iterator<Item = &EntryPoint> {
+        let entry_points = &self.entry_points;
+        entry_points
+            .keys()
+            .filter_map(move |name| entry_points.get(name))
+    }
+
+    /// Calls `entry_point` with `args`, returning the result of the execution.
+    ///
+    /// Returns an [`Error`] without executing anything if the contract has no such entry point, or
+    /// `args` don't match its parameters.
+    pub fn call(&mut self, entry_point: &str, args: RuntimeArgs) -> Result<CallResult> {
+        let entry_point = self.entry_points.get(entry_point).ok_or_else(|| {
+            Error::from(format!(
+                "{} has no entry point {}",
+                Key::from(self.contract_hash),
+                entry_point
+            ))
+        })?;
+        check_args(entry_point, &args)?;
+
+        let deploy_item_builder = DeployItemBuilder::new().with_stored_versioned_contract_by_hash(
+            self.package_hash.value(),
+            None,
+            entry_point.name(),
+            args,
+        );
+        Ok(self.harness.run(self.caller, deploy_item_builder))
+    }
+
+    /// Returns the value stored under `name` in the contract's named keys.
+    pub fn named_key_value<T: CLTyped + FromBytes>(&self, name: &str) -> Result<T> {
+        self.harness
+            .named_key_value(Key::from(self.contract_hash), name)
+    }
+}
+
+/// Checks that `args` holds exactly the parameters of `entry_point`, with matching types.
+fn check_args(entry_point: &EntryPoint, args: &RuntimeArgs) -> Result<()> {
+    for parameter in entry_point.args() {
+        let (name, cl_type): (String, CLType) = parameter.clone().into();
+        match args.get(&name) {
+            None => {
+                return Err(Error::from(format!(
+                    "missing argument {} of entry point {}",
+                    name,
+                    entry_point.name()
+                )))
+            }
+            Some(cl_value) if *cl_value.cl_type() != cl_type => {
+                return Err(Error::from(format!(
+                    "argument {} of entry point {} should be {:?}, but is {:?}",
+                    name,
+                    entry_point.name(),
+                    cl_type,
+                    cl_value.cl_type()
+                )))
+            }
+            Some(_) => (),
+        }
+    }
+    if args.len() != entry_point.args().len() {
+        return Err(Error::from(format!(
+            "entry point {} takes {} arguments, but {} were given",
+            entry_point.name(),
+            entry_point.args().len(),
+            args.len()
+        )));
+    }
+    Ok(())
+}
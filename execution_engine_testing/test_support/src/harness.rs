@@ -0,0 +1,360 @@
+use std::{convert::TryFrom, fmt::Debug};
+
+use rand::Rng;
+
+use casper_execution_engine::{
+    core::{engine_state, execution},
+    shared::{gas::Gas, stored_value::StoredValue},
+    storage::global_state::{in_memory::InMemoryGlobalState, StateProvider},
+};
+use casper_types::{
+    account::AccountHash, bytesrepr::FromBytes, runtime_args, CLTyped, CLValue, ContractEvent,
+    ContractPackageHash, Key, RuntimeArgs, U512,
+};
+
+use crate::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, StepRequestBuilder, WasmTestBuilder,
+        DEFAULT_PAYMENT, DEFAULT_PROTOCOL_VERSION,
+    },
+    Code, Contract, Error, Result,
+};
+
+const ARG_AMOUNT: &str = "amount";
+
+/// A higher-level harness for testing Wasm smart contracts, built with
+/// [`TestContextBuilder::build_harness`](crate::TestContextBuilder::build_harness) or
+/// [`TestContextBuilder::build_lmdb_harness`](crate::TestContextBuilder::build_lmdb_harness).
+///
+/// Every deploy run through the harness is paid for with standard payment, given a random deploy
+/// hash, executed at the harness' current block time and committed, whether or not it succeeded.
+pub struct TestHarness<S = InMemoryGlobalState> {
+    builder: WasmTestBuilder<S>,
+    block_time: u64,
+    era_id: u64,
+}
+
+/// A snapshot of the state of a [`TestHarness`], which can be restored to run several test cases
+/// from the same starting point.
+///
+/// A snapshot is only valid for the harness from which it was taken.
+pub struct Snapshot<S = InMemoryGlobalState> {
+    builder: WasmTestBuilder<S>,
+    block_time: u64,
+    era_id: u64,
+}
+
+impl<S> TestHarness<S>
+where
+    S: StateProvider,
+    engine_state::Error: From<S::Error>,
+    S::Error: Into<execution::Error>,
+{
+    pub(crate) fn new(builder: WasmTestBuilder<S>, block_time: u64) -> Self {
+        TestHarness {
+            builder,
+            block_time,
+            era_id: 0,
+        }
+    }
+
+    /// Runs the given session code as `caller`, returning the result of the execution.
+    pub fn run_session(
+        &mut self,
+        caller: AccountHash,
+        code: Code,
+        args: RuntimeArgs,
+    ) -> CallResult {
+        let deploy_item_builder = DeployItemBuilder::new();
+        let deploy_item_builder = match code {
+            Code::Path(path) => deploy_item_builder.with_session_code(path, args),
+            Code::NamedKey(name, entry_point) => {
+                deploy_item_builder.with_stored_session_named_key(&name, &entry_point, args)
+            }
+            Code::Hash(hash, entry_point) => {
+                deploy_item_builder.with_stored_session_hash(hash.into(), &entry_point, args)
+            }
+        };
+        self.run(caller, deploy_item_builder)
+    }
+
+    /// Returns a handle to the contract stored in the given contract package, calling it as the
+    /// default account until [`Contract::as_account`] is used.
+    ///
+    /// Returns an [`Error`] if there is no contract package under `package_hash`, or it has no
+    /// enabled contract version.
+    pub fn contract(&mut self, package_hash: ContractPackageHash) -> Result<Contract<S>> {
+        Contract::new(self, package_hash)
+    }
+
+    /// Returns a handle to the contract stored in the contract package under the given named key
+    /// of `account`.
+    pub fn contract_by_name(&mut self, account: AccountHash, name: &str) -> Result<Contract<S>> {
+        let package_hash = match self.named_key(Key::Account(account), name)? {
+            Key::Hash(hash) => ContractPackageHash::new(hash),
+            key => {
+                return Err(Error::from(format!(
+                    "named key {} of {} is not a contract package: {}",
+                    name, account, key
+                )))
+            }
+        };
+        self.contract(package_hash)
+    }
+
+    /// Returns the current block time in milliseconds.
+    pub fn block_time(&self) -> u64 {
+        self.block_time
+    }
+
+    /// Advances the block time used for subsequent deploys by `millis` milliseconds.
+    pub fn advance_block_time(&mut self, millis: u64) -> &mut Self {
+        self.block_time += millis;
+        self
+    }
+
+    /// Returns the current era.
+    pub fn era_id(&self) -> u64 {
+        self.era_id
+    }
+
+    /// Ends the current era at the current block time, running the auction and moving to the next
+    /// era.
+    pub fn advance_era(&mut self) -> &mut Self {
+        let step_request = StepRequestBuilder::new()
+            .with_parent_state_hash(self.builder.get_post_state_hash())
+            .with_protocol_version(*DEFAULT_PROTOCOL_VERSION)
+            .with_run_auction(true)
+            .with_next_era_id(self.era_id + 1)
+            .with_era_end_timestamp_millis(self.block_time)
+            .build();
+        self.builder.step(step_request);
+        self.era_id += 1;
+        self
+    }
+
+    /// Returns the balance of the main purse of `account`.
+    ///
+    /// Panics if the account doesn't exist.
+    pub fn balance(&self, account: AccountHash) -> U512 {
+        let account = self
+            .builder
+            .get_account(account)
+            .unwrap_or_else(|| panic!("account {} should exist", account));
+        self.builder.get_purse_balance(account.main_purse())
+    }
+
+    /// Returns the key stored under `name` in the named keys of the account or contract at `base`.
+    pub fn named_key(&self, base: Key, name: &str) -> Result<Key> {
+        let named_keys = match self.builder.query(None, base, &[])? {
+            StoredValue::Account(account) => account.named_keys().clone(),
+            StoredValue::Contract(contract) => contract.named_keys().clone(),
+            _ => {
+                return Err(Error::from(format!(
+                    "{} is neither an account nor a contract",
+                    base
+                )))
+            }
+        };
+        named_keys
+            .get(name)
+            .copied()
+            .ok_or_else(|| Error::from(format!("{} has no named key {}", base, name)))
+    }
+
+    /// Returns the value stored under `name` in the named keys of the account or contract at
+    /// `base`.
+    pub fn named_key_value<T: CLTyped + FromBytes>(&self, base: Key, name: &str) -> Result<T> {
+        let stored_value = self.builder.query(None, base, &[name.to_string()])?;
+        let cl_value = CLValue::try_from(stored_value)?;
+        Ok(cl_value.into_t()?)
+    }
+
+    /// Asserts that the main purse of `account` holds `expected` motes.
+    pub fn assert_balance<T: Into<U512>>(&self, account: AccountHash, expected: T) -> &Self {
+        let expected = expected.into();
+        let actual = self.balance(account);
+        assert_eq!(
+            actual, expected,
+            "balance of {} does not match; expected: {}  actual: {}",
+            account, expected, actual
+        );
+        self
+    }
+
+    /// Asserts that `expected` is stored under `name` in the named keys of the account or contract
+    /// at `base`.
+    pub fn assert_named_key_value<T>(&self, base: Key, name: &str, expected: T) -> &Self
+    where
+        T: CLTyped + FromBytes + PartialEq + Debug,
+    {
+        let actual: T = self
+            .named_key_value(base, name)
+            .unwrap_or_else(|error| panic!("failed to read named key {}: {:?}", name, error));
+        assert_eq!(
+            actual, expected,
+            "named key {} of {} does not match",
+            name, base
+        );
+        self
+    }
+
+    /// Asserts that the account or contract at `base` has no named key `name`.
+    pub fn assert_no_named_key(&self, base: Key, name: &str) -> &Self {
+        assert!(
+            self.named_key(base, name).is_err(),
+            "{} should not have named key {}",
+            base,
+            name
+        );
+        self
+    }
+
+    /// Takes a snapshot of the current state, which can later be restored with
+    /// [`restore`](Self::restore).
+    pub fn snapshot(&self) -> Snapshot<S> {
+        Snapshot {
+            builder: self.builder.clone(),
+            block_time: self.block_time,
+            era_id: self.era_id,
+        }
+    }
+
+    /// Restores the state captured by `snapshot`, discarding everything committed since.
+    pub fn restore(&mut self, snapshot: &Snapshot<S>) -> &mut Self {
+        self.builder = snapshot.builder.clone();
+        self.block_time = snapshot.block_time;
+        self.era_id = snapshot.era_id;
+        self
+    }
+
+    /// Returns the underlying builder, for anything not covered by the harness.
+    pub fn builder(&mut self) -> &mut WasmTestBuilder<S> {
+        &mut self.builder
+    }
+
+    pub(crate) fn run(
+        &mut self,
+        caller: AccountHash,
+        deploy_item_builder: DeployItemBuilder,
+    ) -> CallResult {
+        let deploy_item = deploy_item_builder
+            .with_address(caller)
+            .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+            .with_authorization_keys(&[caller])
+            .with_deploy_hash(rand::thread_rng().gen())
+            .build();
+        let execute_request = ExecuteRequestBuilder::new()
+            .push_deploy(deploy_item)
+            .with_block_time(self.block_time)
+            .build();
+
+        self.builder.exec(execute_request).commit();
+
+        let exec_results = self
+            .builder
+            .get_exec_results()
+            .last()
+            .expect("should have exec results");
+        let exec_result = utils::get_success_result(exec_results);
+        CallResult {
+            error: exec_result
+                .as_error()
+                .map(|_| utils::get_error_message(exec_results)),
+            cost: exec_result.cost(),
+            events: exec_result.effect().events.clone(),
+        }
+    }
+}
+
+/// The result of running a deploy in a [`TestHarness`].
+#[derive(Clone, Debug)]
+pub struct CallResult {
+    error: Option<String>,
+    cost: Gas,
+    events: Vec<ContractEvent>,
+}
+
+impl CallResult {
+    /// Returns `true` if the deploy executed successfully.
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Returns the error message if the deploy failed.
+    pub fn error_message(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Returns the gas used by the deploy.
+    pub fn cost(&self) -> Gas {
+        self.cost
+    }
+
+    /// Returns the events emitted by the deploy.
+    pub fn events(&self) -> &[ContractEvent] {
+        &self.events
+    }
+
+    /// Panics if the deploy failed.
+    pub fn expect_success(&self) -> &Self {
+        if let Some(error) = &self.error {
+            panic!("expected successful execution, but got: {}", error);
+        }
+        self
+    }
+
+    /// Panics if the deploy succeeded, or failed with an error not containing `expected_error`.
+    pub fn expect_failure(&self, expected_error: &str) -> &Self {
+        match &self.error {
+            None => panic!("expected execution to fail with {}", expected_error),
+            Some(error) => assert!(
+                error.contains(expected_error),
+                "expected execution to fail with {}, but got: {}",
+                expected_error,
+                error
+            ),
+        }
+        self
+    }
+
+    /// Panics if the deploy used more than `max_cost` gas.
+    pub fn assert_cost_at_most<T: Into<Gas>>(&self, max_cost: T) -> &Self {
+        let max_cost = max_cost.into();
+        assert!(
+            self.cost <= max_cost,
+            "execution cost {} exceeds {}",
+            self.cost,
+            max_cost
+        );
+        self
+    }
+
+    /// Panics unless the deploy emitted an event called `name` with the given payload.
+    pub fn assert_event<T>(&self, name: &str, payload: T) -> &Self
+    where
+        T: CLTyped + FromBytes + PartialEq + Debug,
+    {
+        let found = self.events.iter().any(|event| {
+            event.name == name
+                && event.payload.clone().into_t::<T>().ok().as_ref() == Some(&payload)
+        });
+        assert!(
+            found,
+            "expected event {} with payload {:?}, but got: {:?}",
+            name, payload, self.events
+        );
+        self
+    }
+
+    /// Panics if the deploy emitted an event called `name`.
+    pub fn assert_no_event(&self, name: &str) -> &Self {
+        assert!(
+            self.events.iter().all(|event| event.name != name),
+            "expected no event {}, but got: {:?}",
+            name,
+            self.events
+        );
+        self
+    }
+}
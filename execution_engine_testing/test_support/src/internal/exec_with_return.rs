@@ -64,6 +64,10 @@ where
         let address_generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
         Rc::new(RefCell::new(address_generator))
     };
+    let random_bytes_generator = {
+        let address_generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
+        Rc::new(RefCell::new(address_generator))
+    };
     let gas_counter = Gas::default();
     let fn_store_id = {
         let fn_store_id = AddressGenerator::new(deploy_hash.as_bytes(), phase);
@@ -118,6 +122,7 @@ where
         fn_store_id,
         address_generator,
         transfer_address_generator,
+        random_bytes_generator,
         protocol_version,
         correlation_id,
         phase,
@@ -54,6 +54,11 @@ impl ExecuteRequestBuilder {
         self
     }
 
+    pub fn with_accumulated_seed(mut self, accumulated_seed: &[u8]) -> Self {
+        self.execute_request.accumulated_seed = accumulated_seed.try_into().unwrap();
+        self
+    }
+
     pub fn build(self) -> ExecuteRequest {
         self.execute_request
     }
@@ -19,7 +19,10 @@ use casper_execution_engine::{
         motes::Motes, newtypes::Blake2bHash, system_config::SystemConfig, wasm_config::WasmConfig,
     },
 };
-use casper_types::{account::AccountHash, ProtocolVersion, PublicKey, SecretKey, U512};
+use casper_types::{
+    account::AccountHash, system::handle_payment::FeeHandling, ProtocolVersion, PublicKey,
+    SecretKey, U512,
+};
 
 use super::DEFAULT_ACCOUNT_INITIAL_BALANCE;
 
@@ -47,6 +50,10 @@ pub const DEFAULT_UNBONDING_DELAY: u64 = 14;
 ///
 /// (1+0.02)^((2^14)/31536000000)-1 is expressed as a fraction below.
 pub const DEFAULT_ROUND_SEIGNIORAGE_RATE: Ratio<u64> = Ratio::new_raw(6414, 623437335209);
+/// Default ratio of the unspent payment which is refunded to the deployer.
+pub const DEFAULT_REFUND_RATIO: Ratio<u64> = Ratio::new_raw(0, 1);
+/// Default handling of the fees paid for deploys.
+pub const DEFAULT_FEE_HANDLING: FeeHandling = FeeHandling::PayToProposer;
 
 pub const DEFAULT_CHAIN_NAME: &str = "gerald";
 pub const DEFAULT_GENESIS_TIMESTAMP_MILLIS: u64 = 0;
@@ -102,6 +109,8 @@ pub static DEFAULT_EXEC_CONFIG: Lazy<ExecConfig> = Lazy::new(|| {
         DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
         DEFAULT_ROUND_SEIGNIORAGE_RATE,
         DEFAULT_UNBONDING_DELAY,
+        DEFAULT_REFUND_RATIO,
+        DEFAULT_FEE_HANDLING,
         DEFAULT_GENESIS_TIMESTAMP_MILLIS,
     )
 });
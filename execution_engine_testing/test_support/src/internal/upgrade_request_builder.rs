@@ -9,7 +9,7 @@ use casper_execution_engine::{
         wasm_config::WasmConfig,
     },
 };
-use casper_types::{Key, ProtocolVersion};
+use casper_types::{system::handle_payment::FeeHandling, Key, ProtocolVersion};
 
 #[derive(Default)]
 pub struct UpgradeRequestBuilder {
@@ -24,6 +24,8 @@ pub struct UpgradeRequestBuilder {
     new_locked_funds_period_millis: Option<u64>,
     new_round_seigniorage_rate: Option<Ratio<u64>>,
     new_unbonding_delay: Option<u64>,
+    new_refund_ratio: Option<Ratio<u64>>,
+    new_fee_handling: Option<FeeHandling>,
     global_state_update: BTreeMap<Key, StoredValue>,
 }
 
@@ -79,6 +81,16 @@ impl UpgradeRequestBuilder {
         self
     }
 
+    pub fn with_new_refund_ratio(mut self, refund_ratio: Ratio<u64>) -> Self {
+        self.new_refund_ratio = Some(refund_ratio);
+        self
+    }
+
+    pub fn with_new_fee_handling(mut self, fee_handling: FeeHandling) -> Self {
+        self.new_fee_handling = Some(fee_handling);
+        self
+    }
+
     pub fn with_new_system_config(mut self, new_system_config: SystemConfig) -> Self {
         self.new_system_config = Some(new_system_config);
         self
@@ -110,6 +122,8 @@ impl UpgradeRequestBuilder {
             self.new_locked_funds_period_millis,
             self.new_round_seigniorage_rate,
             self.new_unbonding_delay,
+            self.new_refund_ratio,
+            self.new_fee_handling,
             self.global_state_update,
         )
     }
@@ -20,7 +20,10 @@ use casper_execution_engine::{
 };
 use casper_types::Key;
 
-use super::{DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG, DEFAULT_UNBONDING_DELAY};
+use super::{
+    DEFAULT_FEE_HANDLING, DEFAULT_REFUND_RATIO, DEFAULT_ROUND_SEIGNIORAGE_RATE,
+    DEFAULT_SYSTEM_CONFIG, DEFAULT_UNBONDING_DELAY,
+};
 use crate::internal::{
     DEFAULT_AUCTION_DELAY, DEFAULT_CHAIN_NAME, DEFAULT_GENESIS_CONFIG_HASH,
     DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_PROTOCOL_VERSION,
@@ -140,6 +143,8 @@ pub fn create_exec_config(accounts: Vec<GenesisAccount>) -> ExecConfig {
     let locked_funds_period_millis = DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
     let round_seigniorage_rate = DEFAULT_ROUND_SEIGNIORAGE_RATE;
     let unbonding_delay = DEFAULT_UNBONDING_DELAY;
+    let refund_ratio = DEFAULT_REFUND_RATIO;
+    let fee_handling = DEFAULT_FEE_HANDLING;
     let genesis_timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
     ExecConfig::new(
         accounts,
@@ -150,6 +155,8 @@ pub fn create_exec_config(accounts: Vec<GenesisAccount>) -> ExecConfig {
         locked_funds_period_millis,
         round_seigniorage_rate,
         unbonding_delay,
+        refund_ratio,
+        fee_handling,
         genesis_timestamp_millis,
     )
 }
@@ -26,6 +26,7 @@ use casper_execution_engine::{
             QueryResult, UpgradeConfig, UpgradeResult,
         },
         execution,
+        runtime::gas_profiler::{self, GasProfile},
     },
     shared::{
         account::Account,
@@ -39,13 +40,13 @@ use casper_execution_engine::{
     },
     storage::{
         global_state::{
-            in_memory::InMemoryGlobalState, lmdb::LmdbGlobalState, CommitResult, StateProvider,
-            StateReader,
+            in_memory::InMemoryGlobalState, lmdb::LmdbGlobalState, CommitResult, DiffResult,
+            StateProvider, StateReader,
         },
         protocol_data_store::lmdb::LmdbProtocolDataStore,
         transaction_source::lmdb::LmdbEnvironment,
         trie::merkle_proof::TrieMerkleProof,
-        trie_store::lmdb::LmdbTrieStore,
+        trie_store::{lmdb::LmdbTrieStore, DiffEntry},
     },
 };
 use casper_types::{
@@ -109,6 +110,10 @@ pub struct WasmTestBuilder<S> {
     standard_payment_hash: Option<ContractHash>,
     /// Auction contract key
     auction_contract_hash: Option<ContractHash>,
+    /// Whether gas usage of subsequent exec calls is profiled
+    gas_profiling: bool,
+    /// Gas profiles of exec calls, populated while gas profiling is enabled
+    gas_profiles: Vec<Option<GasProfile>>,
 }
 
 impl<S> WasmTestBuilder<S> {
@@ -139,6 +144,8 @@ impl Default for InMemoryWasmTestBuilder {
             handle_payment_contract_hash: None,
             standard_payment_hash: None,
             auction_contract_hash: None,
+            gas_profiling: false,
+            gas_profiles: Vec::new(),
         }
     }
 }
@@ -160,6 +167,8 @@ impl<S> Clone for WasmTestBuilder<S> {
             handle_payment_contract_hash: self.handle_payment_contract_hash,
             standard_payment_hash: self.standard_payment_hash,
             auction_contract_hash: self.auction_contract_hash,
+            gas_profiling: self.gas_profiling,
+            gas_profiles: self.gas_profiles.clone(),
         }
     }
 }
@@ -190,6 +199,16 @@ impl InMemoryWasmTestBuilder {
             ..Default::default()
         }
     }
+
+    pub fn new_with_config(engine_config: EngineConfig) -> Self {
+        Self::initialize_logging();
+        let global_state = InMemoryGlobalState::empty().expect("should create global state");
+        let engine_state = EngineState::new(global_state, engine_config);
+        WasmTestBuilder {
+            engine_state: Rc::new(engine_state),
+            ..Default::default()
+        }
+    }
 }
 
 impl LmdbWasmTestBuilder {
@@ -232,6 +251,8 @@ impl LmdbWasmTestBuilder {
             handle_payment_contract_hash: None,
             standard_payment_hash: None,
             auction_contract_hash: None,
+            gas_profiling: false,
+            gas_profiles: Vec::new(),
         }
     }
 
@@ -296,6 +317,8 @@ impl LmdbWasmTestBuilder {
             handle_payment_contract_hash: None,
             standard_payment_hash: None,
             auction_contract_hash: None,
+            gas_profiling: false,
+            gas_profiles: Vec::new(),
         }
     }
 
@@ -332,6 +355,8 @@ where
             standard_payment_hash: result.0.standard_payment_hash,
             auction_contract_hash: result.0.auction_contract_hash,
             genesis_transforms: result.0.genesis_transforms,
+            gas_profiling: result.0.gas_profiling,
+            gas_profiles: Vec::new(),
         }
     }
 
@@ -430,6 +455,29 @@ where
         panic! {query_result};
     }
 
+    /// Returns the values which were added, removed or modified between two state roots.
+    pub fn get_trie_diff(
+        &self,
+        left_root: Blake2bHash,
+        right_root: Blake2bHash,
+    ) -> Vec<DiffEntry<Key, StoredValue>> {
+        let diff_result = self
+            .engine_state
+            .get_trie_diff(
+                CorrelationId::new(),
+                left_root,
+                right_root,
+                None,
+                usize::MAX,
+            )
+            .expect("should get trie diff");
+
+        match diff_result {
+            DiffResult::Success { entries, .. } => entries,
+            DiffResult::RootNotFound(root) => panic!("state root not found: {}", root),
+        }
+    }
+
     pub fn total_supply(&self, maybe_post_state: Option<Blake2bHash>) -> U512 {
         let mint_key: Key = self
             .mint_contract_hash
@@ -456,9 +504,18 @@ where
             exec_request.parent_state_hash = hash;
             exec_request
         };
+        if self.gas_profiling {
+            gas_profiler::start();
+        }
         let maybe_exec_results = self
             .engine_state
             .run_execute(CorrelationId::new(), exec_request);
+        let gas_profile = if self.gas_profiling {
+            gas_profiler::finish()
+        } else {
+            None
+        };
+        self.gas_profiles.push(gas_profile);
         assert!(maybe_exec_results.is_ok());
         // Parse deploy results
         let execution_results = maybe_exec_results.as_ref().unwrap();
@@ -817,6 +874,26 @@ where
         utils::get_exec_costs(exec_results)
     }
 
+    /// Enables profiling of the gas used by subsequent exec calls, attributing it to the entry
+    /// points, Wasm functions, host functions and storage writes which incurred it.
+    pub fn enable_gas_profiling(&mut self) -> &mut Self {
+        self.gas_profiling = true;
+        self
+    }
+
+    /// Returns the gas profile of the exec call at `index`, if gas profiling was enabled for it.
+    ///
+    /// The profile can be written out with [`GasProfile::to_folded_stacks`] and rendered by
+    /// flamegraph tools.
+    pub fn gas_profile(&self, index: usize) -> Option<&GasProfile> {
+        self.gas_profiles.get(index)?.as_ref()
+    }
+
+    /// Returns the gas profile of the last exec call, if gas profiling was enabled for it.
+    pub fn last_exec_gas_profile(&self) -> Option<&GasProfile> {
+        self.gas_profiles.last()?.as_ref()
+    }
+
     pub fn last_exec_gas_cost(&self) -> Gas {
         let exec_results = self
             .exec_results
@@ -66,7 +66,9 @@
 
 mod account;
 mod code;
+mod contract;
 mod error;
+mod harness;
 // This module is not intended to be used by third party crates.
 #[doc(hidden)]
 pub mod internal;
@@ -77,7 +79,9 @@ mod value;
 pub use account::Account;
 pub use casper_types::account::AccountHash;
 pub use code::Code;
+pub use contract::Contract;
 pub use error::{Error, Result};
+pub use harness::{CallResult, Snapshot, TestHarness};
 pub use session::{Session, SessionBuilder, SessionTransferInfo};
 pub use test_context::{TestContext, TestContextBuilder};
 pub use value::Value;
@@ -1,15 +1,21 @@
+use std::ffi::OsStr;
+
 use casper_execution_engine::{
     core::engine_state::{
         genesis::{GenesisAccount, GenesisConfig},
         run_genesis_request::RunGenesisRequest,
     },
     shared::motes::Motes,
+    storage::global_state::lmdb::LmdbGlobalState,
 };
 use casper_types::{AccessRights, Key, PublicKey, URef, U512};
 
 use crate::{
-    internal::{InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG, DEFAULT_GENESIS_CONFIG_HASH},
-    Account, AccountHash, Error, Result, Session, URefAddr, Value,
+    internal::{
+        InMemoryWasmTestBuilder, LmdbWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_GENESIS_CONFIG_HASH,
+    },
+    Account, AccountHash, Error, Result, Session, TestHarness, URefAddr, Value,
 };
 
 /// Context in which to run a test of a Wasm smart contract.
@@ -177,13 +183,38 @@ impl TestContextBuilder {
     /// Builds the [`TestContext`].
     pub fn build(self) -> TestContext {
         let mut inner = InMemoryWasmTestBuilder::default();
-        let run_genesis_request = RunGenesisRequest::new(
+        inner.run_genesis(&self.run_genesis_request());
+        TestContext { inner }
+    }
+
+    /// Builds a [`TestHarness`] backed by in-memory global state.
+    pub fn build_harness(self) -> TestHarness {
+        let genesis_timestamp = self.genesis_config.timestamp();
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder.run_genesis(&self.run_genesis_request());
+        TestHarness::new(builder, genesis_timestamp)
+    }
+
+    /// Builds a [`TestHarness`] backed by LMDB global state stored in `data_dir`.
+    ///
+    /// As global state is never pruned, [`Snapshot`](crate::Snapshot)s of such a harness stay
+    /// valid for as long as it is used.
+    pub fn build_lmdb_harness<T: AsRef<OsStr> + ?Sized>(
+        self,
+        data_dir: &T,
+    ) -> TestHarness<LmdbGlobalState> {
+        let genesis_timestamp = self.genesis_config.timestamp();
+        let mut builder = LmdbWasmTestBuilder::new(data_dir);
+        builder.run_genesis(&self.run_genesis_request());
+        TestHarness::new(builder, genesis_timestamp)
+    }
+
+    fn run_genesis_request(self) -> RunGenesisRequest {
+        RunGenesisRequest::new(
             *DEFAULT_GENESIS_CONFIG_HASH,
             self.genesis_config.protocol_version(),
             self.genesis_config.take_ee_config(),
-        );
-        inner.run_genesis(&run_genesis_request);
-        TestContext { inner }
+        )
     }
 }
 
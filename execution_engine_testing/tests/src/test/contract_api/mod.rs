@@ -5,13 +5,17 @@ mod get_arg;
 mod get_blocktime;
 mod get_caller;
 mod get_phase;
+mod keccak256;
 mod list_named_keys;
 mod main_purse;
 mod mint_purse;
+mod random_bytes;
 mod revert;
+mod sha256;
 mod subcall;
 mod transfer;
 mod transfer_purse_to_account;
 mod transfer_purse_to_purse;
 mod transfer_stored;
 mod transfer_u512_stored;
+mod verify_signature;
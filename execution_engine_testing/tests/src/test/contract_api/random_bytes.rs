@@ -0,0 +1,97 @@
+use casper_engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, RuntimeArgs, RANDOM_BYTES_COUNT};
+
+const RANDOM_BYTES_WASM: &str = "random_bytes.wasm";
+const RANDOM_BYTES_1: &str = "random_bytes_1";
+const RANDOM_BYTES_2: &str = "random_bytes_2";
+const ARG_AMOUNT: &str = "amount";
+
+const ACCUMULATED_SEED_1: [u8; 32] = [1; 32];
+const ACCUMULATED_SEED_2: [u8; 32] = [2; 32];
+const DEPLOY_HASH_1: [u8; 32] = [3; 32];
+const DEPLOY_HASH_2: [u8; 32] = [4; 32];
+
+type RandomBytes = [u8; RANDOM_BYTES_COUNT];
+
+fn query_random_bytes(builder: &InMemoryWasmTestBuilder, name: &str) -> RandomBytes {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let uref = account.named_keys().get(name).expect("should have value");
+
+    builder
+        .query(None, *uref, &[])
+        .expect("should query")
+        .as_cl_value()
+        .cloned()
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should convert")
+}
+
+fn random_bytes(accumulated_seed: [u8; 32], deploy_hash: [u8; 32]) -> (RandomBytes, RandomBytes) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_deploy_hash(deploy_hash)
+            .with_session_code(RANDOM_BYTES_WASM, RuntimeArgs::default())
+            .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+            .build();
+
+        ExecuteRequestBuilder::from_deploy_item(deploy)
+            .with_accumulated_seed(&accumulated_seed)
+            .build()
+    };
+
+    builder.exec(exec_request).commit().expect_success();
+
+    (
+        query_random_bytes(&builder, RANDOM_BYTES_1),
+        query_random_bytes(&builder, RANDOM_BYTES_2),
+    )
+}
+
+#[ignore]
+#[test]
+fn should_return_same_random_bytes_for_same_seed_and_deploy() {
+    assert_eq!(
+        random_bytes(ACCUMULATED_SEED_1, DEPLOY_HASH_1),
+        random_bytes(ACCUMULATED_SEED_1, DEPLOY_HASH_1)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_return_different_random_bytes_on_each_call() {
+    let (random_bytes_1, random_bytes_2) = random_bytes(ACCUMULATED_SEED_1, DEPLOY_HASH_1);
+    assert_ne!(random_bytes_1, random_bytes_2);
+}
+
+#[ignore]
+#[test]
+fn should_return_different_random_bytes_for_different_seeds() {
+    assert_ne!(
+        random_bytes(ACCUMULATED_SEED_1, DEPLOY_HASH_1),
+        random_bytes(ACCUMULATED_SEED_2, DEPLOY_HASH_1)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_return_different_random_bytes_for_different_deploys() {
+    assert_ne!(
+        random_bytes(ACCUMULATED_SEED_1, DEPLOY_HASH_1),
+        random_bytes(ACCUMULATED_SEED_1, DEPLOY_HASH_2)
+    );
+}
@@ -0,0 +1,112 @@
+use ed25519_dalek::ExpandedSecretKey;
+use k256::ecdsa::{signature::Signer, SigningKey as Secp256k1SecretKey};
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, PublicKey, RuntimeArgs, SecretKey, Signature,
+};
+
+const VERIFY_SIGNATURE_WASM: &str = "verify_signature.wasm";
+const ARG_MESSAGE: &str = "message";
+const ARG_SIGNATURE: &str = "signature";
+const ARG_PUBLIC_KEY: &str = "public_key";
+const VERIFY_RESULT: &str = "verify_result";
+
+const MESSAGE: &[u8] = b"message";
+
+fn sign(message: &[u8], secret_key: &SecretKey, public_key: &PublicKey) -> Signature {
+    match (secret_key, public_key) {
+        (SecretKey::Ed25519(secret_key), PublicKey::Ed25519(public_key)) => {
+            let signature = ExpandedSecretKey::from(secret_key).sign(message, public_key);
+            Signature::Ed25519(signature.to_bytes())
+        }
+        (SecretKey::Secp256k1(secret_key_bytes), PublicKey::Secp256k1(_)) => {
+            let signer =
+                Secp256k1SecretKey::from_bytes(secret_key_bytes).expect("should construct signer");
+            Signature::Secp256k1(signer.sign(message))
+        }
+        _ => panic!("secret and public key types must match"),
+    }
+}
+
+fn verify(message: &[u8], signature: Signature, public_key: PublicKey) -> bool {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let signature_bytes = signature.to_bytes().expect("should serialize signature");
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        VERIFY_SIGNATURE_WASM,
+        runtime_args! {
+            ARG_MESSAGE => Bytes::from(message),
+            ARG_SIGNATURE => Bytes::from(signature_bytes),
+            ARG_PUBLIC_KEY => public_key,
+        },
+    )
+    .build();
+
+    builder.exec(exec_request).commit().expect_success();
+
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let uref = account
+        .named_keys()
+        .get(VERIFY_RESULT)
+        .expect("should have value");
+
+    builder
+        .query(None, *uref, &[])
+        .expect("should query")
+        .as_cl_value()
+        .cloned()
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should convert")
+}
+
+#[ignore]
+#[test]
+fn should_verify_ed25519_signature() {
+    let secret_key = SecretKey::ed25519([1; SecretKey::ED25519_LENGTH]);
+    let public_key = PublicKey::from(&secret_key);
+    let signature = sign(MESSAGE, &secret_key, &public_key);
+
+    assert!(verify(MESSAGE, signature, public_key));
+}
+
+#[ignore]
+#[test]
+fn should_verify_secp256k1_signature() {
+    let secret_key = SecretKey::secp256k1([1; SecretKey::SECP256K1_LENGTH]);
+    let public_key = PublicKey::from(&secret_key);
+    let signature = sign(MESSAGE, &secret_key, &public_key);
+
+    assert!(verify(MESSAGE, signature, public_key));
+}
+
+#[ignore]
+#[test]
+fn should_not_verify_signature_of_other_message() {
+    let secret_key = SecretKey::ed25519([1; SecretKey::ED25519_LENGTH]);
+    let public_key = PublicKey::from(&secret_key);
+    let signature = sign(b"other message", &secret_key, &public_key);
+
+    assert!(!verify(MESSAGE, signature, public_key));
+}
+
+#[ignore]
+#[test]
+fn should_not_verify_signature_by_other_key() {
+    let secret_key = SecretKey::secp256k1([1; SecretKey::SECP256K1_LENGTH]);
+    let public_key = PublicKey::from(&secret_key);
+    let signature = sign(MESSAGE, &secret_key, &public_key);
+
+    let other_public_key = PublicKey::from(&SecretKey::secp256k1([2; SecretKey::SECP256K1_LENGTH]));
+    assert!(!verify(MESSAGE, signature, other_public_key));
+}
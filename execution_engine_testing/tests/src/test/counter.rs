@@ -1,8 +1,20 @@
+use assert_matches::assert_matches;
+
 use casper_engine_test_support::{
-    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PROTOCOL_VERSION,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
     DEFAULT_ACCOUNT_ADDR,
 };
-use casper_types::{runtime_args, Key, RuntimeArgs};
+use casper_execution_engine::core::{
+    engine_state::{
+        engine_config::{EngineConfig, DEFAULT_MAX_QUERY_DEPTH},
+        Error,
+    },
+    execution,
+};
+use casper_types::{runtime_args, Key, ProtocolVersion, RuntimeArgs};
 
 const CONTRACT_COUNTER_DEFINE: &str = "counter_define.wasm";
 const HASH_KEY_NAME: &str = "counter_package_hash";
@@ -12,6 +24,7 @@ const ENTRYPOINT_SESSION: &str = "session";
 const COUNTER_CONTRACT_HASH_KEY_NAME: &str = "counter_contract_hash";
 const ARG_COUNTER_METHOD: &str = "method";
 const METHOD_INC: &str = "inc";
+const ARG_UNEXPECTED: &str = "unexpected";
 
 #[ignore]
 #[test]
@@ -211,3 +224,106 @@ fn should_call_counter_contract_directly() {
 
     assert_eq!(value, 1);
 }
+
+/// Installs the counter contract on a network where contracts installed from
+/// `strict_entry_point_args_protocol_version` onwards have their entry point args resolved
+/// strictly, then calls its counter entry point with `args`.
+fn call_counter_entry_point(
+    strict_entry_point_args_protocol_version: ProtocolVersion,
+    args: RuntimeArgs,
+) -> Option<Error> {
+    let engine_config = EngineConfig::new(
+        DEFAULT_MAX_QUERY_DEPTH,
+        Some(strict_entry_point_args_protocol_version),
+    );
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_COUNTER_DEFINE,
+        RuntimeArgs::new(),
+    )
+    .build();
+
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request_1)
+        .expect_success()
+        .commit();
+
+    let exec_request_2 = ExecuteRequestBuilder::versioned_contract_call_by_hash_key_name(
+        *DEFAULT_ACCOUNT_ADDR,
+        HASH_KEY_NAME,
+        None,
+        ENTRYPOINT_COUNTER,
+        args,
+    )
+    .build();
+
+    builder.exec(exec_request_2).commit();
+
+    let response = builder
+        .get_exec_results()
+        .last()
+        .expect("should have last response");
+    assert_eq!(response.len(), 1);
+    let exec_response = response.last().expect("should have response");
+    exec_response.as_error().cloned()
+}
+
+/// Calls the counter entry point with `args` on a network where the counter contract is installed
+/// under the protocol version from which entry point args are resolved strictly.
+fn call_strict_counter_entry_point(args: RuntimeArgs) -> Error {
+    call_counter_entry_point(*DEFAULT_PROTOCOL_VERSION, args).expect("should have error")
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_call_counter_contract_without_required_arg() {
+    let error = call_strict_counter_entry_point(RuntimeArgs::new());
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::MissingArgument(name)) if name == ARG_COUNTER_METHOD
+    );
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_call_counter_contract_with_unexpected_arg() {
+    let error = call_strict_counter_entry_point(runtime_args! {
+        ARG_COUNTER_METHOD => METHOD_INC,
+        ARG_UNEXPECTED => 1u64,
+    });
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::UnexpectedArgument(name)) if name == ARG_UNEXPECTED
+    );
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_call_counter_contract_with_mistyped_arg() {
+    let error = call_strict_counter_entry_point(runtime_args! { ARG_COUNTER_METHOD => 1u64 });
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::InvalidArgumentType { name, .. }) if name == ARG_COUNTER_METHOD
+    );
+}
+
+#[ignore]
+#[test]
+fn should_call_counter_contract_installed_before_strict_args_with_unexpected_arg() {
+    let strict_entry_point_args_protocol_version = ProtocolVersion::from_parts(
+        DEFAULT_PROTOCOL_VERSION.value().major,
+        DEFAULT_PROTOCOL_VERSION.value().minor + 1,
+        0,
+    );
+    let error = call_counter_entry_point(
+        strict_entry_point_args_protocol_version,
+        runtime_args! {
+            ARG_COUNTER_METHOD => METHOD_INC,
+            ARG_UNEXPECTED => 1u64,
+        },
+    );
+    assert!(error.is_none(), "{:?}", error);
+}
@@ -14,5 +14,7 @@ mod step;
 mod storage_costs;
 mod system_contracts;
 mod system_costs;
+mod test_harness;
+mod trie_diff;
 mod upgrade;
 mod wasmless_transfer;
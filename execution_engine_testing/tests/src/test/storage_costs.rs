@@ -126,6 +126,14 @@ static NEW_HOST_FUNCTION_COSTS: Lazy<HostFunctionCosts> = Lazy::new(|| HostFunct
     remove_contract_user_group_urefs: HostFunction::fixed(0),
     print: HostFunction::fixed(0),
     blake2b: HostFunction::fixed(0),
+    new_dictionary: HostFunction::fixed(0),
+    dictionary_get: HostFunction::fixed(0),
+    dictionary_put: HostFunction::fixed(0),
+    emit_event: HostFunction::fixed(0),
+    sha256: HostFunction::fixed(0),
+    keccak256: HostFunction::fixed(0),
+    verify_signature: HostFunction::fixed(0),
+    random_bytes: HostFunction::fixed(0),
 });
 static STORAGE_COSTS_ONLY: Lazy<WasmConfig> = Lazy::new(|| {
     WasmConfig::new(
@@ -1,28 +1,40 @@
+use std::collections::BTreeMap;
+
 use num_traits::Zero;
 use once_cell::sync::Lazy;
 
 use casper_engine_test_support::{
     internal::{
-        InMemoryWasmTestBuilder, DEFAULT_AUCTION_DELAY, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
-        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG,
-        DEFAULT_UNBONDING_DELAY, DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG,
+        InMemoryWasmTestBuilder, DEFAULT_AUCTION_DELAY, DEFAULT_FEE_HANDLING,
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_REFUND_RATIO,
+        DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG, DEFAULT_UNBONDING_DELAY,
+        DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG,
     },
     AccountHash,
 };
 use casper_execution_engine::{
     core::engine_state::{
-        genesis::{ExecConfig, GenesisAccount, GenesisValidator},
+        genesis::{
+            ExecConfig, GenesisAccount, GenesisAccountSetup, GenesisContract, GenesisNamedKeyValue,
+            GenesisValidator,
+        },
         run_genesis_request::RunGenesisRequest,
     },
-    shared::{motes::Motes, stored_value::StoredValue},
+    shared::{motes::Motes, stored_value::StoredValue, wasm::do_nothing_bytes},
+};
+use casper_types::{
+    account::Weight, contracts::DEFAULT_ENTRY_POINT_NAME, system::auction::DelegationRate, CLValue,
+    EntryPoints, Key, ProtocolVersion, PublicKey, SecretKey, U512,
 };
-use casper_types::{system::auction::DelegationRate, ProtocolVersion, PublicKey, SecretKey, U512};
 
 const GENESIS_CONFIG_HASH: [u8; 32] = [127; 32];
 const ACCOUNT_1_BONDED_AMOUNT: u64 = 1_000_000;
 const ACCOUNT_2_BONDED_AMOUNT: u64 = 2_000_000;
 const ACCOUNT_1_BALANCE: u64 = 1_000_000_000;
 const ACCOUNT_2_BALANCE: u64 = 2_000_000_000;
+const CONTRACT_NAME: &str = "genesis_contract";
+const NAMED_KEY_NAME: &str = "genesis_value";
+const NAMED_KEY_VALUE: u64 = 42;
 
 static ACCOUNT_1_PUBLIC_KEY: Lazy<PublicKey> =
     Lazy::new(|| SecretKey::ed25519([42; SecretKey::ED25519_LENGTH]).into());
@@ -70,6 +82,8 @@ fn should_run_genesis() {
     let locked_funds_period = DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
     let round_seigniorage_rate = DEFAULT_ROUND_SEIGNIORAGE_RATE;
     let unbonding_delay = DEFAULT_UNBONDING_DELAY;
+    let refund_ratio = DEFAULT_REFUND_RATIO;
+    let fee_handling = DEFAULT_FEE_HANDLING;
     let genesis_timestamp = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let exec_config = ExecConfig::new(
@@ -81,6 +95,8 @@ fn should_run_genesis() {
         locked_funds_period,
         round_seigniorage_rate,
         unbonding_delay,
+        refund_ratio,
+        fee_handling,
         genesis_timestamp,
     );
     let run_genesis_request =
@@ -141,6 +157,8 @@ fn should_track_total_token_supply_in_mint() {
     let locked_funds_period = DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
     let round_seigniorage_rate = DEFAULT_ROUND_SEIGNIORAGE_RATE;
     let unbonding_delay = DEFAULT_UNBONDING_DELAY;
+    let refund_ratio = DEFAULT_REFUND_RATIO;
+    let fee_handling = DEFAULT_FEE_HANDLING;
     let genesis_tiemstamp = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
     let ee_config = ExecConfig::new(
         accounts.clone(),
@@ -151,6 +169,8 @@ fn should_track_total_token_supply_in_mint() {
         locked_funds_period,
         round_seigniorage_rate,
         unbonding_delay,
+        refund_ratio,
+        fee_handling,
         genesis_tiemstamp,
     );
     let run_genesis_request =
@@ -175,3 +195,121 @@ fn should_track_total_token_supply_in_mint() {
         "unexpected total supply"
     )
 }
+
+#[ignore]
+#[test]
+fn should_run_genesis_with_account_setups_and_contracts() {
+    let mut exec_config = ExecConfig::new(
+        GENESIS_CUSTOM_ACCOUNTS.clone(),
+        *DEFAULT_WASM_CONFIG,
+        *DEFAULT_SYSTEM_CONFIG,
+        DEFAULT_VALIDATOR_SLOTS,
+        DEFAULT_AUCTION_DELAY,
+        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
+        DEFAULT_ROUND_SEIGNIORAGE_RATE,
+        DEFAULT_UNBONDING_DELAY,
+        DEFAULT_REFUND_RATIO,
+        DEFAULT_FEE_HANDLING,
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+    );
+
+    let named_keys: BTreeMap<String, GenesisNamedKeyValue> = {
+        let value = CLValue::from_t(NAMED_KEY_VALUE).expect("should create CLValue");
+        let mut named_keys = BTreeMap::new();
+        named_keys.insert(
+            NAMED_KEY_NAME.to_string(),
+            GenesisNamedKeyValue::Value(value),
+        );
+        named_keys
+    };
+
+    let associated_keys = {
+        let mut associated_keys = BTreeMap::new();
+        associated_keys.insert(*ACCOUNT_2_ADDR, Weight::new(2));
+        associated_keys
+    };
+
+    exec_config.push_account_setup(GenesisAccountSetup::new(
+        *ACCOUNT_1_PUBLIC_KEY,
+        associated_keys,
+        Weight::new(2),
+        Weight::new(3),
+        named_keys.clone(),
+    ));
+    exec_config.push_contract(GenesisContract::new(
+        CONTRACT_NAME.to_string(),
+        *ACCOUNT_2_PUBLIC_KEY,
+        do_nothing_bytes(),
+        EntryPoints::default(),
+        named_keys,
+    ));
+
+    let run_genesis_request = RunGenesisRequest::new(
+        GENESIS_CONFIG_HASH.into(),
+        ProtocolVersion::V1_0_0,
+        exec_config,
+    );
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&run_genesis_request);
+
+    let expected_value =
+        StoredValue::CLValue(CLValue::from_t(NAMED_KEY_VALUE).expect("should create CLValue"));
+
+    let account_1 = builder
+        .get_account(*ACCOUNT_1_ADDR)
+        .expect("account 1 should exist");
+
+    assert_eq!(
+        account_1.get_associated_key_weight(*ACCOUNT_1_ADDR),
+        Some(&Weight::new(1))
+    );
+    assert_eq!(
+        account_1.get_associated_key_weight(*ACCOUNT_2_ADDR),
+        Some(&Weight::new(2))
+    );
+    assert_eq!(*account_1.action_thresholds().deployment(), Weight::new(2));
+    assert_eq!(
+        *account_1.action_thresholds().key_management(),
+        Weight::new(3)
+    );
+
+    let account_1_value = builder
+        .query(
+            None,
+            Key::Account(*ACCOUNT_1_ADDR),
+            &[NAMED_KEY_NAME.to_string()],
+        )
+        .expect("should have named key value");
+    assert_eq!(account_1_value, expected_value);
+
+    let account_2 = builder
+        .get_account(*ACCOUNT_2_ADDR)
+        .expect("account 2 should exist");
+
+    assert!(account_2
+        .named_keys()
+        .contains_key(&format!("{}_package", CONTRACT_NAME)));
+    assert!(account_2
+        .named_keys()
+        .contains_key(&format!("{}_access", CONTRACT_NAME)));
+
+    let contract_key = *account_2
+        .named_keys()
+        .get(CONTRACT_NAME)
+        .expect("should have contract hash");
+
+    if let Ok(StoredValue::Contract(contract)) = builder.query(None, contract_key, &[]) {
+        assert!(contract
+            .entry_points()
+            .has_entry_point(DEFAULT_ENTRY_POINT_NAME));
+    } else {
+        panic!("contract not found at genesis contract hash");
+    }
+
+    let contract_value = builder
+        .query(None, contract_key, &[NAMED_KEY_NAME.to_string()])
+        .expect("should have contract named key value");
+    assert_eq!(contract_value, expected_value);
+}
@@ -0,0 +1,140 @@
+use num_rational::Ratio;
+
+use casper_engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_AUCTION_DELAY,
+        DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_PAYMENT, DEFAULT_PROTOCOL_VERSION,
+        DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG, DEFAULT_UNBONDING_DELAY,
+        DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::engine_state::{
+    genesis::ExecConfig, run_genesis_request::RunGenesisRequest,
+};
+use casper_types::{
+    runtime_args,
+    system::handle_payment::{self, FeeHandling},
+    Key, RuntimeArgs, U512,
+};
+
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+
+fn initialize(refund_ratio: Ratio<u64>, fee_handling: FeeHandling) -> InMemoryWasmTestBuilder {
+    let exec_config = ExecConfig::new(
+        DEFAULT_ACCOUNTS.clone(),
+        *DEFAULT_WASM_CONFIG,
+        *DEFAULT_SYSTEM_CONFIG,
+        DEFAULT_VALIDATOR_SLOTS,
+        DEFAULT_AUCTION_DELAY,
+        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
+        DEFAULT_ROUND_SEIGNIORAGE_RATE,
+        DEFAULT_UNBONDING_DELAY,
+        refund_ratio,
+        fee_handling,
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+    );
+    let run_genesis_request = RunGenesisRequest::new(
+        *DEFAULT_GENESIS_CONFIG_HASH,
+        *DEFAULT_PROTOCOL_VERSION,
+        exec_config,
+    );
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request);
+    builder
+}
+
+fn do_nothing(builder: &mut InMemoryWasmTestBuilder) {
+    let exec_request =
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, DO_NOTHING_WASM, runtime_args! {})
+            .build();
+    builder.exec(exec_request).expect_success().commit();
+}
+
+fn get_accumulation_purse_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let accumulation_purse = builder
+        .get_handle_payment_contract()
+        .named_keys()
+        .get(handle_payment::ACCUMULATION_PURSE_KEY)
+        .and_then(Key::as_uref)
+        .cloned()
+        .expect("should have accumulation purse");
+    builder.get_purse_balance(accumulation_purse)
+}
+
+#[ignore]
+#[test]
+fn should_pay_fees_to_proposer() {
+    let mut builder = initialize(Ratio::new(0, 1), FeeHandling::PayToProposer);
+
+    let proposer_pre_balance = builder.get_proposer_purse_balance();
+    let total_supply_pre = builder.total_supply(None);
+
+    do_nothing(&mut builder);
+
+    assert_eq!(
+        builder.get_proposer_purse_balance(),
+        proposer_pre_balance + *DEFAULT_PAYMENT
+    );
+    assert_eq!(builder.total_supply(None), total_supply_pre);
+}
+
+#[ignore]
+#[test]
+fn should_burn_fees() {
+    let mut builder = initialize(Ratio::new(0, 1), FeeHandling::Burn);
+
+    let proposer_pre_balance = builder.get_proposer_purse_balance();
+    let total_supply_pre = builder.total_supply(None);
+
+    do_nothing(&mut builder);
+
+    assert_eq!(builder.get_proposer_purse_balance(), proposer_pre_balance);
+    assert_eq!(
+        builder.total_supply(None),
+        total_supply_pre - *DEFAULT_PAYMENT
+    );
+}
+
+#[ignore]
+#[test]
+fn should_accumulate_fees() {
+    let mut builder = initialize(Ratio::new(0, 1), FeeHandling::Accumulate);
+
+    let proposer_pre_balance = builder.get_proposer_purse_balance();
+    assert_eq!(get_accumulation_purse_balance(&builder), U512::zero());
+
+    do_nothing(&mut builder);
+
+    assert_eq!(builder.get_proposer_purse_balance(), proposer_pre_balance);
+    assert_eq!(get_accumulation_purse_balance(&builder), *DEFAULT_PAYMENT);
+}
+
+#[ignore]
+#[test]
+fn should_refund_unspent_payment_according_to_refund_ratio() {
+    let mut builder = initialize(Ratio::new(1, 1), FeeHandling::PayToProposer);
+
+    let account_pre_balance = builder.get_purse_balance(
+        builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("should have account")
+            .main_purse(),
+    );
+    let proposer_pre_balance = builder.get_proposer_purse_balance();
+
+    do_nothing(&mut builder);
+
+    let account_post_balance = builder.get_purse_balance(
+        builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("should have account")
+            .main_purse(),
+    );
+    let fee = builder.get_proposer_purse_balance() - proposer_pre_balance;
+
+    assert!(fee < *DEFAULT_PAYMENT, "unspent payment should be refunded");
+    assert_eq!(account_post_balance, account_pre_balance - fee);
+}
@@ -1,3 +1,4 @@
+mod fee_handling;
 mod finalize_payment;
 mod get_payment_purse;
 mod refund_purse;
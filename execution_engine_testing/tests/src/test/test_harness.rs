@@ -0,0 +1,348 @@
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    internal::DEFAULT_GENESIS_TIMESTAMP_MILLIS, Code, TestContextBuilder, TestHarness,
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
+};
+use casper_execution_engine::{
+    core::{engine_state, execution},
+    shared::gas::Gas,
+    storage::global_state::StateProvider,
+};
+use casper_types::{
+    account::AccountHash, runtime_args, Key, PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+const TEST_HARNESS_WASM: &str = "test_harness.wasm";
+const PACKAGE_HASH_KEY_NAME: &str = "test_harness_package_hash";
+const INSTALLED_KEY_NAME: &str = "test_harness_installed";
+const COUNT_KEY_NAME: &str = "count";
+const ENTRY_POINT_INCREMENT: &str = "increment";
+const ENTRY_POINT_SET: &str = "set";
+const ENTRY_POINT_FAIL: &str = "fail";
+const ARG_VALUE: &str = "value";
+const EVENT_COUNT_CHANGED: &str = "count_changed";
+const USER_ERROR: &str = "User(0)";
+
+static ACCOUNT_1_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([200; SecretKey::ED25519_LENGTH]).into());
+static ACCOUNT_1_ADDR: Lazy<AccountHash> = Lazy::new(|| ACCOUNT_1_PK.to_account_hash());
+const ACCOUNT_1_INITIAL_BALANCE: u64 = DEFAULT_ACCOUNT_INITIAL_BALANCE;
+
+fn install<S>(harness: &mut TestHarness<S>)
+where
+    S: StateProvider,
+    engine_state::Error: From<S::Error>,
+    S::Error: Into<execution::Error>,
+{
+    harness
+        .run_session(
+            *DEFAULT_ACCOUNT_ADDR,
+            Code::from(TEST_HARNESS_WASM),
+            RuntimeArgs::new(),
+        )
+        .expect_success();
+}
+
+fn setup() -> TestHarness {
+    let mut harness = TestContextBuilder::new()
+        .with_public_key(*ACCOUNT_1_PK, U512::from(ACCOUNT_1_INITIAL_BALANCE))
+        .build_harness();
+    install(&mut harness);
+    harness
+}
+
+fn count<S>(harness: &mut TestHarness<S>) -> u64
+where
+    S: StateProvider,
+    engine_state::Error: From<S::Error>,
+    S::Error: Into<execution::Error>,
+{
+    harness
+        .contract_by_name(*DEFAULT_ACCOUNT_ADDR, PACKAGE_HASH_KEY_NAME)
+        .expect("should find contract")
+        .named_key_value(COUNT_KEY_NAME)
+        .expect("should read count")
+}
+
+/// Changes the count, block time and era, then checks that restoring a snapshot taken beforehand
+/// undoes all of them.
+fn assert_snapshot_restores<S>(harness: &mut TestHarness<S>)
+where
+    S: StateProvider,
+    engine_state::Error: From<S::Error>,
+    S::Error: Into<execution::Error>,
+{
+    let snapshot = harness.snapshot();
+
+    harness
+        .contract_by_name(*DEFAULT_ACCOUNT_ADDR, PACKAGE_HASH_KEY_NAME)
+        .expect("should find contract")
+        .call(ENTRY_POINT_SET, runtime_args! { ARG_VALUE => 5u64 })
+        .expect("should call")
+        .expect_success();
+    harness.advance_block_time(1_000).advance_era();
+    assert_eq!(count(harness), 5);
+
+    harness.restore(&snapshot);
+    assert_eq!(count(harness), 0);
+    assert_eq!(harness.block_time(), DEFAULT_GENESIS_TIMESTAMP_MILLIS);
+    assert_eq!(harness.era_id(), 0);
+    assert_eq!(harness.builder().get_era(), 0);
+
+    // The restored state can be built upon, and restored again.
+    harness
+        .contract_by_name(*DEFAULT_ACCOUNT_ADDR, PACKAGE_HASH_KEY_NAME)
+        .expect("should find contract")
+        .call(ENTRY_POINT_INCREMENT, RuntimeArgs::new())
+        .expect("should call")
+        .expect_success();
+    assert_eq!(count(harness), 1);
+    harness.restore(&snapshot);
+    assert_eq!(count(harness), 0);
+}
+
+#[ignore]
+#[test]
+fn should_call_contract_entry_points() {
+    let mut harness = setup();
+
+    let mut contract = harness
+        .contract_by_name(*DEFAULT_ACCOUNT_ADDR, PACKAGE_HASH_KEY_NAME)
+        .expect("should find contract");
+    let mut entry_point_names: Vec<_> = contract
+        .entry_points()
+        .map(|entry_point| entry_point.name().to_string())
+        .collect();
+    entry_point_names.sort();
+    assert_eq!(
+        entry_point_names,
+        vec![ENTRY_POINT_FAIL, ENTRY_POINT_INCREMENT, ENTRY_POINT_SET]
+    );
+
+    contract
+        .call(ENTRY_POINT_INCREMENT, RuntimeArgs::new())
+        .expect("should call")
+        .expect_success()
+        .assert_event(EVENT_COUNT_CHANGED, 1u64);
+    contract
+        .call(ENTRY_POINT_SET, runtime_args! { ARG_VALUE => 5u64 })
+        .expect("should call")
+        .expect_success()
+        .assert_event(EVENT_COUNT_CHANGED, 5u64);
+    assert_eq!(contract.named_key_value::<u64>(COUNT_KEY_NAME).unwrap(), 5);
+
+    // Calls can be made by other accounts too.
+    let package_hash = contract.package_hash();
+    harness
+        .contract(package_hash)
+        .expect("should find contract")
+        .as_account(*ACCOUNT_1_ADDR)
+        .call(ENTRY_POINT_INCREMENT, RuntimeArgs::new())
+        .expect("should call")
+        .expect_success();
+    assert!(harness.balance(*ACCOUNT_1_ADDR) < U512::from(ACCOUNT_1_INITIAL_BALANCE));
+    assert_eq!(count(&mut harness), 6);
+}
+
+#[ignore]
+#[test]
+fn should_report_failed_call() {
+    let mut harness = setup();
+
+    let call_result = harness
+        .contract_by_name(*DEFAULT_ACCOUNT_ADDR, PACKAGE_HASH_KEY_NAME)
+        .expect("should find contract")
+        .call(ENTRY_POINT_FAIL, RuntimeArgs::new())
+        .expect("should call");
+    assert!(!call_result.is_success());
+    assert!(call_result.error_message().unwrap().contains(USER_ERROR));
+    assert!(call_result.events().is_empty());
+    call_result.expect_failure(USER_ERROR);
+    assert_eq!(count(&mut harness), 0);
+}
+
+#[ignore]
+#[test]
+fn should_reject_call_not_matching_entry_point() {
+    let mut harness = setup();
+    let exec_results_count = harness.builder().get_exec_results().len();
+
+    let mut contract = harness
+        .contract_by_name(*DEFAULT_ACCOUNT_ADDR, PACKAGE_HASH_KEY_NAME)
+        .expect("should find contract");
+    assert!(contract.call("missing", RuntimeArgs::new()).is_err());
+    assert!(contract.call(ENTRY_POINT_SET, RuntimeArgs::new()).is_err());
+    assert!(contract
+        .call(ENTRY_POINT_SET, runtime_args! { ARG_VALUE => 5u32 })
+        .is_err());
+    assert!(contract
+        .call(
+            ENTRY_POINT_SET,
+            runtime_args! { ARG_VALUE => 5u64, "extra" => 1u64 }
+        )
+        .is_err());
+    assert!(contract
+        .call(ENTRY_POINT_INCREMENT, runtime_args! { ARG_VALUE => 5u64 })
+        .is_err());
+
+    // Nothing was executed.
+    assert_eq!(
+        harness.builder().get_exec_results().len(),
+        exec_results_count
+    );
+    assert!(harness
+        .contract_by_name(*DEFAULT_ACCOUNT_ADDR, INSTALLED_KEY_NAME)
+        .is_err());
+}
+
+#[ignore]
+#[test]
+fn should_advance_block_time_and_era() {
+    let mut harness = setup();
+    assert_eq!(harness.block_time(), DEFAULT_GENESIS_TIMESTAMP_MILLIS);
+    assert_eq!(harness.era_id(), 0);
+
+    harness.advance_block_time(1_000).advance_block_time(500);
+    assert_eq!(
+        harness.block_time(),
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS + 1_500
+    );
+
+    harness.advance_era().advance_era();
+    assert_eq!(harness.era_id(), 2);
+    assert_eq!(harness.builder().get_era(), 2);
+
+    // Deploys still run after the eras have advanced.
+    harness
+        .contract_by_name(*DEFAULT_ACCOUNT_ADDR, PACKAGE_HASH_KEY_NAME)
+        .expect("should find contract")
+        .call(ENTRY_POINT_INCREMENT, RuntimeArgs::new())
+        .expect("should call")
+        .expect_success();
+    assert_eq!(count(&mut harness), 1);
+}
+
+#[ignore]
+#[test]
+fn should_restore_snapshot() {
+    let mut harness = setup();
+    assert_snapshot_restores(&mut harness);
+}
+
+#[ignore]
+#[test]
+fn should_restore_snapshot_of_lmdb_harness() {
+    let data_dir = tempfile::tempdir().expect("should create temp dir");
+    let mut harness = TestContextBuilder::new().build_lmdb_harness(data_dir.path());
+    install(&mut harness);
+    assert_snapshot_restores(&mut harness);
+}
+
+#[ignore]
+#[test]
+fn should_pass_assertions() {
+    let mut harness = setup();
+    let account_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    harness
+        .assert_balance(*ACCOUNT_1_ADDR, ACCOUNT_1_INITIAL_BALANCE)
+        .assert_named_key_value(account_key, INSTALLED_KEY_NAME, true)
+        .assert_no_named_key(account_key, COUNT_KEY_NAME);
+
+    let call_result = harness
+        .contract_by_name(*DEFAULT_ACCOUNT_ADDR, PACKAGE_HASH_KEY_NAME)
+        .expect("should find contract")
+        .call(ENTRY_POINT_INCREMENT, RuntimeArgs::new())
+        .expect("should call");
+    call_result
+        .expect_success()
+        .assert_cost_at_most(call_result.cost())
+        .assert_event(EVENT_COUNT_CHANGED, 1u64)
+        .assert_no_event(ENTRY_POINT_INCREMENT);
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "balance of")]
+fn should_fail_assert_balance() {
+    setup().assert_balance(*ACCOUNT_1_ADDR, ACCOUNT_1_INITIAL_BALANCE + 1);
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "does not match")]
+fn should_fail_assert_named_key_value() {
+    setup().assert_named_key_value(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        INSTALLED_KEY_NAME,
+        false,
+    );
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "should not have named key")]
+fn should_fail_assert_no_named_key() {
+    setup().assert_no_named_key(Key::Account(*DEFAULT_ACCOUNT_ADDR), INSTALLED_KEY_NAME);
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "expected successful execution")]
+fn should_fail_expect_success() {
+    setup()
+        .contract_by_name(*DEFAULT_ACCOUNT_ADDR, PACKAGE_HASH_KEY_NAME)
+        .expect("should find contract")
+        .call(ENTRY_POINT_FAIL, RuntimeArgs::new())
+        .expect("should call")
+        .expect_success();
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "expected execution to fail")]
+fn should_fail_expect_failure() {
+    setup()
+        .contract_by_name(*DEFAULT_ACCOUNT_ADDR, PACKAGE_HASH_KEY_NAME)
+        .expect("should find contract")
+        .call(ENTRY_POINT_INCREMENT, RuntimeArgs::new())
+        .expect("should call")
+        .expect_failure(USER_ERROR);
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "exceeds")]
+fn should_fail_assert_cost_at_most() {
+    setup()
+        .contract_by_name(*DEFAULT_ACCOUNT_ADDR, PACKAGE_HASH_KEY_NAME)
+        .expect("should find contract")
+        .call(ENTRY_POINT_INCREMENT, RuntimeArgs::new())
+        .expect("should call")
+        .assert_cost_at_most(Gas::default());
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "expected event")]
+fn should_fail_assert_event() {
+    setup()
+        .contract_by_name(*DEFAULT_ACCOUNT_ADDR, PACKAGE_HASH_KEY_NAME)
+        .expect("should find contract")
+        .call(ENTRY_POINT_INCREMENT, RuntimeArgs::new())
+        .expect("should call")
+        .assert_event(EVENT_COUNT_CHANGED, 2u64);
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "expected no event")]
+fn should_fail_assert_no_event() {
+    setup()
+        .contract_by_name(*DEFAULT_ACCOUNT_ADDR, PACKAGE_HASH_KEY_NAME)
+        .expect("should find contract")
+        .call(ENTRY_POINT_INCREMENT, RuntimeArgs::new())
+        .expect("should call")
+        .assert_no_event(EVENT_COUNT_CHANGED);
+}
@@ -0,0 +1,79 @@
+use casper_engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::{shared::stored_value::StoredValue, storage::trie_store::DiffEntry};
+use casper_types::{account::AccountHash, runtime_args, system::mint, Key, RuntimeArgs, U512};
+
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+
+#[ignore]
+#[test]
+fn should_diff_state_roots_around_transfer() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST);
+
+    let pre_state_hash = builder.get_post_state_hash();
+
+    let transfer_request = {
+        let deploy_item = DeployItemBuilder::new()
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_empty_payment_bytes(runtime_args! {})
+            .with_transfer_args(runtime_args! {
+                mint::ARG_TARGET => ACCOUNT_1_ADDR,
+                mint::ARG_AMOUNT => U512::from(1000),
+                mint::ARG_ID => <Option<u64>>::None
+            })
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+            .build();
+        ExecuteRequestBuilder::from_deploy_item(deploy_item).build()
+    };
+    builder.exec(transfer_request).expect_success().commit();
+
+    let post_state_hash = builder.get_post_state_hash();
+
+    assert!(builder
+        .get_trie_diff(post_state_hash, post_state_hash)
+        .is_empty());
+
+    let account_1_key = Key::Account(ACCOUNT_1_ADDR);
+    let account_1 = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account 1");
+
+    let diff = builder.get_trie_diff(pre_state_hash, post_state_hash);
+    assert!(diff.contains(&DiffEntry::Added {
+        key: account_1_key,
+        value: StoredValue::Account(account_1.clone()),
+    }));
+
+    let default_account_purse = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account")
+        .main_purse();
+    let default_account_balance_key = Key::Balance(default_account_purse.addr());
+    let default_account_balance = builder.get_purse_balance(default_account_purse);
+    let modified_balance = diff.iter().find_map(|entry| match entry {
+        DiffEntry::Modified { key, new_value, .. } if *key == default_account_balance_key => {
+            Some(new_value.clone())
+        }
+        _ => None,
+    });
+    match modified_balance {
+        Some(StoredValue::CLValue(cl_value)) => assert_eq!(
+            cl_value.into_t::<U512>().expect("should be U512"),
+            default_account_balance
+        ),
+        other => panic!("expected modified balance, got {:?}", other),
+    }
+
+    let reverse_diff = builder.get_trie_diff(post_state_hash, pre_state_hash);
+    assert_eq!(reverse_diff.len(), diff.len());
+    assert!(reverse_diff.contains(&DiffEntry::Removed {
+        key: account_1_key,
+        value: StoredValue::Account(account_1),
+    }));
+}
@@ -8,6 +8,7 @@ use std::{
     env, fs,
     path::{Path, PathBuf},
     str::FromStr,
+    time::Duration,
 };
 
 use anyhow::{self, Context};
@@ -18,11 +19,13 @@ use tracing::{error, info, trace};
 
 use crate::config;
 use casper_node::{
+    crypto::hash::Digest,
     logging,
     reactor::{initializer, joiner, validator, ReactorExit, Runner},
     setup_signal_hooks,
     types::ExitCode,
     utils::WithDir,
+    LocalNetwork, LocalNetworkConfig,
 };
 use prometheus::Registry;
 
@@ -73,6 +76,59 @@ pub enum Cli {
         #[structopt(long)]
         new_config: PathBuf,
     },
+    /// Delete global state which is unreachable from the most recent blocks.
+    ///
+    /// The node must be stopped while this runs.
+    PruneGlobalState {
+        /// Path to configuration file.
+        config: PathBuf,
+        /// Number of most recent blocks whose global state is retained.
+        #[structopt(long)]
+        retained_blocks: usize,
+    },
+    /// Generate a `global_state.toml` file for an emergency upgrade from a list of operations.
+    ///
+    /// Current values are read from the global state under the given state root hash, and a
+    /// description of the changes is printed.  The node must be stopped while this runs.
+    GenerateGlobalStateUpdate {
+        /// Path to configuration file.
+        config: PathBuf,
+        /// Hex-encoded state root hash of the global state to be updated.
+        #[structopt(long)]
+        state_root_hash: String,
+        /// Path to the TOML file listing the operations to apply.
+        #[structopt(long)]
+        operations: PathBuf,
+        /// Path to which the generated `global_state.toml` is written.
+        #[structopt(long)]
+        output: PathBuf,
+    },
+    /// Generate and run a local network of validator nodes on this machine.
+    ///
+    /// Keys, a chainspec bonding every node and per-node configs are generated in the given
+    /// directory, replacing any network previously generated there, and each node is run as a
+    /// child process.  Nodes are then controlled by entering `start <n>`, `stop <n>`,
+    /// `restart <n>`, `wipe <n>`, `status` or `quit` on stdin.
+    LocalNetwork {
+        /// Directory in which the network's keys, configs and storage are created.  It must be
+        /// missing, empty or hold a previously generated network.
+        dir: PathBuf,
+        /// Number of validator nodes.
+        #[structopt(long, default_value = "5")]
+        nodes: usize,
+        /// Directory holding the `config.toml` and `chainspec.toml.in` the nodes are based on.
+        #[structopt(long, default_value = "resources/local")]
+        resources: PathBuf,
+        /// Path to the `casper-node` binary used to run the nodes; defaults to this binary.
+        #[structopt(long)]
+        node_binary: Option<PathBuf>,
+        /// Number of seconds from now until genesis.
+        #[structopt(long, default_value = "30")]
+        genesis_delay: u64,
+        /// Port from which each node's ports are allocated.
+        #[structopt(long, default_value = "34000")]
+        base_port: u16,
+    },
 }
 
 #[derive(Debug)]
@@ -242,6 +298,66 @@ impl Cli {
                 casper_node::migrate_data(WithDir::new(old_root, old_config), new_config)?;
                 Ok(ExitCode::Success as i32)
             }
+            Cli::PruneGlobalState {
+                config,
+                retained_blocks,
+            } => {
+                let config = Self::init(&config, vec![])?;
+
+                info!(version = %env!("CARGO_PKG_VERSION"), "pruning global state");
+                casper_node::prune_global_state(config, retained_blocks)?;
+                Ok(ExitCode::Success as i32)
+            }
+            Cli::GenerateGlobalStateUpdate {
+                config,
+                state_root_hash,
+                operations,
+                output,
+            } => {
+                let config = Self::init(&config, vec![])?;
+                let state_root_hash =
+                    Digest::from_hex(&state_root_hash).context("invalid state root hash")?;
+
+                info!(version = %env!("CARGO_PKG_VERSION"), "generating global state update");
+                let update = casper_node::generate_global_state_update(
+                    config,
+                    state_root_hash,
+                    &operations,
+                )?;
+                fs::write(&output, update.global_state_toml)
+                    .with_context(|| output.display().to_string())?;
+                print!("{}", update.diff);
+                Ok(ExitCode::Success as i32)
+            }
+            Cli::LocalNetwork {
+                dir,
+                nodes,
+                resources,
+                node_binary,
+                genesis_delay,
+                base_port,
+            } => {
+                setup_signal_hooks();
+                logging::init()?;
+
+                let node_binary = match node_binary {
+                    Some(node_binary) => node_binary,
+                    None => env::current_exe().context("could not locate casper-node binary")?,
+                };
+                let config = LocalNetworkConfig {
+                    node_count: nodes,
+                    dir,
+                    resources_dir: resources,
+                    node_binary,
+                    genesis_delay: Duration::from_secs(genesis_delay),
+                    base_port,
+                };
+
+                info!(version = %env!("CARGO_PKG_VERSION"), "starting local network");
+                let mut network = LocalNetwork::generate(config)?;
+                network.supervise()?;
+                Ok(ExitCode::Success as i32)
+            }
         }
     }
 
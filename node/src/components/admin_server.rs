@@ -0,0 +1,134 @@
+//! Admin server
+//!
+//! The admin server provides node operators with a small HTTP API for adjusting and inspecting a
+//! running node. Unlike the REST server, it is only ever bound to a loopback address, since its
+//! endpoints can change the node's behavior and expose internal state.
+//!
+//! The actual server is run in a backgrounded task. Requests are either answered directly (e.g.
+//! the log filter and event queue endpoints, which must keep working even if the reactor is
+//! stalled) or translated into reactor requests to various components.
+//!
+//! Currently the following endpoints are supported:
+//! /log_filter : GET returns the current log filter, PUT replaces it with the request body, which
+//!     uses the same syntax as the `RUST_LOG` environment variable.
+//!     example: curl -X PUT -d 'info,casper_node::components::small_network=debug'
+//!         'http://127.0.0.1:8889/log_filter'
+//! /queues : a JSON dump of all events currently waiting in the reactor's event queues.
+//!     example: curl -X GET 'http://127.0.0.1:8889/queues'
+//! /consensus : a human readable dump of the consensus protocol state of the current era.
+//!     example: curl -X GET 'http://127.0.0.1:8889/consensus'
+//! /peers : the connection state of each peer the node is connected to.
+//!     example: curl -X GET 'http://127.0.0.1:8889/peers'
+//! /memory : the estimated heap memory usage of each component, in bytes.
+//!     example: curl -X GET 'http://127.0.0.1:8889/memory'
+
+mod config;
+mod filters;
+mod http_server;
+
+use std::net::SocketAddr;
+
+use datasize::DataSize;
+use futures::{future::BoxFuture, FutureExt};
+use serde::Serialize;
+use thiserror::Error;
+use tokio::{sync::oneshot, task::JoinHandle};
+use tracing::{debug, error, warn};
+
+use crate::{
+    effect::{
+        requests::{ConsensusRequest, MemoryReportRequest, NetworkInfoRequest},
+        EffectBuilder,
+    },
+    reactor::Finalize,
+    types::NodeId,
+    utils::{self, ListeningError},
+};
+
+pub use config::Config;
+
+/// A helper trait capturing all of the admin server's Request type dependencies.
+pub trait ReactorEventT:
+    From<NetworkInfoRequest<NodeId>>
+    + From<ConsensusRequest>
+    + From<MemoryReportRequest>
+    + Serialize
+    + Send
+{
+}
+
+impl<REv> ReactorEventT for REv where
+    REv: From<NetworkInfoRequest<NodeId>>
+        + From<ConsensusRequest>
+        + From<MemoryReportRequest>
+        + Serialize
+        + Send
+        + 'static
+{
+}
+
+/// Error starting the admin server.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Failed to start listening.
+    #[error(transparent)]
+    Listening(#[from] ListeningError),
+
+    /// The configured address is not a loopback address.
+    #[error("admin server must be bound to a loopback address, got {0}")]
+    NonLoopbackAddress(SocketAddr),
+}
+
+#[derive(DataSize, Debug)]
+pub(crate) struct AdminServer {
+    /// When the message is sent, it signals the server loop to exit cleanly.
+    shutdown_sender: oneshot::Sender<()>,
+    /// The task handle which will only join once the server loop has exited.
+    server_join_handle: Option<JoinHandle<()>>,
+}
+
+impl AdminServer {
+    pub(crate) fn new<REv>(
+        config: Config,
+        effect_builder: EffectBuilder<REv>,
+    ) -> Result<Self, Error>
+    where
+        REv: ReactorEventT,
+    {
+        let address = utils::resolve_address(&config.address)
+            .map_err(|error| Error::Listening(ListeningError::ResolveAddress(error)))?;
+        if !address.ip().is_loopback() {
+            return Err(Error::NonLoopbackAddress(address));
+        }
+
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel::<()>();
+
+        let builder = utils::start_listening(&config.address)?;
+        let server_join_handle =
+            tokio::spawn(http_server::run(builder, effect_builder, shutdown_receiver));
+
+        Ok(AdminServer {
+            shutdown_sender,
+            server_join_handle: Some(server_join_handle),
+        })
+    }
+}
+
+impl Finalize for AdminServer {
+    fn finalize(mut self) -> BoxFuture<'static, ()> {
+        async {
+            let _ = self.shutdown_sender.send(());
+
+            // Wait for the server to exit cleanly.
+            if let Some(join_handle) = self.server_join_handle.take() {
+                match join_handle.await {
+                    Ok(_) => debug!("admin server exited cleanly"),
+                    Err(error) => error!(%error, "could not join admin server task cleanly"),
+                }
+            } else {
+                warn!("admin server shutdown while already shut down")
+            }
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,35 @@
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+
+/// Default binding address for the admin HTTP server.
+///
+/// Uses a random port, and only binds on the loopback interface.
+const DEFAULT_ADDRESS: &str = "127.0.0.1:0";
+
+/// Admin HTTP server configuration.
+#[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
+// Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Whether to run the admin HTTP server.
+    pub enable: bool,
+
+    /// Address to bind admin HTTP server to.  Must be a loopback address.
+    pub address: String,
+}
+
+impl Config {
+    /// Creates a default instance for `AdminServer`.
+    pub fn new() -> Self {
+        Config {
+            enable: false,
+            address: DEFAULT_ADDRESS.to_string(),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::new()
+    }
+}
@@ -0,0 +1,164 @@
+use std::str;
+
+use futures::FutureExt;
+use http::Response;
+use hyper::{body::Bytes, Body};
+use serde::Serialize;
+use tracing::{info, warn};
+use warp::{
+    filters::BoxedFilter,
+    http::{header, StatusCode},
+    reject::Rejection,
+    reply::{self, Reply},
+    Filter,
+};
+
+use super::ReactorEventT;
+use crate::{
+    effect::EffectBuilder,
+    logging,
+    types::{NodeId, PeerConnectionState},
+};
+
+/// The log filter URL path.
+pub const LOG_FILTER_API_PATH: &str = "log_filter";
+
+/// The event queues URL path.
+pub const QUEUES_API_PATH: &str = "queues";
+
+/// The consensus state URL path.
+pub const CONSENSUS_API_PATH: &str = "consensus";
+
+/// The peers URL path.
+pub const PEERS_API_PATH: &str = "peers";
+
+/// The memory usage URL path.
+pub const MEMORY_API_PATH: &str = "memory";
+
+/// The connection state of a single peer, as returned by the peers endpoint.
+#[derive(Serialize)]
+struct PeerEntry {
+    node_id: String,
+    #[serde(flatten)]
+    state: PeerConnectionState,
+}
+
+pub(super) fn create_get_log_filter_filter() -> BoxedFilter<(Response<Body>,)> {
+    warp::get()
+        .and(warp::path(LOG_FILTER_API_PATH))
+        .map(|| match logging::current_filter() {
+            Ok(filter) => reply::with_status(filter, StatusCode::OK).into_response(),
+            Err(error) => {
+                warn!(%error, "failed to get log filter");
+                reply::with_status(error.to_string(), StatusCode::INTERNAL_SERVER_ERROR)
+                    .into_response()
+            }
+        })
+        .boxed()
+}
+
+pub(super) fn create_set_log_filter_filter() -> BoxedFilter<(Response<Body>,)> {
+    warp::put()
+        .and(warp::path(LOG_FILTER_API_PATH))
+        .and(warp::body::bytes())
+        .map(|body: Bytes| {
+            let result = str::from_utf8(&body)
+                .map_err(anyhow::Error::from)
+                .and_then(|directives| logging::reload_filter(directives.trim()));
+            match result {
+                Ok(()) => {
+                    info!(filter = %String::from_utf8_lossy(&body).trim(), "log filter updated");
+                    reply::with_status(String::new(), StatusCode::OK).into_response()
+                }
+                Err(error) => {
+                    reply::with_status(error.to_string(), StatusCode::BAD_REQUEST).into_response()
+                }
+            }
+        })
+        .boxed()
+}
+
+pub(super) fn create_queues_filter<REv: ReactorEventT>(
+    effect_builder: EffectBuilder<REv>,
+) -> BoxedFilter<(Response<Body>,)> {
+    warp::get()
+        .and(warp::path(QUEUES_API_PATH))
+        .and_then(move || {
+            effect_builder
+                .dump_event_queues()
+                .map(|result| match result {
+                    Ok(json) => Ok::<_, Rejection>(
+                        reply::with_header(json, header::CONTENT_TYPE, "application/json")
+                            .into_response(),
+                    ),
+                    Err(error) => {
+                        warn!(%error, "failed to serialize event queues");
+                        Ok(
+                            reply::with_status(
+                                error.to_string(),
+                                StatusCode::INTERNAL_SERVER_ERROR,
+                            )
+                            .into_response(),
+                        )
+                    }
+                })
+        })
+        .boxed()
+}
+
+pub(super) fn create_consensus_filter<REv: ReactorEventT>(
+    effect_builder: EffectBuilder<REv>,
+) -> BoxedFilter<(Response<Body>,)> {
+    warp::get()
+        .and(warp::path(CONSENSUS_API_PATH))
+        .and_then(move || {
+            effect_builder
+                .dump_consensus_state()
+                .map(|maybe_state| match maybe_state {
+                    Some(state) => Ok::<_, Rejection>(
+                        reply::with_status(state, StatusCode::OK).into_response(),
+                    ),
+                    None => Ok(reply::with_status(
+                        "no active consensus era".to_string(),
+                        StatusCode::NOT_FOUND,
+                    )
+                    .into_response()),
+                })
+        })
+        .boxed()
+}
+
+pub(super) fn create_peers_filter<REv: ReactorEventT>(
+    effect_builder: EffectBuilder<REv>,
+) -> BoxedFilter<(Response<Body>,)> {
+    warp::get()
+        .and(warp::path(PEERS_API_PATH))
+        .and_then(move || {
+            effect_builder
+                .network_peer_connection_states::<NodeId>()
+                .map(|states| {
+                    let body: Vec<_> = states
+                        .into_iter()
+                        .map(|(node_id, state)| PeerEntry {
+                            node_id: node_id.to_string(),
+                            state,
+                        })
+                        .collect();
+                    Ok::<_, Rejection>(reply::json(&body).into_response())
+                })
+        })
+        .boxed()
+}
+
+pub(super) fn create_memory_filter<REv: ReactorEventT>(
+    effect_builder: EffectBuilder<REv>,
+) -> BoxedFilter<(Response<Body>,)> {
+    warp::get()
+        .and(warp::path(MEMORY_API_PATH))
+        .and_then(move || {
+            effect_builder
+                .get_memory_report()
+                .map(|report| Ok::<_, Rejection>(reply::json(&report).into_response()))
+        })
+        .boxed()
+}
@@ -0,0 +1,50 @@
+use std::convert::Infallible;
+
+use futures::{future, TryFutureExt};
+use hyper::server::{conn::AddrIncoming, Builder};
+use tokio::sync::oneshot;
+use tracing::{info, warn};
+use warp::Filter;
+
+use super::{filters, ReactorEventT};
+use crate::effect::EffectBuilder;
+
+/// Run the admin HTTP server.
+///
+/// A message received on `shutdown_receiver` will cause the server to exit cleanly.
+pub(super) async fn run<REv: ReactorEventT>(
+    builder: Builder<AddrIncoming>,
+    effect_builder: EffectBuilder<REv>,
+    shutdown_receiver: oneshot::Receiver<()>,
+) {
+    let routes = filters::create_get_log_filter_filter()
+        .or(filters::create_set_log_filter_filter())
+        .unify()
+        .or(filters::create_queues_filter(effect_builder))
+        .unify()
+        .or(filters::create_consensus_filter(effect_builder))
+        .unify()
+        .or(filters::create_peers_filter(effect_builder))
+        .unify()
+        .or(filters::create_memory_filter(effect_builder))
+        .unify();
+
+    let service = warp::service(routes);
+
+    // Start the server, passing a oneshot receiver to allow the server to be shut down gracefully.
+    let make_svc =
+        hyper::service::make_service_fn(move |_| future::ok::<_, Infallible>(service.clone()));
+
+    let server = builder.serve(make_svc);
+    info!(address = %server.local_addr(), "started admin server");
+
+    // Shutdown the server gracefully.
+    let _ = server
+        .with_graceful_shutdown(async {
+            shutdown_receiver.await.ok();
+        })
+        .map_err(|error| {
+            warn!(%error, "error running admin server");
+        })
+        .await;
+}
@@ -123,6 +123,10 @@ pub(crate) struct BlockExecutor {
     parent_map: HashMap<BlockHeight, ExecutedBlockSummary>,
     /// Finalized blocks waiting for their pre-state hash to start executing.
     exec_queue: HashMap<BlockHeight, (FinalizedBlock, VecDeque<Deploy>)>,
+    /// Height of the most recent finalized block received for execution.
+    last_finalized_height: Option<BlockHeight>,
+    /// Height of the most recent block whose execution has completed.
+    last_executed_height: Option<BlockHeight>,
     /// Metrics to track current chain height.
     #[data_size(skip)]
     metrics: BlockExecutorMetrics,
@@ -145,6 +149,8 @@ impl BlockExecutor {
             ),
             parent_map: HashMap::new(),
             exec_queue: HashMap::new(),
+            last_finalized_height: None,
+            last_executed_height: None,
             metrics,
         }
     }
@@ -155,6 +161,8 @@ impl BlockExecutor {
     /// to carry over the last finalized block so that the next blocks in the linear chain
     /// have the state to build on.
     pub(crate) fn with_parent_map(mut self, lfb: Option<Block>) -> Self {
+        self.last_finalized_height = lfb.as_ref().map(Block::height);
+        self.last_executed_height = self.last_finalized_height;
         let parent_map = lfb
             .into_iter()
             .map(|block| {
@@ -172,6 +180,16 @@ impl BlockExecutor {
         self
     }
 
+    /// Returns the height of the most recent finalized block received for execution.
+    pub(crate) fn last_finalized_height(&self) -> Option<u64> {
+        self.last_finalized_height
+    }
+
+    /// Returns the height of the most recent block whose execution has completed.
+    pub(crate) fn last_executed_height(&self) -> Option<u64> {
+        self.last_executed_height
+    }
+
     /// Gets the deploy(s) of the given finalized block from storage.
     fn get_deploys<REv: ReactorEventT>(
         &mut self,
@@ -230,6 +248,7 @@ impl BlockExecutor {
         self.metrics
             .chain_height
             .set(state.finalized_block.height() as i64);
+        self.last_executed_height = Some(state.finalized_block.height());
         let block = self.create_block(
             state.finalized_block,
             state.state_root_hash,
@@ -307,6 +326,7 @@ impl BlockExecutor {
             vec![Ok(deploy_item)],
             self.protocol_version,
             state.finalized_block.proposer(),
+            state.accumulated_seed.into(),
         );
 
         // TODO: this is currently working coincidentally because we are passing only one
@@ -330,12 +350,13 @@ impl BlockExecutor {
         finalized_block: FinalizedBlock,
         deploys: VecDeque<Deploy>,
     ) -> Effects<Event> {
-        if let Some(state_root_hash) = self.pre_state_hash(&finalized_block) {
+        if let Some((state_root_hash, accumulated_seed)) = self.pre_state(&finalized_block) {
             let state = Box::new(State {
                 finalized_block,
                 remaining_deploys: deploys,
                 execution_results: HashMap::new(),
                 state_root_hash,
+                accumulated_seed,
             });
             self.execute_next_deploy_or_create_block(effect_builder, state)
         } else {
@@ -370,12 +391,14 @@ impl BlockExecutor {
                 let height = finalized_block.height();
                 debug!("no pre-state hash for height {}", height);
                 // re-check the parent map - the parent might have been executed in the meantime!
-                if let Some(state_root_hash) = self.pre_state_hash(&finalized_block) {
+                if let Some((state_root_hash, accumulated_seed)) = self.pre_state(&finalized_block)
+                {
                     let state = Box::new(State {
                         finalized_block,
                         remaining_deploys: deploys,
                         execution_results: HashMap::new(),
                         state_root_hash,
+                        accumulated_seed,
                     });
                     self.execute_next_deploy_or_create_block(effect_builder, state)
                 } else {
@@ -488,17 +511,29 @@ impl BlockExecutor {
         block
     }
 
-    fn pre_state_hash(&mut self, finalized_block: &FinalizedBlock) -> Option<Digest> {
-        if self.is_initial_block_child(finalized_block) {
-            Some(self.initial_state.state_root_hash)
+    /// Returns the pre-state hash of the given finalized block and the accumulated seed of the
+    /// block which will be created from it, if its parent has already been executed.
+    fn pre_state(&mut self, finalized_block: &FinalizedBlock) -> Option<(Digest, Digest)> {
+        let (state_root_hash, parent_seed) = if self.is_initial_block_child(finalized_block) {
+            let parent_seed = self
+                .initial_state
+                .block_summary
+                .as_ref()
+                .map(|summary| summary.accumulated_seed)
+                .unwrap_or_default();
+            (self.initial_state.state_root_hash, parent_seed)
         } else {
             // Try to get the parent's post-state-hash from the `parent_map`.
             // We're subtracting 1 from the height as we want to get _parent's_ post-state hash.
             let parent_block_height = finalized_block.height() - 1;
             self.parent_map
                 .get(&parent_block_height)
-                .map(|summary| summary.state_root_hash)
-        }
+                .map(|summary| (summary.state_root_hash, summary.accumulated_seed))?
+        };
+        Some((
+            state_root_hash,
+            finalized_block.accumulated_seed(parent_seed),
+        ))
     }
 
     /// Returns true if the `finalized_block` is an immediate child of the initial block, ie.
@@ -521,6 +556,7 @@ impl<REv: ReactorEventT> Component<REv> for BlockExecutor {
         match event {
             Event::Request(BlockExecutorRequest::ExecuteBlock(finalized_block)) => {
                 debug!(?finalized_block, "execute block");
+                self.last_finalized_height = Some(finalized_block.height());
                 effect_builder
                     .get_block_at_height_local(finalized_block.height())
                     .event(move |maybe_block| {
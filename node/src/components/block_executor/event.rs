@@ -171,4 +171,6 @@ pub struct State {
     /// Current state root hash of global storage.  Is initialized with the parent block's
     /// state hash, and is updated after each commit.
     pub state_root_hash: Digest,
+    /// Accumulated seed of the block being executed.
+    pub accumulated_seed: Digest,
 }
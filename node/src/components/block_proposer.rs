@@ -3,6 +3,7 @@
 //! The block proposer stores deploy hashes in memory, tracking their suitability for inclusion into
 //! a new block. Upon request, it returns a list of candidates that can be included.
 
+mod config;
 mod deploy_sets;
 mod event;
 mod metrics;
@@ -20,6 +21,8 @@ use datasize::DataSize;
 use prometheus::{self, Registry};
 use tracing::{debug, error, info, trace, warn};
 
+use casper_types::PublicKey;
+
 use crate::{
     components::Component,
     effect::{
@@ -33,6 +36,7 @@ use crate::{
     },
     NodeRng,
 };
+pub use config::Config;
 pub(crate) use deploy_sets::BlockProposerDeploySets;
 pub(crate) use event::{DeployType, Event};
 use metrics::BlockProposerMetrics;
@@ -80,6 +84,8 @@ enum BlockProposerState {
         state_key: Vec<u8>,
         /// The deploy config from the current chainspec.
         deploy_config: DeployConfig,
+        /// The block proposer configuration.
+        config: Config,
     },
     /// Normal operation.
     Ready(BlockProposerReady),
@@ -92,6 +98,7 @@ impl BlockProposer {
         effect_builder: EffectBuilder<REv>,
         next_finalized_block: BlockHeight,
         chainspec: &Chainspec,
+        config: Config,
     ) -> Result<(Self, Effects<Event>), prometheus::Error>
     where
         REv: From<Event> + From<StorageRequest> + From<StateStoreRequest> + Send + 'static,
@@ -111,6 +118,7 @@ impl BlockProposer {
                 pending: Vec::new(),
                 state_key,
                 deploy_config: chainspec.deploy_config,
+                config,
             },
             metrics: BlockProposerMetrics::new(registry)?,
         };
@@ -143,6 +151,7 @@ where
                     ref mut pending,
                     state_key,
                     deploy_config,
+                    config,
                 },
                 Event::Loaded {
                     finalized_deploys,
@@ -156,6 +165,7 @@ where
                     ),
                     unhandled_finalized: Default::default(),
                     deploy_config: *deploy_config,
+                    config: *config,
                     state_key: state_key.clone(),
                     request_queue: Default::default(),
                 };
@@ -209,6 +219,8 @@ struct BlockProposerReady {
     unhandled_finalized: HashSet<DeployHash>,
     /// We don't need the whole Chainspec here, just the deploy config.
     deploy_config: DeployConfig,
+    /// The block proposer configuration.
+    config: Config,
     /// Key for storing the block proposer state.
     state_key: Vec<u8>,
     /// The queue of requests awaiting being handled.
@@ -250,6 +262,15 @@ impl BlockProposerReady {
                         .ignore()
                 }
             }
+            Event::Request(BlockProposerRequest::GetPendingDeploys { responder }) => responder
+                .respond(
+                    self.sets
+                        .pending
+                        .iter()
+                        .map(|(hash, deploy_type)| (*hash, deploy_type.clone()))
+                        .collect(),
+                )
+                .ignore(),
             Event::BufferDeploy { hash, deploy_type } => {
                 self.add_deploy_or_transfer(Timestamp::now(), hash, *deploy_type);
                 Effects::new()
@@ -325,9 +346,20 @@ impl BlockProposerReady {
         // only add the deploy if it isn't contained in a finalized block
         if self.sets.finalized_deploys.contains_key(&hash) {
             info!(%hash, "deploy rejected from the buffer");
-        } else {
-            self.sets.pending.insert(hash, deploy_or_transfer);
-            info!(%hash, "added deploy to the buffer");
+            return;
+        }
+        match self
+            .sets
+            .pending
+            .insert(hash, deploy_or_transfer, self.config.max_pending_deploys)
+        {
+            None => info!(%hash, "added deploy to the buffer"),
+            Some(dropped) if dropped == hash => {
+                info!(%hash, "buffer full; deploy rejected due to low priority")
+            }
+            Some(evicted) => {
+                info!(%hash, %evicted, "added deploy to the buffer; evicted lowest-priority deploy")
+            }
         }
     }
 
@@ -394,6 +426,9 @@ impl BlockProposerReady {
     }
 
     /// Returns a list of candidates for inclusion into a block.
+    ///
+    /// Candidates are considered in order of gas price, then age, and no more than the configured
+    /// number of deploys from any one account are included.
     fn propose_proto_block(
         &mut self,
         deploy_config: DeployConfig,
@@ -402,13 +437,19 @@ impl BlockProposerReady {
         random_bit: bool,
     ) -> ProtoBlock {
         let mut appendable_block = AppendableBlock::new(deploy_config, block_timestamp);
+        let max_per_account = self.config.max_deploys_per_account_per_block;
+        let mut account_counts: HashMap<PublicKey, usize> = HashMap::new();
+        let account_full = |account_counts: &HashMap<PublicKey, usize>, account: &PublicKey| {
+            account_counts.get(account).copied().unwrap_or_default() >= max_per_account
+        };
 
         // We prioritize transfers over deploys, so we try to include them first.
-        for (hash, deploy_type) in &self.sets.pending {
+        for (hash, deploy_type) in self.sets.pending.iter() {
             if !deploy_type.is_transfer()
                 || !self.deps_resolved(&deploy_type.header(), &past_deploys)
                 || past_deploys.contains(hash)
                 || self.contains_finalized(hash)
+                || account_full(&account_counts, deploy_type.header().account())
             {
                 continue;
             }
@@ -424,15 +465,20 @@ impl BlockProposerReady {
                         error!(?err, "unexpected error when adding transfer")
                     }
                 }
+            } else {
+                *account_counts
+                    .entry(*deploy_type.header().account())
+                    .or_default() += 1;
             }
         }
 
         // Now we try to add other deploys to the block.
-        for (hash, deploy_type) in &self.sets.pending {
+        for (hash, deploy_type) in self.sets.pending.iter() {
             if deploy_type.is_transfer()
                 || !self.deps_resolved(&deploy_type.header(), &past_deploys)
                 || past_deploys.contains(hash)
                 || self.contains_finalized(hash)
+                || account_full(&account_counts, deploy_type.header().account())
             {
                 continue;
             }
@@ -459,6 +505,10 @@ impl BlockProposerReady {
                         error!("payment_amount couldn't be converted from motes to gas")
                     }
                 }
+            } else {
+                *account_counts
+                    .entry(*deploy_type.header().account())
+                    .or_default() += 1;
             }
         }
 
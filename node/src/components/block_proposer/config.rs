@@ -0,0 +1,29 @@
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+
+/// Default maximum number of deploys and transfers held in the pending pool.
+const DEFAULT_MAX_PENDING_DEPLOYS: usize = 50_000;
+
+/// Default maximum number of deploys and transfers from a single account included in a block.
+const DEFAULT_MAX_DEPLOYS_PER_ACCOUNT_PER_BLOCK: usize = 10;
+
+/// Block proposer configuration.
+#[derive(Copy, Clone, DataSize, Debug, Deserialize, Serialize)]
+// Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Maximum number of deploys and transfers held in the pending pool.  Once reached, the
+    /// lowest-priority deploys are evicted to make room for higher-priority ones.
+    pub max_pending_deploys: usize,
+    /// Maximum number of deploys and transfers from a single account included in a block.
+    pub max_deploys_per_account_per_block: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            max_pending_deploys: DEFAULT_MAX_PENDING_DEPLOYS,
+            max_deploys_per_account_per_block: DEFAULT_MAX_DEPLOYS_PER_ACCOUNT_PER_BLOCK,
+        }
+    }
+}
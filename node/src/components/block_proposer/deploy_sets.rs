@@ -1,5 +1,6 @@
 use std::{
-    collections::HashMap,
+    cmp::Ordering,
+    collections::{BTreeSet, HashMap},
     fmt::{self, Display, Formatter},
 };
 
@@ -13,7 +14,7 @@ use crate::types::{Chainspec, DeployHash, DeployHeader, Timestamp};
 #[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
 pub struct BlockProposerDeploySets {
     /// The collection of deploys pending for inclusion in a block.
-    pub(super) pending: HashMap<DeployHash, DeployType>,
+    pub(super) pending: PendingDeploys,
     /// The deploys that have already been included in a finalized block.
     pub(super) finalized_deploys: HashMap<DeployHash, DeployHeader>,
     /// The next block height we expect to be finalized.
@@ -28,7 +29,7 @@ pub struct BlockProposerDeploySets {
 
 impl Default for BlockProposerDeploySets {
     fn default() -> Self {
-        let pending = HashMap::new();
+        let pending = Default::default();
         let finalized_deploys = Default::default();
         let next_finalized = Default::default();
         let finalization_queue = Default::default();
@@ -48,7 +49,7 @@ impl BlockProposerDeploySets {
         next_finalized_height: u64,
     ) -> BlockProposerDeploySets {
         BlockProposerDeploySets {
-            pending: HashMap::new(),
+            pending: Default::default(),
             finalized_deploys: finalized_deploys.into_iter().collect(),
             next_finalized: next_finalized_height,
             finalization_queue: Default::default(),
@@ -83,7 +84,7 @@ impl BlockProposerDeploySets {
     /// Prunes expired deploy information from the BlockProposerState, returns the total deploys
     /// pruned
     pub(crate) fn prune(&mut self, current_instant: Timestamp) -> usize {
-        let pending = prune_pending_deploys(&mut self.pending, current_instant);
+        let pending = self.pending.prune(current_instant);
         let finalized = prune_deploys(&mut self.finalized_deploys, current_instant);
         pending + finalized
     }
@@ -100,13 +101,122 @@ pub(super) fn prune_deploys(
     initial_len - deploys.len()
 }
 
-/// Prunes expired deploy information from an individual pending deploy collection, returns the
-/// total deploys pruned
-pub(super) fn prune_pending_deploys(
-    deploys: &mut HashMap<DeployHash, DeployType>,
-    current_instant: Timestamp,
-) -> usize {
-    let initial_len = deploys.len();
-    deploys.retain(|_hash, wrapper| !wrapper.header().expired(current_instant));
-    initial_len - deploys.len()
+/// The order in which pending deploys are considered for inclusion in a block: deploys with a
+/// higher gas price come first, then older deploys, with the deploy hash as a tie-breaker.
+#[derive(Clone, Copy, DataSize, Debug, PartialEq, Eq, Deserialize, Serialize)]
+struct DeployPriority {
+    gas_price: u64,
+    timestamp: Timestamp,
+    hash: DeployHash,
+}
+
+impl DeployPriority {
+    fn new(hash: DeployHash, header: &DeployHeader) -> Self {
+        DeployPriority {
+            gas_price: header.gas_price(),
+            timestamp: header.timestamp(),
+            hash,
+        }
+    }
+}
+
+impl Ord for DeployPriority {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .gas_price
+            .cmp(&self.gas_price)
+            .then_with(|| self.timestamp.cmp(&other.timestamp))
+            .then_with(|| self.hash.cmp(&other.hash))
+    }
+}
+
+impl PartialOrd for DeployPriority {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The pool of deploys pending for inclusion in a block, ordered by priority.
+#[derive(Clone, DataSize, Debug, Default, Deserialize, Serialize)]
+pub(crate) struct PendingDeploys {
+    /// The pending deploys.
+    deploys: HashMap<DeployHash, DeployType>,
+    /// The priorities of all entries in `deploys`, highest priority first.
+    by_priority: BTreeSet<DeployPriority>,
+}
+
+impl PendingDeploys {
+    /// Returns the number of pending deploys.
+    pub(super) fn len(&self) -> usize {
+        self.deploys.len()
+    }
+
+    /// Returns `true` if the given deploy is pending.
+    #[cfg(test)]
+    pub(super) fn contains(&self, hash: &DeployHash) -> bool {
+        self.deploys.contains_key(hash)
+    }
+
+    /// Adds a deploy to the pool.
+    ///
+    /// If this would cause the pool to hold more than `max_len` deploys, the lowest-priority deploy
+    /// is dropped, which may be the given deploy itself.  Returns the hash of the dropped deploy,
+    /// if any.
+    pub(super) fn insert(
+        &mut self,
+        hash: DeployHash,
+        deploy_type: DeployType,
+        max_len: usize,
+    ) -> Option<DeployHash> {
+        let priority = DeployPriority::new(hash, deploy_type.header());
+        if !self.deploys.contains_key(&hash) && self.deploys.len() >= max_len {
+            match self.by_priority.iter().next_back() {
+                Some(lowest) if *lowest > priority => {
+                    let evicted = lowest.hash;
+                    self.remove(&evicted);
+                    self.insert_unchecked(priority, deploy_type);
+                    Some(evicted)
+                }
+                _ => Some(hash),
+            }
+        } else {
+            self.remove(&hash);
+            self.insert_unchecked(priority, deploy_type);
+            None
+        }
+    }
+
+    fn insert_unchecked(&mut self, priority: DeployPriority, deploy_type: DeployType) {
+        self.by_priority.insert(priority);
+        self.deploys.insert(priority.hash, deploy_type);
+    }
+
+    /// Removes a deploy from the pool, returning it if it was pending.
+    pub(super) fn remove(&mut self, hash: &DeployHash) -> Option<DeployType> {
+        let deploy_type = self.deploys.remove(hash)?;
+        self.by_priority
+            .remove(&DeployPriority::new(*hash, deploy_type.header()));
+        Some(deploy_type)
+    }
+
+    /// Iterates over the pending deploys, highest priority first.
+    pub(super) fn iter(&self) -> impl Iterator<Item = (&DeployHash, &DeployType)> {
+        self.by_priority
+            .iter()
+            .filter_map(move |priority| self.deploys.get_key_value(&priority.hash))
+    }
+
+    /// Prunes expired deploys, returns the total deploys pruned.
+    pub(super) fn prune(&mut self, current_instant: Timestamp) -> usize {
+        let initial_len = self.deploys.len();
+        let by_priority = &mut self.by_priority;
+        self.deploys.retain(|hash, deploy_type| {
+            let expired = deploy_type.header().expired(current_instant);
+            if expired {
+                by_priority.remove(&DeployPriority::new(*hash, deploy_type.header()));
+            }
+            !expired
+        });
+        initial_len - self.deploys.len()
+    }
 }
@@ -65,6 +65,24 @@ fn generate_deploy(
     gas_price: u64,
 ) -> Deploy {
     let secret_key = SecretKey::random(rng);
+    generate_deploy_from(
+        &secret_key,
+        timestamp,
+        ttl,
+        dependencies,
+        payment_amount,
+        gas_price,
+    )
+}
+
+fn generate_deploy_from(
+    secret_key: &SecretKey,
+    timestamp: Timestamp,
+    ttl: TimeDiff,
+    dependencies: Vec<DeployHash>,
+    payment_amount: Gas,
+    gas_price: u64,
+) -> Deploy {
     let chain_name = "chain".to_string();
     let args = runtime_args! {
         ARG_AMOUNT => payment_amount.value()
@@ -86,7 +104,7 @@ fn generate_deploy(
         chain_name,
         payment,
         session,
-        &secret_key,
+        secret_key,
     )
 }
 
@@ -94,6 +112,7 @@ fn create_test_proposer() -> BlockProposerReady {
     BlockProposerReady {
         sets: Default::default(),
         deploy_config: Default::default(),
+        config: Default::default(),
         state_key: b"block-proposer-test".to_vec(),
         request_queue: Default::default(),
         unhandled_finalized: Default::default(),
@@ -246,6 +265,149 @@ fn should_add_and_take_deploys() {
     assert!(deploys.contains(&deploy4.id()));
 }
 
+#[test]
+fn should_propose_deploys_in_order_of_gas_price_then_age() {
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let block_time = Timestamp::from(120);
+
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+    let old_cheap = generate_deploy(
+        &mut rng,
+        Timestamp::from(100),
+        ttl,
+        vec![],
+        default_gas_payment(),
+        1,
+    );
+    let new_cheap = generate_deploy(
+        &mut rng,
+        Timestamp::from(110),
+        ttl,
+        vec![],
+        default_gas_payment(),
+        1,
+    );
+    let new_expensive = generate_deploy(
+        &mut rng,
+        Timestamp::from(110),
+        ttl,
+        vec![],
+        default_gas_payment(),
+        5,
+    );
+    for deploy in &[&new_cheap, &old_cheap, &new_expensive] {
+        proposer.add_deploy_or_transfer(block_time, *deploy.id(), deploy.deploy_type().unwrap());
+    }
+
+    let pending: Vec<_> = proposer
+        .sets
+        .pending
+        .iter()
+        .map(|(hash, _)| *hash)
+        .collect();
+    assert_eq!(
+        pending,
+        vec![*new_expensive.id(), *old_cheap.id(), *new_cheap.id()]
+    );
+
+    // with room for only two deploys, the cheapest newest one should be left out
+    let mut deploy_config = DeployConfig::default();
+    deploy_config.block_max_deploy_count = 2;
+    let block = proposer.propose_proto_block(deploy_config, block_time, HashSet::new(), true);
+    let deploys = block.wasm_deploys();
+    assert_eq!(deploys.len(), 2);
+    assert!(deploys.contains(new_expensive.id()));
+    assert!(deploys.contains(old_cheap.id()));
+}
+
+#[test]
+fn should_limit_deploys_per_account_per_block() {
+    let creation_time = Timestamp::from(100);
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let block_time = Timestamp::from(120);
+
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+    proposer.config.max_deploys_per_account_per_block = 2;
+
+    let secret_key = SecretKey::random(&mut rng);
+    for _ in 0..3 {
+        let deploy = generate_deploy_from(
+            &secret_key,
+            creation_time,
+            ttl,
+            vec![],
+            default_gas_payment(),
+            DEFAULT_TEST_GAS_PRICE,
+        );
+        proposer.add_deploy_or_transfer(block_time, *deploy.id(), deploy.deploy_type().unwrap());
+    }
+    let other_deploy = generate_deploy(
+        &mut rng,
+        creation_time,
+        ttl,
+        vec![],
+        default_gas_payment(),
+        DEFAULT_TEST_GAS_PRICE,
+    );
+    proposer.add_deploy_or_transfer(
+        block_time,
+        *other_deploy.id(),
+        other_deploy.deploy_type().unwrap(),
+    );
+
+    let block =
+        proposer.propose_proto_block(DeployConfig::default(), block_time, HashSet::new(), true);
+    let deploys = block.wasm_deploys();
+    assert_eq!(deploys.len(), 3);
+    assert!(deploys.contains(other_deploy.id()));
+}
+
+#[test]
+fn should_evict_lowest_priority_deploy_when_pool_is_full() {
+    let creation_time = Timestamp::from(100);
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let block_time = Timestamp::from(120);
+
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+    proposer.config.max_pending_deploys = 2;
+
+    let mut generate = |gas_price| {
+        generate_deploy(
+            &mut rng,
+            creation_time,
+            ttl,
+            vec![],
+            default_gas_payment(),
+            gas_price,
+        )
+    };
+    let cheap = generate(1);
+    let medium = generate(2);
+    let expensive = generate(3);
+    let cheapest = generate(1);
+
+    proposer.add_deploy_or_transfer(block_time, *cheap.id(), cheap.deploy_type().unwrap());
+    proposer.add_deploy_or_transfer(block_time, *medium.id(), medium.deploy_type().unwrap());
+    // the pool is full, so the cheap deploy should make way for the expensive one
+    proposer.add_deploy_or_transfer(
+        block_time,
+        *expensive.id(),
+        expensive.deploy_type().unwrap(),
+    );
+    assert_eq!(proposer.sets.pending.len(), 2);
+    assert!(!proposer.sets.pending.contains(cheap.id()));
+    assert!(proposer.sets.pending.contains(medium.id()));
+    assert!(proposer.sets.pending.contains(expensive.id()));
+
+    // a deploy with a lower priority than everything in the full pool should be rejected
+    proposer.add_deploy_or_transfer(block_time, *cheapest.id(), cheapest.deploy_type().unwrap());
+    assert_eq!(proposer.sets.pending.len(), 2);
+    assert!(!proposer.sets.pending.contains(cheapest.id()));
+}
+
 #[test]
 fn should_successfully_prune() {
     let expired_time = Timestamp::from(201);
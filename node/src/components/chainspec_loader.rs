@@ -468,6 +468,8 @@ impl ChainspecLoader {
             Some(self.chainspec.core_config.locked_funds_period.millis()),
             Some(self.chainspec.core_config.round_seigniorage_rate),
             Some(self.chainspec.core_config.unbonding_delay),
+            Some(self.chainspec.core_config.refund_ratio),
+            Some(self.chainspec.core_config.fee_handling),
             global_state_update,
         ))
     }
@@ -383,6 +383,9 @@ where
             Event::ConsensusRequest(ConsensusRequest::Status(responder)) => {
                 handling_es.status(responder)
             }
+            Event::ConsensusRequest(ConsensusRequest::DumpState(responder)) => {
+                handling_es.dump_state(responder)
+            }
         }
     }
 }
@@ -202,4 +202,7 @@ pub(crate) trait ConsensusProtocol<I, C: Context>: Send {
 
     // TODO: Make this lees Highway-specific.
     fn next_round_length(&self) -> Option<TimeDiff>;
+
+    /// Returns a human-readable dump of the protocol state, for diagnostics.
+    fn dump_state(&self) -> String;
 }
@@ -1321,6 +1321,25 @@ where
         responder.respond((public_key, round_length)).ignore()
     }
 
+    /// Responds with a dump of the current era's protocol state, if the era is active.
+    pub(super) fn dump_state(&self, responder: Responder<Option<String>>) -> Effects<Event<I>> {
+        let current_era = self.era_supervisor.current_era;
+        let dump = self
+            .era_supervisor
+            .active_eras
+            .get(&current_era)
+            .map(|era| {
+                format!(
+                    "era {} (started at {}, height {}):\n{}",
+                    current_era.0,
+                    era.start_time,
+                    era.start_height,
+                    era.consensus.dump_state()
+                )
+            });
+        responder.respond(dump).ignore()
+    }
+
     fn disconnect(&self, sender: I) -> Effects<Event<I>> {
         self.effect_builder
             .announce_disconnect_from_peer(sender)
@@ -878,4 +878,8 @@ where
     fn next_round_length(&self) -> Option<TimeDiff> {
         self.highway.next_round_length()
     }
+
+    fn dump_state(&self) -> String {
+        format!("{:#?}", self.highway.state())
+    }
 }
@@ -6,6 +6,7 @@ pub use config::Config;
 pub use types::{EraValidatorsRequest, ValidatorWeightsByEraIdRequest};
 
 use std::{
+    collections::VecDeque,
     fmt::{self, Debug, Display, Formatter},
     sync::Arc,
     time::Instant,
@@ -18,26 +19,29 @@ use prometheus::{self, Histogram, HistogramOpts, Registry};
 use serde::Serialize;
 use thiserror::Error;
 use tokio::task;
-use tracing::{error, trace};
+use tracing::{error, info, trace};
 
 use casper_execution_engine::{
     core::engine_state::{
-        genesis::GenesisResult, EngineConfig, EngineState, Error, GetEraValidatorsError,
-        GetEraValidatorsRequest,
+        genesis::GenesisResult, EngineConfig, EngineState, Error, GetBidsRequest, GetBidsResult,
+        GetEraValidatorsError, GetEraValidatorsRequest, QueryRequest, QueryResult,
     },
     shared::newtypes::{Blake2bHash, CorrelationId},
     storage::{
-        error::lmdb::Error as StorageLmdbError, global_state::lmdb::LmdbGlobalState,
+        error::lmdb::Error as StorageLmdbError,
+        global_state::lmdb::LmdbGlobalState,
+        protocol_data::ProtocolData,
         protocol_data_store::lmdb::LmdbProtocolDataStore,
-        transaction_source::lmdb::LmdbEnvironment, trie_store::lmdb::LmdbTrieStore,
+        transaction_source::lmdb::LmdbEnvironment,
+        trie_store::{lmdb::LmdbTrieStore, PruneResult},
     },
 };
 use casper_types::{system::auction::ValidatorWeights, ProtocolVersion};
 
 use crate::{
-    components::Component,
+    components::{consensus::EraId, Component},
     effect::{requests::ContractRuntimeRequest, EffectBuilder, EffectExt, Effects},
-    types::Chainspec,
+    types::{Block, Chainspec},
     utils::WithDir,
     NodeRng, StorageConfig,
 };
@@ -47,6 +51,20 @@ use crate::{
 pub struct ContractRuntime {
     engine_state: Arc<EngineState<LmdbGlobalState>>,
     metrics: Arc<ContractRuntimeMetrics>,
+    /// The number of most recent eras whose global state is retained when pruning, if enabled.
+    ///
+    /// This is the storage component's `retained_eras`, so the global state of every block which
+    /// has not been pruned from storage remains available.
+    retained_eras: Option<u64>,
+    /// The maximum number of trie nodes visited or deleted per pruning step.
+    trie_pruning_batch_size: usize,
+    /// The era IDs and state roots of the added blocks in the retained eras, oldest first.
+    #[data_size(skip)]
+    recent_state_roots: VecDeque<(EraId, Blake2bHash)>,
+    /// The earliest era all of whose blocks have been added since the component was created.
+    first_complete_era: Option<EraId>,
+    /// Whether a pruning pass is currently running.
+    pruning_in_progress: bool,
 }
 
 impl Debug for ContractRuntime {
@@ -61,12 +79,18 @@ pub enum Event {
     /// A request made of the contract runtime component.
     #[from]
     Request(ContractRuntimeRequest),
+    /// A block has been added to the linear chain.
+    BlockAdded(Box<Block>),
+    /// A step of pruning unreachable global state tries has completed.
+    TriesPruned(#[serde(skip_serializing)] Result<PruneResult, Error>),
 }
 
 impl Display for Event {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Event::Request(request) => write!(f, "{}", request),
+            Event::BlockAdded(block) => write!(f, "block added {}", block.hash()),
+            Event::TriesPruned(result) => write!(f, "tries pruned: {:?}", result),
         }
     }
 }
@@ -87,6 +111,7 @@ pub struct ContractRuntimeMetrics {
     missing_trie_keys: Histogram,
     put_trie: Histogram,
     read_trie: Histogram,
+    get_trie_diff: Histogram,
 }
 
 /// Value of upper bound of histogram.
@@ -130,6 +155,8 @@ const PUT_TRIE_NAME: &str = "contract_runtime_put_trie";
 const PUT_TRIE_HELP: &str = "tracking run of engine_state.put_trie in seconds.";
 const MISSING_TRIE_KEYS_NAME: &str = "contract_runtime_missing_trie_keys";
 const MISSING_TRIE_KEYS_HELP: &str = "tracking run of engine_state.missing_trie_keys in seconds.";
+const GET_TRIE_DIFF_NAME: &str = "contract_runtime_get_trie_diff";
+const GET_TRIE_DIFF_HELP: &str = "tracking run of engine_state.get_trie_diff in seconds.";
 
 /// Create prometheus Histogram and register.
 fn register_histogram_metric(
@@ -189,6 +216,11 @@ impl ContractRuntimeMetrics {
                 MISSING_TRIE_KEYS_NAME,
                 MISSING_TRIE_KEYS_HELP,
             )?,
+            get_trie_diff: register_histogram_metric(
+                registry,
+                GET_TRIE_DIFF_NAME,
+                GET_TRIE_DIFF_HELP,
+            )?,
         })
     }
 }
@@ -558,6 +590,84 @@ where
                 }
                 .ignore()
             }
+            Event::Request(ContractRuntimeRequest::GetTrieDiff {
+                from_state_root_hash,
+                to_state_root_hash,
+                start_after,
+                max_entries,
+                responder,
+            }) => {
+                trace!(
+                    ?from_state_root_hash,
+                    ?to_state_root_hash,
+                    "get_trie_diff request"
+                );
+                let engine_state = Arc::clone(&self.engine_state);
+                let metrics = Arc::clone(&self.metrics);
+                async move {
+                    let correlation_id = CorrelationId::new();
+                    let result = task::spawn_blocking(move || {
+                        let start = Instant::now();
+                        let result = engine_state.get_trie_diff(
+                            correlation_id,
+                            from_state_root_hash,
+                            to_state_root_hash,
+                            start_after,
+                            max_entries,
+                        );
+                        metrics.get_trie_diff.observe(start.elapsed().as_secs_f64());
+                        result
+                    })
+                    .await
+                    .expect("should run");
+                    trace!(?result, "get_trie_diff response");
+                    responder.respond(result).await
+                }
+                .ignore()
+            }
+            Event::BlockAdded(block) => {
+                let retained_eras = match self.retained_eras {
+                    Some(retained_eras) => retained_eras,
+                    None => return Effects::new(),
+                };
+                let header = block.header();
+                let era_id = header.era_id();
+                self.recent_state_roots
+                    .push_back((era_id, (*header.state_root_hash()).into()));
+                if header.is_switch_block() && self.first_complete_era.is_none() {
+                    self.first_complete_era = Some(era_id.successor());
+                }
+                // As in storage, the era of the highest block always counts as retained.
+                let first_retained_era_id = era_id.saturating_sub(retained_eras.saturating_sub(1));
+                while matches!(
+                    self.recent_state_roots.front(),
+                    Some((oldest_era_id, _)) if *oldest_era_id < first_retained_era_id
+                ) {
+                    self.recent_state_roots.pop_front();
+                }
+                // Only start pruning once the state roots of all blocks in the retained eras are
+                // known, so that state of blocks still held in storage is not deleted.
+                let all_retained_roots_known =
+                    self.first_complete_era.map_or(false, |first_complete_era| {
+                        first_complete_era <= first_retained_era_id
+                    });
+                if self.pruning_in_progress || !all_retained_roots_known {
+                    return Effects::new();
+                }
+                self.pruning_in_progress = true;
+                self.prune_tries()
+            }
+            Event::TriesPruned(Ok(PruneResult::InProgress)) => self.prune_tries(),
+            Event::TriesPruned(Ok(PruneResult::Finished { deleted_count })) => {
+                info!(%deleted_count, "finished pruning global state");
+                self.pruning_in_progress = false;
+                Effects::new()
+            }
+            Event::TriesPruned(Err(error)) => {
+                error!(%error, "failed to prune global state");
+                self.pruning_in_progress = false;
+                Effects::new()
+            }
         }
     }
 }
@@ -577,6 +687,7 @@ impl ContractRuntime {
     pub(crate) fn new(
         storage_config: WithDir<StorageConfig>,
         contract_runtime_config: &Config,
+        strict_entry_point_args_protocol_version: Option<ProtocolVersion>,
         registry: &Registry,
     ) -> Result<Self, ConfigError> {
         let path = storage_config.with_dir(storage_config.value().path.clone());
@@ -599,7 +710,10 @@ impl ContractRuntime {
         )?);
 
         let global_state = LmdbGlobalState::empty(environment, trie_store, protocol_data_store)?;
-        let engine_config = EngineConfig::new(contract_runtime_config.max_query_depth());
+        let engine_config = EngineConfig::new(
+            contract_runtime_config.max_query_depth(),
+            strict_entry_point_args_protocol_version,
+        );
 
         let engine_state = Arc::new(EngineState::new(global_state, engine_config));
 
@@ -607,9 +721,34 @@ impl ContractRuntime {
         Ok(ContractRuntime {
             engine_state,
             metrics,
+            retained_eras: storage_config.value().retained_eras(),
+            trie_pruning_batch_size: contract_runtime_config.trie_pruning_batch_size(),
+            recent_state_roots: VecDeque::new(),
+            first_complete_era: None,
+            pruning_in_progress: false,
         })
     }
 
+    /// Runs a single bounded step of pruning tries unreachable from the recent state roots.
+    fn prune_tries(&self) -> Effects<Event> {
+        let engine_state = Arc::clone(&self.engine_state);
+        let retained_roots: Vec<Blake2bHash> = self
+            .recent_state_roots
+            .iter()
+            .map(|(_, state_root_hash)| *state_root_hash)
+            .collect();
+        let batch_size = self.trie_pruning_batch_size;
+        async move {
+            let correlation_id = CorrelationId::new();
+            task::spawn_blocking(move || {
+                engine_state.prune_tries(correlation_id, &retained_roots, batch_size)
+            })
+            .await
+            .expect("should run")
+        }
+        .event(Event::TriesPruned)
+    }
+
     /// Commits a genesis using a chainspec
     fn commit_genesis(&self, chainspec: Arc<Chainspec>) -> Result<GenesisResult, Error> {
         let correlation_id = CorrelationId::new();
@@ -629,6 +768,48 @@ impl ContractRuntime {
         )
     }
 
+    /// Prunes all tries unreachable from `retained_roots`, blocking until finished.
+    ///
+    /// Returns the number of deleted tries.
+    pub(crate) fn prune_global_state(&self, retained_roots: &[Blake2bHash]) -> Result<u64, Error> {
+        let correlation_id = CorrelationId::new();
+        loop {
+            if let PruneResult::Finished { deleted_count } = self.engine_state.prune_tries(
+                correlation_id,
+                retained_roots,
+                self.trie_pruning_batch_size,
+            )? {
+                return Ok(deleted_count);
+            }
+        }
+    }
+
+    /// Queries the global state, blocking until finished.
+    pub(crate) fn query_global_state(
+        &self,
+        query_request: QueryRequest,
+    ) -> Result<QueryResult, Error> {
+        self.engine_state
+            .run_query(CorrelationId::new(), query_request)
+    }
+
+    /// Retrieves all bids from the global state, blocking until finished.
+    pub(crate) fn get_bids(
+        &self,
+        get_bids_request: GetBidsRequest,
+    ) -> Result<GetBidsResult, Error> {
+        self.engine_state
+            .get_bids(CorrelationId::new(), get_bids_request)
+    }
+
+    /// Retrieves the protocol data for the given protocol version.
+    pub(crate) fn get_protocol_data(
+        &self,
+        protocol_version: ProtocolVersion,
+    ) -> Result<Option<ProtocolData>, Error> {
+        self.engine_state.get_protocol_data(protocol_version)
+    }
+
     /// Retrieve trie keys for the integrity check.
     pub fn trie_store_check(&self, trie_keys: Vec<Blake2bHash>) -> Vec<Blake2bHash> {
         let correlation_id = CorrelationId::new();
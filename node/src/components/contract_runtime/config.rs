@@ -6,6 +6,7 @@ use casper_execution_engine::shared::utils;
 const DEFAULT_MAX_GLOBAL_STATE_SIZE: usize = 805_306_368_000; // 750 GiB
 const DEFAULT_MAX_READERS: u32 = 512;
 const DEFAULT_MAX_QUERY_DEPTH: u64 = 5;
+const DEFAULT_TRIE_PRUNING_BATCH_SIZE: usize = 10_000;
 
 /// Contract runtime configuration.
 #[derive(Clone, Copy, DataSize, Debug, Deserialize, Serialize)]
@@ -26,6 +27,10 @@ pub struct Config {
     ///
     /// Defaults to 5.
     max_query_depth: Option<u64>,
+    /// The maximum number of trie nodes visited or deleted per pruning step.
+    ///
+    /// Defaults to 10,000.
+    trie_pruning_batch_size: Option<usize>,
 }
 
 impl Config {
@@ -44,6 +49,11 @@ impl Config {
     pub(crate) fn max_query_depth(&self) -> u64 {
         self.max_query_depth.unwrap_or(DEFAULT_MAX_QUERY_DEPTH)
     }
+
+    pub(crate) fn trie_pruning_batch_size(&self) -> usize {
+        self.trie_pruning_batch_size
+            .unwrap_or(DEFAULT_TRIE_PRUNING_BATCH_SIZE)
+    }
 }
 
 impl Default for Config {
@@ -52,6 +62,7 @@ impl Default for Config {
             max_global_state_size: Some(DEFAULT_MAX_GLOBAL_STATE_SIZE),
             max_readers: Some(DEFAULT_MAX_READERS),
             max_query_depth: Some(DEFAULT_MAX_QUERY_DEPTH),
+            trie_pruning_batch_size: Some(DEFAULT_TRIE_PRUNING_BATCH_SIZE),
         }
     }
 }
@@ -1,9 +1,10 @@
 mod config;
 mod event;
 
-use std::{convert::Infallible, fmt::Debug};
+use std::{collections::BTreeSet, convert::Infallible, fmt::Debug};
 
 use thiserror::Error;
+use tokio::task;
 use tracing::{debug, error, info};
 
 use crate::{
@@ -17,7 +18,8 @@ use crate::{
     utils::Source,
     NodeRng,
 };
-use casper_types::Key;
+use casper_execution_engine::shared::{account::Account, wasm_config::WasmConfig};
+use casper_types::{account::AccountHash, Key, ProtocolVersion};
 
 use crate::effect::Responder;
 pub use config::Config;
@@ -34,6 +36,22 @@ pub enum Error {
     /// A deploy was sent from account with insufficient balance.
     #[error("insufficient balance")]
     InsufficientBalance,
+    /// A deploy was approved by a key which is not associated with the sending account.
+    #[error("approval signer {0} is not an associated key of the account")]
+    UnassociatedApprovalSigner(AccountHash),
+    /// The combined weight of a deploy's approvals is below the account's deployment threshold.
+    #[error(
+        "approvals have a combined weight of {approvals_weight}, missing {missing_weight} to \
+        meet the account's deployment threshold of {deployment_threshold}"
+    )]
+    DeploymentThresholdNotMet {
+        /// The account's deployment threshold.
+        deployment_threshold: u8,
+        /// The combined weight of the deploy's approvals.
+        approvals_weight: u8,
+        /// The additional weight required to meet the threshold.
+        missing_weight: u8,
+    },
 }
 
 /// A helper trait constraining `DeployAcceptor` compatible reactor events.
@@ -63,7 +81,9 @@ impl<REv> ReactorEventT for REv where
 #[derive(Debug)]
 pub struct DeployAcceptor {
     chain_name: String,
+    protocol_version: ProtocolVersion,
     deploy_config: DeployConfig,
+    wasm_config: WasmConfig,
     verify_accounts: bool,
 }
 
@@ -71,7 +91,9 @@ impl DeployAcceptor {
     pub(crate) fn new(config: Config, chainspec: &Chainspec) -> Self {
         DeployAcceptor {
             chain_name: chainspec.network_config.name.clone(),
+            protocol_version: chainspec.protocol_version(),
             deploy_config: chainspec.deploy_config,
+            wasm_config: chainspec.wasm_config,
             verify_accounts: config.verify_accounts(),
         }
     }
@@ -88,46 +110,105 @@ impl DeployAcceptor {
         maybe_responder: Option<Responder<Result<(), Error>>>,
     ) -> Effects<Event> {
         let mut cloned_deploy = deploy.clone();
-        let mut effects = Effects::new();
-        let is_acceptable = cloned_deploy.is_acceptable(&self.chain_name, &self.deploy_config);
-        if let Err(error) = is_acceptable {
-            // The client has submitted an invalid deploy. Return an error to the RPC component via
-            // the responder.
-            if let Some(responder) = maybe_responder {
-                effects.extend(responder.respond(Err(Error::InvalidDeploy(error))).ignore());
-            }
-            effects.extend(
-                effect_builder
-                    .announce_invalid_deploy(deploy, source)
-                    .ignore(),
+        if let Err(error) = cloned_deploy.is_acceptable(&self.chain_name, &self.deploy_config) {
+            return self.reject_invalid_deploy(
+                effect_builder,
+                deploy,
+                source,
+                maybe_responder,
+                error,
             );
-            return effects;
         }
 
+        // Validating the Wasm is expensive, so only do it for deploys received from clients, and
+        // do it on the blocking pool rather than in the reactor.
+        if source.from_client() {
+            let wasm_config = self.wasm_config;
+            let protocol_version = self.protocol_version;
+            return async move {
+                task::spawn_blocking(move || {
+                    cloned_deploy.has_valid_wasm(&wasm_config, protocol_version)
+                })
+                .await
+                .unwrap_or_else(|error| {
+                    error!(%error, "wasm validation task failed");
+                    Err(DeployValidationFailure::WasmValidationFailed(
+                        error.to_string(),
+                    ))
+                })
+            }
+            .event(move |result| Event::WasmValidationResult {
+                deploy,
+                source,
+                result,
+                maybe_responder,
+            });
+        }
+
+        self.verify_account_or_store(effect_builder, deploy, source, maybe_responder)
+    }
+
+    fn wasm_validation<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        deploy: Box<Deploy>,
+        source: Source<NodeId>,
+        result: Result<(), DeployValidationFailure>,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+    ) -> Effects<Event> {
+        match result {
+            Ok(()) => self.verify_account_or_store(effect_builder, deploy, source, maybe_responder),
+            Err(error) => {
+                self.reject_invalid_deploy(effect_builder, deploy, source, maybe_responder, error)
+            }
+        }
+    }
+
+    fn reject_invalid_deploy<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        deploy: Box<Deploy>,
+        source: Source<NodeId>,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+        error: DeployValidationFailure,
+    ) -> Effects<Event> {
+        let mut effects = Effects::new();
+        // The client has submitted an invalid deploy. Return an error to the RPC component via the
+        // responder.
+        if let Some(responder) = maybe_responder {
+            effects.extend(responder.respond(Err(Error::InvalidDeploy(error))).ignore());
+        }
+        effects.extend(
+            effect_builder
+                .announce_invalid_deploy(deploy, source)
+                .ignore(),
+        );
+        effects
+    }
+
+    fn verify_account_or_store<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        deploy: Box<Deploy>,
+        source: Source<NodeId>,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+    ) -> Effects<Event> {
         let account_key = deploy.header().account().to_account_hash().into();
 
         // Verify account if deploy received from client and node is configured to do so.
         if source.from_client() && self.verify_accounts {
             return effect_builder
-                .is_verified_account(account_key)
-                .event(move |verified| Event::AccountVerificationResult {
+                .get_account_and_check_balance(account_key)
+                .event(move |maybe_account| Event::AccountVerificationResult {
                     deploy,
                     source,
                     account_key,
-                    verified,
+                    maybe_account,
                     maybe_responder,
                 });
         }
 
-        effect_builder
-            .immediately()
-            .event(move |_| Event::AccountVerificationResult {
-                deploy,
-                source,
-                account_key,
-                verified: Some(true),
-                maybe_responder,
-            })
+        self.store_valid_deploy(effect_builder, deploy, source, maybe_responder)
     }
 
     fn account_verification<REv: ReactorEventT>(
@@ -136,53 +217,29 @@ impl DeployAcceptor {
         deploy: Box<Deploy>,
         source: Source<NodeId>,
         account_key: Key,
-        verified: Option<bool>,
+        maybe_account: Option<(Box<Account>, bool)>,
         maybe_responder: Option<Responder<Result<(), Error>>>,
     ) -> Effects<Event> {
-        let mut effects = Effects::new();
+        let verification_result = match maybe_account {
+            Some((account, true)) => verify_approvals(&account, &deploy),
+            Some((_, false)) => Err(Error::InsufficientBalance),
+            None => Err(Error::InvalidAccount),
+        };
 
-        match verified {
-            Some(true) => {
-                // The client submitted a valid deploy. Return an Ok status to the RPC component via
-                // the responder.
-                if let Some(responder) = maybe_responder {
-                    effects.extend(responder.respond(Ok(())).ignore());
-                }
-
-                effects.extend(effect_builder.put_deploy_to_storage(deploy.clone()).event(
-                    move |is_new| Event::PutToStorageResult {
-                        deploy,
-                        source,
-                        is_new,
-                    },
-                ));
-
-                return effects;
+        let error = match verification_result {
+            Ok(()) => {
+                return self.store_valid_deploy(effect_builder, deploy, source, maybe_responder)
             }
+            Err(error) => error,
+        };
 
-            Some(false) => {
-                info! {
-                    "Received deploy from account {} that does not have minimum balance required", account_key
-                };
-                // The client has submitted a deploy from an account that does not have minimum
-                // balance required. Return an error message to the RPC component via the responder.
-                if let Some(responder) = maybe_responder {
-                    effects.extend(responder.respond(Err(Error::InsufficientBalance)).ignore());
-                }
-            }
-
-            None => {
-                // The client has submitted an invalid deploy. Return an error message to the RPC
-                // component via the responder.
-                info! {
-                    "Received deploy from invalid account using {}", account_key
-                };
-                if let Some(responder) = maybe_responder {
-                    effects.extend(responder.respond(Err(Error::InvalidAccount)).ignore());
-                }
-            }
+        info!(%account_key, %error, "rejected deploy {}", deploy.id());
+        // The client has submitted a deploy which its account can't send. Return an error message
+        // to the RPC component via the responder.
+        let mut effects = Effects::new();
+        if let Some(responder) = maybe_responder {
+            effects.extend(responder.respond(Err(error)).ignore());
         }
-
         effects.extend(
             effect_builder
                 .announce_invalid_deploy(deploy, source)
@@ -191,6 +248,32 @@ impl DeployAcceptor {
         effects
     }
 
+    fn store_valid_deploy<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        deploy: Box<Deploy>,
+        source: Source<NodeId>,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+    ) -> Effects<Event> {
+        let mut effects = Effects::new();
+        // The client submitted a valid deploy. Return an Ok status to the RPC component via the
+        // responder.
+        if let Some(responder) = maybe_responder {
+            effects.extend(responder.respond(Ok(())).ignore());
+        }
+
+        effects.extend(
+            effect_builder
+                .put_deploy_to_storage(deploy.clone())
+                .event(move |is_new| Event::PutToStorageResult {
+                    deploy,
+                    source,
+                    is_new,
+                }),
+        );
+        effects
+    }
+
     fn handle_put_to_storage<REv: ReactorEventT>(
         &mut self,
         effect_builder: EffectBuilder<REv>,
@@ -224,6 +307,12 @@ impl<REv: ReactorEventT> Component<REv> for DeployAcceptor {
                 source,
                 responder,
             } => self.accept(effect_builder, deploy, source, responder),
+            Event::WasmValidationResult {
+                deploy,
+                source,
+                result,
+                maybe_responder,
+            } => self.wasm_validation(effect_builder, deploy, source, result, maybe_responder),
             Event::PutToStorageResult {
                 deploy,
                 source,
@@ -233,16 +322,155 @@ impl<REv: ReactorEventT> Component<REv> for DeployAcceptor {
                 deploy,
                 source,
                 account_key,
-                verified,
+                maybe_account,
                 maybe_responder,
             } => self.account_verification(
                 effect_builder,
                 deploy,
                 source,
                 account_key,
-                verified,
+                maybe_account,
                 maybe_responder,
             ),
         }
     }
 }
+
+/// Checks that every approval of `deploy` was made by a key associated with `account`, and that
+/// the combined weight of the approving keys meets the account's deployment threshold.
+fn verify_approvals(account: &Account, deploy: &Deploy) -> Result<(), Error> {
+    let mut approving_keys = BTreeSet::new();
+    for approval in deploy.approvals() {
+        let account_hash = approval.signer().to_account_hash();
+        if account.get_associated_key_weight(account_hash).is_none() {
+            return Err(Error::UnassociatedApprovalSigner(account_hash));
+        }
+        approving_keys.insert(account_hash);
+    }
+
+    // Mirror the execution engine, which saturates rather than overflows when summing weights.
+    let approvals_weight = approving_keys
+        .into_iter()
+        .filter_map(|account_hash| account.get_associated_key_weight(account_hash))
+        .fold(0u8, |total, weight| total.saturating_add(weight.value()));
+    let deployment_threshold = account.action_thresholds().deployment().value();
+    if approvals_weight < deployment_threshold {
+        return Err(Error::DeploymentThresholdNotMet {
+            deployment_threshold,
+            approvals_weight,
+            missing_weight: deployment_threshold - approvals_weight,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use casper_execution_engine::shared::account::{ActionThresholds, AssociatedKeys};
+    use casper_types::{account::Weight, contracts::NamedKeys, PublicKey, SecretKey, URef};
+
+    use super::*;
+    use crate::{
+        crypto::AsymmetricKeyExt,
+        testing::TestRng,
+        types::{TimeDiff, Timestamp},
+    };
+
+    fn account_hash(secret_key: &SecretKey) -> AccountHash {
+        PublicKey::from(secret_key).to_account_hash()
+    }
+
+    /// Returns an account with the given associated keys and weights, and a deployment threshold
+    /// of 3.
+    fn multisig_account(keys: &[(&SecretKey, u8)]) -> Account {
+        let (primary_key, primary_weight) = keys[0];
+        let mut associated_keys =
+            AssociatedKeys::new(account_hash(primary_key), Weight::new(primary_weight));
+        for (secret_key, weight) in &keys[1..] {
+            associated_keys
+                .add_key(account_hash(secret_key), Weight::new(*weight))
+                .unwrap();
+        }
+        let action_thresholds = ActionThresholds::new(Weight::new(3), Weight::new(3)).unwrap();
+        Account::new(
+            account_hash(primary_key),
+            NamedKeys::new(),
+            URef::default(),
+            associated_keys,
+            action_thresholds,
+        )
+    }
+
+    fn deploy_signed_by(rng: &mut TestRng, secret_keys: &[&SecretKey]) -> Deploy {
+        let mut deploy = Deploy::new(
+            Timestamp::now(),
+            TimeDiff::from(60_000),
+            1,
+            vec![],
+            String::from("casper-example"),
+            rng.gen(),
+            rng.gen(),
+            secret_keys[0],
+        );
+        for secret_key in &secret_keys[1..] {
+            deploy.sign(secret_key);
+        }
+        deploy
+    }
+
+    #[test]
+    fn should_accept_approvals_meeting_deployment_threshold() {
+        let mut rng = TestRng::new();
+        let (key_1, key_2, key_3) = (
+            SecretKey::random(&mut rng),
+            SecretKey::random(&mut rng),
+            SecretKey::random(&mut rng),
+        );
+        let account = multisig_account(&[(&key_1, 2), (&key_2, 1), (&key_3, 1)]);
+
+        let deploy = deploy_signed_by(&mut rng, &[&key_1, &key_2]);
+        assert!(verify_approvals(&account, &deploy).is_ok());
+
+        let deploy = deploy_signed_by(&mut rng, &[&key_1, &key_2, &key_3]);
+        assert!(verify_approvals(&account, &deploy).is_ok());
+    }
+
+    #[test]
+    fn should_reject_approvals_below_deployment_threshold() {
+        let mut rng = TestRng::new();
+        let (key_1, key_2) = (SecretKey::random(&mut rng), SecretKey::random(&mut rng));
+        let account = multisig_account(&[(&key_1, 2), (&key_2, 1)]);
+
+        // Duplicate approvals must not be counted twice.
+        let deploy = deploy_signed_by(&mut rng, &[&key_1, &key_1]);
+        match verify_approvals(&account, &deploy) {
+            Err(Error::DeploymentThresholdNotMet {
+                deployment_threshold: 3,
+                approvals_weight: 2,
+                missing_weight: 1,
+            }) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_reject_approval_from_unassociated_key() {
+        let mut rng = TestRng::new();
+        let (key_1, key_2, other_key) = (
+            SecretKey::random(&mut rng),
+            SecretKey::random(&mut rng),
+            SecretKey::random(&mut rng),
+        );
+        let account = multisig_account(&[(&key_1, 2), (&key_2, 1)]);
+
+        let deploy = deploy_signed_by(&mut rng, &[&key_1, &key_2, &other_key]);
+        match verify_approvals(&account, &deploy) {
+            Err(Error::UnassociatedApprovalSigner(signer)) => {
+                assert_eq!(signer, account_hash(&other_key))
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
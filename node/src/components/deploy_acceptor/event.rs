@@ -6,8 +6,9 @@ use super::Source;
 use crate::{
     components::deploy_acceptor::Error,
     effect::{announcements::RpcServerAnnouncement, Responder},
-    types::{Deploy, NodeId},
+    types::{Deploy, DeployValidationFailure, NodeId},
 };
+use casper_execution_engine::shared::account::Account;
 use casper_types::Key;
 
 /// `DeployAcceptor` events.
@@ -19,18 +20,28 @@ pub enum Event {
         source: Source<NodeId>,
         responder: Option<Responder<Result<(), Error>>>,
     },
+    /// The result of validating the payment and session Wasm of a `Deploy` received from a client.
+    WasmValidationResult {
+        deploy: Box<Deploy>,
+        source: Source<NodeId>,
+        #[serde(skip_serializing)]
+        result: Result<(), DeployValidationFailure>,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+    },
     /// The result of the `DeployAcceptor` putting a `Deploy` to the storage component.
     PutToStorageResult {
         deploy: Box<Deploy>,
         source: Source<NodeId>,
         is_new: bool,
     },
-    /// The result of verifying `Account` exists and has meets minimum balance requirements.
+    /// The result of retrieving the `Account` which sent the `Deploy`, along with whether it meets
+    /// minimum balance requirements.
     AccountVerificationResult {
         deploy: Box<Deploy>,
         source: Source<NodeId>,
         account_key: Key,
-        verified: Option<bool>,
+        #[serde(skip_serializing)]
+        maybe_account: Option<(Box<Account>, bool)>,
         maybe_responder: Option<Responder<Result<(), Error>>>,
     },
 }
@@ -53,6 +64,10 @@ impl Display for Event {
             Event::Accept { deploy, source, .. } => {
                 write!(formatter, "accept {} from {}", deploy.id(), source)
             }
+            Event::WasmValidationResult { deploy, result, .. } => {
+                let prefix = if result.is_ok() { "" } else { "in" };
+                write!(formatter, "{}valid wasm in {}", prefix, deploy.id())
+            }
             Event::PutToStorageResult { deploy, is_new, .. } => {
                 if *is_new {
                     write!(formatter, "put new {} to storage", deploy.id())
@@ -63,16 +78,16 @@ impl Display for Event {
             Event::AccountVerificationResult {
                 deploy,
                 account_key,
-                verified,
+                maybe_account,
                 ..
             } => {
-                let prefix = if verified.unwrap_or(false) { "" } else { "in" };
+                let prefix = if maybe_account.is_some() { "" } else { "un" };
                 write!(
                     formatter,
-                    "{}valid deploy {} from account {}",
+                    "{}known account {} for deploy {}",
                     prefix,
-                    deploy.id(),
-                    account_key
+                    account_key,
+                    deploy.id()
                 )
             }
         }
@@ -15,13 +15,19 @@
 //!
 //! This component uses a ring buffer for outbound events providing some robustness against
 //! unintended subscriber disconnects, if a disconnected subscriber re-subscribes before the buffer
-//! has advanced past their last received event.
+//! has advanced past their last received event.  If an event index path is configured, the buffer
+//! is persisted on disk so that this also holds across node restarts.
+//!
+//! Clients can subscribe to all events via the root path, or to a subset of event types via the
+//! `main`, `deploys` and `signatures` sub-paths.  Subscriptions can be further filtered by event
+//! type, deploy account, contract hash and block proposer via the query string.
 //!
 //! For details about the SSE model and a list of supported SSEs, see:
 //! <https://github.com/CasperLabs/ceps/blob/master/text/0009-client-api.md#rpcs>
 
 mod config;
 mod event;
+mod event_index;
 mod http_server;
 mod sse_server;
 
@@ -29,12 +35,13 @@ use std::{convert::Infallible, fmt::Debug};
 
 use datasize::DataSize;
 use semver::Version;
+use thiserror::Error;
 use tokio::sync::mpsc::{self, UnboundedSender};
 
 use super::Component;
 use crate::{
     effect::{EffectBuilder, Effects},
-    utils::{self, ListeningError},
+    utils::{self, ListeningError, WithDir},
     NodeRng,
 };
 
@@ -42,6 +49,17 @@ pub use config::Config;
 pub(crate) use event::Event;
 pub use sse_server::SseData;
 
+/// An error starting the event stream server.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Failed to bind the HTTP server.
+    #[error(transparent)]
+    Listening(#[from] ListeningError),
+    /// Failed to open the on-disk event index.
+    #[error(transparent)]
+    EventIndex(#[from] event_index::Error),
+}
+
 /// A helper trait whose bounds represent the requirements for a reactor event that `run_server` can
 /// work with.
 pub trait ReactorEventT: From<Event> + Send {}
@@ -57,7 +75,16 @@ pub(crate) struct EventStreamServer {
 }
 
 impl EventStreamServer {
-    pub(crate) fn new(config: Config, api_version: Version) -> Result<Self, ListeningError> {
+    pub(crate) fn new(config: WithDir<Config>, api_version: Version) -> Result<Self, Error> {
+        let event_index = match &config.value().event_index_path {
+            Some(path) => Some(event_index::EventIndex::open(
+                config.with_dir(path.clone()),
+                config.value().event_stream_buffer_length,
+            )?),
+            None => None,
+        };
+        let (_, config) = config.into_parts();
+
         let (sse_data_sender, sse_data_receiver) = mpsc::unbounded_channel();
         let builder = utils::start_listening(&config.address)?;
         tokio::spawn(http_server::run(
@@ -65,6 +92,7 @@ impl EventStreamServer {
             api_version,
             builder,
             sse_data_receiver,
+            event_index,
         ));
 
         Ok(EventStreamServer { sse_data_sender })
@@ -100,15 +128,26 @@ where
                 deploy_header,
                 block_hash,
                 execution_result,
-            } => self.broadcast(SseData::DeployProcessed {
-                deploy_hash: Box::new(deploy_hash),
-                account: *deploy_header.account(),
-                timestamp: deploy_header.timestamp(),
-                ttl: deploy_header.ttl(),
-                dependencies: deploy_header.dependencies().clone(),
-                block_hash: Box::new(block_hash),
-                execution_result,
-            }),
+            } => {
+                let contract_events = execution_result.events().to_vec();
+                let mut effects = self.broadcast(SseData::DeployProcessed {
+                    deploy_hash: Box::new(deploy_hash),
+                    account: *deploy_header.account(),
+                    timestamp: deploy_header.timestamp(),
+                    ttl: deploy_header.ttl(),
+                    dependencies: deploy_header.dependencies().clone(),
+                    block_hash: Box::new(block_hash),
+                    execution_result,
+                });
+                for event in contract_events {
+                    effects.extend(self.broadcast(SseData::ContractEvent {
+                        deploy_hash: Box::new(deploy_hash),
+                        block_hash: Box::new(block_hash),
+                        event: Box::new(event),
+                    }));
+                }
+                effects
+            }
             Event::Fault {
                 era_id,
                 public_key,
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use datasize::DataSize;
 use serde::{Deserialize, Serialize};
 
@@ -26,6 +28,12 @@ pub struct Config {
     /// Number of SSEs to buffer.
     pub event_stream_buffer_length: u32,
 
+    /// The path to the folder where the most recent `event_stream_buffer_length` SSEs are
+    /// persisted, allowing clients to resume their subscriptions across node restarts.
+    ///
+    /// If unset, SSEs are only buffered in memory.
+    pub event_index_path: Option<PathBuf>,
+
     /// The number of events to buffer in the tokio broadcast channel to help slower clients to try
     /// to avoid missing events.  See <https://docs.rs/tokio/0.2.22/tokio/sync/broadcast/index.html#lagging>
     /// for further details.
@@ -41,6 +49,7 @@ impl Config {
         Config {
             address: DEFAULT_ADDRESS.to_string(),
             event_stream_buffer_length: DEFAULT_EVENT_STREAM_BUFFER_LENGTH,
+            event_index_path: None,
             broadcast_channel_size: DEFAULT_BROADCAST_CHANNEL_SIZE,
             qps_limit: DEFAULT_QPS_LIMIT,
         }
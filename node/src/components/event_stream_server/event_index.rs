@@ -0,0 +1,381 @@
+//! An on-disk index of the most recent SSEs, allowing clients to resume their subscriptions via
+//! `start_from` even after the node has been restarted.
+
+use std::{fs, io, path::PathBuf, sync::Arc};
+
+use lmdb::{
+    Cursor, Database, DatabaseFlags, Environment, EnvironmentFlags, Transaction, WriteFlags,
+};
+use thiserror::Error;
+use tokio::task;
+use tracing::{error, warn};
+
+use super::sse_server::{Id, ServerSentEvent};
+
+/// Filename for the LMDB database created by the event index.
+const EVENT_INDEX_DB_FILENAME: &str = "sse_index.lmdb";
+
+/// The maximum size of the event index database.
+///
+/// The size should be a multiple of the OS page size.
+const MAX_EVENT_INDEX_SIZE: usize = 4 * 1024 * 1024 * 1024;
+
+/// The event index is only ever accessed by the event stream server's task, one blocking operation
+/// at a time.
+const MAX_TRANSACTIONS: u32 = 1;
+
+/// OS-specific lmdb flags.
+#[cfg(not(target_os = "macos"))]
+const OS_FLAGS: EnvironmentFlags = EnvironmentFlags::WRITE_MAP;
+
+/// OS-specific lmdb flags.
+///
+/// Mac OS X exhibits performance regressions when `WRITE_MAP` is used.
+#[cfg(target_os = "macos")]
+const OS_FLAGS: EnvironmentFlags = EnvironmentFlags::empty();
+
+/// An error opening the event index.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Failed to create the directory holding the event index.
+    #[error("failed to create event index directory {}: {}", .0.display(), .1)]
+    CreateDirectory(PathBuf, io::Error),
+    /// Error from the underlying database.
+    #[error("event index database error: {0}")]
+    Lmdb(#[from] lmdb::Error),
+}
+
+/// The most recent SSEs, keyed by their big-endian IDs and persisted as JSON.
+///
+/// The indexed IDs are contiguous, but as IDs wrap around, the newest may be followed by the
+/// oldest at the end of the key range.
+///
+/// Reads and writes are synchronous LMDB transactions, so they are run on tokio's blocking pool
+/// rather than on the event stream server's task.
+#[derive(Clone, Debug)]
+pub(super) struct EventIndex {
+    env: Arc<Environment>,
+    db: Database,
+    /// The number of events retained.
+    retained_events: u32,
+    /// The ID to be assigned to the first event after opening the index.
+    next_id: Id,
+}
+
+impl EventIndex {
+    /// Opens (or creates) the event index in the given directory, retaining at most
+    /// `retained_events` events.
+    pub(super) fn open(root: PathBuf, retained_events: u32) -> Result<Self, Error> {
+        if !root.exists() {
+            fs::create_dir_all(&root).map_err(|err| Error::CreateDirectory(root.clone(), err))?;
+        }
+
+        let env = Environment::new()
+            .set_flags(OS_FLAGS | EnvironmentFlags::NO_SUB_DIR | EnvironmentFlags::NO_TLS)
+            .set_max_readers(MAX_TRANSACTIONS)
+            .set_map_size(MAX_EVENT_INDEX_SIZE)
+            .open(&root.join(EVENT_INDEX_DB_FILENAME))?;
+        let db = env.create_db(None, DatabaseFlags::empty())?;
+
+        let next_id = {
+            let txn = env.begin_ro_txn()?;
+            let mut cursor = txn.open_ro_cursor(db)?;
+            let ids: Vec<Id> = cursor
+                .iter_start()
+                .map(|(raw_key, _)| decode_id(raw_key))
+                .collect();
+            // If the IDs wrapped around, the newest is the last before the gap preceding the
+            // oldest.
+            let newest_id = if is_wrapped(&txn, db)? {
+                ids.windows(2)
+                    .find(|pair| pair[1] != pair[0].wrapping_add(1))
+                    .map(|pair| pair[0])
+            } else {
+                ids.last().copied()
+            };
+            newest_id.map_or(0, |id| id.wrapping_add(1))
+        };
+
+        Ok(EventIndex {
+            env: Arc::new(env),
+            db,
+            retained_events,
+            next_id,
+        })
+    }
+
+    /// Returns the ID which should be assigned to the next event.
+    pub(super) fn next_id(&self) -> Id {
+        self.next_id
+    }
+
+    /// Persists the given event, evicting all events other than the configured number of most
+    /// recent ones.
+    ///
+    /// Failures are logged rather than returned, since an event which failed to be indexed has
+    /// still been broadcast to all current subscribers.
+    pub(super) async fn put(&self, event: &ServerSentEvent) {
+        let id = match event.id {
+            Some(id) => id,
+            None => return,
+        };
+        let value = match serde_json::to_vec(&event.data) {
+            Ok(value) => value,
+            Err(error) => {
+                error!(%error, id, "failed to serialize event for event index");
+                return;
+            }
+        };
+        let event_index = self.clone();
+        match task::spawn_blocking(move || event_index.write(id, &value)).await {
+            Ok(Ok(())) => (),
+            Ok(Err(error)) => error!(%error, id, "failed to write event to event index"),
+            Err(error) => error!(%error, id, "event index write task failed"),
+        }
+    }
+
+    fn write(&self, id: Id, value: &[u8]) -> Result<(), lmdb::Error> {
+        let mut txn = self.env.begin_rw_txn()?;
+        txn.put(self.db, &id.to_be_bytes(), &value, WriteFlags::empty())?;
+        for evicted_id in self.evicted_ids(&txn, id)? {
+            txn.del(self.db, &evicted_id.to_be_bytes(), None)?;
+        }
+        txn.commit()
+    }
+
+    /// Returns the IDs of all indexed events outside the window of the `retained_events` IDs
+    /// ending at `newest_id`, which must be indexed.
+    ///
+    /// Normally only the single event preceding the window is evicted, but after the number of
+    /// retained events is lowered, any number of older events may remain from before.
+    fn evicted_ids<T: Transaction>(&self, txn: &T, newest_id: Id) -> Result<Vec<Id>, lmdb::Error> {
+        let is_retained = |id: Id| newest_id.wrapping_sub(id) < self.retained_events;
+        let mut cursor = txn.open_ro_cursor(self.db)?;
+        // Those before the window, unless it wraps around to include the lowest IDs.
+        let mut evicted_ids: Vec<Id> = cursor
+            .iter_start()
+            .map(|(raw_key, _)| decode_id(raw_key))
+            .take_while(|id| *id < newest_id && !is_retained(*id))
+            .collect();
+        if !is_retained(newest_id) {
+            evicted_ids.push(newest_id);
+        }
+        // Those after the newest, which are left over from before the IDs wrapped around, up to
+        // the start of the window if it wraps around.
+        evicted_ids.extend(
+            cursor
+                .iter_from(newest_id.to_be_bytes())
+                .skip(1)
+                .map(|(raw_key, _)| decode_id(raw_key))
+                .take_while(|id| !is_retained(*id)),
+        );
+        Ok(evicted_ids)
+    }
+
+    /// Returns all indexed events from the one with ID `start_from` onwards, in the order they were
+    /// indexed.
+    pub(super) async fn events_from(&self, start_from: Id) -> Vec<ServerSentEvent> {
+        let event_index = self.clone();
+        match task::spawn_blocking(move || event_index.read_from(start_from)).await {
+            Ok(Ok(events)) => events,
+            Ok(Err(error)) => {
+                error!(%error, "failed to read from event index");
+                Vec::new()
+            }
+            Err(error) => {
+                error!(%error, "event index read task failed");
+                Vec::new()
+            }
+        }
+    }
+
+    fn read_from(&self, start_from: Id) -> Result<Vec<ServerSentEvent>, lmdb::Error> {
+        let txn = self.env.begin_ro_txn()?;
+        if is_wrapped(&txn, self.db)? {
+            return self.read_wrapped_from(&txn, start_from);
+        }
+        let mut cursor = txn.open_ro_cursor(self.db)?;
+        let start_key = start_from.to_be_bytes();
+        // `iter_from` panics if there is no key at or after the given one.  The indexed IDs are
+        // contiguous, so if `start_from` isn't indexed it is either older than every retained
+        // event, in which case all are returned, or newer than every one, in which case none are.
+        let iter = match txn.get(self.db, &start_key) {
+            Ok(_) => cursor.iter_from(start_key),
+            Err(lmdb::Error::NotFound) => {
+                let oldest_id = cursor.iter().next().map(|(raw_key, _)| decode_id(raw_key));
+                match oldest_id {
+                    Some(oldest_id) if start_from < oldest_id => cursor.iter_start(),
+                    _ => return Ok(Vec::new()),
+                }
+            }
+            Err(error) => return Err(error),
+        };
+        Ok(iter.filter_map(decode_event).collect())
+    }
+
+    /// Returns the events from `start_from` in the order they were indexed, given that the IDs
+    /// wrapped around.
+    ///
+    /// This only happens for the `retained_events` events following the wraparound, so all are
+    /// read and then reordered, rather than seeking to `start_from`.
+    fn read_wrapped_from<T: Transaction>(
+        &self,
+        txn: &T,
+        start_from: Id,
+    ) -> Result<Vec<ServerSentEvent>, lmdb::Error> {
+        let mut cursor = txn.open_ro_cursor(self.db)?;
+        let mut entries: Vec<(&[u8], &[u8])> = cursor.iter_start().collect();
+        // Move the newer events, from zero up to the gap before the oldest, to the end.
+        let newer_count = entries
+            .windows(2)
+            .position(|pair| decode_id(pair[1].0) != decode_id(pair[0].0).wrapping_add(1))
+            .map_or(entries.len(), |position| position + 1);
+        entries.rotate_left(newer_count);
+
+        // If `start_from` isn't indexed, it is treated as newer than every retained event if it
+        // is at most half the ID range after the newest, and as older than every one otherwise.
+        let start = match entries
+            .iter()
+            .position(|(raw_key, _)| decode_id(raw_key) == start_from)
+        {
+            Some(position) => position,
+            None => {
+                let newest_id = entries.last().map_or(0, |(raw_key, _)| decode_id(raw_key));
+                if start_from.wrapping_sub(newest_id) <= Id::MAX / 2 {
+                    entries.len()
+                } else {
+                    0
+                }
+            }
+        };
+        Ok(entries.drain(start..).filter_map(decode_event).collect())
+    }
+}
+
+/// Returns whether the indexed IDs wrapped around, i.e. both the lowest and highest IDs are
+/// indexed.
+fn is_wrapped<T: Transaction>(txn: &T, db: Database) -> Result<bool, lmdb::Error> {
+    let is_indexed = |id: Id| match txn.get(db, &id.to_be_bytes()) {
+        Ok(_) => Ok(true),
+        Err(lmdb::Error::NotFound) => Ok(false),
+        Err(error) => Err(error),
+    };
+    Ok(is_indexed(0)? && is_indexed(Id::MAX)?)
+}
+
+/// Decodes an indexed event, logging and skipping it if unreadable.
+fn decode_event((raw_key, raw_value): (&[u8], &[u8])) -> Option<ServerSentEvent> {
+    let id = decode_id(raw_key);
+    match serde_json::from_slice(raw_value) {
+        Ok(data) => Some(ServerSentEvent { id: Some(id), data }),
+        Err(error) => {
+            warn!(%error, id, "skipping unreadable event in event index");
+            None
+        }
+    }
+}
+
+/// Decodes a big-endian event ID from a database key.
+fn decode_id(raw_key: &[u8]) -> Id {
+    let mut id_bytes = [0; 4];
+    id_bytes.copy_from_slice(raw_key);
+    Id::from_be_bytes(id_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use semver::Version;
+
+    use casper_types::{PublicKey, SecretKey};
+
+    use super::*;
+    use crate::{
+        components::{consensus::EraId, event_stream_server::SseData},
+        crypto::AsymmetricKeyExt,
+        testing::TestRng,
+        types::Timestamp,
+    };
+
+    fn random_event(rng: &mut TestRng, id: Id) -> ServerSentEvent {
+        ServerSentEvent {
+            id: Some(id),
+            data: SseData::Fault {
+                era_id: EraId(id as u64),
+                public_key: PublicKey::from(&SecretKey::random(rng)),
+                timestamp: Timestamp::now(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn should_retain_events_across_reopening() {
+        let mut rng = TestRng::new();
+        let tempdir = tempfile::tempdir().unwrap();
+        let root = tempdir.path().join("sse");
+
+        let events: Vec<_> = (0..5).map(|id| random_event(&mut rng, id)).collect();
+        {
+            let event_index = EventIndex::open(root.clone(), 3).unwrap();
+            assert_eq!(event_index.next_id(), 0);
+            for event in &events {
+                event_index.put(event).await;
+            }
+            // The API version event has no ID and isn't indexed.
+            event_index
+                .put(&ServerSentEvent::initial_event(Version::new(1, 0, 0)))
+                .await;
+        }
+
+        let event_index = EventIndex::open(root, 3).unwrap();
+        assert_eq!(event_index.next_id(), 5);
+        assert_eq!(event_index.events_from(0).await, events[2..].to_vec());
+        assert_eq!(event_index.events_from(3).await, events[3..].to_vec());
+        assert!(event_index.events_from(5).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_evict_events_outside_lowered_window() {
+        let mut rng = TestRng::new();
+        let tempdir = tempfile::tempdir().unwrap();
+        let root = tempdir.path().join("sse");
+
+        let events: Vec<_> = (0..6).map(|id| random_event(&mut rng, id)).collect();
+        {
+            let event_index = EventIndex::open(root.clone(), 5).unwrap();
+            for event in &events[..5] {
+                event_index.put(event).await;
+            }
+            assert_eq!(event_index.events_from(0).await, events[..5].to_vec());
+        }
+
+        let event_index = EventIndex::open(root, 2).unwrap();
+        event_index.put(&events[5]).await;
+        assert_eq!(event_index.events_from(0).await, events[4..].to_vec());
+    }
+
+    #[tokio::test]
+    async fn should_retain_events_across_id_wraparound() {
+        let mut rng = TestRng::new();
+        let tempdir = tempfile::tempdir().unwrap();
+        let root = tempdir.path().join("sse");
+
+        let ids = [Id::MAX - 2, Id::MAX - 1, Id::MAX, 0, 1];
+        let events: Vec<_> = ids.iter().map(|id| random_event(&mut rng, *id)).collect();
+        {
+            let event_index = EventIndex::open(root.clone(), 3).unwrap();
+            for event in &events {
+                event_index.put(event).await;
+            }
+        }
+
+        let event_index = EventIndex::open(root, 3).unwrap();
+        assert_eq!(event_index.next_id(), 2);
+        assert_eq!(
+            event_index.events_from(Id::MAX - 2).await,
+            events[2..].to_vec()
+        );
+        assert_eq!(event_index.events_from(Id::MAX).await, events[2..].to_vec());
+        assert_eq!(event_index.events_from(0).await, events[3..].to_vec());
+        assert!(event_index.events_from(2).await.is_empty());
+    }
+}
@@ -15,6 +15,7 @@ use tracing::{info, trace};
 use wheelbuf::WheelBuf;
 
 use super::{
+    event_index::EventIndex,
     sse_server::{self, BroadcastChannelMessage, ServerSentEvent},
     Config, SseData,
 };
@@ -23,11 +24,15 @@ use super::{
 ///
 /// `data_receiver` will provide the server with local events which should then be sent to all
 /// subscribed clients.
+///
+/// If `event_index` is provided, events are persisted to it and replayed from it for clients
+/// specifying `start_from`, otherwise only the in-memory buffer is used.
 pub(super) async fn run(
     config: Config,
     api_version: Version,
     builder: Builder<AddrIncoming>,
     mut data_receiver: mpsc::UnboundedReceiver<SseData>,
+    event_index: Option<EventIndex>,
 ) {
     // Event stream channels and filter.
     let (broadcaster, mut new_subscriber_info_receiver, sse_filter) =
@@ -54,8 +59,9 @@ pub(super) async fn run(
 
     let server_joiner = tokio::spawn(server_with_shutdown);
 
-    // Initialize the index and buffer for the SSEs.
-    let mut event_index = 0_u32;
+    // Initialize the next event ID and buffer for the SSEs.  If there is an on-disk index, continue
+    // numbering from the last event indexed before the node was restarted.
+    let mut next_event_id = event_index.as_ref().map_or(0, EventIndex::next_id);
     let mut buffer = WheelBuf::new(vec![
         ServerSentEvent::initial_event(api_version.clone());
         config.event_stream_buffer_length as usize
@@ -76,12 +82,24 @@ pub(super) async fn run(
                         // If the client supplied a "start_from" index, provide the buffered events.
                         // If they requested more than is buffered, just provide the whole buffer.
                         if let Some(start_index) = subscriber.start_from {
-                            for event in buffer
-                                .iter()
-                                .skip_while(|event| event.id.unwrap() < start_index)
-                            {
-                                // As per sending `SSE_INITIAL_EVENT`, we don't care if this errors.
-                                let _ = subscriber.initial_events_sender.send(event.clone());
+                            match event_index.as_ref() {
+                                Some(event_index) => {
+                                    for event in event_index.events_from(start_index).await {
+                                        // As per sending `SSE_INITIAL_EVENT`, we don't care if this
+                                        // errors.
+                                        let _ = subscriber.initial_events_sender.send(event);
+                                    }
+                                }
+                                None => {
+                                    for event in buffer
+                                        .iter()
+                                        .skip_while(|event| event.id.unwrap() < start_index)
+                                    {
+                                        // As per sending `SSE_INITIAL_EVENT`, we don't care if this
+                                        // errors.
+                                        let _ = subscriber.initial_events_sender.send(event.clone());
+                                    }
+                                }
                             }
                         }
                     }
@@ -92,13 +110,16 @@ pub(super) async fn run(
                         Some(data) => {
                             // Buffer the data and broadcast it to subscribed clients.
                             trace!("Event stream server received {:?}", data);
-                            let event = ServerSentEvent { id: Some(event_index), data };
-                            buffer.push(event.clone());
+                            let event = ServerSentEvent { id: Some(next_event_id), data };
+                            match event_index.as_ref() {
+                                Some(event_index) => event_index.put(&event).await,
+                                None => buffer.push(event.clone()),
+                            }
                             let message = BroadcastChannelMessage::ServerSentEvent(event);
                             // This can validly fail if there are no connected clients, so don't log
                             // the error.
                             let _ = broadcaster.send(message);
-                            event_index = event_index.wrapping_add(1);
+                            next_event_id = next_event_id.wrapping_add(1);
                         }
                         None => {
                             // The data sender has been dropped - exit the loop.
@@ -1,7 +1,9 @@
 //! Types and functions used by the http server to manage the event-stream.
 
+use std::str::FromStr;
+
 use datasize::DataSize;
-use futures::{Stream, StreamExt};
+use futures::{future, Stream, StreamExt};
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use tokio::sync::{
@@ -11,22 +13,50 @@ use tokio::sync::{
 use tracing::{error, info, trace};
 use warp::{
     filters::BoxedFilter,
+    http::StatusCode,
+    reply::Response,
     sse::{self, ServerSentEvent as WarpServerSentEvent},
     Filter, Reply,
 };
 
-use casper_types::{ExecutionResult, PublicKey};
+use casper_types::{AsymmetricType, ContractEvent, ContractHash, ExecutionResult, PublicKey};
 
 use crate::{
     components::consensus::EraId,
     types::{Block, BlockHash, DeployHash, FinalitySignature, TimeDiff, Timestamp},
 };
 
-/// The URL path.
+/// The URL root path.  Subscribing to this path streams all event types.
 pub const SSE_API_PATH: &str = "events";
+/// The URL sub-path for subscribing to all event types other than finality signatures.
+pub const SSE_API_MAIN_PATH: &str = "main";
+/// The URL sub-path for subscribing to deploy-related events only.
+pub const SSE_API_DEPLOYS_PATH: &str = "deploys";
+/// The URL sub-path for subscribing to finality signatures only.
+pub const SSE_API_SIGNATURES_PATH: &str = "signatures";
+
+/// The event types streamed via the `main` sub-path.
+const MAIN_EVENT_TYPES: &[EventType] = &[
+    EventType::BlockAdded,
+    EventType::DeployProcessed,
+    EventType::Fault,
+    EventType::ContractEvent,
+];
+/// The event types streamed via the `deploys` sub-path.
+const DEPLOYS_EVENT_TYPES: &[EventType] = &[EventType::DeployProcessed, EventType::ContractEvent];
+/// The event types streamed via the `signatures` sub-path.
+const SIGNATURES_EVENT_TYPES: &[EventType] = &[EventType::FinalitySignature];
+/// All event types which can be subscribed to.
+const ALL_EVENT_TYPES: &[EventType] = &[
+    EventType::BlockAdded,
+    EventType::DeployProcessed,
+    EventType::Fault,
+    EventType::FinalitySignature,
+    EventType::ContractEvent,
+];
 
 /// The "id" field of the events sent on the event stream to clients.
-type Id = u32;
+pub(super) type Id = u32;
 
 /// The "data" field of the events sent on the event stream to clients.
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, DataSize)]
@@ -59,6 +89,136 @@ pub enum SseData {
     },
     /// New finality signature received.
     FinalitySignature(Box<FinalitySignature>),
+    /// An event emitted by a contract while executing the given deploy, which forms part of the
+    /// given block.
+    ContractEvent {
+        deploy_hash: Box<DeployHash>,
+        block_hash: Box<BlockHash>,
+        #[data_size(skip)]
+        event: Box<ContractEvent>,
+    },
+}
+
+impl SseData {
+    /// Returns the type of this event, or `None` for `ApiVersion` which is not subject to
+    /// filtering.
+    fn event_type(&self) -> Option<EventType> {
+        match self {
+            SseData::ApiVersion(_) => None,
+            SseData::BlockAdded { .. } => Some(EventType::BlockAdded),
+            SseData::DeployProcessed { .. } => Some(EventType::DeployProcessed),
+            SseData::Fault { .. } => Some(EventType::Fault),
+            SseData::FinalitySignature(_) => Some(EventType::FinalitySignature),
+            SseData::ContractEvent { .. } => Some(EventType::ContractEvent),
+        }
+    }
+}
+
+/// The types of event which a client can filter its subscription by.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(super) enum EventType {
+    BlockAdded,
+    DeployProcessed,
+    Fault,
+    FinalitySignature,
+    ContractEvent,
+}
+
+impl FromStr for EventType {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        ALL_EVENT_TYPES
+            .iter()
+            .find(|event_type| format!("{:?}", event_type) == input)
+            .copied()
+            .ok_or_else(|| format!("unknown event type '{}'", input))
+    }
+}
+
+/// The restrictions a client has placed on the events streamed to it.
+///
+/// Each criterion only applies to the event types which carry the relevant attribute; e.g. the
+/// `proposer` criterion filters `BlockAdded` events but has no effect on `Fault` events.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(super) struct SubscriptionFilter {
+    /// The event types to stream.
+    event_types: Vec<EventType>,
+    /// Only stream `DeployProcessed` events for deploys sent by this account.
+    account: Option<PublicKey>,
+    /// Only stream `ContractEvent`s emitted by this contract.
+    contract_hash: Option<ContractHash>,
+    /// Only stream `BlockAdded` events for blocks proposed by this validator.
+    proposer: Option<PublicKey>,
+}
+
+impl SubscriptionFilter {
+    /// Constructs a filter from the event types implied by the URL path and the query string.
+    fn new(path_event_types: &[EventType], query: &Query) -> Result<Self, String> {
+        let event_types = match &query.event_type {
+            Some(names) => {
+                let requested = names
+                    .split(',')
+                    .map(EventType::from_str)
+                    .collect::<Result<Vec<_>, _>>()?;
+                path_event_types
+                    .iter()
+                    .filter(|event_type| requested.contains(event_type))
+                    .copied()
+                    .collect()
+            }
+            None => path_event_types.to_vec(),
+        };
+        let parse_public_key = |field: &str, maybe_hex: &Option<String>| {
+            maybe_hex
+                .as_ref()
+                .map(|hex| {
+                    PublicKey::from_hex(hex)
+                        .map_err(|error| format!("invalid {}: {}", field, error))
+                })
+                .transpose()
+        };
+        let contract_hash = query
+            .contract_hash
+            .as_deref()
+            .map(ContractHash::from_formatted_str)
+            .transpose()
+            .map_err(|error| format!("invalid contract_hash: {}", error))?;
+
+        Ok(SubscriptionFilter {
+            event_types,
+            account: parse_public_key("account", &query.account)?,
+            contract_hash,
+            proposer: parse_public_key("proposer", &query.proposer)?,
+        })
+    }
+
+    /// Returns `true` if the given event should be streamed to the client.
+    fn matches(&self, data: &SseData) -> bool {
+        let event_type = match data.event_type() {
+            Some(event_type) => event_type,
+            None => return true,
+        };
+        if !self.event_types.contains(&event_type) {
+            return false;
+        }
+        match data {
+            SseData::BlockAdded { block, .. } => self
+                .proposer
+                .as_ref()
+                .map_or(true, |proposer| block.body().proposer() == proposer),
+            SseData::DeployProcessed { account, .. } => self
+                .account
+                .as_ref()
+                .map_or(true, |filter_account| account == filter_account),
+            SseData::ContractEvent { event, .. } => {
+                self.contract_hash.map_or(true, |contract_hash| {
+                    event.contract_hash == Some(contract_hash)
+                })
+            }
+            SseData::ApiVersion(_) | SseData::Fault { .. } | SseData::FinalitySignature(_) => true,
+        }
+    }
 }
 
 /// The components of a single SSE.
@@ -101,10 +261,21 @@ pub(super) struct NewSubscriberInfo {
     pub(super) initial_events_sender: mpsc::UnboundedSender<ServerSentEvent>,
 }
 
-/// The endpoint's query string, e.g. `http://localhost:22777/events?start_from=999`
+/// The endpoint's query string, e.g.
+/// `http://localhost:22777/events/main?start_from=999&event_type=BlockAdded,Fault`
+///
+/// * `event_type` - comma-separated names of the event types to stream
+/// * `account` - hex-encoded public key; only stream deploys sent by this account
+/// * `contract_hash` - formatted contract hash, e.g. "contract-0102..."; only stream contract
+///   events emitted by this contract
+/// * `proposer` - hex-encoded public key; only stream blocks proposed by this validator
 #[derive(Deserialize, Debug)]
 struct Query {
     start_from: Option<Id>,
+    event_type: Option<String>,
+    account: Option<String>,
+    contract_hash: Option<String>,
+    proposer: Option<String>,
 }
 
 /// Creates the message-passing channels required to run the event-stream server and the warp filter
@@ -124,33 +295,64 @@ pub(super) fn create_channels_and_filter(
     // client subscription.
     let (new_subscriber_info_sender, new_subscriber_info_receiver) = mpsc::unbounded_channel();
 
+    let sub_path = |name: &'static str, event_types: &'static [EventType]| {
+        warp::path(name)
+            .and(warp::path::end())
+            .map(move || event_types)
+    };
+    let path_event_types = warp::path::end()
+        .map(|| ALL_EVENT_TYPES)
+        .or(sub_path(SSE_API_MAIN_PATH, MAIN_EVENT_TYPES))
+        .unify()
+        .or(sub_path(SSE_API_DEPLOYS_PATH, DEPLOYS_EVENT_TYPES))
+        .unify()
+        .or(sub_path(SSE_API_SIGNATURES_PATH, SIGNATURES_EVENT_TYPES))
+        .unify();
+
     let filter = warp::get()
         .and(warp::path(SSE_API_PATH))
-        .and(warp::query().map(move |query: Query| {
-            // Create a channel for the client's handler to receive the stream of initial events.
-            let (initial_events_sender, initial_events_receiver) = mpsc::unbounded_channel();
-
-            // Supply the server with the sender part of the channel along with the client's
-            // requested starting point.
-            let new_subscriber_info = NewSubscriberInfo {
-                start_from: query.start_from,
-                initial_events_sender,
-            };
-            if new_subscriber_info_sender
-                .send(new_subscriber_info)
-                .is_err()
-            {
-                error!("failed to send new subscriber info");
-            }
+        .and(path_event_types)
+        .and(warp::query())
+        .map(
+            move |path_event_types: &'static [EventType], query: Query| -> Response {
+                let subscription_filter = match SubscriptionFilter::new(path_event_types, &query) {
+                    Ok(subscription_filter) => subscription_filter,
+                    Err(error) => {
+                        info!(%error, "invalid event stream query");
+                        return warp::reply::with_status(error, StatusCode::BAD_REQUEST)
+                            .into_response();
+                    }
+                };
 
-            // Create a channel for the client's handler to receive the stream of ongoing events.
-            let ongoing_events_receiver = cloned_broadcaster.subscribe();
+                // Create a channel for the client's handler to receive the stream of initial
+                // events.
+                let (initial_events_sender, initial_events_receiver) = mpsc::unbounded_channel();
+
+                // Supply the server with the sender part of the channel along with the client's
+                // requested starting point.
+                let new_subscriber_info = NewSubscriberInfo {
+                    start_from: query.start_from,
+                    initial_events_sender,
+                };
+                if new_subscriber_info_sender
+                    .send(new_subscriber_info)
+                    .is_err()
+                {
+                    error!("failed to send new subscriber info");
+                }
 
-            sse::reply(sse::keep_alive().stream(stream_to_client(
-                initial_events_receiver,
-                ongoing_events_receiver,
-            )))
-        }))
+                // Create a channel for the client's handler to receive the stream of ongoing
+                // events.
+                let ongoing_events_receiver = cloned_broadcaster.subscribe();
+
+                sse::reply(sse::keep_alive().stream(stream_to_client(
+                    initial_events_receiver,
+                    ongoing_events_receiver,
+                    subscription_filter,
+                )))
+                .into_response()
+            },
+        )
         .boxed();
 
     (broadcaster, new_subscriber_info_receiver, filter)
@@ -167,13 +369,25 @@ pub(super) fn create_channels_and_filter(
 /// either the client disconnects, or the server shuts down (indicated by sending a `Shutdown`
 /// variant via the channel).  This channel will receive all SSEs created from the moment the client
 /// subscribed to the server's event stream.
+///
+/// Events not matching `subscription_filter` are dropped from both the initial and ongoing events.
 fn stream_to_client(
     initial_events: mpsc::UnboundedReceiver<ServerSentEvent>,
     ongoing_events: broadcast::Receiver<BroadcastChannelMessage>,
+    subscription_filter: SubscriptionFilter,
 ) -> impl Stream<Item = Result<impl WarpServerSentEvent, RecvError>> + 'static {
     initial_events
         .map(|event| Ok(BroadcastChannelMessage::ServerSentEvent(event)))
         .chain(ongoing_events)
+        .filter(move |result| {
+            let keep = match result {
+                Ok(BroadcastChannelMessage::ServerSentEvent(event)) => {
+                    subscription_filter.matches(&event.data)
+                }
+                _ => true,
+            };
+            future::ready(keep)
+        })
         .map(|result| {
             trace!(?result);
             match result {
@@ -183,7 +397,8 @@ fn stream_to_client(
                         (Some(id), &SseData::BlockAdded { .. })
                         | (Some(id), &SseData::DeployProcessed { .. })
                         | (Some(id), &SseData::FinalitySignature(_))
-                        | (Some(id), &SseData::Fault { .. }) => {
+                        | (Some(id), &SseData::Fault { .. })
+                        | (Some(id), &SseData::ContractEvent { .. }) => {
                             Ok((sse::id(id), sse::json(event.data)).boxed())
                         }
                         _ => unreachable!("only ApiVersion may have no event ID"),
@@ -202,3 +417,68 @@ fn stream_to_client(
             }
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use casper_types::CLValue;
+
+    use super::*;
+
+    fn query(event_type: Option<&str>, contract_hash: Option<&str>) -> Query {
+        Query {
+            start_from: None,
+            event_type: event_type.map(str::to_string),
+            account: None,
+            contract_hash: contract_hash.map(str::to_string),
+            proposer: None,
+        }
+    }
+
+    fn contract_event(contract_hash: Option<ContractHash>) -> SseData {
+        SseData::ContractEvent {
+            deploy_hash: Box::new(DeployHash::default()),
+            block_hash: Box::new(BlockHash::default()),
+            event: Box::new(ContractEvent {
+                contract_hash,
+                name: "token_minted".to_string(),
+                payload: CLValue::from_t(1_u64).unwrap(),
+            }),
+        }
+    }
+
+    #[test]
+    fn should_restrict_event_types_to_sub_path() {
+        let filter = SubscriptionFilter::new(
+            MAIN_EVENT_TYPES,
+            &query(Some("Fault,FinalitySignature"), None),
+        )
+        .unwrap();
+        assert_eq!(filter.event_types, vec![EventType::Fault]);
+
+        let filter = SubscriptionFilter::new(SIGNATURES_EVENT_TYPES, &query(None, None)).unwrap();
+        assert!(!filter.matches(&contract_event(None)));
+        // The API version is always sent.
+        assert!(filter.matches(&SseData::ApiVersion(Version::new(1, 0, 0))));
+    }
+
+    #[test]
+    fn should_reject_invalid_query() {
+        assert!(SubscriptionFilter::new(ALL_EVENT_TYPES, &query(Some("Nonsense"), None)).is_err());
+        assert!(
+            SubscriptionFilter::new(ALL_EVENT_TYPES, &query(None, Some("not-a-hash"))).is_err()
+        );
+    }
+
+    #[test]
+    fn should_filter_contract_events_by_contract_hash() {
+        let contract_hash = ContractHash::new([1; 32]);
+        let filter = SubscriptionFilter::new(
+            DEPLOYS_EVENT_TYPES,
+            &query(None, Some(&contract_hash.to_formatted_string())),
+        )
+        .unwrap();
+        assert!(filter.matches(&contract_event(Some(contract_hash))));
+        assert!(!filter.matches(&contract_event(Some(ContractHash::new([2; 32])))));
+        assert!(!filter.matches(&contract_event(None)));
+    }
+}
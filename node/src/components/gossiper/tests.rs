@@ -166,7 +166,8 @@ impl reactor::Reactor for Reactor {
 
         let contract_runtime_config = contract_runtime::Config::default();
         let contract_runtime =
-            ContractRuntime::new(storage_withdir, &contract_runtime_config, &registry).unwrap();
+            ContractRuntime::new(storage_withdir, &contract_runtime_config, None, &registry)
+                .unwrap();
 
         let deploy_acceptor = DeployAcceptor::new(
             deploy_acceptor::Config::new(false),
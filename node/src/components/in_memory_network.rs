@@ -278,19 +278,32 @@
 //!
 //! # }); // end of tokio::block_on
 //! ```
+//!
+//! # Fault injection
+//!
+//! Delivery can be degraded through the `NetworkFaults` of the active network, see
+//! `with_active_faults`. Links can be given delays, loss, reordering and duplication, and sets of
+//! nodes can be partitioned from each other. `testing::network::Network` provides `partition` and
+//! `heal` helpers on top of this.
+
+mod faults;
 
 use std::{
     any::Any,
     cell::RefCell,
-    collections::{HashMap, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
     convert::Infallible,
     fmt::{self, Display, Formatter},
     sync::{Arc, RwLock},
+    time::Duration,
 };
 
 use rand::seq::IteratorRandom;
 use serde::Serialize;
-use tokio::sync::mpsc::{self, error::SendError};
+use tokio::{
+    sync::mpsc::{self, error::SendError},
+    time,
+};
 use tracing::{debug, error, info, warn};
 
 use crate::{
@@ -305,6 +318,7 @@ use crate::{
     types::NodeId,
     NodeRng,
 };
+pub use faults::{DelayDistribution, LinkFaults, NetworkFaults};
 
 /// A network.
 type Network<P> = Arc<RwLock<HashMap<NodeId, mpsc::UnboundedSender<(NodeId, P)>>>>;
@@ -331,6 +345,32 @@ thread_local! {
     ///
     /// The type is dynamic, every network can be of a distinct type when the payload `P` differs.
     static ACTIVE_NETWORK: RefCell<Option<Box<dyn Any>>> = RefCell::new(None);
+
+    /// The faults of the currently active network.
+    ///
+    /// Kept separately from `ACTIVE_NETWORK` so that they can be accessed without knowing the
+    /// payload type of the network.
+    static ACTIVE_FAULTS: RefCell<Option<Arc<RwLock<NetworkFaults>>>> = RefCell::new(None);
+}
+
+/// Runs `f` on the faults of the active network, returning its result.
+///
+/// # Panics
+///
+/// Panics if there is no active network or the internal lock has been poisoned.
+pub fn with_active_faults<F, T>(f: F) -> T
+where
+    F: FnOnce(&mut NetworkFaults) -> T,
+{
+    ACTIVE_FAULTS.with(|active_faults| {
+        let active_faults = active_faults.borrow();
+        let mut faults = active_faults
+            .as_ref()
+            .expect("tried to access faults without active network set")
+            .write()
+            .expect("poisoned lock");
+        f(&mut faults)
+    })
 }
 
 /// The network controller is used to control the network topology (e.g. adding and removing nodes).
@@ -338,6 +378,8 @@ thread_local! {
 pub struct NetworkController<P> {
     /// Channels for network communication.
     nodes: Network<P>,
+    /// Faults injected into message delivery, shared with every node.
+    faults: Arc<RwLock<NetworkFaults>>,
 }
 
 impl<P> NetworkController<P>
@@ -349,6 +391,7 @@ where
         let _ = logging::init();
         NetworkController {
             nodes: Default::default(),
+            faults: Default::default(),
         }
     }
 
@@ -359,8 +402,11 @@ where
     /// Panics if the internal lock has been poisoned.
     pub fn create_active() {
         let _ = logging::init();
+        let network = Self::new();
+        let faults = network.faults.clone();
         ACTIVE_NETWORK
-            .with(|active_network| active_network.borrow_mut().replace(Box::new(Self::new())));
+            .with(|active_network| active_network.borrow_mut().replace(Box::new(network)));
+        ACTIVE_FAULTS.with(|active_faults| active_faults.borrow_mut().replace(faults));
     }
 
     /// Removes the active network.
@@ -370,6 +416,7 @@ where
     /// Panics if the internal lock has been poisoned, a network with the wrong type of message was
     /// removed or if there was no network at at all.
     pub fn remove_active() {
+        ACTIVE_FAULTS.with(|active_faults| active_faults.borrow_mut().take());
         assert!(
             ACTIVE_NETWORK.with(|active_network| {
                 active_network
@@ -438,7 +485,12 @@ where
     where
         REv: From<NetworkAnnouncement<NodeId, P>> + Send,
     {
-        InMemoryNetwork::new_with_data(event_queue, NodeId::random(rng), self.nodes.clone())
+        InMemoryNetwork::new_with_data(
+            event_queue,
+            NodeId::random(rng),
+            self.nodes.clone(),
+            self.faults.clone(),
+        )
     }
 }
 
@@ -450,6 +502,9 @@ pub struct InMemoryNetwork<P> {
 
     /// The nodes map, contains the incoming channel for each virtual node.
     nodes: Network<P>,
+
+    /// Faults injected into message delivery.
+    faults: Arc<RwLock<NetworkFaults>>,
 }
 
 impl<P> InMemoryNetwork<P>
@@ -471,6 +526,7 @@ where
         event_queue: EventQueueHandle<REv>,
         node_id: NodeId,
         nodes: Network<P>,
+        faults: Arc<RwLock<NetworkFaults>>,
     ) -> Self
     where
         REv: From<NetworkAnnouncement<NodeId, P>> + Send,
@@ -486,7 +542,11 @@ where
 
         tokio::spawn(receiver_task(event_queue, receiver));
 
-        InMemoryNetwork { node_id, nodes }
+        InMemoryNetwork {
+            node_id,
+            nodes,
+            faults,
+        }
     }
 
     /// Returns this node's ID.
@@ -498,11 +558,14 @@ where
 
 impl<P> InMemoryNetwork<P>
 where
-    P: Display,
+    P: Display + Clone + Send + 'static,
 {
     /// Internal helper, sends a payload to a node, ignoring but logging all errors.
+    ///
+    /// Faults configured for the link are applied, drawing all random decisions from `rng`.
     fn send(
         &self,
+        rng: &mut NodeRng,
         nodes: &HashMap<NodeId, mpsc::UnboundedSender<(NodeId, P)>>,
         dest: NodeId,
         payload: P,
@@ -511,22 +574,57 @@ where
             panic!("can't send message to self");
         }
 
-        match nodes.get(&dest) {
-            Some(sender) => {
-                if let Err(SendError((_, msg))) = sender.send((self.node_id.clone(), payload)) {
-                    warn!(%dest, %msg, "could not send message (send error)");
+        let sender = match nodes.get(&dest) {
+            Some(sender) => sender,
+            None => {
+                info!(%dest, %payload, "dropping message to non-existent recipient");
+                return;
+            }
+        };
 
-                    // We do nothing else, the message is just dropped.
-                }
+        let delays = match self.faults.read() {
+            Ok(faults) => faults.plan_delivery(&self.node_id, &dest, rng),
+            Err(_) => {
+                error!("network faults lock has been poisoned");
+                return;
+            }
+        };
+        if delays.is_empty() {
+            debug!(%dest, %payload, "dropping message due to injected fault");
+        }
+
+        for delay in delays {
+            let message = (self.node_id.clone(), payload.clone());
+            if delay == Duration::from_secs(0) {
+                deliver(sender, dest.clone(), message);
+            } else {
+                let sender = sender.clone();
+                let dest = dest.clone();
+                tokio::spawn(async move {
+                    time::delay_for(delay).await;
+                    deliver(&sender, dest, message);
+                });
             }
-            None => info!(%dest, %payload, "dropping message to non-existent recipient"),
         }
     }
 }
 
+/// Hands a message to the receiver task of `dest`, ignoring but logging errors.
+fn deliver<P: Display>(
+    sender: &mpsc::UnboundedSender<(NodeId, P)>,
+    dest: NodeId,
+    message: (NodeId, P),
+) {
+    if let Err(SendError((_, msg))) = sender.send(message) {
+        warn!(%dest, %msg, "could not send message (send error)");
+
+        // We do nothing else, the message is just dropped.
+    }
+}
+
 impl<P, REv> Component<REv> for InMemoryNetwork<P>
 where
-    P: Display + Clone,
+    P: Display + Clone + Send + 'static,
 {
     type Event = Event<P>;
     type ConstructionError = Infallible;
@@ -548,7 +646,7 @@ where
                 }
 
                 if let Ok(guard) = self.nodes.read() {
-                    self.send(&guard, *dest, *payload);
+                    self.send(rng, &guard, *dest, *payload);
                 } else {
                     error!("network lock has been poisoned")
                 };
@@ -557,8 +655,14 @@ where
             }
             NetworkRequest::Broadcast { payload, responder } => {
                 if let Ok(guard) = self.nodes.read() {
-                    for dest in guard.keys().filter(|&node_id| node_id != &self.node_id) {
-                        self.send(&guard, dest.clone(), *payload.clone());
+                    // Send in a fixed order, so that injected faults are reproducible.
+                    let dests: BTreeSet<_> = guard
+                        .keys()
+                        .filter(|&node_id| node_id != &self.node_id)
+                        .cloned()
+                        .collect();
+                    for dest in dests {
+                        self.send(rng, &guard, dest, *payload.clone());
                     }
                 } else {
                     error!("network lock has been poisoned")
@@ -577,12 +681,15 @@ where
                         .keys()
                         .filter(|&node_id| !exclude.contains(node_id) && node_id != &self.node_id)
                         .cloned()
+                        .collect::<BTreeSet<_>>()
+                        .into_iter()
                         .choose_multiple(rng, count)
                         .into_iter()
                         .collect();
                     // Not terribly efficient, but will always get us the maximum amount of nodes.
-                    for dest in chosen.iter() {
-                        self.send(&guard, dest.clone(), *payload.clone());
+                    // Send in a fixed order, so that injected faults are reproducible.
+                    for dest in chosen.iter().collect::<BTreeSet<_>>() {
+                        self.send(rng, &guard, dest.clone(), *payload.clone());
                     }
                     responder.respond(chosen).ignore()
                 } else {
@@ -0,0 +1,293 @@
+//! Fault injection for the in-memory network.
+//!
+//! By default every message is delivered instantly. `NetworkFaults` allows tests to degrade
+//! individual links (delays, loss, reordering and duplication) and to partition sets of nodes from
+//! each other. All random decisions are drawn from the RNG passed in by the sending node, so a test
+//! seeded with a fixed `TestRng` sees the same faults on every run.
+
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+use rand::Rng;
+use tokio::time::Instant;
+
+use crate::types::NodeId;
+
+/// The distribution from which the delay of a single message is drawn.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DelayDistribution {
+    /// Messages are delivered instantly.
+    None,
+    /// Every message is delayed by the same amount.
+    Constant(Duration),
+    /// The delay is drawn uniformly from the range `min..=max`.
+    Uniform {
+        /// Minimum delay.
+        min: Duration,
+        /// Maximum delay.
+        max: Duration,
+    },
+}
+
+impl Default for DelayDistribution {
+    fn default() -> Self {
+        DelayDistribution::None
+    }
+}
+
+impl DelayDistribution {
+    /// Draws a delay from the distribution.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Duration {
+        match self {
+            DelayDistribution::None => Duration::from_secs(0),
+            DelayDistribution::Constant(delay) => *delay,
+            DelayDistribution::Uniform { min, max } => rng.gen_range(*min..=*max),
+        }
+    }
+}
+
+/// Faults applied to messages sent over a single link.
+///
+/// All probabilities must be in the range `0.0..=1.0`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LinkFaults {
+    /// Distribution of the delay of each message.
+    pub delay: DelayDistribution,
+    /// Probability of a message being dropped.
+    pub drop_probability: f64,
+    /// Probability of a message being held back by an additional `reorder_delay`, allowing
+    /// messages sent after it to overtake it.
+    pub reorder_probability: f64,
+    /// Additional delay applied to messages chosen for reordering.
+    pub reorder_delay: Duration,
+    /// Probability of a message being delivered twice.
+    pub duplicate_probability: f64,
+}
+
+impl LinkFaults {
+    /// Decides the fate of a single message.
+    ///
+    /// Returns the delay of every copy to be delivered; an empty result means the message is
+    /// dropped.
+    fn plan_delivery<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<Duration> {
+        // Links without faults must not consume randomness, so that enabling faults on one link
+        // does not change the behavior of the others.
+        if *self == LinkFaults::default() {
+            return vec![Duration::from_secs(0)];
+        }
+
+        if chance(rng, self.drop_probability) {
+            return vec![];
+        }
+
+        let copies = if chance(rng, self.duplicate_probability) {
+            2
+        } else {
+            1
+        };
+
+        (0..copies)
+            .map(|_| {
+                let delay = self.delay.sample(rng);
+                if chance(rng, self.reorder_probability) {
+                    delay + self.reorder_delay
+                } else {
+                    delay
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns `true` with the given probability, without drawing from `rng` if it is zero.
+fn chance<R: Rng + ?Sized>(rng: &mut R, probability: f64) -> bool {
+    probability > 0.0 && rng.gen_bool(probability)
+}
+
+/// A partition preventing any communication between two sets of nodes.
+#[derive(Clone, Debug)]
+struct Partition {
+    side_a: HashSet<NodeId>,
+    side_b: HashSet<NodeId>,
+    /// The partition heals on its own once this instant has passed, if set.
+    until: Option<Instant>,
+}
+
+impl Partition {
+    /// Returns whether the partition separates `sender` from `recipient` at `now`.
+    fn separates(&self, sender: &NodeId, recipient: &NodeId, now: Instant) -> bool {
+        if self.until.map_or(false, |until| now >= until) {
+            return false;
+        }
+        (self.side_a.contains(sender) && self.side_b.contains(recipient))
+            || (self.side_b.contains(sender) && self.side_a.contains(recipient))
+    }
+}
+
+/// The faults injected into an in-memory network.
+#[derive(Clone, Debug, Default)]
+pub struct NetworkFaults {
+    /// Faults for links without a specific configuration.
+    default_link: LinkFaults,
+    /// Faults for specific links, keyed by sender and recipient.
+    links: HashMap<(NodeId, NodeId), LinkFaults>,
+    /// Currently configured partitions.
+    partitions: Vec<Partition>,
+}
+
+impl NetworkFaults {
+    /// Sets the faults for all links without a specific configuration.
+    pub fn set_default_link_faults(&mut self, faults: LinkFaults) {
+        self.default_link = faults;
+    }
+
+    /// Sets the faults for messages sent from `sender` to `recipient`.
+    ///
+    /// The link in the opposite direction is not affected.
+    pub fn set_link_faults(&mut self, sender: NodeId, recipient: NodeId, faults: LinkFaults) {
+        self.links.insert((sender, recipient), faults);
+    }
+
+    /// Partitions the nodes in `side_a` from the nodes in `side_b`, optionally only for
+    /// `duration`.
+    ///
+    /// Messages between nodes on the same side, or to nodes not mentioned, are unaffected.
+    pub fn partition<A, B>(&mut self, side_a: A, side_b: B, duration: Option<Duration>)
+    where
+        A: IntoIterator<Item = NodeId>,
+        B: IntoIterator<Item = NodeId>,
+    {
+        self.partitions.push(Partition {
+            side_a: side_a.into_iter().collect(),
+            side_b: side_b.into_iter().collect(),
+            until: duration.map(|duration| Instant::now() + duration),
+        });
+    }
+
+    /// Removes all partitions.
+    ///
+    /// Link faults are left in place.
+    pub fn heal(&mut self) {
+        self.partitions.clear();
+    }
+
+    /// Removes all partitions and link faults, restoring flawless delivery.
+    pub fn clear(&mut self) {
+        *self = NetworkFaults::default();
+    }
+
+    /// Returns whether messages from `sender` to `recipient` are currently blocked by a partition.
+    pub fn is_partitioned(&self, sender: &NodeId, recipient: &NodeId) -> bool {
+        let now = Instant::now();
+        self.partitions
+            .iter()
+            .any(|partition| partition.separates(sender, recipient, now))
+    }
+
+    /// Decides the fate of a single message from `sender` to `recipient`.
+    ///
+    /// Returns the delay of every copy to be delivered; an empty result means the message is
+    /// dropped.
+    pub(super) fn plan_delivery<R: Rng + ?Sized>(
+        &self,
+        sender: &NodeId,
+        recipient: &NodeId,
+        rng: &mut R,
+    ) -> Vec<Duration> {
+        if self.is_partitioned(sender, recipient) {
+            return vec![];
+        }
+        self.links
+            .get(&(sender.clone(), recipient.clone()))
+            .unwrap_or(&self.default_link)
+            .plan_delivery(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
+
+    use super::*;
+    use crate::testing::TestRng;
+
+    #[test]
+    fn no_faults_delivers_instantly_without_using_rng() {
+        let mut rng = TestRng::new();
+        let sender = NodeId::random(&mut rng);
+        let recipient = NodeId::random(&mut rng);
+        let faults = NetworkFaults::default();
+
+        let seed = rng.gen();
+        let mut used_rng = Pcg64Mcg::seed_from_u64(seed);
+        let mut unused_rng = Pcg64Mcg::seed_from_u64(seed);
+        assert_eq!(
+            faults.plan_delivery(&sender, &recipient, &mut used_rng),
+            vec![Duration::from_secs(0)]
+        );
+        assert_eq!(used_rng.gen::<u64>(), unused_rng.gen::<u64>());
+    }
+
+    #[test]
+    fn faults_are_deterministic() {
+        let mut rng = TestRng::new();
+        let sender = NodeId::random(&mut rng);
+        let recipient = NodeId::random(&mut rng);
+        let mut faults = NetworkFaults::default();
+        faults.set_default_link_faults(LinkFaults {
+            delay: DelayDistribution::Uniform {
+                min: Duration::from_millis(10),
+                max: Duration::from_millis(500),
+            },
+            drop_probability: 0.2,
+            reorder_probability: 0.3,
+            reorder_delay: Duration::from_secs(1),
+            duplicate_probability: 0.1,
+        });
+
+        let seed = rng.gen();
+        let mut first_rng = Pcg64Mcg::seed_from_u64(seed);
+        let mut second_rng = Pcg64Mcg::seed_from_u64(seed);
+        for _ in 0..100 {
+            assert_eq!(
+                faults.plan_delivery(&sender, &recipient, &mut first_rng),
+                faults.plan_delivery(&sender, &recipient, &mut second_rng)
+            );
+        }
+    }
+
+    #[test]
+    fn partition_blocks_both_directions_until_healed() {
+        let mut rng = TestRng::new();
+        let alice = NodeId::random(&mut rng);
+        let bob = NodeId::random(&mut rng);
+        let carol = NodeId::random(&mut rng);
+        let mut faults = NetworkFaults::default();
+
+        faults.partition(vec![alice.clone()], vec![bob.clone()], None);
+        assert!(faults.plan_delivery(&alice, &bob, &mut rng).is_empty());
+        assert!(faults.plan_delivery(&bob, &alice, &mut rng).is_empty());
+        assert!(!faults.plan_delivery(&alice, &carol, &mut rng).is_empty());
+
+        faults.heal();
+        assert!(!faults.plan_delivery(&alice, &bob, &mut rng).is_empty());
+    }
+
+    #[test]
+    fn timed_partition_expires() {
+        let mut rng = TestRng::new();
+        let alice = NodeId::random(&mut rng);
+        let bob = NodeId::random(&mut rng);
+        let mut faults = NetworkFaults::default();
+
+        faults.partition(
+            vec![alice.clone()],
+            vec![bob.clone()],
+            Some(Duration::from_secs(0)),
+        );
+        assert!(!faults.is_partitioned(&alice, &bob));
+    }
+}
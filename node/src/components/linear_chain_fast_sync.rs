@@ -24,6 +24,7 @@ use crate::{
     effect::{EffectBuilder, EffectExt, EffectOptionExt, Effects},
     types::{
         ActivationPoint, Block, BlockByHeight, BlockHash, BlockHeader, Chainspec, FinalizedBlock,
+        SyncProgress,
     },
     NodeRng,
 };
@@ -302,6 +303,29 @@ impl<I: Clone + PartialEq + 'static> LinearChainFastSync<I> {
         }
     }
 
+    /// Returns the progress of the linear chain synchronization.
+    pub(crate) fn sync_progress(&self) -> SyncProgress {
+        let (trusted_hash, target_height) = match &self.state {
+            State::SyncingTrustedHash {
+                trusted_hash,
+                highest_block_seen,
+                ..
+            }
+            | State::SyncingDescendants {
+                trusted_hash,
+                highest_block_seen,
+                ..
+            } => (Some(*trusted_hash), Some(*highest_block_seen)),
+            State::None | State::Done => (None, None),
+        };
+        SyncProgress {
+            trusted_hash,
+            target_height,
+            current_height: self.latest_block().map(Block::height),
+            is_synced: self.is_synced(),
+        }
+    }
+
     pub(crate) fn latest_block(&self) -> Option<&Block> {
         match &self.state {
             State::SyncingTrustedHash { latest_block, .. } => Option::as_ref(&*latest_block),
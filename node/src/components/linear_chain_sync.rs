@@ -48,7 +48,8 @@ use crate::{
     effect::{EffectBuilder, EffectExt, EffectOptionExt, Effects},
     fatal,
     types::{
-        ActivationPoint, Block, BlockByHeight, BlockHash, Chainspec, FinalizedBlock, TimeDiff,
+        ActivationPoint, Block, BlockByHeight, BlockHash, Chainspec, FinalizedBlock, SyncProgress,
+        TimeDiff,
     },
     NodeRng,
 };
@@ -496,6 +497,29 @@ impl<I: Clone + PartialEq + 'static> LinearChainSync<I> {
             .event(|_| Event::Shutdown(true))
     }
 
+    /// Returns the progress of the linear chain synchronization.
+    pub(crate) fn sync_progress(&self) -> SyncProgress {
+        let (trusted_hash, target_height) = match &self.state {
+            State::SyncingTrustedHash {
+                trusted_hash,
+                highest_block_seen,
+                ..
+            }
+            | State::SyncingDescendants {
+                trusted_hash,
+                highest_block_seen,
+                ..
+            } => (Some(*trusted_hash), Some(*highest_block_seen)),
+            State::None | State::Done(_) => (None, None),
+        };
+        SyncProgress {
+            trusted_hash,
+            target_height,
+            current_height: self.latest_block().map(Block::height),
+            is_synced: self.is_synced(),
+        }
+    }
+
     pub(crate) fn latest_block(&self) -> Option<&Block> {
         match &self.state {
             State::SyncingTrustedHash { latest_block, .. } => Option::as_ref(&*latest_block),
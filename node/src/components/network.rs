@@ -59,7 +59,7 @@ use crate::{
     },
     fatal,
     reactor::{EventQueueHandle, Finalize, QueueKind, ReactorEvent},
-    types::{Chainspec, NodeId},
+    types::{Chainspec, NodeId, PeerConnectionState},
     utils::{self, ds, CountingReceiver, CountingSender, DisplayIter},
     NodeRng,
 };
@@ -1006,6 +1006,21 @@ impl<REv: ReactorEventT<P>, P: PayloadT> Component<REv> for Network<REv, P> {
                         .collect();
                     responder.respond(peers).ignore()
                 }
+                NetworkInfoRequest::GetPeerConnectionStates { responder } => {
+                    let states = self
+                        .peers
+                        .iter()
+                        .map(|(node_id, endpoint)| {
+                            let state = PeerConnectionState {
+                                address: endpoint.get_remote_address().to_string(),
+                                incoming: endpoint.is_listener(),
+                                outgoing: endpoint.is_dialer(),
+                            };
+                            (node_id.clone(), state)
+                        })
+                        .collect();
+                    responder.respond(states).ignore()
+                }
             },
         }
     }
@@ -36,7 +36,7 @@ use crate::{
     effect::{
         requests::{
             ChainspecLoaderRequest, ConsensusRequest, MetricsRequest, NetworkInfoRequest,
-            StorageRequest,
+            ReactorStatusRequest, StorageRequest,
         },
         EffectBuilder, EffectExt, Effects,
     },
@@ -59,6 +59,7 @@ pub trait ReactorEventT:
     + From<ChainspecLoaderRequest>
     + From<ConsensusRequest>
     + From<MetricsRequest>
+    + From<ReactorStatusRequest>
     + Send
 {
 }
@@ -71,6 +72,7 @@ impl<REv> ReactorEventT for REv where
         + From<ChainspecLoaderRequest>
         + From<ConsensusRequest>
         + From<MetricsRequest>
+        + From<ReactorStatusRequest>
         + Send
         + 'static
 {
@@ -126,14 +128,21 @@ where
     ) -> Effects<Self::Event> {
         match event {
             Event::RestRequest(RestRequest::GetStatus { responder }) => async move {
-                let (last_added_block, peers, chainspec_info, consensus_status) = join!(
-                    effect_builder.get_highest_block_from_storage(),
-                    effect_builder.network_peers(),
-                    effect_builder.get_chainspec_info(),
-                    effect_builder.consensus_status()
+                let (last_added_block, peers, chainspec_info, consensus_status, reactor_status) =
+                    join!(
+                        effect_builder.get_highest_block_from_storage(),
+                        effect_builder.network_peers(),
+                        effect_builder.get_chainspec_info(),
+                        effect_builder.consensus_status(),
+                        effect_builder.get_reactor_status()
+                    );
+                let status_feed = StatusFeed::new(
+                    last_added_block,
+                    peers,
+                    chainspec_info,
+                    consensus_status,
+                    reactor_status,
                 );
-                let status_feed =
-                    StatusFeed::new(last_added_block, peers, chainspec_info, consensus_status);
                 responder.respond(status_feed).await;
             }
             .ignore(),
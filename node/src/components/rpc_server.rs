@@ -19,20 +19,22 @@ mod event;
 mod http_server;
 pub mod rpcs;
 
-use std::{convert::Infallible, fmt::Debug};
+use std::{convert::Infallible, fmt::Debug, sync::Arc};
 
 use datasize::DataSize;
 use futures::join;
 use semver::Version;
+use thiserror::Error;
+use tokio::sync::Semaphore;
 
 use casper_execution_engine::{
     core::engine_state::{
-        self, BalanceRequest, BalanceResult, GetBidsRequest, GetEraValidatorsError, QueryRequest,
-        QueryResult,
+        self, BalanceRequest, BalanceResult, DeployItem, ExecuteRequest, GetBidsRequest,
+        GetEraValidatorsError, QueryRequest, QueryResult,
     },
     storage::protocol_data::ProtocolData,
 };
-use casper_types::{system::auction::EraValidators, Key, ProtocolVersion, URef};
+use casper_types::{system::auction::EraValidators, ExecutionResult, Key, ProtocolVersion, URef};
 
 use self::rpcs::chain::BlockIdentifier;
 
@@ -43,12 +45,13 @@ use crate::{
     effect::{
         announcements::RpcServerAnnouncement,
         requests::{
-            ChainspecLoaderRequest, ConsensusRequest, ContractRuntimeRequest, LinearChainRequest,
-            MetricsRequest, NetworkInfoRequest, RpcRequest, StorageRequest,
+            BlockProposerRequest, ChainspecLoaderRequest, ConsensusRequest, ContractRuntimeRequest,
+            LinearChainRequest, MetricsRequest, NetworkInfoRequest, ReactorStatusRequest,
+            RpcRequest, StorageRequest,
         },
         EffectBuilder, EffectExt, Effects, Responder,
     },
-    types::{NodeId, StatusFeed},
+    types::{Block, Deploy, NodeId, StatusFeed},
     utils::{self, ListeningError},
     NodeRng,
 };
@@ -61,12 +64,14 @@ pub trait ReactorEventT:
     From<Event>
     + From<RpcRequest<NodeId>>
     + From<RpcServerAnnouncement>
+    + From<BlockProposerRequest>
     + From<ChainspecLoaderRequest>
     + From<ContractRuntimeRequest>
     + From<ConsensusRequest>
     + From<LinearChainRequest<NodeId>>
     + From<MetricsRequest>
     + From<NetworkInfoRequest<NodeId>>
+    + From<ReactorStatusRequest>
     + From<StorageRequest>
     + Send
 {
@@ -76,20 +81,58 @@ impl<REv> ReactorEventT for REv where
     REv: From<Event>
         + From<RpcRequest<NodeId>>
         + From<RpcServerAnnouncement>
+        + From<BlockProposerRequest>
         + From<ChainspecLoaderRequest>
         + From<ContractRuntimeRequest>
         + From<ConsensusRequest>
         + From<LinearChainRequest<NodeId>>
         + From<MetricsRequest>
         + From<NetworkInfoRequest<NodeId>>
+        + From<ReactorStatusRequest>
         + From<StorageRequest>
         + Send
         + 'static
 {
 }
 
+/// An error speculatively executing a deploy.
+#[derive(Debug, Error)]
+pub enum SpeculativeExecError {
+    /// The configured maximum number of concurrent speculative executions has been reached.
+    #[error("too many speculative executions in progress; try again later")]
+    TooManyRequests,
+    /// The global state of the requested block is not available.
+    #[error("state root hash {0} not found")]
+    RootNotFound(Digest),
+    /// The contract runtime didn't return a result for the deploy.
+    #[error("no execution result returned")]
+    MissingExecutionResult,
+}
+
+/// An error getting the differences between two state roots.
+#[derive(Debug, Error)]
+pub enum GetDiffError {
+    /// More entries were requested than the configured maximum.
+    #[error("requested limit of {requested} entries exceeds the maximum of {max}")]
+    LimitExceeded {
+        /// The requested number of entries.
+        requested: u64,
+        /// The configured maximum number of entries.
+        max: u64,
+    },
+    /// The contract runtime failed to compare the state roots.
+    #[error(transparent)]
+    Engine(#[from] engine_state::Error),
+}
+
 #[derive(DataSize, Debug)]
-pub(crate) struct RpcServer {}
+pub(crate) struct RpcServer {
+    /// Permits limiting the number of concurrently running speculative executions.
+    #[data_size(skip)]
+    speculative_exec_permits: Arc<Semaphore>,
+    /// The maximum number of entries returned by a single "state_get_diff" request.
+    max_diff_entries: u64,
+}
 
 impl RpcServer {
     pub(crate) fn new<REv>(
@@ -108,7 +151,12 @@ impl RpcServer {
             config.qps_limit,
         ));
 
-        Ok(RpcServer {})
+        Ok(RpcServer {
+            speculative_exec_permits: Arc::new(Semaphore::new(
+                config.max_concurrent_speculative_execs,
+            )),
+            max_diff_entries: config.max_diff_entries,
+        })
     }
 }
 
@@ -175,6 +223,47 @@ impl RpcServer {
                 main_responder: responder,
             })
     }
+
+    fn handle_speculative_exec<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        block: Block,
+        deploy: Deploy,
+        responder: Responder<Result<ExecutionResult, SpeculativeExecError>>,
+    ) -> Effects<Event> {
+        let permits = Arc::clone(&self.speculative_exec_permits);
+        async move {
+            // Reject rather than queue the request if the limit has been reached, as speculative
+            // executions are potentially long-running.
+            let _permit = match permits.try_acquire() {
+                Ok(permit) => permit,
+                Err(_) => {
+                    return responder
+                        .respond(Err(SpeculativeExecError::TooManyRequests))
+                        .await
+                }
+            };
+            let state_root_hash = *block.header().state_root_hash();
+            let execute_request = ExecuteRequest::new(
+                state_root_hash.into(),
+                block.header().timestamp().millis(),
+                vec![Ok(DeployItem::from(deploy))],
+                block.header().protocol_version(),
+                block.body().proposer().clone(),
+                block.header().accumulated_seed().into(),
+            );
+            // Only the execution results are used; the effects are never committed.
+            let result = match effect_builder.request_execute(execute_request).await {
+                Ok(mut execution_results) => execution_results
+                    .pop_front()
+                    .map(|ee_execution_result| ExecutionResult::from(&ee_execution_result))
+                    .ok_or(SpeculativeExecError::MissingExecutionResult),
+                Err(_) => Err(SpeculativeExecError::RootNotFound(state_root_hash)),
+            };
+            responder.respond(result).await
+        }
+        .ignore()
+    }
 }
 
 impl<REv> Component<REv> for RpcServer
@@ -271,6 +360,38 @@ where
                 purse_uref,
                 responder,
             }) => self.handle_get_balance(effect_builder, state_root_hash, purse_uref, responder),
+            Event::RpcRequest(RpcRequest::GetTrieDiff {
+                from_state_root_hash,
+                to_state_root_hash,
+                start_after,
+                limit,
+                responder,
+            }) => {
+                let max_entries = match limit {
+                    Some(requested) if requested > self.max_diff_entries => {
+                        let error = GetDiffError::LimitExceeded {
+                            requested,
+                            max: self.max_diff_entries,
+                        };
+                        return responder.respond(Err(error)).ignore();
+                    }
+                    Some(requested) => requested,
+                    None => self.max_diff_entries,
+                };
+                async move {
+                    let result = effect_builder
+                        .get_trie_diff(
+                            from_state_root_hash.into(),
+                            to_state_root_hash.into(),
+                            start_after,
+                            max_entries as usize,
+                        )
+                        .await
+                        .map_err(GetDiffError::from);
+                    responder.respond(result).await
+                }
+                .ignore()
+            }
             Event::RpcRequest(RpcRequest::GetDeploy { hash, responder }) => effect_builder
                 .get_deploy_and_metadata_from_storage(hash)
                 .event(move |result| Event::GetDeployResult {
@@ -278,6 +399,38 @@ where
                     result: Box::new(result),
                     main_responder: responder,
                 }),
+            Event::RpcRequest(RpcRequest::GetAccountDeploys {
+                account_hash,
+                offset,
+                limit,
+                responder,
+            }) => effect_builder
+                .get_account_deploys_from_storage(account_hash, offset, limit)
+                .event(move |result| Event::GetAccountDeploysResult {
+                    account_hash,
+                    result,
+                    main_responder: responder,
+                }),
+            Event::RpcRequest(RpcRequest::IsBlockPruned {
+                block_identifier,
+                responder,
+            }) => async move {
+                let is_pruned = effect_builder
+                    .is_block_pruned_in_storage(block_identifier)
+                    .await;
+                responder.respond(is_pruned).await
+            }
+            .ignore(),
+            Event::RpcRequest(RpcRequest::SpeculativeExec {
+                block,
+                deploy,
+                responder,
+            }) => self.handle_speculative_exec(effect_builder, *block, *deploy, responder),
+            Event::RpcRequest(RpcRequest::GetPendingDeploys { responder }) => async move {
+                let pending_deploys = effect_builder.get_pending_deploys().await;
+                responder.respond(pending_deploys).await
+            }
+            .ignore(),
             Event::RpcRequest(RpcRequest::GetPeers { responder }) => effect_builder
                 .network_peers()
                 .event(move |peers| Event::GetPeersResult {
@@ -285,14 +438,21 @@ where
                     main_responder: responder,
                 }),
             Event::RpcRequest(RpcRequest::GetStatus { responder }) => async move {
-                let (last_added_block, peers, chainspec_info, consensus_status) = join!(
-                    effect_builder.get_highest_block_from_storage(),
-                    effect_builder.network_peers(),
-                    effect_builder.get_chainspec_info(),
-                    effect_builder.consensus_status()
+                let (last_added_block, peers, chainspec_info, consensus_status, reactor_status) =
+                    join!(
+                        effect_builder.get_highest_block_from_storage(),
+                        effect_builder.network_peers(),
+                        effect_builder.get_chainspec_info(),
+                        effect_builder.consensus_status(),
+                        effect_builder.get_reactor_status()
+                    );
+                let status_feed = StatusFeed::new(
+                    last_added_block,
+                    peers,
+                    chainspec_info,
+                    consensus_status,
+                    reactor_status,
                 );
-                let status_feed =
-                    StatusFeed::new(last_added_block, peers, chainspec_info, consensus_status);
                 responder.respond(status_feed).await;
             }
             .ignore(),
@@ -337,6 +497,11 @@ where
                 result,
                 main_responder,
             } => main_responder.respond(*result).ignore(),
+            Event::GetAccountDeploysResult {
+                account_hash: _,
+                result,
+                main_responder,
+            } => main_responder.respond(result).ignore(),
             Event::GetPeersResult {
                 peers,
                 main_responder,
@@ -7,6 +7,10 @@ use serde::{Deserialize, Serialize};
 const DEFAULT_ADDRESS: &str = "0.0.0.0:0";
 /// Default rate limit in qps.
 const DEFAULT_QPS_LIMIT: u64 = 100;
+/// Default maximum number of concurrently running speculative executions.
+const DEFAULT_MAX_CONCURRENT_SPECULATIVE_EXECS: usize = 2;
+/// Default maximum number of entries returned by a single "state_get_diff" request.
+const DEFAULT_MAX_DIFF_ENTRIES: u64 = 1000;
 
 /// JSON-RPC HTTP server configuration.
 #[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
@@ -18,6 +22,14 @@ pub struct Config {
 
     /// Max rate limit in qps.
     pub qps_limit: u64,
+
+    /// Maximum number of "speculative_exec" requests being executed concurrently.  Further
+    /// requests are rejected until one completes.  A value of 0 disables the RPC.
+    pub max_concurrent_speculative_execs: usize,
+
+    /// Maximum number of entries returned by a single "state_get_diff" request.  Requests asking
+    /// for more are rejected.
+    pub max_diff_entries: u64,
 }
 
 impl Config {
@@ -26,6 +38,8 @@ impl Config {
         Config {
             address: DEFAULT_ADDRESS.to_string(),
             qps_limit: DEFAULT_QPS_LIMIT,
+            max_concurrent_speculative_execs: DEFAULT_MAX_CONCURRENT_SPECULATIVE_EXECS,
+            max_diff_entries: DEFAULT_MAX_DIFF_ENTRIES,
         }
     }
 }
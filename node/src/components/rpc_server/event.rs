@@ -9,12 +9,15 @@ use casper_execution_engine::{
     core::engine_state::{self, BalanceResult, GetBidsResult, GetEraValidatorsError, QueryResult},
     storage::protocol_data::ProtocolData,
 };
-use casper_types::{system::auction::EraValidators, Transfer};
+use casper_types::{account::AccountHash, system::auction::EraValidators, Transfer};
 
 use crate::{
     effect::{requests::RpcRequest, Responder},
     rpcs::chain::BlockIdentifier,
-    types::{Block, BlockHash, BlockSignatures, Deploy, DeployHash, DeployMetadata, NodeId},
+    types::{
+        AccountDeploy, Block, BlockHash, BlockSignatures, Deploy, DeployHash, DeployMetadata,
+        NodeId,
+    },
 };
 
 #[derive(Debug, From)]
@@ -52,6 +55,11 @@ pub enum Event {
         result: Box<Option<(Deploy, DeployMetadata)>>,
         main_responder: Responder<Option<(Deploy, DeployMetadata)>>,
     },
+    GetAccountDeploysResult {
+        account_hash: AccountHash,
+        result: Vec<AccountDeploy>,
+        main_responder: Responder<Vec<AccountDeploy>>,
+    },
     GetPeersResult {
         peers: BTreeMap<NodeId, String>,
         main_responder: Responder<BTreeMap<NodeId, String>>,
@@ -110,6 +118,16 @@ impl Display for Event {
             Event::GetDeployResult { hash, result, .. } => {
                 write!(formatter, "get deploy result for {}: {:?}", hash, result)
             }
+            Event::GetAccountDeploysResult {
+                account_hash,
+                result,
+                ..
+            } => write!(
+                formatter,
+                "get account deploys result for {}: {} entries",
+                account_hash,
+                result.len()
+            ),
             Event::GetPeersResult { peers, .. } => write!(formatter, "get peers: {}", peers.len()),
             Event::GetMetricsResult { text, .. } => match text {
                 Some(txt) => write!(formatter, "get metrics ({} bytes)", txt.len()),
@@ -53,6 +53,8 @@ pub(super) async fn run<REv: ReactorEventT>(
     // RPC filters.
     let rpc_put_deploy =
         rpcs::account::PutDeploy::create_filter(effect_builder, api_version.clone());
+    let rpc_get_account_deploys =
+        rpcs::account::GetAccountDeploys::create_filter(effect_builder, api_version.clone());
     let rpc_get_block = rpcs::chain::GetBlock::create_filter(effect_builder, api_version.clone());
     let rpc_get_block_transfers =
         rpcs::chain::GetBlockTransfers::create_filter(effect_builder, api_version.clone());
@@ -61,13 +63,20 @@ pub(super) async fn run<REv: ReactorEventT>(
     let rpc_get_item = rpcs::state::GetItem::create_filter(effect_builder, api_version.clone());
     let rpc_get_balance =
         rpcs::state::GetBalance::create_filter(effect_builder, api_version.clone());
+    let rpc_get_dictionary_item =
+        rpcs::state::GetDictionaryItem::create_filter(effect_builder, api_version.clone());
+    let rpc_get_diff = rpcs::state::GetDiff::create_filter(effect_builder, api_version.clone());
     let rpc_get_deploy = rpcs::info::GetDeploy::create_filter(effect_builder, api_version.clone());
     let rpc_get_peers = rpcs::info::GetPeers::create_filter(effect_builder, api_version.clone());
     let rpc_get_status = rpcs::info::GetStatus::create_filter(effect_builder, api_version.clone());
+    let rpc_get_pending_deploys =
+        rpcs::info::GetPendingDeploys::create_filter(effect_builder, api_version.clone());
     let rpc_get_era_info =
         rpcs::chain::GetEraInfoBySwitchBlock::create_filter(effect_builder, api_version.clone());
     let rpc_get_auction_info =
         rpcs::state::GetAuctionInfo::create_filter(effect_builder, api_version.clone());
+    let rpc_speculative_exec =
+        rpcs::speculative_exec::SpeculativeExec::create_filter(effect_builder, api_version.clone());
     let rpc_get_rpcs = rpcs::docs::ListRpcs::create_filter(effect_builder, api_version);
 
     // Catch requests where the method is not one we handle.
@@ -90,16 +99,21 @@ pub(super) async fn run<REv: ReactorEventT>(
     //        update to or move away from warp_json_rpc.
     let service = warp_json_rpc::service(
         rpc_put_deploy
+            .or(rpc_get_account_deploys)
             .or(rpc_get_block)
             .or(rpc_get_block_transfers)
             .or(rpc_get_state_root_hash)
             .or(rpc_get_item)
             .or(rpc_get_balance)
+            .or(rpc_get_dictionary_item)
+            .or(rpc_get_diff)
             .or(rpc_get_deploy)
             .or(rpc_get_peers)
             .or(rpc_get_status)
+            .or(rpc_get_pending_deploys)
             .or(rpc_get_era_info)
             .or(rpc_get_auction_info)
+            .or(rpc_speculative_exec)
             .or(rpc_get_rpcs)
             .or(unknown_method)
             .or(parse_failure),
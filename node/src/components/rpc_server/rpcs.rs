@@ -6,6 +6,7 @@ pub mod account;
 pub mod chain;
 pub mod docs;
 pub mod info;
+pub mod speculative_exec;
 pub mod state;
 
 use std::str;
@@ -45,6 +46,14 @@ enum ErrorCode {
     GetBalanceFailed = -32006,
     GetBalanceFailedToExecute = -32007,
     InvalidDeploy = -32008,
+    BlockPruned = -32009,
+    GetDiffFailed = -32010,
+    GetDiffFailedToExecute = -32011,
+    ParseDictionarySeedURef = -32012,
+    UnexpectedStateRootHash = -32013,
+    SpeculativeExecFailed = -32014,
+    TooManySpeculativeExecs = -32015,
+    GetDiffLimitExceeded = -32016,
 }
 
 #[derive(Debug)]
@@ -283,8 +292,13 @@ mod common {
     use casper_execution_engine::core::engine_state::{self, QueryResult};
     use casper_types::bytesrepr::ToBytes;
 
-    use super::ErrorCode;
-    use crate::types::json_compatibility::StoredValue;
+    use super::{chain::BlockIdentifier, ErrorCode, ReactorEventT, RpcRequest};
+    use crate::{
+        crypto::hash::Digest,
+        effect::EffectBuilder,
+        reactor::QueueKind,
+        types::{json_compatibility::StoredValue, BlockHash, JsonSignedBlockHeader},
+    };
 
     pub(super) static MERKLE_PROOF: Lazy<String> = Lazy::new(|| {
         String::from(
@@ -335,4 +349,42 @@ mod common {
 
         Ok((value_compat, proof_bytes))
     }
+
+    /// Retrieves the header of the given block along with its finality signatures, ensuring the
+    /// block's state root hash is the expected one.
+    pub(super) async fn get_signed_block_header<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        block_hash: BlockHash,
+        state_root_hash: Digest,
+    ) -> Result<JsonSignedBlockHeader, (ErrorCode, String)> {
+        let maybe_block_and_signatures = effect_builder
+            .make_request(
+                |responder| RpcRequest::GetBlock {
+                    maybe_id: Some(BlockIdentifier::Hash(block_hash)),
+                    responder,
+                },
+                QueueKind::Api,
+            )
+            .await;
+
+        let (block, signatures) = match maybe_block_and_signatures {
+            Some(block_and_signatures) => block_and_signatures,
+            None => {
+                let error_msg = format!("block {} not known", block_hash);
+                return Err((ErrorCode::NoSuchBlock, error_msg));
+            }
+        };
+
+        if *block.header().state_root_hash() != state_root_hash {
+            let error_msg = format!(
+                "block {} has state root hash {}, not {}",
+                block_hash,
+                block.header().state_root_hash(),
+                state_root_hash
+            );
+            return Err((ErrorCode::UnexpectedStateRootHash, error_msg));
+        }
+
+        Ok(JsonSignedBlockHeader::new(block.take_header(), signatures))
+    }
 }
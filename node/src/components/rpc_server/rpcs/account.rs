@@ -15,6 +15,8 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
+use casper_types::PublicKey;
+
 use super::{
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
     Error, ReactorEventT, RpcRequest, RpcWithParams, RpcWithParamsExt,
@@ -23,9 +25,12 @@ use crate::{
     components::rpc_server::rpcs::ErrorCode,
     effect::EffectBuilder,
     reactor::QueueKind,
-    types::{Deploy, DeployHash},
+    types::{AccountDeploy, Block, Deploy, DeployHash},
 };
 
+/// The maximum number of entries returned by a single "account_get_deploys" request.
+pub const MAX_ACCOUNT_DEPLOYS_PAGE_SIZE: u64 = 100;
+
 static PUT_DEPLOY_PARAMS: Lazy<PutDeployParams> = Lazy::new(|| PutDeployParams {
     deploy: Deploy::doc_example().clone(),
 });
@@ -33,6 +38,22 @@ static PUT_DEPLOY_RESULT: Lazy<PutDeployResult> = Lazy::new(|| PutDeployResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
     deploy_hash: *Deploy::doc_example().id(),
 });
+static GET_ACCOUNT_DEPLOYS_PARAMS: Lazy<GetAccountDeploysParams> =
+    Lazy::new(|| GetAccountDeploysParams {
+        public_key: Deploy::doc_example().header().account().clone(),
+        offset: Some(0),
+        limit: Some(10),
+    });
+static GET_ACCOUNT_DEPLOYS_RESULT: Lazy<GetAccountDeploysResult> =
+    Lazy::new(|| GetAccountDeploysResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        deploys: vec![AccountDeploy {
+            block_height: Block::doc_example().height(),
+            deploy_hash: *Deploy::doc_example().id(),
+            block_hash: *Block::doc_example().hash(),
+        }],
+        next_offset: None,
+    });
 
 /// Params for "account_put_deploy" RPC request.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
@@ -122,3 +143,95 @@ impl RpcWithParamsExt for PutDeploy {
         .boxed()
     }
 }
+
+/// Params for "account_get_deploys" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetAccountDeploysParams {
+    /// The public key of the account which sent the deploys.
+    pub public_key: PublicKey,
+    /// The number of entries to skip. Defaults to 0.
+    pub offset: Option<u64>,
+    /// The maximum number of entries to return. Defaults to, and is capped at, 100.
+    pub limit: Option<u64>,
+}
+
+impl DocExample for GetAccountDeploysParams {
+    fn doc_example() -> &'static Self {
+        &*GET_ACCOUNT_DEPLOYS_PARAMS
+    }
+}
+
+/// Result for "account_get_deploys" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetAccountDeploysResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The deploys sent by the account, ordered by the height of the block they were executed in.
+    pub deploys: Vec<AccountDeploy>,
+    /// The offset at which to request the next page, or `None` if this is the last page.
+    pub next_offset: Option<u64>,
+}
+
+impl DocExample for GetAccountDeploysResult {
+    fn doc_example() -> &'static Self {
+        &*GET_ACCOUNT_DEPLOYS_RESULT
+    }
+}
+
+/// "account_get_deploys" RPC.
+pub struct GetAccountDeploys {}
+
+impl RpcWithParams for GetAccountDeploys {
+    const METHOD: &'static str = "account_get_deploys";
+    type RequestParams = GetAccountDeploysParams;
+    type ResponseResult = GetAccountDeploysResult;
+}
+
+impl RpcWithParamsExt for GetAccountDeploys {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let account_hash = params.public_key.to_account_hash();
+            let offset = params.offset.unwrap_or_default();
+            let limit = params
+                .limit
+                .unwrap_or(MAX_ACCOUNT_DEPLOYS_PAGE_SIZE)
+                .min(MAX_ACCOUNT_DEPLOYS_PAGE_SIZE);
+
+            // Request one more entry than required to find out whether there is a further page.
+            let mut deploys = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetAccountDeploys {
+                        account_hash,
+                        offset,
+                        limit: limit + 1,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let next_offset = if deploys.len() as u64 > limit {
+                deploys.truncate(limit as usize);
+                Some(offset + limit)
+            } else {
+                None
+            };
+
+            let result = Self::ResponseResult {
+                api_version,
+                deploys,
+                next_offset,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
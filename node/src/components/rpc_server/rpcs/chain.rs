@@ -33,6 +33,8 @@ use crate::{
 pub use era_summary::EraSummary;
 use era_summary::ERA_SUMMARY;
 
+pub use crate::types::BlockIdentifier;
+
 static GET_BLOCK_PARAMS: Lazy<GetBlockParams> = Lazy::new(|| GetBlockParams {
     block_identifier: BlockIdentifier::Hash(Block::doc_example().id()),
 });
@@ -67,16 +69,6 @@ static GET_ERA_INFO_RESULT: Lazy<GetEraInfoResult> = Lazy::new(|| GetEraInfoResu
     era_summary: Some(ERA_SUMMARY.clone()),
 });
 
-/// Identifier for possible ways to retrieve a block.
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub enum BlockIdentifier {
-    /// Identify and retrieve the block with its hash.
-    Hash(BlockHash),
-    /// Identify and retrieve the block with its height.
-    Height(u64),
-}
-
 /// Params for "chain_get_block" RPC request.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -450,7 +442,7 @@ async fn get_block<REv: ReactorEventT>(
     }
 }
 
-async fn get_block_with_metadata<REv: ReactorEventT>(
+pub(super) async fn get_block_with_metadata<REv: ReactorEventT>(
     maybe_id: Option<BlockIdentifier>,
     effect_builder: EffectBuilder<REv>,
 ) -> Result<Option<(Block, BlockSignatures)>, warp_json_rpc::Error> {
@@ -467,7 +459,23 @@ async fn get_block_with_metadata<REv: ReactorEventT>(
         .await;
 
     if maybe_result.is_none() && getting_specific_block {
-        info!("failed to get {:?} from storage", maybe_id.unwrap());
+        let block_identifier = maybe_id.unwrap();
+        info!("failed to get {:?} from storage", block_identifier);
+        let is_pruned = effect_builder
+            .make_request(
+                |responder| RpcRequest::IsBlockPruned {
+                    block_identifier,
+                    responder,
+                },
+                QueueKind::Api,
+            )
+            .await;
+        if is_pruned {
+            return Err(warp_json_rpc::Error::custom(
+                ErrorCode::BlockPruned as i64,
+                "block has been pruned",
+            ));
+        }
         return Err(warp_json_rpc::Error::custom(
             ErrorCode::NoSuchBlock as i64,
             "block not known",
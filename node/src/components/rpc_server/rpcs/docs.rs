@@ -18,10 +18,11 @@ use serde_json::{json, Value};
 use warp_json_rpc::Builder;
 
 use super::{
-    account::PutDeploy,
+    account::{GetAccountDeploys, PutDeploy},
     chain::{GetBlock, GetBlockTransfers, GetStateRootHash},
-    info::{GetDeploy, GetPeers, GetStatus},
-    state::{GetAuctionInfo, GetBalance, GetItem},
+    info::{GetDeploy, GetPeers, GetPendingDeploys, GetStatus},
+    speculative_exec::SpeculativeExec,
+    state::{GetAuctionInfo, GetBalance, GetDictionaryItem, GetDiff, GetItem},
     Error, ReactorEventT, RpcWithOptionalParams, RpcWithParams, RpcWithoutParams,
     RpcWithoutParamsExt,
 };
@@ -68,8 +69,14 @@ static OPEN_RPC_SCHEMA: Lazy<OpenRpcSchema> = Lazy::new(|| {
 
     schema.push_with_params::<PutDeploy>("receives a Deploy to be executed by the network");
     schema.push_with_params::<GetDeploy>("returns a Deploy from the network");
+    schema.push_with_params::<GetAccountDeploys>(
+        "returns a page of the Deploys sent by an account, ordered by Block height",
+    );
     schema.push_without_params::<GetPeers>("returns a list of peers connected to the node");
     schema.push_without_params::<GetStatus>("returns the current status of the node");
+    schema.push_without_params::<GetPendingDeploys>(
+        "returns the Deploys awaiting inclusion in a Block, in order of priority",
+    );
     schema.push_with_optional_params::<GetBlock>("returns a Block from the network");
     schema.push_with_optional_params::<GetBlockTransfers>(
         "returns all transfers for a Block from the network",
@@ -79,12 +86,21 @@ static OPEN_RPC_SCHEMA: Lazy<OpenRpcSchema> = Lazy::new(|| {
     );
     schema.push_with_params::<GetItem>("returns a stored value from the network");
     schema.push_with_params::<GetBalance>("returns a purse's balance from the network");
+    schema.push_with_params::<GetDictionaryItem>(
+        "returns an item from a Dictionary, identified by its seed URef and item key",
+    );
+    schema.push_with_params::<GetDiff>(
+        "returns the stored values which changed between two state root hashes",
+    );
     schema.push_with_optional_params::<GetEraInfoBySwitchBlock>(
         "returns an EraInfo from the network",
     );
     schema.push_without_params::<GetAuctionInfo>(
         "returns the bids and validators as of the most recently added Block",
     );
+    schema.push_with_params::<SpeculativeExec>(
+        "executes a Deploy on top of a Block's global state without committing its effects",
+    );
 
     schema
 });
@@ -15,7 +15,7 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
-use casper_types::ExecutionResult;
+use casper_types::{ExecutionResult, PublicKey, U512};
 
 use super::{
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
@@ -25,7 +25,9 @@ use super::{
 use crate::{
     effect::EffectBuilder,
     reactor::QueueKind,
-    types::{Block, BlockHash, Deploy, DeployHash, GetStatusResult, Item, PeersMap},
+    types::{
+        Block, BlockHash, Deploy, DeployHash, GetStatusResult, Item, PeersMap, TimeDiff, Timestamp,
+    },
 };
 
 static GET_DEPLOY_PARAMS: Lazy<GetDeployParams> = Lazy::new(|| GetDeployParams {
@@ -43,6 +45,22 @@ static GET_PEERS_RESULT: Lazy<GetPeersResult> = Lazy::new(|| GetPeersResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
     peers: GetStatusResult::doc_example().peers.clone(),
 });
+static GET_PENDING_DEPLOYS_RESULT: Lazy<GetPendingDeploysResult> = Lazy::new(|| {
+    let deploy = Deploy::doc_example();
+    let header = deploy.header();
+    GetPendingDeploysResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        pending_deploys: vec![JsonPendingDeploy {
+            deploy_hash: *deploy.id(),
+            account: header.account().clone(),
+            timestamp: header.timestamp(),
+            ttl: header.ttl(),
+            gas_price: header.gas_price(),
+            payment_amount: U512::from(1_000_000_000),
+            is_transfer: false,
+        }],
+    }
+});
 
 /// Params for "info_get_deploy" RPC request.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
@@ -196,6 +214,90 @@ impl RpcWithoutParamsExt for GetPeers {
     }
 }
 
+/// A deploy held in the block proposer's pool, awaiting inclusion in a block.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct JsonPendingDeploy {
+    /// The deploy hash.
+    pub deploy_hash: DeployHash,
+    /// The account which created the deploy.
+    pub account: PublicKey,
+    /// When the deploy was created.
+    pub timestamp: Timestamp,
+    /// How long the deploy is valid for.
+    pub ttl: TimeDiff,
+    /// The gas price offered by the deploy.
+    pub gas_price: u64,
+    /// The amount of motes the deploy pays for its execution.
+    pub payment_amount: U512,
+    /// Whether the deploy is a native transfer.
+    pub is_transfer: bool,
+}
+
+/// Result for "info_get_pending_deploys" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetPendingDeploysResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The pending deploys, in the order in which they will be considered for inclusion in a
+    /// block.
+    pub pending_deploys: Vec<JsonPendingDeploy>,
+}
+
+impl DocExample for GetPendingDeploysResult {
+    fn doc_example() -> &'static Self {
+        &*GET_PENDING_DEPLOYS_RESULT
+    }
+}
+
+/// "info_get_pending_deploys" RPC.
+pub struct GetPendingDeploys {}
+
+impl RpcWithoutParams for GetPendingDeploys {
+    const METHOD: &'static str = "info_get_pending_deploys";
+    type ResponseResult = GetPendingDeploysResult;
+}
+
+impl RpcWithoutParamsExt for GetPendingDeploys {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let pending_deploys = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetPendingDeploys { responder },
+                    QueueKind::Api,
+                )
+                .await
+                .into_iter()
+                .map(|(deploy_hash, deploy_type)| {
+                    let header = deploy_type.header();
+                    JsonPendingDeploy {
+                        deploy_hash,
+                        account: header.account().clone(),
+                        timestamp: header.timestamp(),
+                        ttl: header.ttl(),
+                        gas_price: header.gas_price(),
+                        payment_amount: deploy_type.payment_amount().value(),
+                        is_transfer: deploy_type.is_transfer(),
+                    }
+                })
+                .collect();
+
+            let result = Self::ResponseResult {
+                api_version,
+                pending_deploys,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
 /// "info_get_status" RPC.
 pub struct GetStatus {}
 
@@ -0,0 +1,158 @@
+//! RPC for executing a deploy without committing its effects to global state.
+
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use std::str;
+
+use futures::{future::BoxFuture, FutureExt};
+use http::Response;
+use hyper::Body;
+use once_cell::sync::Lazy;
+use schemars::JsonSchema;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use warp_json_rpc::Builder;
+
+use casper_types::ExecutionResult;
+
+use super::{
+    chain::{self, BlockIdentifier},
+    docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
+    Error, ErrorCode, ReactorEventT, RpcRequest, RpcWithParams, RpcWithParamsExt,
+};
+use crate::{
+    components::rpc_server::SpeculativeExecError,
+    effect::EffectBuilder,
+    reactor::QueueKind,
+    types::{Block, BlockHash, Deploy},
+};
+
+static SPECULATIVE_EXEC_PARAMS: Lazy<SpeculativeExecParams> = Lazy::new(|| SpeculativeExecParams {
+    deploy: Deploy::doc_example().clone(),
+    block_identifier: Some(BlockIdentifier::Hash(*Block::doc_example().hash())),
+});
+static SPECULATIVE_EXEC_RESULT: Lazy<SpeculativeExecResult> = Lazy::new(|| SpeculativeExecResult {
+    api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+    block_hash: *Block::doc_example().hash(),
+    execution_result: ExecutionResult::example().clone(),
+});
+
+/// Params for "speculative_exec" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SpeculativeExecParams {
+    /// The `Deploy` to execute.
+    pub deploy: Deploy,
+    /// The block on top of whose global state the deploy is executed.  If `None`, the highest
+    /// block is used.
+    #[serde(default)]
+    pub block_identifier: Option<BlockIdentifier>,
+}
+
+impl DocExample for SpeculativeExecParams {
+    fn doc_example() -> &'static Self {
+        &*SPECULATIVE_EXEC_PARAMS
+    }
+}
+
+/// Result for "speculative_exec" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SpeculativeExecResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The hash of the block on top of whose global state the deploy was executed.
+    pub block_hash: BlockHash,
+    /// The result of executing the deploy.
+    pub execution_result: ExecutionResult,
+}
+
+impl DocExample for SpeculativeExecResult {
+    fn doc_example() -> &'static Self {
+        &*SPECULATIVE_EXEC_RESULT
+    }
+}
+
+/// "speculative_exec" RPC
+pub struct SpeculativeExec {}
+
+impl RpcWithParams for SpeculativeExec {
+    const METHOD: &'static str = "speculative_exec";
+    type RequestParams = SpeculativeExecParams;
+    type ResponseResult = SpeculativeExecResult;
+}
+
+impl RpcWithParamsExt for SpeculativeExec {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let mut deploy = params.deploy;
+            let deploy_hash = *deploy.id();
+            if let Err(error) = deploy.is_valid() {
+                info!(%deploy_hash, %error, "invalid deploy submitted for speculative execution");
+                let error = warp_json_rpc::Error::custom(
+                    ErrorCode::InvalidDeploy as i64,
+                    error.to_string(),
+                );
+                return Ok(response_builder.error(error)?);
+            }
+
+            // Get the block on top of which to execute the deploy.
+            let maybe_block_id = params.block_identifier;
+            let block = match chain::get_block_with_metadata(maybe_block_id, effect_builder).await {
+                Ok(Some((block, _))) => block,
+                Ok(None) => {
+                    let error = warp_json_rpc::Error::custom(
+                        ErrorCode::NoSuchBlock as i64,
+                        "block not known",
+                    );
+                    return Ok(response_builder.error(error)?);
+                }
+                Err(error) => return Ok(response_builder.error(error)?),
+            };
+            let block_hash = *block.hash();
+
+            let speculative_exec_result = effect_builder
+                .make_request(
+                    |responder| RpcRequest::SpeculativeExec {
+                        block: Box::new(block),
+                        deploy: Box::new(deploy),
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            match speculative_exec_result {
+                Ok(execution_result) => {
+                    let result = Self::ResponseResult {
+                        api_version,
+                        block_hash,
+                        execution_result,
+                    };
+                    Ok(response_builder.success(result)?)
+                }
+                Err(error) => {
+                    info!(%deploy_hash, %block_hash, %error, "speculative execution failed");
+                    let error_code = match error {
+                        SpeculativeExecError::TooManyRequests => ErrorCode::TooManySpeculativeExecs,
+                        SpeculativeExecError::RootNotFound(_)
+                        | SpeculativeExecError::MissingExecutionResult => {
+                            ErrorCode::SpeculativeExecFailed
+                        }
+                    };
+                    let error = warp_json_rpc::Error::custom(error_code as i64, error.to_string());
+                    Ok(response_builder.error(error)?)
+                }
+            }
+        }
+        .boxed()
+    }
+}
@@ -3,7 +3,7 @@
 // TODO - remove once schemars stops causing warning.
 #![allow(clippy::field_reassign_with_default)]
 
-use std::str;
+use std::{convert::TryFrom, str};
 
 use futures::{future::BoxFuture, FutureExt};
 use http::Response;
@@ -15,7 +15,11 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
-use casper_execution_engine::core::engine_state::{BalanceResult, GetBidsResult};
+use casper_execution_engine::{
+    core::engine_state::{BalanceResult, GetBidsResult},
+    shared::stored_value::StoredValue as ExecutionEngineStoredValue,
+    storage::{global_state::DiffResult, trie_store::DiffEntry},
+};
 use casper_types::{bytesrepr::ToBytes, CLValue, Key, ProtocolVersion, URef, U512};
 
 use super::{
@@ -23,6 +27,7 @@ use super::{
     Error, ErrorCode, ReactorEventT, RpcRequest, RpcWithParams, RpcWithParamsExt,
 };
 use crate::{
+    components::rpc_server::GetDiffError,
     crypto::hash::Digest,
     effect::EffectBuilder,
     reactor::QueueKind,
@@ -32,7 +37,7 @@ use crate::{
     },
     types::{
         json_compatibility::{AuctionState, StoredValue},
-        Block,
+        Block, BlockHash, JsonSignedBlockHeader,
     },
 };
 
@@ -40,21 +45,57 @@ static GET_ITEM_PARAMS: Lazy<GetItemParams> = Lazy::new(|| GetItemParams {
     state_root_hash: *Block::doc_example().header().state_root_hash(),
     key: "deploy-af684263911154d26fa05be9963171802801a0b6aff8f199b7391eacb8edc9e1".to_string(),
     path: vec!["inner".to_string()],
+    block_hash: Some(*Block::doc_example().hash()),
 });
 static GET_ITEM_RESULT: Lazy<GetItemResult> = Lazy::new(|| GetItemResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
     stored_value: StoredValue::CLValue(CLValue::from_t(1u64).unwrap()),
     merkle_proof: MERKLE_PROOF.clone(),
+    signed_block_header: Some(JsonSignedBlockHeader::doc_example().clone()),
 });
 static GET_BALANCE_PARAMS: Lazy<GetBalanceParams> = Lazy::new(|| GetBalanceParams {
     state_root_hash: *Block::doc_example().header().state_root_hash(),
     purse_uref: "uref-09480c3248ef76b603d386f3f4f8a5f87f597d4eaffd475433f861af187ab5db-007"
         .to_string(),
+    block_hash: Some(*Block::doc_example().hash()),
 });
 static GET_BALANCE_RESULT: Lazy<GetBalanceResult> = Lazy::new(|| GetBalanceResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
     balance_value: U512::from(123_456),
     merkle_proof: MERKLE_PROOF.clone(),
+    signed_block_header: Some(JsonSignedBlockHeader::doc_example().clone()),
+});
+static GET_DICTIONARY_ITEM_PARAMS: Lazy<GetDictionaryItemParams> =
+    Lazy::new(|| GetDictionaryItemParams {
+        state_root_hash: *Block::doc_example().header().state_root_hash(),
+        dictionary_seed_uref:
+            "uref-09480c3248ef76b603d386f3f4f8a5f87f597d4eaffd475433f861af187ab5db-007".to_string(),
+        dictionary_item_key: "a_unique_entry_identifier".to_string(),
+    });
+static GET_DICTIONARY_ITEM_RESULT: Lazy<GetDictionaryItemResult> =
+    Lazy::new(|| GetDictionaryItemResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        dictionary_key:
+            "dictionary-67518854aa916c97d4e53df8570c8217ccc259da2721b692102d76acd0ee8d1f"
+                .to_string(),
+        stored_value: StoredValue::CLValue(CLValue::from_t(1u64).unwrap()),
+        merkle_proof: MERKLE_PROOF.clone(),
+    });
+static GET_DIFF_PARAMS: Lazy<GetDiffParams> = Lazy::new(|| GetDiffParams {
+    from_state_root_hash: *Block::doc_example().header().state_root_hash(),
+    to_state_root_hash: *Block::doc_example().header().state_root_hash(),
+    start_after: None,
+    limit: Some(100),
+});
+static GET_DIFF_RESULT: Lazy<GetDiffResult> = Lazy::new(|| GetDiffResult {
+    api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+    entries: vec![TrieDiffEntry {
+        key: "deploy-af684263911154d26fa05be9963171802801a0b6aff8f199b7391eacb8edc9e1".to_string(),
+        kind: TrieDiffKind::Modified,
+        old_value: Some(StoredValue::CLValue(CLValue::from_t(1u64).unwrap())),
+        new_value: Some(StoredValue::CLValue(CLValue::from_t(2u64).unwrap())),
+    }],
+    next_start_after: None,
 });
 static GET_AUCTION_INFO_RESULT: Lazy<GetAuctionInfoResult> = Lazy::new(|| GetAuctionInfoResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
@@ -72,6 +113,10 @@ pub struct GetItemParams {
     /// The path components starting from the key as base.
     #[serde(default)]
     pub path: Vec<String>,
+    /// The hash of the block whose state root hash is `state_root_hash`.  If provided, the block's
+    /// header and finality signatures are included in the result.
+    #[serde(default)]
+    pub block_hash: Option<BlockHash>,
 }
 
 impl DocExample for GetItemParams {
@@ -91,6 +136,8 @@ pub struct GetItemResult {
     pub stored_value: StoredValue,
     /// The merkle proof.
     pub merkle_proof: String,
+    /// The header and finality signatures of the requested block, if a block hash was provided.
+    pub signed_block_header: Option<JsonSignedBlockHeader>,
 }
 
 impl DocExample for GetItemResult {
@@ -130,6 +177,25 @@ impl RpcWithParamsExt for GetItem {
                 }
             };
 
+            // Get the requested block's header, if any.
+            let signed_block_header = match params.block_hash {
+                Some(block_hash) => match common::get_signed_block_header(
+                    effect_builder,
+                    block_hash,
+                    params.state_root_hash,
+                )
+                .await
+                {
+                    Ok(signed_block_header) => Some(signed_block_header),
+                    Err((error_code, error_msg)) => {
+                        info!("{}", error_msg);
+                        return Ok(response_builder
+                            .error(warp_json_rpc::Error::custom(error_code as i64, error_msg))?);
+                    }
+                },
+                None => None,
+            };
+
             // Run the query.
             let query_result = effect_builder
                 .make_request(
@@ -156,6 +222,7 @@ impl RpcWithParamsExt for GetItem {
                 api_version,
                 stored_value,
                 merkle_proof: hex::encode(proof_bytes),
+                signed_block_header,
             };
 
             Ok(response_builder.success(result)?)
@@ -172,6 +239,10 @@ pub struct GetBalanceParams {
     pub state_root_hash: Digest,
     /// Formatted URef.
     pub purse_uref: String,
+    /// The hash of the block whose state root hash is `state_root_hash`.  If provided, the block's
+    /// header and finality signatures are included in the result.
+    #[serde(default)]
+    pub block_hash: Option<BlockHash>,
 }
 
 impl DocExample for GetBalanceParams {
@@ -191,6 +262,8 @@ pub struct GetBalanceResult {
     pub balance_value: U512,
     /// The merkle proof.
     pub merkle_proof: String,
+    /// The header and finality signatures of the requested block, if a block hash was provided.
+    pub signed_block_header: Option<JsonSignedBlockHeader>,
 }
 
 impl DocExample for GetBalanceResult {
@@ -230,6 +303,25 @@ impl RpcWithParamsExt for GetBalance {
                 }
             };
 
+            // Get the requested block's header, if any.
+            let signed_block_header = match params.block_hash {
+                Some(block_hash) => match common::get_signed_block_header(
+                    effect_builder,
+                    block_hash,
+                    params.state_root_hash,
+                )
+                .await
+                {
+                    Ok(signed_block_header) => Some(signed_block_header),
+                    Err((error_code, error_msg)) => {
+                        info!("{}", error_msg);
+                        return Ok(response_builder
+                            .error(warp_json_rpc::Error::custom(error_code as i64, error_msg))?);
+                    }
+                },
+                None => None,
+            };
+
             // Get the balance.
             let balance_result = effect_builder
                 .make_request(
@@ -277,6 +369,324 @@ impl RpcWithParamsExt for GetBalance {
                 api_version,
                 balance_value,
                 merkle_proof,
+                signed_block_header,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
+/// Params for "state_get_dictionary_item" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDictionaryItemParams {
+    /// Hash of the state root.
+    pub state_root_hash: Digest,
+    /// The dictionary's seed URef as formatted string.
+    pub dictionary_seed_uref: String,
+    /// The key of the item within the dictionary.
+    pub dictionary_item_key: String,
+}
+
+impl DocExample for GetDictionaryItemParams {
+    fn doc_example() -> &'static Self {
+        &*GET_DICTIONARY_ITEM_PARAMS
+    }
+}
+
+/// Result for "state_get_dictionary_item" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDictionaryItemResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The `casper_types::Key` under which the item is stored, as formatted string.
+    pub dictionary_key: String,
+    /// The stored value.
+    pub stored_value: StoredValue,
+    /// The merkle proof.
+    pub merkle_proof: String,
+}
+
+impl DocExample for GetDictionaryItemResult {
+    fn doc_example() -> &'static Self {
+        &*GET_DICTIONARY_ITEM_RESULT
+    }
+}
+
+/// "state_get_dictionary_item" RPC.
+pub struct GetDictionaryItem {}
+
+impl RpcWithParams for GetDictionaryItem {
+    const METHOD: &'static str = "state_get_dictionary_item";
+    type RequestParams = GetDictionaryItemParams;
+    type ResponseResult = GetDictionaryItemResult;
+}
+
+impl RpcWithParamsExt for GetDictionaryItem {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            // Try to parse the dictionary's seed URef from the params.
+            let seed_uref = match URef::from_formatted_str(&params.dictionary_seed_uref)
+                .map_err(|error| format!("failed to parse dictionary_seed_uref: {:?}", error))
+            {
+                Ok(uref) => uref,
+                Err(error_msg) => {
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::ParseDictionarySeedURef as i64,
+                        error_msg,
+                    ))?);
+                }
+            };
+
+            let dictionary_key = Key::dictionary(seed_uref, params.dictionary_item_key.as_bytes());
+
+            // Run the query.
+            let query_result = effect_builder
+                .make_request(
+                    |responder| RpcRequest::QueryGlobalState {
+                        state_root_hash: params.state_root_hash,
+                        base_key: dictionary_key,
+                        path: vec![],
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let (stored_value, proof_bytes) = match common::extract_query_result(query_result) {
+                Ok(tuple) => tuple,
+                Err((error_code, error_msg)) => {
+                    info!("{}", error_msg);
+                    return Ok(response_builder
+                        .error(warp_json_rpc::Error::custom(error_code as i64, error_msg))?);
+                }
+            };
+
+            let result = Self::ResponseResult {
+                api_version,
+                dictionary_key: dictionary_key.to_formatted_string(),
+                stored_value,
+                merkle_proof: hex::encode(proof_bytes),
+            };
+
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
+/// Params for "state_get_diff" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDiffParams {
+    /// The hash of the state root to compare from.
+    pub from_state_root_hash: Digest,
+    /// The hash of the state root to compare to.
+    pub to_state_root_hash: Digest,
+    /// `casper_types::Key` as formatted string, after which to start returning entries.  Used to
+    /// request the next page of a diff.
+    #[serde(default)]
+    pub start_after: Option<String>,
+    /// The maximum number of entries to return.  Defaults to the node's configured maximum;
+    /// requesting more is an error.
+    #[serde(default)]
+    pub limit: Option<u64>,
+}
+
+impl DocExample for GetDiffParams {
+    fn doc_example() -> &'static Self {
+        &*GET_DIFF_PARAMS
+    }
+}
+
+/// The kind of change made to a stored value.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema)]
+pub enum TrieDiffKind {
+    /// The value was added.
+    Added,
+    /// The value was removed.
+    Removed,
+    /// The value was modified.
+    Modified,
+}
+
+/// A change made to a stored value between two state roots.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TrieDiffEntry {
+    /// `casper_types::Key` as formatted string.
+    pub key: String,
+    /// The kind of change.
+    pub kind: TrieDiffKind,
+    /// The stored value under the "from" state root, if any.
+    pub old_value: Option<StoredValue>,
+    /// The stored value under the "to" state root, if any.
+    pub new_value: Option<StoredValue>,
+}
+
+impl TryFrom<DiffEntry<Key, ExecutionEngineStoredValue>> for TrieDiffEntry {
+    type Error = String;
+
+    fn try_from(entry: DiffEntry<Key, ExecutionEngineStoredValue>) -> Result<Self, Self::Error> {
+        let to_json = |value: &ExecutionEngineStoredValue| {
+            StoredValue::try_from(value)
+                .map_err(|error| format!("failed to encode stored value: {:?}", error))
+        };
+        let (key, kind, old_value, new_value) = match entry {
+            DiffEntry::Added { key, value } => (key, TrieDiffKind::Added, None, Some(value)),
+            DiffEntry::Removed { key, value } => (key, TrieDiffKind::Removed, Some(value), None),
+            DiffEntry::Modified {
+                key,
+                old_value,
+                new_value,
+            } => (
+                key,
+                TrieDiffKind::Modified,
+                Some(old_value),
+                Some(new_value),
+            ),
+        };
+        Ok(TrieDiffEntry {
+            key: key.to_formatted_string(),
+            kind,
+            old_value: old_value.as_ref().map(to_json).transpose()?,
+            new_value: new_value.as_ref().map(to_json).transpose()?,
+        })
+    }
+}
+
+/// Result for "state_get_diff" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDiffResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The stored values which were added, removed or modified, ordered by their serialized keys.
+    pub entries: Vec<TrieDiffEntry>,
+    /// The `start_after` at which to request the next page, or `None` if this is the last page.
+    pub next_start_after: Option<String>,
+}
+
+impl DocExample for GetDiffResult {
+    fn doc_example() -> &'static Self {
+        &*GET_DIFF_RESULT
+    }
+}
+
+/// "state_get_diff" RPC.
+pub struct GetDiff {}
+
+impl RpcWithParams for GetDiff {
+    const METHOD: &'static str = "state_get_diff";
+    type RequestParams = GetDiffParams;
+    type ResponseResult = GetDiffResult;
+}
+
+impl RpcWithParamsExt for GetDiff {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            // Try to parse the `casper_types::Key` to start after, if any.
+            let start_after = match params
+                .start_after
+                .as_deref()
+                .map(Key::from_formatted_str)
+                .transpose()
+                .map_err(|error| format!("failed to parse start_after key: {:?}", error))
+            {
+                Ok(start_after) => start_after,
+                Err(error_msg) => {
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::ParseQueryKey as i64,
+                        error_msg,
+                    ))?);
+                }
+            };
+
+            // Compare the two state roots.
+            let diff_result = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetTrieDiff {
+                        from_state_root_hash: params.from_state_root_hash,
+                        to_state_root_hash: params.to_state_root_hash,
+                        start_after,
+                        limit: params.limit,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let (diff_entries, has_more) = match diff_result {
+                Ok(DiffResult::Success { entries, has_more }) => (entries, has_more),
+                Ok(DiffResult::RootNotFound(root)) => {
+                    let error_msg = format!("get-diff failed: state root {} not found", root);
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::GetDiffFailed as i64,
+                        error_msg,
+                    ))?);
+                }
+                Err(error @ GetDiffError::LimitExceeded { .. }) => {
+                    let error_msg = format!("get-diff failed: {}", error);
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::GetDiffLimitExceeded as i64,
+                        error_msg,
+                    ))?);
+                }
+                Err(GetDiffError::Engine(error)) => {
+                    let error_msg = format!("get-diff failed to execute: {}", error);
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::GetDiffFailedToExecute as i64,
+                        error_msg,
+                    ))?);
+                }
+            };
+
+            let next_start_after = if has_more {
+                diff_entries
+                    .last()
+                    .map(|entry| entry.key().to_formatted_string())
+            } else {
+                None
+            };
+
+            let entries = match diff_entries
+                .into_iter()
+                .map(TrieDiffEntry::try_from)
+                .collect::<Result<Vec<_>, _>>()
+            {
+                Ok(entries) => entries,
+                Err(error_msg) => {
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::GetDiffFailed as i64,
+                        error_msg,
+                    ))?);
+                }
+            };
+
+            let result = Self::ResponseResult {
+                api_version,
+                entries,
+                next_start_after,
             };
             Ok(response_builder.success(result)?)
         }
@@ -96,7 +96,7 @@ use crate::{
     },
     reactor::{EventQueueHandle, Finalize, QueueKind, ReactorEvent},
     tls::{self, TlsCert, ValidationError},
-    types::{NodeId, TimeDiff, Timestamp},
+    types::{NodeId, PeerConnectionState, TimeDiff, Timestamp},
     utils, NodeRng,
 };
 use chain_info::ChainInfo;
@@ -871,6 +871,31 @@ where
         ret
     }
 
+    /// Returns the state of the connections to each connected peer.
+    pub(crate) fn peer_connection_states(&self) -> BTreeMap<NodeId, PeerConnectionState> {
+        let mut ret = BTreeMap::new();
+        for (node_id, connection) in &self.outgoing {
+            ret.insert(
+                node_id.clone(),
+                PeerConnectionState {
+                    address: connection.peer_address.to_string(),
+                    incoming: false,
+                    outgoing: true,
+                },
+            );
+        }
+        for (node_id, connection) in &self.incoming {
+            ret.entry(node_id.clone())
+                .or_insert_with(|| PeerConnectionState {
+                    address: connection.peer_address.to_string(),
+                    incoming: false,
+                    outgoing: false,
+                })
+                .incoming = true;
+        }
+        ret
+    }
+
     /// Returns whether or not this node has been disconnected from all known nodes.
     fn is_not_connected_to_any_known_address(&self) -> bool {
         for &known_address in &self.known_addresses {
@@ -1031,6 +1056,9 @@ where
                 NetworkInfoRequest::GetPeers { responder } => {
                     responder.respond(self.peers()).ignore()
                 }
+                NetworkInfoRequest::GetPeerConnectionStates { responder } => {
+                    responder.respond(self.peer_connection_states()).ignore()
+                }
             },
             Event::GossipOurAddress => {
                 let mut effects = self.gossip_our_address(effect_builder);
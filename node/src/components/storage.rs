@@ -7,8 +7,9 @@
 //! * storing and loading deploys,
 //! * [temporary until refactored] holding `DeployMetadata` for each deploy,
 //! * holding a read-only copy of the chainspec,
-//! * keeping an index of blocks by height and
-//! * [unimplemented] managing disk usage by pruning blocks and deploys from storage.
+//! * keeping an index of blocks by height,
+//! * keeping an index of executed deploys by the account that sent them and
+//! * optionally managing disk usage by pruning blocks and deploys of old eras from storage.
 //!
 //! Any I/O performed by the component is done on the event handling thread, this is on purpose as
 //! the assumption is that caching by LMDB will offset any gains from offloading it onto a separate
@@ -26,10 +27,23 @@
 //! * Storing a deploy or block that already exists (same hash) is fine and will silently be
 //!   accepted.
 //!
+//! ## Pruning
+//!
+//! If configured to retain only a number of eras, the storage removes the bodies, deploys,
+//! transfers and execution results of blocks from older eras. Headers are removed as well, except
+//! for those of switch blocks, which are always kept. Pruning is done incrementally: every time a
+//! block is stored, at most a configured number of the oldest prunable blocks are removed.
+//!
+//! All heights below the lowest unpruned block are considered pruned, which allows distinguishing
+//! pruned blocks from unknown ones when queried by height.
+//!
 //! ## Indices
 //!
-//! The current implementation keeps only in-memory indices, which are not persisted, based upon the
-//! estimate that they are reasonably quick to rebuild on start-up and do not take up much memory.
+//! The block indices are kept only in-memory and are not persisted, based upon the estimate that
+//! they are reasonably quick to rebuild on start-up and do not take up much memory.
+//!
+//! The account deploy index is too large to be kept in memory. It is stored in its own database,
+//! maintained whenever execution results are stored and rebuilt from the block store on start-up.
 //!
 //! ## Errors
 //!
@@ -41,9 +55,10 @@ mod lmdb_ext;
 mod tests;
 
 #[cfg(test)]
-use std::{collections::BTreeSet, convert::TryFrom};
+use std::collections::BTreeSet;
 use std::{
     collections::{btree_map::Entry, BTreeMap},
+    convert::{TryFrom, TryInto},
     fmt::{self, Display, Formatter},
     fs, io, mem,
     path::PathBuf,
@@ -52,7 +67,8 @@ use std::{
 use datasize::DataSize;
 use derive_more::From;
 use lmdb::{
-    Cursor, Database, DatabaseFlags, Environment, EnvironmentFlags, Transaction, WriteFlags,
+    Cursor, Database, DatabaseFlags, Environment, EnvironmentFlags, RwTransaction, Transaction,
+    WriteFlags,
 };
 use semver::Version;
 use serde::{Deserialize, Serialize};
@@ -63,10 +79,9 @@ use thiserror::Error;
 use tracing::{debug, error, info};
 
 use super::Component;
-#[cfg(test)]
-use crate::crypto::hash::Digest;
 use crate::{
     components::consensus::EraId,
+    crypto::hash::Digest,
     effect::{
         requests::{StateStoreRequest, StorageRequest},
         EffectBuilder, EffectExt, Effects,
@@ -74,14 +89,17 @@ use crate::{
     fatal,
     reactor::ReactorEvent,
     types::{
-        Block, BlockBody, BlockHash, BlockHeader, BlockSignatures, Deploy, DeployHash,
-        DeployHeader, DeployMetadata, TimeDiff,
+        AccountDeploy, Block, BlockBody, BlockHash, BlockHeader, BlockIdentifier, BlockSignatures,
+        Deploy, DeployHash, DeployHeader, DeployMetadata, TimeDiff,
     },
     utils::WithDir,
     NodeRng,
 };
 use casper_execution_engine::shared::newtypes::Blake2bHash;
-use casper_types::{ExecutionResult, ProtocolVersion, Transfer, Transform};
+use casper_types::{
+    account::AccountHash, bytesrepr::U64_SERIALIZED_LENGTH, ExecutionResult, ProtocolVersion,
+    Transfer, Transform,
+};
 use lmdb_ext::{LmdbExtError, TransactionExt, WriteTransactionExt};
 
 /// Filename for the LMDB database created by the Storage component.
@@ -102,8 +120,21 @@ const DEFAULT_MAX_DEPLOY_STORE_SIZE: usize = 300 * GIB;
 const DEFAULT_MAX_DEPLOY_METADATA_STORE_SIZE: usize = 300 * GIB;
 /// Default max state store size.
 const DEFAULT_MAX_STATE_STORE_SIZE: usize = 10 * GIB;
+/// Default maximum number of blocks pruned after storing a block.
+const DEFAULT_PRUNING_BATCH_SIZE: usize = 10;
 /// Maximum number of allowed dbs.
-const MAX_DB_COUNT: u32 = 7;
+const MAX_DB_COUNT: u32 = 8;
+/// Key in the state store database under which the height below which all blocks have been pruned
+/// is stored.
+const PRUNED_BELOW_HEIGHT_KEY: &[u8] = b"storage_pruned_below_height";
+/// Key in the state store database under which the version of the account deploys index is stored.
+const ACCOUNT_DEPLOYS_INDEX_VERSION_KEY: &[u8] = b"storage_account_deploys_index_version";
+/// Version of the layout of the account deploys index. Changing it causes the index to be rebuilt
+/// from the block store at the next startup.
+const ACCOUNT_DEPLOYS_INDEX_VERSION: u32 = 1;
+/// Length of a single entry in the account deploys database: the big-endian block height, followed
+/// by the deploy hash and the block hash.
+const ACCOUNT_DEPLOY_ENTRY_LENGTH: usize = U64_SERIALIZED_LENGTH + 2 * Digest::LENGTH;
 
 /// OS-specific lmdb flags.
 #[cfg(not(target_os = "macos"))]
@@ -126,6 +157,8 @@ pub enum Event {
     /// Incoming state storage request.
     #[from]
     StateStoreRequest(StateStoreRequest),
+    /// Prune the oldest blocks which are not in one of the retained eras.
+    Prune,
 }
 
 /// A storage component initialization error.
@@ -194,10 +227,22 @@ pub struct Storage {
     /// The state storage database.
     #[data_size(skip)]
     state_store_db: Database,
+    /// The account deploys database, holding an entry for each executed deploy under the account
+    /// hash of its sender.
+    #[data_size(skip)]
+    account_deploys_db: Database,
     /// A map of block height to block ID.
+    ///
+    /// Entries of pruned switch blocks are retained, as their headers are kept.
     block_height_index: BTreeMap<u64, BlockHash>,
     /// A map of era ID to switch block ID.
     switch_block_era_id_index: BTreeMap<EraId, BlockHash>,
+    /// The number of most recent eras whose blocks are not pruned, if pruning is enabled.
+    retained_eras: Option<u64>,
+    /// The maximum number of blocks pruned after storing a block.
+    pruning_batch_size: usize,
+    /// The height below which all blocks have been pruned.
+    pruned_below_height: u64,
 }
 
 impl<REv> Component<REv> for Storage
@@ -214,10 +259,11 @@ where
         event: Self::Event,
     ) -> Effects<Self::Event> {
         let result = match event {
-            Event::StorageRequest(req) => self.handle_storage_request::<REv>(req),
+            Event::StorageRequest(req) => self.handle_storage_request::<REv>(effect_builder, req),
             Event::StateStoreRequest(req) => {
                 self.handle_state_store_request::<REv>(effect_builder, req)
             }
+            Event::Prune => self.prune().map(|()| Effects::new()),
         };
 
         // Any error is turned into a fatal effect, the component itself does not panic. Note that
@@ -273,6 +319,9 @@ impl Storage {
         let transfer_db = env.create_db(Some("transfer"), DatabaseFlags::empty())?;
         let state_store_db = env.create_db(Some("state_store"), DatabaseFlags::empty())?;
         let block_body_db = env.create_db(Some("block_body"), DatabaseFlags::empty())?;
+        // Entries are sorted duplicates under a single account hash key, so that they are ordered
+        // by block height.
+        let account_deploys_db = env.create_db(Some("account_deploys"), DatabaseFlags::DUP_SORT)?;
 
         // We now need to restore the block-height index. Log messages allow timing here.
         info!("reindexing block store");
@@ -280,6 +329,7 @@ impl Storage {
         let mut switch_block_era_id_index = BTreeMap::new();
         let mut block_txn = env.begin_rw_txn()?;
         let mut cursor = block_txn.open_rw_cursor(block_header_db)?;
+        let mut removed_blocks = false;
 
         // Note: `iter_start` has an undocumented panic if called on an empty database. We rely on
         //       the iterator being at the start when created.
@@ -296,6 +346,7 @@ impl Storage {
                 // reverted.
                 if block.era_id() >= invalid_era && block.protocol_version() < protocol_version {
                     cursor.del(WriteFlags::empty())?;
+                    removed_blocks = true;
                     continue;
                 }
             }
@@ -321,6 +372,29 @@ impl Storage {
         // Check the integrity of the block metadata database.
         check_block_metadata_db(&env, &block_metadata_db)?;
 
+        // Restore the pruning progress.
+        let pruned_below_height = env
+            .begin_ro_txn()?
+            .get_value(state_store_db, &PRUNED_BELOW_HEIGHT_KEY)?
+            .unwrap_or_default();
+
+        // Rebuild the account deploys index if it was created by a different version of the node
+        // or has just been created, or if blocks have been removed above.
+        let account_deploys_index_version: Option<u32> = env
+            .begin_ro_txn()?
+            .get_value(state_store_db, &ACCOUNT_DEPLOYS_INDEX_VERSION_KEY)?;
+        if removed_blocks || account_deploys_index_version != Some(ACCOUNT_DEPLOYS_INDEX_VERSION) {
+            reindex_account_deploys(
+                &env,
+                &block_header_db,
+                &block_body_db,
+                &deploy_db,
+                &state_store_db,
+                &account_deploys_db,
+                &block_height_index,
+            )?;
+        }
+
         Ok(Storage {
             root,
             env,
@@ -331,8 +405,14 @@ impl Storage {
             deploy_metadata_db,
             transfer_db,
             state_store_db,
+            account_deploys_db,
             block_height_index,
             switch_block_era_id_index,
+            retained_eras: config.retained_eras,
+            pruning_batch_size: config
+                .pruning_batch_size
+                .unwrap_or(DEFAULT_PRUNING_BATCH_SIZE),
+            pruned_below_height,
         })
     }
 
@@ -404,7 +484,11 @@ impl Storage {
     }
 
     /// Handles a storage request.
-    fn handle_storage_request<REv>(&mut self, req: StorageRequest) -> Result<Effects<Event>, Error>
+    fn handle_storage_request<REv>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        req: StorageRequest,
+    ) -> Result<Effects<Event>, Error>
     where
         Self: Component<REv>,
     {
@@ -435,7 +519,12 @@ impl Storage {
                     &mut self.switch_block_era_id_index,
                     block.header(),
                 )?;
-                responder.respond(true).ignore()
+                let mut effects = responder.respond(true).ignore();
+                // Pruning is done in a separate event, so it does not delay the response.
+                if self.retained_eras.is_some() {
+                    effects.extend(effect_builder.immediately().event(|()| Event::Prune));
+                }
+                effects
             }
             StorageRequest::GetBlock {
                 block_hash,
@@ -520,7 +609,30 @@ impl Storage {
 
                 let mut transfers: Vec<Transfer> = vec![];
 
+                // The block height is required for the account deploys index. If the block has not
+                // been stored yet, the index entries are created during the next reindex instead.
+                let maybe_block_height = txn
+                    .get_value::<_, BlockHeader>(self.block_header_db, &*block_hash)?
+                    .map(|block_header| block_header.height());
+
                 for (deploy_hash, execution_result) in execution_results {
+                    if let Some(block_height) = maybe_block_height {
+                        if let Some(deploy_header) =
+                            self.get_deploy_header(&mut txn, &deploy_hash)?
+                        {
+                            put_account_deploy(
+                                &mut txn,
+                                self.account_deploys_db,
+                                &deploy_header.account().to_account_hash(),
+                                &AccountDeploy {
+                                    block_height,
+                                    deploy_hash,
+                                    block_hash: *block_hash,
+                                },
+                            )?;
+                        }
+                    }
+
                     let mut metadata = self
                         .get_deploy_metadata(&mut txn, &deploy_hash)?
                         .unwrap_or_default();
@@ -690,6 +802,35 @@ impl Storage {
             StorageRequest::GetFinalizedDeploys { ttl, responder } => {
                 responder.respond(self.get_finalized_deploys(ttl)?).ignore()
             }
+            StorageRequest::IsBlockPruned {
+                block_identifier,
+                responder,
+            } => {
+                let is_pruned = match block_identifier {
+                    BlockIdentifier::Hash(block_hash) => {
+                        let mut txn = self.env.begin_ro_txn()?;
+                        match txn.get_value::<_, BlockHeader>(self.block_header_db, &block_hash)? {
+                            Some(block_header) => block_header.height() < self.pruned_below_height,
+                            None => false,
+                        }
+                    }
+                    BlockIdentifier::Height(height) => height < self.pruned_below_height,
+                };
+                responder.respond(is_pruned).ignore()
+            }
+            StorageRequest::GetAccountDeploys {
+                account_hash,
+                offset,
+                limit,
+                responder,
+            } => responder
+                .respond(self.get_account_deploys(
+                    &mut self.env.begin_ro_txn()?,
+                    &account_hash,
+                    offset,
+                    limit,
+                )?)
+                .ignore(),
         })
     }
 
@@ -812,6 +953,24 @@ impl Storage {
         Some(blake_hashes)
     }
 
+    /// Retrieves the state root hashes of the `count` highest blocks in storage.
+    pub(crate) fn get_recent_state_root_hashes(
+        &self,
+        count: usize,
+    ) -> Result<Vec<Blake2bHash>, Error> {
+        let mut txn = self.env.begin_ro_txn()?;
+        let mut state_root_hashes = Vec::with_capacity(count);
+        for block_hash in self.block_height_index.values().rev().take(count) {
+            let header: BlockHeader = match txn.get_value(self.block_header_db, block_hash)? {
+                Some(header) => header,
+                None => continue,
+            };
+            state_root_hashes.push(Blake2bHash::from(*header.state_root_hash()));
+        }
+        txn.commit()?;
+        Ok(state_root_hashes)
+    }
+
     /// Retrieves a single block in a separate transaction from storage.
     fn get_single_block<Tx: Transaction>(
         &self,
@@ -891,6 +1050,150 @@ impl Storage {
         Ok(tx.get_value(self.transfer_db, block_hash)?)
     }
 
+    /// Prunes the oldest blocks which are not in one of the retained eras.
+    ///
+    /// At most `pruning_batch_size` blocks are pruned per call, which is triggered by an
+    /// `Event::Prune` scheduled after each stored block. Does nothing if pruning is
+    /// disabled.
+    fn prune(&mut self) -> Result<(), Error> {
+        let retained_eras = match self.retained_eras {
+            Some(retained_eras) => retained_eras,
+            None => return Ok(()),
+        };
+        let mut txn = self.env.begin_rw_txn()?;
+        let highest_era_id = match self.get_highest_block_header(&mut txn)? {
+            Some(block_header) => block_header.era_id(),
+            None => return Ok(()),
+        };
+        // The era of the highest block always counts as retained.
+        let first_retained_era_id = EraId(
+            highest_era_id
+                .0
+                .saturating_sub(retained_eras.saturating_sub(1)),
+        );
+
+        let candidates: Vec<(u64, BlockHash)> = self
+            .block_height_index
+            .range(self.pruned_below_height..)
+            .take(self.pruning_batch_size)
+            .map(|(height, block_hash)| (*height, *block_hash))
+            .collect();
+        let mut pruned_below_height = self.pruned_below_height;
+        let mut removed_heights = vec![];
+        for (height, block_hash) in candidates {
+            let block_header: BlockHeader =
+                match txn.get_value(self.block_header_db, &block_hash)? {
+                    Some(block_header) => block_header,
+                    None => break,
+                };
+            if block_header.era_id() >= first_retained_era_id {
+                break;
+            }
+            self.prune_block(&mut txn, &block_header)?;
+            if !block_header.is_switch_block() {
+                removed_heights.push(height);
+            }
+            pruned_below_height = height + 1;
+        }
+
+        if pruned_below_height == self.pruned_below_height {
+            return Ok(());
+        }
+        txn.put_value(
+            self.state_store_db,
+            &PRUNED_BELOW_HEIGHT_KEY,
+            &pruned_below_height,
+            true,
+        )?;
+        txn.commit()?;
+        for height in removed_heights {
+            self.block_height_index.remove(&height);
+        }
+        debug!(%pruned_below_height, "pruned blocks");
+        self.pruned_below_height = pruned_below_height;
+        Ok(())
+    }
+
+    /// Removes the body, deploys, transfers and execution results of a block. Its header and
+    /// finality signatures are removed as well, unless it is a switch block.
+    fn prune_block(
+        &self,
+        txn: &mut RwTransaction,
+        block_header: &BlockHeader,
+    ) -> Result<(), LmdbExtError> {
+        let block_hash = block_header.hash();
+        if let Some(block_body) =
+            txn.get_value::<_, BlockBody>(self.block_body_db, block_header.body_hash())?
+        {
+            for deploy_hash in block_body
+                .deploy_hashes()
+                .iter()
+                .chain(block_body.transfer_hashes())
+            {
+                if let Some(deploy) = txn.get_value::<_, Deploy>(self.deploy_db, deploy_hash)? {
+                    del_account_deploy(
+                        txn,
+                        self.account_deploys_db,
+                        &deploy.header().account().to_account_hash(),
+                        &AccountDeploy {
+                            block_height: block_header.height(),
+                            deploy_hash: *deploy_hash,
+                            block_hash,
+                        },
+                    )?;
+                }
+                txn.del_value(self.deploy_db, deploy_hash)?;
+                txn.del_value(self.deploy_metadata_db, deploy_hash)?;
+            }
+            // Bodies are stored under their hash, so blocks without any deploys proposed by the
+            // same validator share a body. These are small, so we simply keep them.
+            if !block_body.deploy_hashes().is_empty() || !block_body.transfer_hashes().is_empty() {
+                txn.del_value(self.block_body_db, block_header.body_hash())?;
+            }
+        }
+        txn.del_value(self.transfer_db, &block_hash)?;
+        if !block_header.is_switch_block() {
+            txn.del_value(self.block_metadata_db, &block_hash)?;
+            txn.del_value(self.block_header_db, &block_hash)?;
+        }
+        Ok(())
+    }
+
+    /// Retrieves the header of the highest block from the storage, if one exists.
+    fn get_highest_block_header<Tx: Transaction>(
+        &self,
+        txn: &mut Tx,
+    ) -> Result<Option<BlockHeader>, LmdbExtError> {
+        self.block_height_index
+            .values()
+            .last()
+            .map(|block_hash| txn.get_value(self.block_header_db, block_hash))
+            .transpose()
+            .map(Option::flatten)
+    }
+
+    /// Retrieves a page of the deploys sent by the given account, ordered by block height.
+    ///
+    /// Skips the first `offset` entries and returns at most `limit` entries.
+    fn get_account_deploys<Tx: Transaction>(
+        &self,
+        txn: &mut Tx,
+        account_hash: &AccountHash,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<AccountDeploy>, LmdbExtError> {
+        let mut cursor = txn.open_ro_cursor(self.account_deploys_db)?;
+        let iter = match cursor.iter_dup_of(account_hash) {
+            Ok(iter) => iter,
+            Err(lmdb::Error::NotFound) => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+        iter.skip(offset as usize)
+            .take(limit as usize)
+            .map(|(_, raw_val)| parse_account_deploy(raw_val))
+            .collect()
+    }
+
     /// Retrieves finality signatures for a block with a given block hash
     fn get_finality_signatures<Tx: Transaction>(
         &self,
@@ -971,6 +1274,15 @@ pub struct Config {
     ///
     /// The size should be a multiple of the OS page size.
     max_state_store_size: usize,
+    /// The number of most recent eras for which block bodies, deploys, transfers and execution
+    /// results are retained. Switch block headers are always retained.
+    ///
+    /// If unset, nothing is ever pruned.
+    retained_eras: Option<u64>,
+    /// The maximum number of blocks pruned each time a new block is stored.
+    ///
+    /// Defaults to 10.
+    pruning_batch_size: Option<usize>,
 }
 
 impl Default for Config {
@@ -982,11 +1294,18 @@ impl Default for Config {
             max_deploy_store_size: DEFAULT_MAX_DEPLOY_STORE_SIZE,
             max_deploy_metadata_store_size: DEFAULT_MAX_DEPLOY_METADATA_STORE_SIZE,
             max_state_store_size: DEFAULT_MAX_STATE_STORE_SIZE,
+            retained_eras: None,
+            pruning_batch_size: None,
         }
     }
 }
 
 impl Config {
+    /// Returns the number of most recent eras which are retained, if pruning is enabled.
+    pub(crate) fn retained_eras(&self) -> Option<u64> {
+        self.retained_eras
+    }
+
     /// Returns a default `Config` suitable for tests, along with a `TempDir` which must be kept
     /// alive for the duration of the test since its destructor removes the dir from the filesystem.
     #[cfg(test)]
@@ -1007,6 +1326,7 @@ impl Display for Event {
         match self {
             Event::StorageRequest(req) => req.fmt(f),
             Event::StateStoreRequest(req) => req.fmt(f),
+            Event::Prune => write!(f, "prune"),
         }
     }
 }
@@ -1146,3 +1466,125 @@ fn check_block_metadata_db(
     info!("Check for block_metadata_db complete");
     Ok(())
 }
+
+/// Utility function to rebuild the account deploys database from the block store at bringup, and
+/// record the version of the rebuilt index.
+///
+/// Only deploys that are present in the deploy database are indexed.
+fn reindex_account_deploys(
+    env: &Environment,
+    block_header_db: &Database,
+    block_body_db: &Database,
+    deploy_db: &Database,
+    state_store_db: &Database,
+    account_deploys_db: &Database,
+    block_height_index: &BTreeMap<u64, BlockHash>,
+) -> Result<(), LmdbExtError> {
+    info!("reindexing account deploys");
+    let mut txn = env.begin_rw_txn()?;
+    txn.clear_db(*account_deploys_db)?;
+
+    for (&block_height, block_hash) in block_height_index {
+        let block_header: BlockHeader = match txn.get_value(*block_header_db, block_hash)? {
+            Some(block_header) => block_header,
+            None => continue,
+        };
+        let block_body: BlockBody = match txn.get_value(*block_body_db, block_header.body_hash())? {
+            Some(block_body) => block_body,
+            None => continue,
+        };
+        for deploy_hash in block_body
+            .deploy_hashes()
+            .iter()
+            .chain(block_body.transfer_hashes())
+        {
+            let deploy: Deploy = match txn.get_value(*deploy_db, deploy_hash)? {
+                Some(deploy) => deploy,
+                None => continue,
+            };
+            put_account_deploy(
+                &mut txn,
+                *account_deploys_db,
+                &deploy.header().account().to_account_hash(),
+                &AccountDeploy {
+                    block_height,
+                    deploy_hash: *deploy_hash,
+                    block_hash: *block_hash,
+                },
+            )?;
+        }
+    }
+
+    txn.put_value(
+        *state_store_db,
+        &ACCOUNT_DEPLOYS_INDEX_VERSION_KEY,
+        &ACCOUNT_DEPLOYS_INDEX_VERSION,
+        true,
+    )?;
+    txn.commit()?;
+    info!("account deploys reindexing complete");
+    Ok(())
+}
+
+/// Adds an entry to the account deploys database.
+///
+/// Adding an already existing entry is a no-op.
+fn put_account_deploy(
+    txn: &mut RwTransaction,
+    account_deploys_db: Database,
+    account_hash: &AccountHash,
+    account_deploy: &AccountDeploy,
+) -> Result<(), LmdbExtError> {
+    match txn.put(
+        account_deploys_db,
+        account_hash,
+        &account_deploy_entry(account_deploy),
+        WriteFlags::NO_DUP_DATA,
+    ) {
+        Ok(()) | Err(lmdb::Error::KeyExist) => Ok(()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Removes an entry from the account deploys database.
+///
+/// Removing a non-existent entry is a no-op.
+fn del_account_deploy(
+    txn: &mut RwTransaction,
+    account_deploys_db: Database,
+    account_hash: &AccountHash,
+    account_deploy: &AccountDeploy,
+) -> Result<(), LmdbExtError> {
+    let entry = account_deploy_entry(account_deploy);
+    match txn.del(account_deploys_db, account_hash, Some(entry.as_slice())) {
+        Ok(()) | Err(lmdb::Error::NotFound) => Ok(()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Encodes an entry of the account deploys database.
+fn account_deploy_entry(account_deploy: &AccountDeploy) -> Vec<u8> {
+    let mut entry = Vec::with_capacity(ACCOUNT_DEPLOY_ENTRY_LENGTH);
+    // The height is stored big-endian, as LMDB sorts duplicates lexicographically.
+    entry.extend_from_slice(&account_deploy.block_height.to_be_bytes());
+    entry.extend_from_slice(account_deploy.deploy_hash.as_ref());
+    entry.extend_from_slice(account_deploy.block_hash.as_ref());
+    entry
+}
+
+/// Parses an entry of the account deploys database.
+fn parse_account_deploy(raw_val: &[u8]) -> Result<AccountDeploy, LmdbExtError> {
+    if raw_val.len() != ACCOUNT_DEPLOY_ENTRY_LENGTH {
+        return Err(LmdbExtError::DataCorrupted(Box::new(
+            lmdb::Error::BadValSize,
+        )));
+    }
+    let (height_bytes, remainder) = raw_val.split_at(U64_SERIALIZED_LENGTH);
+    let (deploy_hash_bytes, block_hash_bytes) = remainder.split_at(Digest::LENGTH);
+    let corrupted = |err| LmdbExtError::DataCorrupted(Box::new(err));
+    Ok(AccountDeploy {
+        block_height: u64::from_be_bytes(height_bytes.try_into().map_err(corrupted)?),
+        deploy_hash: DeployHash::new(Digest::try_from(deploy_hash_bytes).map_err(corrupted)?),
+        block_hash: BlockHash::new(Digest::try_from(block_hash_bytes).map_err(corrupted)?),
+    })
+}
@@ -2,22 +2,29 @@
 
 use std::{borrow::Cow, collections::HashMap};
 
+use lmdb::Transaction;
 use rand::{prelude::SliceRandom, Rng};
 use semver::Version;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use smallvec::smallvec;
 
-use casper_types::ExecutionResult;
+use casper_types::{account::AccountHash, ExecutionResult, ProtocolVersion, PublicKey, SecretKey};
 
-use super::{Config, Storage};
+use super::{
+    lmdb_ext::WriteTransactionExt, Config, Event, Storage, ACCOUNT_DEPLOYS_INDEX_VERSION_KEY,
+};
 use crate::{
     components::consensus::EraId,
+    crypto::{hash::Digest, AsymmetricKeyExt},
     effect::{
         requests::{StateStoreRequest, StorageRequest},
         Multiple,
     },
     testing::{ComponentHarness, TestRng, UnitTestEvent},
-    types::{Block, BlockHash, Deploy, DeployHash, DeployMetadata},
+    types::{
+        AccountDeploy, Block, BlockHash, BlockIdentifier, Deploy, DeployHash, DeployMetadata,
+        FinalizedBlock, ProtoBlock, TimeDiff, Timestamp,
+    },
     utils::WithDir,
 };
 
@@ -31,6 +38,8 @@ fn new_config(harness: &ComponentHarness<UnitTestEvent>) -> Config {
         max_deploy_store_size: 50 * MIB,
         max_deploy_metadata_store_size: 50 * MIB,
         max_state_store_size: 50 * MIB,
+        retained_eras: None,
+        pruning_batch_size: None,
     }
 }
 
@@ -71,6 +80,30 @@ fn storage_fixture_with_hard_reset(
     .expect("could not create storage component fixture")
 }
 
+/// Storage component test fixture.
+///
+/// Creates a storage component in a temporary directory, which prunes all blocks that are not in
+/// one of the given number of most recent eras.
+///
+/// # Panics
+///
+/// Panics if setting up the storage fixture fails.
+fn storage_fixture_with_pruning(
+    harness: &ComponentHarness<UnitTestEvent>,
+    retained_eras: u64,
+) -> Storage {
+    let cfg = Config {
+        retained_eras: Some(retained_eras),
+        ..new_config(harness)
+    };
+    Storage::new(
+        &WithDir::new(harness.tmp.path(), cfg),
+        None,
+        Version::new(1, 0, 0),
+    )
+    .expect("could not create storage component fixture")
+}
+
 /// Creates a random block with a specific block height.
 fn random_block_at_height(rng: &mut TestRng, height: u64) -> Box<Block> {
     let mut block = Box::new(Block::random(rng));
@@ -78,6 +111,47 @@ fn random_block_at_height(rng: &mut TestRng, height: u64) -> Box<Block> {
     block
 }
 
+/// Creates a random deploy signed by the given secret key.
+fn random_deploy_from(rng: &mut TestRng, secret_key: &SecretKey) -> Deploy {
+    Deploy::new(
+        Timestamp::random(rng),
+        TimeDiff::from(rng.gen_range(60_000..3_600_000)),
+        rng.gen_range(1..100),
+        vec![],
+        String::from("casper-example"),
+        rng.gen(),
+        rng.gen(),
+        secret_key,
+    )
+}
+
+/// Creates a random non-switch block in the given era and at the given height which contains the
+/// given deploys.
+fn random_block_with_deploys(
+    rng: &mut TestRng,
+    era_id: EraId,
+    height: u64,
+    deploys: &[&Deploy],
+) -> Box<Block> {
+    let deploy_hashes = deploys.iter().map(|deploy| *deploy.id()).collect();
+    let proto_block = ProtoBlock::new(deploy_hashes, vec![], Timestamp::now(), rng.gen());
+    let finalized_block = FinalizedBlock::new(
+        proto_block,
+        None,
+        era_id,
+        height,
+        PublicKey::from(&SecretKey::random(rng)),
+    );
+    Box::new(Block::new(
+        BlockHash::new(Digest::random(rng)),
+        Digest::random(rng),
+        Digest::random(rng),
+        finalized_block,
+        None,
+        ProtocolVersion::V1_0_0,
+    ))
+}
+
 /// Requests block at a specific height from a storage component.
 fn get_block_at_height(
     harness: &mut ComponentHarness<UnitTestEvent>,
@@ -185,6 +259,13 @@ fn put_block(
     response
 }
 
+/// Prunes old blocks in a storage component, as scheduled after storing a block.
+fn prune(harness: &mut ComponentHarness<UnitTestEvent>, storage: &mut Storage) {
+    let effects = harness.send_event(storage, Event::Prune);
+    assert!(effects.is_empty());
+    assert!(harness.is_idle());
+}
+
 /// Stores a deploy in a storage component.
 fn put_deploy(
     harness: &mut ComponentHarness<UnitTestEvent>,
@@ -217,6 +298,63 @@ fn put_execution_results(
     response
 }
 
+/// Requests a page of the deploys sent by an account from a storage component.
+fn get_account_deploys(
+    harness: &mut ComponentHarness<UnitTestEvent>,
+    storage: &mut Storage,
+    account_hash: AccountHash,
+    offset: u64,
+    limit: u64,
+) -> Vec<AccountDeploy> {
+    let response = harness.send_request(storage, move |responder| {
+        StorageRequest::GetAccountDeploys {
+            account_hash,
+            offset,
+            limit,
+            responder,
+        }
+        .into()
+    });
+    assert!(harness.is_idle());
+    response
+}
+
+/// Checks whether a block has been pruned by a storage component.
+fn is_block_pruned(
+    harness: &mut ComponentHarness<UnitTestEvent>,
+    storage: &mut Storage,
+    block_identifier: BlockIdentifier,
+) -> bool {
+    let response = harness.send_request(storage, move |responder| {
+        StorageRequest::IsBlockPruned {
+            block_identifier,
+            responder,
+        }
+        .into()
+    });
+    assert!(harness.is_idle());
+    response
+}
+
+/// Stores a block together with its deploys and random execution results for them.
+fn put_executed_block(
+    harness: &mut ComponentHarness<UnitTestEvent>,
+    storage: &mut Storage,
+    block: Box<Block>,
+    deploys: &[&Deploy],
+) {
+    let block_hash = *block.hash();
+    for deploy in deploys {
+        put_deploy(harness, storage, Box::new((*deploy).clone()));
+    }
+    assert!(put_block(harness, storage, block));
+    let execution_results = deploys
+        .iter()
+        .map(|deploy| (*deploy.id(), harness.rng.gen()))
+        .collect();
+    put_execution_results(harness, storage, block_hash, execution_results);
+}
+
 /// Saves state from the storage component.
 fn save_state<T>(
     harness: &mut ComponentHarness<UnitTestEvent>,
@@ -864,3 +1002,308 @@ fn should_hard_reset() {
     // Check the highest block is `None`.
     assert!(get_highest_block(&mut harness, &mut storage).is_none());
 }
+
+#[test]
+fn store_and_page_through_account_deploys() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    let alice = SecretKey::random(&mut harness.rng);
+    let bob = SecretKey::random(&mut harness.rng);
+    let alice_deploys: Vec<Deploy> = (0..3)
+        .map(|_| random_deploy_from(&mut harness.rng, &alice))
+        .collect();
+    let bob_deploy = random_deploy_from(&mut harness.rng, &bob);
+
+    // Store the blocks out of order, with Bob's deploy sharing a block with one of Alice's.
+    let block_1 = random_block_with_deploys(&mut harness.rng, EraId(0), 1, &[&alice_deploys[0]]);
+    let block_2 = random_block_with_deploys(
+        &mut harness.rng,
+        EraId(0),
+        2,
+        &[&alice_deploys[1], &bob_deploy],
+    );
+    let block_3 = random_block_with_deploys(&mut harness.rng, EraId(0), 3, &[&alice_deploys[2]]);
+    put_executed_block(
+        &mut harness,
+        &mut storage,
+        block_3.clone(),
+        &[&alice_deploys[2]],
+    );
+    put_executed_block(
+        &mut harness,
+        &mut storage,
+        block_1.clone(),
+        &[&alice_deploys[0]],
+    );
+    put_executed_block(
+        &mut harness,
+        &mut storage,
+        block_2.clone(),
+        &[&alice_deploys[1], &bob_deploy],
+    );
+
+    let expected: Vec<AccountDeploy> = vec![&block_1, &block_2, &block_3]
+        .into_iter()
+        .zip(alice_deploys.iter())
+        .map(|(block, deploy)| AccountDeploy {
+            block_height: block.height(),
+            deploy_hash: *deploy.id(),
+            block_hash: *block.hash(),
+        })
+        .collect();
+
+    let alice_hash = PublicKey::from(&alice).to_account_hash();
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, alice_hash, 0, 10),
+        expected
+    );
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, alice_hash, 1, 1),
+        expected[1..2].to_vec()
+    );
+    assert!(get_account_deploys(&mut harness, &mut storage, alice_hash, 3, 10).is_empty());
+
+    // Storing execution results for the same block again must not create duplicate entries.
+    put_executed_block(&mut harness, &mut storage, block_1, &[&alice_deploys[0]]);
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, alice_hash, 0, 10),
+        expected
+    );
+
+    let bob_hash = PublicKey::from(&bob).to_account_hash();
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, bob_hash, 0, 10),
+        vec![AccountDeploy {
+            block_height: 2,
+            deploy_hash: *bob_deploy.id(),
+            block_hash: *block_2.hash(),
+        }]
+    );
+
+    let unknown_hash = AccountHash::new([42; 32]);
+    assert!(get_account_deploys(&mut harness, &mut storage, unknown_hash, 0, 10).is_empty());
+}
+
+#[test]
+fn persist_account_deploys_across_instantiations() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    let secret_key = SecretKey::random(&mut harness.rng);
+    let deploy = random_deploy_from(&mut harness.rng, &secret_key);
+    let block = random_block_with_deploys(&mut harness.rng, EraId(0), 7, &[&deploy]);
+    put_executed_block(&mut harness, &mut storage, block.clone(), &[&deploy]);
+
+    let account_hash = PublicKey::from(&secret_key).to_account_hash();
+    let expected = vec![AccountDeploy {
+        block_height: 7,
+        deploy_hash: *deploy.id(),
+        block_hash: *block.hash(),
+    }];
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, account_hash, 0, 10),
+        expected
+    );
+
+    // Rebuild the component, which keeps the existing account deploys index.
+    let (on_disk, rng) = harness.into_parts();
+    let mut harness = ComponentHarness::builder()
+        .on_disk(on_disk)
+        .rng(rng)
+        .build();
+    let mut storage = storage_fixture(&harness);
+
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, account_hash, 0, 10),
+        expected
+    );
+}
+
+#[test]
+fn reindex_account_deploys_only_if_index_version_differs() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    let secret_key = SecretKey::random(&mut harness.rng);
+    let deploy = random_deploy_from(&mut harness.rng, &secret_key);
+    let block = random_block_with_deploys(&mut harness.rng, EraId(0), 7, &[&deploy]);
+    put_executed_block(&mut harness, &mut storage, block.clone(), &[&deploy]);
+
+    let account_hash = PublicKey::from(&secret_key).to_account_hash();
+    let expected = vec![AccountDeploy {
+        block_height: 7,
+        deploy_hash: *deploy.id(),
+        block_hash: *block.hash(),
+    }];
+
+    // Remove the deploy, so that rebuilding the index would drop its entry.
+    let mut txn = storage.env.begin_rw_txn().unwrap();
+    assert!(txn.del_value(storage.deploy_db, deploy.id()).unwrap());
+    txn.commit().unwrap();
+
+    let (on_disk, rng) = harness.into_parts();
+    let mut harness = ComponentHarness::builder()
+        .on_disk(on_disk)
+        .rng(rng)
+        .build();
+    let mut storage = storage_fixture(&harness);
+
+    // The index is up to date, so it is not rebuilt on startup.
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, account_hash, 0, 10),
+        expected
+    );
+
+    // Pretend the index was written by an older version of the node.
+    let mut txn = storage.env.begin_rw_txn().unwrap();
+    txn.put_value(
+        storage.state_store_db,
+        &ACCOUNT_DEPLOYS_INDEX_VERSION_KEY,
+        &0u32,
+        true,
+    )
+    .unwrap();
+    txn.commit().unwrap();
+
+    let (on_disk, rng) = harness.into_parts();
+    let mut harness = ComponentHarness::builder()
+        .on_disk(on_disk)
+        .rng(rng)
+        .build();
+    let mut storage = storage_fixture(&harness);
+
+    assert!(get_account_deploys(&mut harness, &mut storage, account_hash, 0, 10).is_empty());
+}
+
+#[test]
+fn prune_blocks_of_old_eras() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture_with_pruning(&harness, 2);
+
+    let secret_key = SecretKey::random(&mut harness.rng);
+    let deploys: Vec<Deploy> = (0..3)
+        .map(|_| random_deploy_from(&mut harness.rng, &secret_key))
+        .collect();
+
+    // Two eras with a switch block each, followed by a block of a third era.
+    let block_0 = random_block_with_deploys(&mut harness.rng, EraId(0), 0, &[&deploys[0]]);
+    let block_1 = Box::new(Block::random_with_specifics(
+        &mut harness.rng,
+        EraId(0),
+        1,
+        true,
+    ));
+    let block_2 = random_block_with_deploys(&mut harness.rng, EraId(1), 2, &[&deploys[1]]);
+    let block_3 = Box::new(Block::random_with_specifics(
+        &mut harness.rng,
+        EraId(1),
+        3,
+        true,
+    ));
+    let block_4 = random_block_with_deploys(&mut harness.rng, EraId(2), 4, &[&deploys[2]]);
+
+    put_executed_block(&mut harness, &mut storage, block_0.clone(), &[&deploys[0]]);
+    assert!(put_block(&mut harness, &mut storage, block_1.clone()));
+    put_executed_block(&mut harness, &mut storage, block_2.clone(), &[&deploys[1]]);
+    assert!(put_block(&mut harness, &mut storage, block_3.clone()));
+    prune(&mut harness, &mut storage);
+
+    // Nothing is pruned while at most two eras are known.
+    assert!(get_block_at_height(&mut harness, &mut storage, 0).is_some());
+    assert!(!is_block_pruned(
+        &mut harness,
+        &mut storage,
+        BlockIdentifier::Height(0)
+    ));
+
+    // Storing a block of the third era and pruning afterwards removes the first one.
+    put_executed_block(&mut harness, &mut storage, block_4.clone(), &[&deploys[2]]);
+    prune(&mut harness, &mut storage);
+
+    assert!(get_block_at_height(&mut harness, &mut storage, 0).is_none());
+    assert!(get_block_at_height(&mut harness, &mut storage, 1).is_none());
+    for height in 2..5 {
+        assert!(get_block_at_height(&mut harness, &mut storage, height).is_some());
+    }
+    assert!(get_deploy_and_metadata(&mut harness, &mut storage, *deploys[0].id()).is_none());
+    assert!(get_deploy_and_metadata(&mut harness, &mut storage, *deploys[1].id()).is_some());
+
+    // Only the header of the switch block is retained.
+    assert!(!storage.block_height_index.contains_key(&0));
+    assert_eq!(storage.block_height_index.get(&1), Some(block_1.hash()));
+
+    let account_hash = PublicKey::from(&secret_key).to_account_hash();
+    let expected_account_deploys = vec![
+        AccountDeploy {
+            block_height: 2,
+            deploy_hash: *deploys[1].id(),
+            block_hash: *block_2.hash(),
+        },
+        AccountDeploy {
+            block_height: 4,
+            deploy_hash: *deploys[2].id(),
+            block_hash: *block_4.hash(),
+        },
+    ];
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, account_hash, 0, 10),
+        expected_account_deploys
+    );
+
+    let check_pruned = |harness: &mut ComponentHarness<UnitTestEvent>, storage: &mut Storage| {
+        assert!(is_block_pruned(
+            harness,
+            storage,
+            BlockIdentifier::Height(0)
+        ));
+        assert!(is_block_pruned(
+            harness,
+            storage,
+            BlockIdentifier::Height(1)
+        ));
+        assert!(!is_block_pruned(
+            harness,
+            storage,
+            BlockIdentifier::Height(2)
+        ));
+        assert!(!is_block_pruned(
+            harness,
+            storage,
+            BlockIdentifier::Height(5)
+        ));
+        // The header of a pruned non-switch block is gone, so it is indistinguishable from an
+        // unknown block when queried by hash.
+        assert!(!is_block_pruned(
+            harness,
+            storage,
+            BlockIdentifier::Hash(*block_0.hash())
+        ));
+        assert!(is_block_pruned(
+            harness,
+            storage,
+            BlockIdentifier::Hash(*block_1.hash())
+        ));
+        assert!(!is_block_pruned(
+            harness,
+            storage,
+            BlockIdentifier::Hash(*block_2.hash())
+        ));
+    };
+    check_pruned(&mut harness, &mut storage);
+
+    // The pruning progress must survive a restart.
+    let (on_disk, rng) = harness.into_parts();
+    let mut harness = ComponentHarness::builder()
+        .on_disk(on_disk)
+        .rng(rng)
+        .build();
+    let mut storage = storage_fixture_with_pruning(&harness, 2);
+
+    check_pruned(&mut harness, &mut storage);
+    assert_eq!(storage.block_height_index.get(&1), Some(block_1.hash()));
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, account_hash, 0, 10),
+        expected_account_deploys
+    );
+}
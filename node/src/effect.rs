@@ -92,17 +92,23 @@ use casper_execution_engine::{
         MAX_PAYMENT,
     },
     shared::{
-        additive_map::AdditiveMap, newtypes::Blake2bHash, stored_value::StoredValue,
-        transform::Transform,
+        account::Account, additive_map::AdditiveMap, newtypes::Blake2bHash,
+        stored_value::StoredValue, transform::Transform,
+    },
+    storage::{
+        global_state::{CommitResult, DiffResult},
+        protocol_data::ProtocolData,
+        trie::Trie,
     },
-    storage::{global_state::CommitResult, protocol_data::ProtocolData, trie::Trie},
 };
 use casper_types::{
-    system::auction::EraValidators, ExecutionResult, Key, ProtocolVersion, PublicKey, Transfer,
+    account::AccountHash, system::auction::EraValidators, ExecutionResult, Key, ProtocolVersion,
+    PublicKey, Transfer,
 };
 
 use crate::{
     components::{
+        block_proposer::DeployType,
         chainspec_loader::NextUpgrade,
         consensus::{BlockContext, EraId},
         contract_runtime::EraValidatorsRequest,
@@ -114,9 +120,10 @@ use crate::{
     effect::requests::LinearChainRequest,
     reactor::{EventQueueHandle, QueueKind},
     types::{
-        Block, BlockByHeight, BlockHash, BlockHeader, BlockLike, BlockSignatures, Chainspec,
-        ChainspecInfo, Deploy, DeployHash, DeployHeader, DeployMetadata, FinalitySignature,
-        FinalizedBlock, Item, ProtoBlock, TimeDiff, Timestamp,
+        AccountDeploy, Block, BlockByHeight, BlockHash, BlockHeader, BlockIdentifier, BlockLike,
+        BlockSignatures, Chainspec, ChainspecInfo, Deploy, DeployHash, DeployHeader,
+        DeployMetadata, FinalitySignature, FinalizedBlock, Item, PeerConnectionState, ProtoBlock,
+        ReactorStatus, TimeDiff, Timestamp,
     },
     utils::Source,
 };
@@ -127,8 +134,9 @@ use announcements::{
 };
 use requests::{
     BlockExecutorRequest, BlockProposerRequest, BlockValidationRequest, ChainspecLoaderRequest,
-    ConsensusRequest, ContractRuntimeRequest, FetcherRequest, MetricsRequest, NetworkInfoRequest,
-    NetworkRequest, ProtoBlockRequest, StateStoreRequest, StorageRequest,
+    ConsensusRequest, ContractRuntimeRequest, FetcherRequest, MemoryReportRequest, MetricsRequest,
+    NetworkInfoRequest, NetworkRequest, ProtoBlockRequest, ReactorStatusRequest, StateStoreRequest,
+    StorageRequest,
 };
 
 /// A pinned, boxed future that produces one or more events.
@@ -464,6 +472,41 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Retrieves the status of the currently running reactor.
+    pub(crate) async fn get_reactor_status(self) -> ReactorStatus
+    where
+        REv: From<ReactorStatusRequest>,
+    {
+        self.make_request(
+            |responder| ReactorStatusRequest::GetStatus { responder },
+            QueueKind::Api,
+        )
+        .await
+    }
+
+    /// Estimates the heap memory used by each component of the reactor, in bytes.
+    pub(crate) async fn get_memory_report(self) -> BTreeMap<String, i64>
+    where
+        REv: From<MemoryReportRequest>,
+    {
+        self.make_request(
+            |responder| MemoryReportRequest::EstimateHeapSizes { responder },
+            QueueKind::Api,
+        )
+        .await
+    }
+
+    /// Serializes the contents of the reactor's event queues to JSON.
+    ///
+    /// The queues are read directly rather than via the reactor, so this also works if the reactor
+    /// is stalled.
+    pub(crate) async fn dump_event_queues(self) -> Result<Vec<u8>, serde_json::Error>
+    where
+        REv: Serialize,
+    {
+        self.0.queues_json().await
+    }
+
     /// Retrieves block at `height` from the Linear Chain component.
     pub(crate) async fn get_block_at_height_local<I>(self, height: u64) -> Option<Block>
     where
@@ -554,6 +597,19 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Gets the state of the connections to all connected network peers.
+    pub(crate) async fn network_peer_connection_states<I>(self) -> BTreeMap<I, PeerConnectionState>
+    where
+        REv: From<NetworkInfoRequest<I>>,
+        I: Send + 'static,
+    {
+        self.make_request(
+            |responder| NetworkInfoRequest::GetPeerConnectionStates { responder },
+            QueueKind::Api,
+        )
+        .await
+    }
+
     /// Announces that a network message has been received.
     pub(crate) async fn announce_message_received<I, P>(self, sender: I, payload: P)
     where
@@ -948,6 +1004,43 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Gets a page of the deploys sent by the given account from the account deploys index.
+    pub(crate) async fn get_account_deploys_from_storage(
+        self,
+        account_hash: AccountHash,
+        offset: u64,
+        limit: u64,
+    ) -> Vec<AccountDeploy>
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::GetAccountDeploys {
+                account_hash,
+                offset,
+                limit,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
+    /// Checks whether the specified block has been pruned from storage.
+    pub(crate) async fn is_block_pruned_in_storage(self, block_identifier: BlockIdentifier) -> bool
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::IsBlockPruned {
+                block_identifier,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
     /// Gets the requested block and its associated metadata.
     pub(crate) async fn get_block_at_height_with_metadata_from_storage(
         self,
@@ -1089,6 +1182,18 @@ impl<REv> EffectBuilder<REv> {
         (proto_block, block_context)
     }
 
+    /// Gets the contents of the block proposer's pending deploy pool, highest priority first.
+    pub(crate) async fn get_pending_deploys(self) -> Vec<(DeployHash, DeployType)>
+    where
+        REv: From<BlockProposerRequest>,
+    {
+        self.make_request(
+            |responder| BlockProposerRequest::GetPendingDeploys { responder },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
     /// Passes a finalized proto-block to the block executor component to execute it.
     pub(crate) async fn execute_block(self, finalized_block: FinalizedBlock)
     where
@@ -1394,7 +1499,14 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
-    pub(crate) async fn is_verified_account(self, account_key: Key) -> Option<bool>
+    /// Retrieves the `Account` stored under `account_key` as at the highest block, along with
+    /// whether its main purse holds at least the maximum payment amount.
+    ///
+    /// Returns `None` if the account doesn't exist or its balance can't be determined.
+    pub(crate) async fn get_account_and_check_balance(
+        self,
+        account_key: Key,
+    ) -> Option<(Box<Account>, bool)>
     where
         REv: From<ContractRuntimeRequest>,
         REv: From<StorageRequest>,
@@ -1410,7 +1522,8 @@ impl<REv> EffectBuilder<REv> {
                     let balance_request = BalanceRequest::new(state_hash, purse_uref);
                     if let Ok(balance_result) = self.get_balance(balance_request).await {
                         if let Some(motes) = balance_result.motes() {
-                            return Some(motes >= &*MAX_PAYMENT);
+                            let has_minimum_balance = motes >= &*MAX_PAYMENT;
+                            return Some((Box::new(account), has_minimum_balance));
                         }
                     }
                 }
@@ -1492,6 +1605,31 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Gets up to `max_entries` changes to the global state between two state root hashes,
+    /// starting after the key `start_after` if given.
+    pub(crate) async fn get_trie_diff(
+        self,
+        from_state_root_hash: Blake2bHash,
+        to_state_root_hash: Blake2bHash,
+        start_after: Option<Key>,
+        max_entries: usize,
+    ) -> Result<DiffResult, engine_state::Error>
+    where
+        REv: From<ContractRuntimeRequest>,
+    {
+        self.make_request(
+            |responder| ContractRuntimeRequest::GetTrieDiff {
+                from_state_root_hash,
+                to_state_root_hash,
+                start_after,
+                max_entries,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
     /// Runs the end of era step using the system smart contract.
     pub(crate) async fn run_step(
         self,
@@ -1543,6 +1681,15 @@ impl<REv> EffectBuilder<REv> {
             .await
     }
 
+    /// Gets a human-readable dump of the current era's consensus protocol state, if any.
+    pub(crate) async fn dump_consensus_state(self) -> Option<String>
+    where
+        REv: From<ConsensusRequest>,
+    {
+        self.make_request(ConsensusRequest::DumpState, QueueKind::Api)
+            .await
+    }
+
     /// Check if validator is bonded in the future era (`era_id`).
     /// This information is known only by the Contract Runtime since consensus component
     /// knows only about currently active eras.
@@ -32,9 +32,14 @@ use casper_execution_engine::{
         additive_map::AdditiveMap, newtypes::Blake2bHash, stored_value::StoredValue,
         transform::Transform,
     },
-    storage::{global_state::CommitResult, protocol_data::ProtocolData, trie::Trie},
+    storage::{
+        global_state::{CommitResult, DiffResult},
+        protocol_data::ProtocolData,
+        trie::Trie,
+    },
 };
 use casper_types::{
+    account::AccountHash,
     system::auction::{EraValidators, ValidatorWeights},
     ExecutionResult, Key, ProtocolVersion, PublicKey, Transfer, URef,
 };
@@ -42,17 +47,19 @@ use casper_types::{
 use super::Responder;
 use crate::{
     components::{
+        block_proposer::DeployType,
         consensus::EraId,
         contract_runtime::{EraValidatorsRequest, ValidatorWeightsByEraIdRequest},
         deploy_acceptor::Error,
         fetcher::FetchResult,
+        rpc_server::{GetDiffError, SpeculativeExecError},
     },
     crypto::hash::Digest,
-    rpcs::chain::BlockIdentifier,
     types::{
-        Block as LinearBlock, Block, BlockHash, BlockHeader, BlockSignatures, Chainspec,
-        ChainspecInfo, Deploy, DeployHash, DeployHeader, DeployMetadata, FinalitySignature,
-        FinalizedBlock, Item, NodeId, ProtoBlock, StatusFeed, TimeDiff, Timestamp,
+        AccountDeploy, Block as LinearBlock, Block, BlockHash, BlockHeader, BlockIdentifier,
+        BlockSignatures, Chainspec, ChainspecInfo, Deploy, DeployHash, DeployHeader,
+        DeployMetadata, FinalitySignature, FinalizedBlock, Item, NodeId, PeerConnectionState,
+        ProtoBlock, ReactorStatus, StatusFeed, TimeDiff, Timestamp,
     },
     utils::DisplayIter,
 };
@@ -80,6 +87,44 @@ impl Display for MetricsRequest {
     }
 }
 
+/// A request for a report of the reactor's memory usage.
+#[derive(Debug)]
+pub enum MemoryReportRequest {
+    /// Estimate the heap memory used by each component using `DataSize`.
+    EstimateHeapSizes {
+        /// Responder returning the estimated heap sizes in bytes, keyed by component name.
+        responder: Responder<BTreeMap<String, i64>>,
+    },
+}
+
+impl Display for MemoryReportRequest {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MemoryReportRequest::EstimateHeapSizes { .. } => {
+                write!(formatter, "estimate heap sizes")
+            }
+        }
+    }
+}
+
+/// A request for the status of the currently running reactor.
+#[derive(Debug)]
+pub enum ReactorStatusRequest {
+    /// Get the phase, uptime and synchronization progress of the reactor.
+    GetStatus {
+        /// Responder returning the reactor's status.
+        responder: Responder<ReactorStatus>,
+    },
+}
+
+impl Display for ReactorStatusRequest {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ReactorStatusRequest::GetStatus { .. } => write!(formatter, "get reactor status"),
+        }
+    }
+}
+
 const _NETWORK_EVENT_SIZE: usize = mem::size_of::<NetworkRequest<NodeId, String>>();
 const_assert!(_NETWORK_EVENT_SIZE < 89);
 
@@ -187,6 +232,11 @@ pub enum NetworkInfoRequest<I> {
         // TODO - change the `String` field to a `libp2p::Multiaddr` once small_network is removed.
         responder: Responder<BTreeMap<I, String>>,
     },
+    /// Get the state of the connections to all connected peers.
+    GetPeerConnectionStates {
+        /// Responder to be called with the connection state of each connected peer.
+        responder: Responder<BTreeMap<I, PeerConnectionState>>,
+    },
 }
 
 impl<I> Display for NetworkInfoRequest<I>
@@ -196,6 +246,9 @@ where
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         match self {
             NetworkInfoRequest::GetPeers { responder: _ } => write!(formatter, "get peers"),
+            NetworkInfoRequest::GetPeerConnectionStates { .. } => {
+                write!(formatter, "get peer connection states")
+            }
         }
     }
 }
@@ -291,6 +344,24 @@ pub enum StorageRequest {
         /// Responder to call with the results.
         responder: Responder<Vec<(DeployHash, DeployHeader)>>,
     },
+    /// Retrieve a page of the deploys sent by an account, ordered by block height.
+    GetAccountDeploys {
+        /// Hash of the account which sent the deploys.
+        account_hash: AccountHash,
+        /// Number of entries to skip.
+        offset: u64,
+        /// Maximum number of entries to return.
+        limit: u64,
+        /// Responder to call with the results.
+        responder: Responder<Vec<AccountDeploy>>,
+    },
+    /// Check whether the given block has been pruned from storage.
+    IsBlockPruned {
+        /// The identifier (can either be a hash or the height) of the block.
+        block_identifier: BlockIdentifier,
+        /// Responder to call with the result.
+        responder: Responder<bool>,
+    },
     /// Store execution results for a set of deploys of a single block.
     ///
     /// Will return a fatal error if there are already execution results known for a specific
@@ -415,6 +486,19 @@ impl Display for StorageRequest {
             StorageRequest::GetFinalizedDeploys { ttl, .. } => {
                 write!(formatter, "get finalized deploys, ttl: {:?}", ttl)
             }
+            StorageRequest::GetAccountDeploys {
+                account_hash,
+                offset,
+                limit,
+                ..
+            } => write!(
+                formatter,
+                "get deploys of {}, offset: {}, limit: {}",
+                account_hash, offset, limit
+            ),
+            StorageRequest::IsBlockPruned {
+                block_identifier, ..
+            } => write!(formatter, "is block pruned {:?}", block_identifier),
         }
     }
 }
@@ -479,6 +563,11 @@ pub struct ProtoBlockRequest {
 pub enum BlockProposerRequest {
     /// Request a list of deploys to propose in a new block.
     RequestProtoBlock(ProtoBlockRequest),
+    /// Request the contents of the pending deploy pool, highest priority first.
+    GetPendingDeploys {
+        /// Responder to call with the pending deploys.
+        responder: Responder<Vec<(DeployHash, DeployType)>>,
+    },
 }
 
 impl Display for BlockProposerRequest {
@@ -497,6 +586,9 @@ impl Display for BlockProposerRequest {
                 past_deploys.len(),
                 next_finalized
             ),
+            BlockProposerRequest::GetPendingDeploys { .. } => {
+                write!(formatter, "get pending deploys")
+            }
         }
     }
 }
@@ -573,6 +665,19 @@ pub enum RpcRequest<I> {
         /// Responder to call with the result.
         responder: Responder<Result<BalanceResult, engine_state::Error>>,
     },
+    /// Get the changes to the global state between two state root hashes.
+    GetTrieDiff {
+        /// The state root hash to compare from.
+        from_state_root_hash: Digest,
+        /// The state root hash to compare to.
+        to_state_root_hash: Digest,
+        /// The key after which to start returning entries, if any.
+        start_after: Option<Key>,
+        /// The maximum number of entries to return, if not the configured maximum.
+        limit: Option<u64>,
+        /// Responder to call with the result.
+        responder: Responder<Result<DiffResult, GetDiffError>>,
+    },
     /// Return the specified deploy and metadata if it exists, else `None`.
     GetDeploy {
         /// The hash of the deploy to be retrieved.
@@ -580,6 +685,39 @@ pub enum RpcRequest<I> {
         /// Responder to call with the result.
         responder: Responder<Option<(Deploy, DeployMetadata)>>,
     },
+    /// Return a page of the deploys sent by an account.
+    GetAccountDeploys {
+        /// The hash of the account which sent the deploys.
+        account_hash: AccountHash,
+        /// Number of entries to skip.
+        offset: u64,
+        /// Maximum number of entries to return.
+        limit: u64,
+        /// Responder to call with the result.
+        responder: Responder<Vec<AccountDeploy>>,
+    },
+    /// Return whether the specified block has been pruned from storage.
+    IsBlockPruned {
+        /// The identifier (can either be a hash or the height) of the block.
+        block_identifier: BlockIdentifier,
+        /// Responder to call with the result.
+        responder: Responder<bool>,
+    },
+    /// Execute a deploy on top of the global state of the given block without committing the
+    /// resulting effects.
+    SpeculativeExec {
+        /// The block on top of whose global state the deploy is executed.
+        block: Box<Block>,
+        /// The deploy to be executed.
+        deploy: Box<Deploy>,
+        /// Responder to call with the result.
+        responder: Responder<Result<ExecutionResult, SpeculativeExecError>>,
+    },
+    /// Return the contents of the block proposer's pending deploy pool.
+    GetPendingDeploys {
+        /// Responder to call with the result.
+        responder: Responder<Vec<(DeployHash, DeployType)>>,
+    },
     /// Return the connected peers.
     GetPeers {
         /// Responder to call with the result.
@@ -643,7 +781,36 @@ impl<I> Display for RpcRequest<I> {
                 "balance {}, purse_uref: {}",
                 state_root_hash, purse_uref
             ),
+            RpcRequest::GetTrieDiff {
+                from_state_root_hash,
+                to_state_root_hash,
+                ..
+            } => write!(
+                formatter,
+                "trie diff from {} to {}",
+                from_state_root_hash, to_state_root_hash
+            ),
             RpcRequest::GetDeploy { hash, .. } => write!(formatter, "get {}", hash),
+            RpcRequest::GetAccountDeploys {
+                account_hash,
+                offset,
+                limit,
+                ..
+            } => write!(
+                formatter,
+                "get deploys of {}, offset: {}, limit: {}",
+                account_hash, offset, limit
+            ),
+            RpcRequest::IsBlockPruned {
+                block_identifier, ..
+            } => write!(formatter, "is block pruned {:?}", block_identifier),
+            RpcRequest::SpeculativeExec { block, deploy, .. } => write!(
+                formatter,
+                "speculatively execute {} on top of {}",
+                deploy.id(),
+                block.hash()
+            ),
+            RpcRequest::GetPendingDeploys { .. } => write!(formatter, "get pending deploys"),
             RpcRequest::GetPeers { .. } => write!(formatter, "get peers"),
             RpcRequest::GetStatus { .. } => write!(formatter, "get status"),
             RpcRequest::GetMetrics { .. } => write!(formatter, "get metrics"),
@@ -806,6 +973,19 @@ pub enum ContractRuntimeRequest {
         /// Responder to call with the result.
         responder: Responder<Result<Vec<Blake2bHash>, engine_state::Error>>,
     },
+    /// Get the changes to the global state between two state root hashes.
+    GetTrieDiff {
+        /// The state root hash to compare from.
+        from_state_root_hash: Blake2bHash,
+        /// The state root hash to compare to.
+        to_state_root_hash: Blake2bHash,
+        /// The key after which to start returning entries, if any.
+        start_after: Option<Key>,
+        /// The maximum number of entries to return.
+        max_entries: usize,
+        /// Responder to call with the result.
+        responder: Responder<Result<DiffResult, engine_state::Error>>,
+    },
 }
 
 impl Display for ContractRuntimeRequest {
@@ -888,6 +1068,15 @@ impl Display for ContractRuntimeRequest {
                     trie_key
                 )
             }
+            ContractRuntimeRequest::GetTrieDiff {
+                from_state_root_hash,
+                to_state_root_hash,
+                ..
+            } => write!(
+                formatter,
+                "get trie diff from {} to {}",
+                from_state_root_hash, to_state_root_hash
+            ),
         }
     }
 }
@@ -997,6 +1186,8 @@ pub enum ConsensusRequest {
     IsBondedValidator(EraId, PublicKey, Responder<bool>),
     /// Request for our public key, and if we're a validator, the next round length.
     Status(Responder<(PublicKey, Option<TimeDiff>)>),
+    /// Request for a human-readable dump of the current era's protocol state, if any.
+    DumpState(Responder<Option<String>>),
 }
 
 /// ChainspecLoader component requests.
@@ -0,0 +1,70 @@
+//! Offline pruning of unreachable global state.
+
+use prometheus::Registry;
+use thiserror::Error;
+use tracing::info;
+
+use casper_execution_engine::core::engine_state::Error as EngineStateError;
+
+use crate::{
+    components::{
+        contract_runtime::{ConfigError as ContractRuntimeError, ContractRuntime},
+        storage::{Error as StorageError, Storage},
+    },
+    reactor::validator::Config,
+    types::{chainspec, Chainspec},
+    utils::{Loadable, WithDir},
+};
+
+/// Error returned as a result of pruning global state.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Error loading the chainspec.
+    #[error("error loading chainspec: {0}")]
+    LoadChainspec(chainspec::Error),
+
+    /// Error opening or reading the linear chain storage.
+    #[error("storage error: {0}")]
+    Storage(#[from] StorageError),
+
+    /// Error opening the global state store.
+    #[error("contract runtime error: {0}")]
+    ContractRuntime(#[from] ContractRuntimeError),
+
+    /// Error while pruning the global state store.
+    #[error("error pruning global state: {0}")]
+    Prune(#[from] EngineStateError),
+
+    /// No state roots to retain were found.
+    #[error("no blocks found in storage; refusing to prune global state")]
+    NoRetainedStateRoots,
+}
+
+/// Deletes all global state tries which are unreachable from the state roots of the
+/// `retained_blocks` highest blocks in storage.
+///
+/// The node must not be running while this is called.  Returns the number of deleted tries.
+pub fn prune_global_state(config: WithDir<Config>, retained_blocks: usize) -> Result<u64, Error> {
+    let chainspec = Chainspec::from_path(config.dir()).map_err(Error::LoadChainspec)?;
+    let protocol_version = chainspec.protocol_config.version.clone();
+    let storage_config = config.map_ref(|cfg| cfg.storage.clone());
+    let storage = Storage::new(&storage_config, None, protocol_version)?;
+    let retained_roots = storage.get_recent_state_root_hashes(retained_blocks)?;
+    if retained_roots.is_empty() {
+        return Err(Error::NoRetainedStateRoots);
+    }
+
+    let contract_runtime = ContractRuntime::new(
+        storage_config,
+        &config.value().contract_runtime,
+        chainspec.strict_entry_point_args_protocol_version(),
+        &Registry::new(),
+    )?;
+    info!(
+        retained_state_roots = retained_roots.len(),
+        "collected state roots to retain"
+    );
+    let deleted_count = contract_runtime.prune_global_state(&retained_roots)?;
+    info!(%deleted_count, "finished pruning global state");
+    Ok(deleted_count)
+}
@@ -0,0 +1,1071 @@
+//! Generation of `global_state.toml` files for emergency upgrades.
+//!
+//! Rather than hand-serializing `StoredValue`s, the required changes are described as typed
+//! operations in a TOML file, e.g.
+//!
+//! ```toml
+//! [[set_balance]]
+//! account = "01..."
+//! balance = "1000000000"
+//!
+//! [[add_associated_key]]
+//! account = "01..."
+//! associated_key = "account-hash-..."
+//! weight = 1
+//!
+//! [[set_named_key]]
+//! account = "01..."
+//! name = "my_key"
+//! key = "uref-...-007"
+//!
+//! [[replace_validator]]
+//! old_public_key = "01..."
+//! new_public_key = "01..."
+//!
+//! [rotate_validators]
+//! public_keys = ["01...", "01..."]
+//! ```
+//!
+//! The current values are read from the global state under a given state root hash and the
+//! operations are applied in the order listed above.  The resulting `global_state.toml` is
+//! validated before being returned along with a human-readable diff of the changes.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    convert::TryFrom,
+    path::Path,
+};
+
+use prometheus::Registry;
+use serde::Deserialize;
+use thiserror::Error;
+
+use casper_execution_engine::{
+    core::engine_state::{
+        Error as EngineStateError, GetBidsRequest, GetBidsResult, QueryRequest, QueryResult,
+    },
+    shared::{account::Account, stored_value::StoredValue},
+};
+use casper_types::{
+    account::{AccountHash, AddKeyFailure, Weight},
+    bytesrepr::{self, FromBytes, ToBytes},
+    system::{
+        auction::{
+            Bid, Bids, Delegator, SeigniorageRecipient, SeigniorageRecipients,
+            SeigniorageRecipientsSnapshot, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY,
+        },
+        mint::TOTAL_SUPPLY_KEY,
+    },
+    AsymmetricType, CLTyped, CLValue, CLValueError, ContractHash, Key, ProtocolVersion, PublicKey,
+    U512,
+};
+
+use crate::{
+    components::contract_runtime::{ConfigError as ContractRuntimeError, ContractRuntime},
+    crypto::hash::Digest,
+    reactor::validator::Config,
+    types::{
+        chainspec::{self, GlobalStateUpdate, GlobalStateUpdateConfig},
+        json_compatibility, Chainspec,
+    },
+    utils::{self, Loadable, ReadFileError, WithDir},
+};
+
+/// Error returned as a result of generating a global state update.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Error loading the chainspec.
+    #[error("error loading chainspec: {0}")]
+    LoadChainspec(chainspec::Error),
+
+    /// Error opening the global state store.
+    #[error("contract runtime error: {0}")]
+    ContractRuntime(#[from] ContractRuntimeError),
+
+    /// Error reading the operations file.
+    #[error("error reading operations file: {0}")]
+    ReadOperations(ReadFileError),
+
+    /// Error decoding the operations file.
+    #[error("error decoding operations file: {0}")]
+    DecodeOperations(toml::de::Error),
+
+    /// Error reading from the global state store.
+    #[error("error reading global state: {0}")]
+    EngineState(#[from] EngineStateError),
+
+    /// The global state query failed.
+    #[error("error querying global state: {0}")]
+    Query(String),
+
+    /// The given state root hash is not in the global state store.
+    #[error("state root hash {0} not found in global state")]
+    RootNotFound(Digest),
+
+    /// No protocol data is stored for the chainspec's protocol version.
+    #[error("no protocol data found for protocol version {0}")]
+    MissingProtocolData(ProtocolVersion),
+
+    /// A required value is missing from global state.
+    #[error("no value found under {}", .0.to_formatted_string())]
+    MissingValue(Key),
+
+    /// A value in global state is of an unexpected type.
+    #[error("expected {expected} under {}", key.to_formatted_string())]
+    UnexpectedValue {
+        /// The key under which the value is stored.
+        key: Key,
+        /// The expected type of the value.
+        expected: &'static str,
+    },
+
+    /// A contract has no named key of the given name.
+    #[error("no named key {name} in contract under {}", key.to_formatted_string())]
+    MissingNamedKey {
+        /// The key under which the contract is stored.
+        key: Key,
+        /// The name of the missing named key.
+        name: String,
+    },
+
+    /// A key in the operations file could not be parsed.
+    #[error("invalid key {key}: {error}")]
+    InvalidKey {
+        /// The formatted key.
+        key: String,
+        /// The parsing error.
+        error: String,
+    },
+
+    /// Adding an associated key to an account failed.
+    #[error("cannot add associated key to account of {account}: {error}")]
+    AddAssociatedKey {
+        /// The public key of the account.
+        account: PublicKey,
+        /// The underlying error.
+        error: AddKeyFailure,
+    },
+
+    /// The given validator has no bid.
+    #[error("no bid found for {0}")]
+    MissingBid(PublicKey),
+
+    /// The given validator already has a bid.
+    #[error("a bid already exists for {0}")]
+    BidExists(PublicKey),
+
+    /// Changing a balance would take the total supply out of range.
+    #[error("total supply out of range after setting balance")]
+    TotalSupplyOutOfRange,
+
+    /// Error converting to or from a `CLValue`.
+    #[error("CLValue error: {0}")]
+    CLValue(CLValueError),
+
+    /// Error (de)serializing a stored value.
+    #[error("serialization error: {0}")]
+    Serialization(bytesrepr::Error),
+
+    /// The generated update failed validation.
+    #[error("invalid global state update: {0}")]
+    Validation(String),
+
+    /// Error encoding the generated update to TOML.
+    #[error("error encoding global state update: {0}")]
+    EncodeUpdate(#[from] toml::ser::Error),
+
+    /// Error encoding a stored value to JSON for the diff.
+    #[error("error encoding diff: {0}")]
+    EncodeDiff(#[from] serde_json::Error),
+}
+
+impl From<CLValueError> for Error {
+    fn from(error: CLValueError) -> Self {
+        Error::CLValue(error)
+    }
+}
+
+impl From<bytesrepr::Error> for Error {
+    fn from(error: bytesrepr::Error) -> Self {
+        Error::Serialization(error)
+    }
+}
+
+/// Sets the balance of an account's main purse, adjusting the total supply accordingly.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SetBalance {
+    account: PublicKey,
+    balance: U512,
+}
+
+/// Adds an associated key to an account.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AddAssociatedKey {
+    account: PublicKey,
+    associated_key: AccountHash,
+    weight: Weight,
+}
+
+/// Adds or overwrites a named key of an account.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SetNamedKey {
+    account: PublicKey,
+    name: String,
+    /// The key in its formatted string form, e.g. `uref-...-007`.
+    key: String,
+}
+
+/// Moves a validator's bid, including its delegators, to a new public key.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ReplaceValidator {
+    old_public_key: PublicKey,
+    new_public_key: PublicKey,
+}
+
+/// Makes the given validators the only active ones, in all recorded future eras.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RotateValidators {
+    public_keys: Vec<PublicKey>,
+}
+
+/// The operations making up a global state update.
+#[derive(Clone, Default, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Operations {
+    #[serde(default)]
+    set_balance: Vec<SetBalance>,
+    #[serde(default)]
+    add_associated_key: Vec<AddAssociatedKey>,
+    #[serde(default)]
+    set_named_key: Vec<SetNamedKey>,
+    #[serde(default)]
+    replace_validator: Vec<ReplaceValidator>,
+    #[serde(default)]
+    rotate_validators: Option<RotateValidators>,
+}
+
+/// A generated global state update.
+#[derive(Debug)]
+pub struct GeneratedGlobalStateUpdate {
+    /// The contents of the `global_state.toml` file.
+    pub global_state_toml: String,
+    /// A human-readable description of the changes.
+    pub diff: String,
+}
+
+/// Generates a global state update by applying the operations listed in the file at
+/// `operations_path` to the global state under `state_root_hash`.
+///
+/// The node must not be running while this is called.
+pub fn generate_global_state_update(
+    config: WithDir<Config>,
+    state_root_hash: Digest,
+    operations_path: &Path,
+) -> Result<GeneratedGlobalStateUpdate, Error> {
+    let chainspec = Chainspec::from_path(config.dir()).map_err(Error::LoadChainspec)?;
+    let protocol_version = chainspec.protocol_version();
+    let operations_bytes = utils::read_file(operations_path).map_err(Error::ReadOperations)?;
+    let operations: Operations =
+        toml::from_slice(&operations_bytes).map_err(Error::DecodeOperations)?;
+
+    let contract_runtime = ContractRuntime::new(
+        config.map_ref(|cfg| cfg.storage.clone()),
+        &config.value().contract_runtime,
+        chainspec.strict_entry_point_args_protocol_version(),
+        &Registry::new(),
+    )?;
+    let protocol_data = contract_runtime
+        .get_protocol_data(protocol_version)?
+        .ok_or(Error::MissingProtocolData(protocol_version))?;
+    let reader = ContractRuntimeReader {
+        contract_runtime: &contract_runtime,
+        state_root_hash,
+    };
+    let system_contracts = SystemContracts {
+        mint: protocol_data.mint(),
+        auction: protocol_data.auction(),
+    };
+    generate(&reader, system_contracts, operations)
+}
+
+fn generate<R: StateReader>(
+    reader: &R,
+    system_contracts: SystemContracts,
+    operations: Operations,
+) -> Result<GeneratedGlobalStateUpdate, Error> {
+    let mut builder = UpdateBuilder::new(reader, system_contracts);
+    for set_balance in &operations.set_balance {
+        builder.set_balance(set_balance)?;
+    }
+    for add_associated_key in &operations.add_associated_key {
+        builder.add_associated_key(add_associated_key)?;
+    }
+    for set_named_key in &operations.set_named_key {
+        builder.set_named_key(set_named_key)?;
+    }
+    for replace_validator in &operations.replace_validator {
+        builder.replace_validator(replace_validator)?;
+    }
+    if let Some(rotate_validators) = &operations.rotate_validators {
+        builder.rotate_validators(rotate_validators)?;
+    }
+    builder.finish()
+}
+
+/// Read access to the global state being updated.
+trait StateReader {
+    /// Returns the value stored under `key`, if any.
+    fn read(&self, key: &Key) -> Result<Option<StoredValue>, Error>;
+
+    /// Returns all bids.
+    fn bids(&self) -> Result<Bids, Error>;
+}
+
+/// Reads from the global state under a given state root hash via the contract runtime.
+struct ContractRuntimeReader<'a> {
+    contract_runtime: &'a ContractRuntime,
+    state_root_hash: Digest,
+}
+
+impl StateReader for ContractRuntimeReader<'_> {
+    fn read(&self, key: &Key) -> Result<Option<StoredValue>, Error> {
+        let query_request = QueryRequest::new(self.state_root_hash.into(), *key, vec![]);
+        match self.contract_runtime.query_global_state(query_request)? {
+            QueryResult::Success { value, .. } => Ok(Some(*value)),
+            QueryResult::ValueNotFound(_) => Ok(None),
+            QueryResult::RootNotFound => Err(Error::RootNotFound(self.state_root_hash)),
+            QueryResult::CircularReference(message) => Err(Error::Query(message)),
+            QueryResult::DepthLimit { depth } => {
+                Err(Error::Query(format!("depth limit of {} reached", depth)))
+            }
+        }
+    }
+
+    fn bids(&self) -> Result<Bids, Error> {
+        let get_bids_request = GetBidsRequest::new(self.state_root_hash.into());
+        match self.contract_runtime.get_bids(get_bids_request)? {
+            GetBidsResult::Success { bids } => Ok(bids),
+            GetBidsResult::RootNotFound => Err(Error::RootNotFound(self.state_root_hash)),
+        }
+    }
+}
+
+/// The hashes of the system contracts whose state is updated.
+#[derive(Clone, Copy, Debug)]
+struct SystemContracts {
+    mint: ContractHash,
+    auction: ContractHash,
+}
+
+/// Accumulates the new values written by the operations.
+struct UpdateBuilder<'a, R> {
+    reader: &'a R,
+    system_contracts: SystemContracts,
+    /// The new values, keyed by normalized key.
+    writes: BTreeMap<Key, StoredValue>,
+    /// A description of each applied operation.
+    summary: Vec<String>,
+}
+
+impl<'a, R: StateReader> UpdateBuilder<'a, R> {
+    fn new(reader: &'a R, system_contracts: SystemContracts) -> Self {
+        UpdateBuilder {
+            reader,
+            system_contracts,
+            writes: BTreeMap::new(),
+            summary: vec![],
+        }
+    }
+
+    /// Reads the value under `key`, taking into account previously applied operations.
+    fn read(&self, key: Key) -> Result<Option<StoredValue>, Error> {
+        let key = key.normalize();
+        match self.writes.get(&key) {
+            Some(value) => Ok(Some(value.clone())),
+            None => self.reader.read(&key),
+        }
+    }
+
+    fn write(&mut self, key: Key, value: StoredValue) {
+        let _ = self.writes.insert(key.normalize(), value);
+    }
+
+    fn read_account(&self, public_key: &PublicKey) -> Result<Account, Error> {
+        let key = Key::Account(public_key.to_account_hash());
+        match self.read(key)? {
+            Some(StoredValue::Account(account)) => Ok(account),
+            Some(_) => Err(Error::UnexpectedValue {
+                key,
+                expected: "account",
+            }),
+            None => Err(Error::MissingValue(key)),
+        }
+    }
+
+    fn read_cl_value<T: CLTyped + FromBytes>(&self, key: Key) -> Result<T, Error> {
+        match self.read(key)? {
+            Some(StoredValue::CLValue(cl_value)) => Ok(cl_value.into_t()?),
+            Some(_) => Err(Error::UnexpectedValue {
+                key,
+                expected: "CLValue",
+            }),
+            None => Err(Error::MissingValue(key)),
+        }
+    }
+
+    fn write_cl_value<T: CLTyped + ToBytes>(&mut self, key: Key, value: T) -> Result<(), Error> {
+        let cl_value = CLValue::from_t(value)?;
+        self.write(key, StoredValue::CLValue(cl_value));
+        Ok(())
+    }
+
+    fn read_bid(&self, public_key: &PublicKey) -> Result<Option<Bid>, Error> {
+        let key = Key::Bid(public_key.to_account_hash());
+        match self.read(key)? {
+            Some(StoredValue::Bid(bid)) => Ok(Some(*bid)),
+            Some(_) => Err(Error::UnexpectedValue {
+                key,
+                expected: "bid",
+            }),
+            None => Ok(None),
+        }
+    }
+
+    fn write_bid(&mut self, bid: Bid) {
+        let key = Key::Bid(bid.validator_public_key().to_account_hash());
+        self.write(key, StoredValue::Bid(Box::new(bid)));
+    }
+
+    /// Returns all bids, taking into account previously applied operations.
+    fn bids(&self) -> Result<Bids, Error> {
+        let mut bids = self.reader.bids()?;
+        for value in self.writes.values() {
+            if let StoredValue::Bid(bid) = value {
+                let _ = bids.insert(*bid.validator_public_key(), (**bid).clone());
+            }
+        }
+        Ok(bids)
+    }
+
+    /// Returns the key stored under `name` in the named keys of the given system contract.
+    fn system_contract_named_key(
+        &self,
+        contract_hash: ContractHash,
+        name: &str,
+    ) -> Result<Key, Error> {
+        let key = Key::Hash(contract_hash.value());
+        let contract = match self.read(key)? {
+            Some(StoredValue::Contract(contract)) => contract,
+            Some(_) => {
+                return Err(Error::UnexpectedValue {
+                    key,
+                    expected: "contract",
+                })
+            }
+            None => return Err(Error::MissingValue(key)),
+        };
+        contract
+            .named_keys()
+            .get(name)
+            .copied()
+            .ok_or_else(|| Error::MissingNamedKey {
+                key,
+                name: name.to_string(),
+            })
+    }
+
+    /// Applies `update` to the seigniorage recipients of every era in the auction's snapshot.
+    fn update_seigniorage_recipients<F>(&mut self, mut update: F) -> Result<(), Error>
+    where
+        F: FnMut(&mut SeigniorageRecipients),
+    {
+        let snapshot_key = self.system_contract_named_key(
+            self.system_contracts.auction,
+            SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY,
+        )?;
+        let mut snapshot: SeigniorageRecipientsSnapshot = self.read_cl_value(snapshot_key)?;
+        for recipients in snapshot.values_mut() {
+            update(recipients);
+        }
+        self.write_cl_value(snapshot_key, snapshot)
+    }
+
+    fn set_balance(&mut self, set_balance: &SetBalance) -> Result<(), Error> {
+        let account = self.read_account(&set_balance.account)?;
+        let balance_key = Key::Balance(account.main_purse().addr());
+        let old_balance: U512 = self.read_cl_value(balance_key)?;
+        let total_supply_key =
+            self.system_contract_named_key(self.system_contracts.mint, TOTAL_SUPPLY_KEY)?;
+        let old_total_supply: U512 = self.read_cl_value(total_supply_key)?;
+        let new_total_supply = if set_balance.balance >= old_balance {
+            old_total_supply.checked_add(set_balance.balance - old_balance)
+        } else {
+            old_total_supply.checked_sub(old_balance - set_balance.balance)
+        }
+        .ok_or(Error::TotalSupplyOutOfRange)?;
+
+        self.write_cl_value(balance_key, set_balance.balance)?;
+        self.write_cl_value(total_supply_key, new_total_supply)?;
+        self.summary.push(format!(
+            "set balance of {} from {} to {}, changing total supply from {} to {}",
+            set_balance.account.to_hex(),
+            old_balance,
+            set_balance.balance,
+            old_total_supply,
+            new_total_supply
+        ));
+        Ok(())
+    }
+
+    fn add_associated_key(&mut self, add_associated_key: &AddAssociatedKey) -> Result<(), Error> {
+        let mut account = self.read_account(&add_associated_key.account)?;
+        account
+            .add_associated_key(add_associated_key.associated_key, add_associated_key.weight)
+            .map_err(|error| Error::AddAssociatedKey {
+                account: add_associated_key.account,
+                error,
+            })?;
+        self.write(
+            Key::Account(account.account_hash()),
+            StoredValue::Account(account),
+        );
+        self.summary.push(format!(
+            "add associated key {} with weight {} to account of {}",
+            add_associated_key.associated_key,
+            add_associated_key.weight.value(),
+            add_associated_key.account.to_hex()
+        ));
+        Ok(())
+    }
+
+    fn set_named_key(&mut self, set_named_key: &SetNamedKey) -> Result<(), Error> {
+        let key =
+            Key::from_formatted_str(&set_named_key.key).map_err(|error| Error::InvalidKey {
+                key: set_named_key.key.clone(),
+                error: error.to_string(),
+            })?;
+        let mut account = self.read_account(&set_named_key.account)?;
+        let old_key = account
+            .named_keys_mut()
+            .insert(set_named_key.name.clone(), key);
+        self.write(
+            Key::Account(account.account_hash()),
+            StoredValue::Account(account),
+        );
+        self.summary.push(match old_key {
+            Some(old_key) => format!(
+                "change named key {} of account of {} from {} to {}",
+                set_named_key.name,
+                set_named_key.account.to_hex(),
+                old_key.to_formatted_string(),
+                set_named_key.key
+            ),
+            None => format!(
+                "add named key {} = {} to account of {}",
+                set_named_key.name,
+                set_named_key.key,
+                set_named_key.account.to_hex()
+            ),
+        });
+        Ok(())
+    }
+
+    fn replace_validator(&mut self, replace_validator: &ReplaceValidator) -> Result<(), Error> {
+        let old_public_key = replace_validator.old_public_key;
+        let new_public_key = replace_validator.new_public_key;
+        let old_bid = self
+            .read_bid(&old_public_key)?
+            .ok_or(Error::MissingBid(old_public_key))?;
+        if self.read_bid(&new_public_key)?.is_some() {
+            return Err(Error::BidExists(new_public_key));
+        }
+
+        let new_bid = rekey_bid(&old_bid, new_public_key);
+        let mut emptied_bid = old_bid;
+        *emptied_bid.staked_amount_mut() = U512::zero();
+        emptied_bid.delegators_mut().clear();
+        emptied_bid.deactivate();
+        self.write_bid(new_bid);
+        self.write_bid(emptied_bid);
+
+        self.update_seigniorage_recipients(|recipients| {
+            if let Some(recipient) = recipients.remove(&old_public_key) {
+                let _ = recipients.insert(new_public_key, recipient);
+            }
+        })?;
+        self.summary.push(format!(
+            "replace validator {} with {}",
+            old_public_key.to_hex(),
+            new_public_key.to_hex()
+        ));
+        Ok(())
+    }
+
+    fn rotate_validators(&mut self, rotate_validators: &RotateValidators) -> Result<(), Error> {
+        let public_keys: BTreeSet<PublicKey> =
+            rotate_validators.public_keys.iter().copied().collect();
+        let bids = self.bids()?;
+        if let Some(public_key) = public_keys.iter().find(|key| !bids.contains_key(key)) {
+            return Err(Error::MissingBid(*public_key));
+        }
+
+        for (public_key, bid) in &bids {
+            let inactive = !public_keys.contains(public_key);
+            if bid.inactive() != inactive {
+                let mut bid = bid.clone();
+                if inactive {
+                    bid.deactivate();
+                } else {
+                    bid.activate();
+                }
+                self.write_bid(bid);
+            }
+        }
+
+        let new_recipients: SeigniorageRecipients = public_keys
+            .iter()
+            .map(|public_key| (*public_key, SeigniorageRecipient::from(&bids[public_key])))
+            .collect();
+        self.update_seigniorage_recipients(|recipients| *recipients = new_recipients.clone())?;
+        self.summary.push(format!(
+            "rotate validator set to {}",
+            public_keys
+                .iter()
+                .map(AsymmetricType::to_hex)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+        Ok(())
+    }
+
+    /// Encodes and validates the update, and describes the changes.
+    fn finish(self) -> Result<GeneratedGlobalStateUpdate, Error> {
+        let config = GlobalStateUpdateConfig::from_values(&self.writes)?;
+        self.validate(config.clone())?;
+        let global_state_toml = toml::to_string_pretty(&config)?;
+        let diff = self.diff()?;
+        Ok(GeneratedGlobalStateUpdate {
+            global_state_toml,
+            diff,
+        })
+    }
+
+    /// Checks that `config` decodes to exactly the written values, and that no value changes
+    /// type.
+    fn validate(&self, config: GlobalStateUpdateConfig) -> Result<(), Error> {
+        let update = GlobalStateUpdate::try_from(config)
+            .map_err(|error| Error::Validation(error.to_string()))?;
+        if update.0.len() != self.writes.len() {
+            return Err(Error::Validation(format!(
+                "expected {} entries, found {}",
+                self.writes.len(),
+                update.0.len()
+            )));
+        }
+        for (key, bytes) in update.0 {
+            let formatted_key = key.to_formatted_string();
+            let expected = self.writes.get(&key).ok_or_else(|| {
+                Error::Validation(format!("unexpected entry for {}", formatted_key))
+            })?;
+            let value: StoredValue = bytesrepr::deserialize(bytes.into())?;
+            if value != *expected {
+                return Err(Error::Validation(format!(
+                    "entry for {} does not decode to the expected value",
+                    formatted_key
+                )));
+            }
+            if let Some(old_value) = self.reader.read(&key)? {
+                if old_value.type_name() != value.type_name() {
+                    return Err(Error::Validation(format!(
+                        "entry for {} changes the value type from {} to {}",
+                        formatted_key,
+                        old_value.type_name(),
+                        value.type_name()
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the summary of the applied operations, followed by the old and new JSON
+    /// representation of every changed value.
+    fn diff(&self) -> Result<String, Error> {
+        let mut diff = String::new();
+        for line in &self.summary {
+            diff.push_str(&format!("* {}\n", line));
+        }
+        for (key, new_value) in &self.writes {
+            diff.push_str(&format!("\n{}\n", key.to_formatted_string()));
+            if let Some(old_value) = self.reader.read(key)? {
+                for line in to_json(&old_value)?.lines() {
+                    diff.push_str(&format!("- {}\n", line));
+                }
+            }
+            for line in to_json(new_value)?.lines() {
+                diff.push_str(&format!("+ {}\n", line));
+            }
+        }
+        Ok(diff)
+    }
+}
+
+fn to_json(value: &StoredValue) -> Result<String, Error> {
+    let json_value = json_compatibility::StoredValue::try_from(value)?;
+    Ok(serde_json::to_string_pretty(&json_value)?)
+}
+
+/// Returns a copy of `bid` belonging to `new_public_key`, keeping its purse, stake, delegators
+/// and vesting schedules.
+fn rekey_bid(bid: &Bid, new_public_key: PublicKey) -> Bid {
+    let mut new_bid = match bid.vesting_schedule() {
+        Some(vesting_schedule) => Bid::locked(
+            new_public_key,
+            *bid.bonding_purse(),
+            *bid.staked_amount(),
+            *bid.delegation_rate(),
+            vesting_schedule.initial_release_timestamp_millis(),
+        ),
+        None => Bid::unlocked(
+            new_public_key,
+            *bid.bonding_purse(),
+            *bid.staked_amount(),
+            *bid.delegation_rate(),
+        ),
+    };
+    if let (Some(new_schedule), Some(old_schedule)) =
+        (new_bid.vesting_schedule_mut(), bid.vesting_schedule())
+    {
+        *new_schedule = *old_schedule;
+    }
+
+    for (delegator_public_key, delegator) in bid.delegators() {
+        let mut new_delegator = match delegator.vesting_schedule() {
+            Some(vesting_schedule) => Delegator::locked(
+                *delegator_public_key,
+                *delegator.staked_amount(),
+                *delegator.bonding_purse(),
+                new_public_key,
+                vesting_schedule.initial_release_timestamp_millis(),
+            ),
+            None => Delegator::unlocked(
+                *delegator_public_key,
+                *delegator.staked_amount(),
+                *delegator.bonding_purse(),
+                new_public_key,
+            ),
+        };
+        if let (Some(new_schedule), Some(old_schedule)) = (
+            new_delegator.vesting_schedule_mut(),
+            delegator.vesting_schedule(),
+        ) {
+            *new_schedule = *old_schedule;
+        }
+        let _ = new_bid
+            .delegators_mut()
+            .insert(*delegator_public_key, new_delegator);
+    }
+
+    if bid.inactive() {
+        new_bid.deactivate();
+    }
+    new_bid
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{
+        contracts::NamedKeys, system::auction::DelegationRate, AccessRights, Contract,
+        ContractPackageHash, ContractWasmHash, EntryPoints, SecretKey, URef,
+    };
+
+    use super::*;
+
+    const MINT: ContractHash = ContractHash::new([1; 32]);
+    const AUCTION: ContractHash = ContractHash::new([2; 32]);
+    const TOTAL_SUPPLY: URef = URef::new([3; 32], AccessRights::READ_ADD_WRITE);
+    const SNAPSHOT: URef = URef::new([4; 32], AccessRights::READ_ADD_WRITE);
+    const DELEGATION_RATE: DelegationRate = 10;
+
+    /// Global state held in memory.
+    struct InMemoryReader(BTreeMap<Key, StoredValue>);
+
+    impl StateReader for InMemoryReader {
+        fn read(&self, key: &Key) -> Result<Option<StoredValue>, Error> {
+            Ok(self.0.get(key).cloned())
+        }
+
+        fn bids(&self) -> Result<Bids, Error> {
+            Ok(self
+                .0
+                .values()
+                .filter_map(|value| match value {
+                    StoredValue::Bid(bid) => Some((*bid.validator_public_key(), (**bid).clone())),
+                    _ => None,
+                })
+                .collect())
+        }
+    }
+
+    fn public_key(seed: u8) -> PublicKey {
+        PublicKey::from(&SecretKey::ed25519([seed; 32]))
+    }
+
+    fn system_contract(name: &str, uref: URef) -> StoredValue {
+        let mut named_keys = NamedKeys::new();
+        let _ = named_keys.insert(name.to_string(), Key::URef(uref));
+        StoredValue::Contract(Contract::new(
+            ContractPackageHash::new([0; 32]),
+            ContractWasmHash::new([0; 32]),
+            named_keys,
+            EntryPoints::new(),
+            ProtocolVersion::V1_0_0,
+        ))
+    }
+
+    fn cl_value<T: CLTyped + ToBytes>(value: T) -> StoredValue {
+        StoredValue::CLValue(CLValue::from_t(value).unwrap())
+    }
+
+    /// Returns global state with a funded account for `public_key(1)` and bids for
+    /// `public_key(10)` and `public_key(11)`, both of which are in the seigniorage snapshot.
+    fn global_state() -> InMemoryReader {
+        let mut state = BTreeMap::new();
+        let purse = URef::new([5; 32], AccessRights::READ_ADD_WRITE);
+        let account_hash = public_key(1).to_account_hash();
+        let account = Account::create(account_hash, NamedKeys::new(), purse);
+        let _ = state.insert(Key::Account(account_hash), StoredValue::Account(account));
+        let _ = state.insert(Key::Balance(purse.addr()), cl_value(U512::from(100)));
+        let _ = state.insert(
+            Key::Hash(MINT.value()),
+            system_contract(TOTAL_SUPPLY_KEY, TOTAL_SUPPLY),
+        );
+        let _ = state.insert(
+            Key::URef(TOTAL_SUPPLY).normalize(),
+            cl_value(U512::from(1_000)),
+        );
+
+        let mut recipients = SeigniorageRecipients::new();
+        for seed in 10..12 {
+            let bonding_purse = URef::new([seed; 32], AccessRights::READ_ADD_WRITE);
+            let mut bid = Bid::unlocked(
+                public_key(seed),
+                bonding_purse,
+                U512::from(50),
+                DELEGATION_RATE,
+            );
+            let _ = bid.delegators_mut().insert(
+                public_key(20),
+                Delegator::locked(
+                    public_key(20),
+                    U512::from(5),
+                    bonding_purse,
+                    public_key(seed),
+                    1_000,
+                ),
+            );
+            let _ = recipients.insert(public_key(seed), SeigniorageRecipient::from(&bid));
+            let _ = state.insert(
+                Key::Bid(public_key(seed).to_account_hash()),
+                StoredValue::Bid(Box::new(bid)),
+            );
+        }
+        let snapshot: SeigniorageRecipientsSnapshot =
+            (0..2).map(|era| (era, recipients.clone())).collect();
+        let _ = state.insert(
+            Key::Hash(AUCTION.value()),
+            system_contract(SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY, SNAPSHOT),
+        );
+        let _ = state.insert(Key::URef(SNAPSHOT).normalize(), cl_value(snapshot));
+        InMemoryReader(state)
+    }
+
+    fn builder(reader: &InMemoryReader) -> UpdateBuilder<'_, InMemoryReader> {
+        let system_contracts = SystemContracts {
+            mint: MINT,
+            auction: AUCTION,
+        };
+        UpdateBuilder::new(reader, system_contracts)
+    }
+
+    #[test]
+    fn should_set_balance_and_adjust_total_supply() {
+        let reader = global_state();
+        let mut builder = builder(&reader);
+        builder
+            .set_balance(&SetBalance {
+                account: public_key(1),
+                balance: U512::from(30),
+            })
+            .unwrap();
+
+        let purse = builder.read_account(&public_key(1)).unwrap().main_purse();
+        let balance: U512 = builder.read_cl_value(Key::Balance(purse.addr())).unwrap();
+        let total_supply: U512 = builder.read_cl_value(Key::URef(TOTAL_SUPPLY)).unwrap();
+        assert_eq!(balance, U512::from(30));
+        assert_eq!(total_supply, U512::from(930));
+        assert_eq!(builder.writes.len(), 2);
+    }
+
+    #[test]
+    fn should_reject_total_supply_underflow() {
+        let reader = global_state();
+        let mut builder = builder(&reader);
+        builder
+            .write_cl_value(Key::URef(TOTAL_SUPPLY), U512::from(10))
+            .unwrap();
+        let result = builder.set_balance(&SetBalance {
+            account: public_key(1),
+            balance: U512::zero(),
+        });
+        assert!(matches!(result, Err(Error::TotalSupplyOutOfRange)));
+    }
+
+    #[test]
+    fn should_add_associated_key_and_named_key() {
+        let reader = global_state();
+        let mut builder = builder(&reader);
+        let associated_key = public_key(2).to_account_hash();
+        builder
+            .add_associated_key(&AddAssociatedKey {
+                account: public_key(1),
+                associated_key,
+                weight: Weight::new(1),
+            })
+            .unwrap();
+        let named_key = Key::Hash([7; 32]);
+        builder
+            .set_named_key(&SetNamedKey {
+                account: public_key(1),
+                name: "my_key".to_string(),
+                key: named_key.to_formatted_string(),
+            })
+            .unwrap();
+
+        let account = builder.read_account(&public_key(1)).unwrap();
+        assert!(account.get_associated_key_weight(associated_key).is_some());
+        assert_eq!(account.named_keys().get("my_key"), Some(&named_key));
+        assert_eq!(builder.writes.len(), 1);
+
+        let result = builder.set_named_key(&SetNamedKey {
+            account: public_key(1),
+            name: "my_key".to_string(),
+            key: "not a key".to_string(),
+        });
+        assert!(matches!(result, Err(Error::InvalidKey { .. })));
+    }
+
+    #[test]
+    fn should_replace_validator() {
+        let reader = global_state();
+        let mut builder = builder(&reader);
+        builder
+            .replace_validator(&ReplaceValidator {
+                old_public_key: public_key(10),
+                new_public_key: public_key(12),
+            })
+            .unwrap();
+
+        let old_bid = builder.read_bid(&public_key(10)).unwrap().unwrap();
+        assert!(old_bid.inactive());
+        assert!(old_bid.staked_amount().is_zero());
+        assert!(old_bid.delegators().is_empty());
+
+        let new_bid = builder.read_bid(&public_key(12)).unwrap().unwrap();
+        assert!(!new_bid.inactive());
+        assert_eq!(*new_bid.staked_amount(), U512::from(50));
+        let delegator = &new_bid.delegators()[&public_key(20)];
+        assert_eq!(*delegator.validator_public_key(), public_key(12));
+        assert!(delegator.vesting_schedule().is_some());
+
+        let snapshot: SeigniorageRecipientsSnapshot =
+            builder.read_cl_value(Key::URef(SNAPSHOT)).unwrap();
+        for recipients in snapshot.values() {
+            assert!(recipients.contains_key(&public_key(12)));
+            assert!(!recipients.contains_key(&public_key(10)));
+        }
+
+        let result = builder.replace_validator(&ReplaceValidator {
+            old_public_key: public_key(11),
+            new_public_key: public_key(12),
+        });
+        assert!(matches!(result, Err(Error::BidExists(_))));
+    }
+
+    #[test]
+    fn should_rotate_validators() {
+        let reader = global_state();
+        let mut builder = builder(&reader);
+        builder
+            .rotate_validators(&RotateValidators {
+                public_keys: vec![public_key(11)],
+            })
+            .unwrap();
+
+        assert!(builder
+            .read_bid(&public_key(10))
+            .unwrap()
+            .unwrap()
+            .inactive());
+        assert!(!builder
+            .read_bid(&public_key(11))
+            .unwrap()
+            .unwrap()
+            .inactive());
+        let snapshot: SeigniorageRecipientsSnapshot =
+            builder.read_cl_value(Key::URef(SNAPSHOT)).unwrap();
+        for recipients in snapshot.values() {
+            assert_eq!(
+                recipients.keys().copied().collect::<Vec<_>>(),
+                vec![public_key(11)]
+            );
+        }
+
+        let result = builder.rotate_validators(&RotateValidators {
+            public_keys: vec![public_key(13)],
+        });
+        assert!(matches!(result, Err(Error::MissingBid(_))));
+    }
+
+    #[test]
+    fn should_generate_valid_global_state_update() {
+        let operations: Operations = toml::from_str(&format!(
+            r#"
+            [[set_balance]]
+            account = "{}"
+            balance = "250"
+
+            [rotate_validators]
+            public_keys = ["{}"]
+            "#,
+            public_key(1).to_hex(),
+            public_key(10).to_hex()
+        ))
+        .unwrap();
+        let system_contracts = SystemContracts {
+            mint: MINT,
+            auction: AUCTION,
+        };
+        let generated = generate(&global_state(), system_contracts, operations).unwrap();
+
+        let config: GlobalStateUpdateConfig = toml::from_str(&generated.global_state_toml).unwrap();
+        let update = GlobalStateUpdate::try_from(config).unwrap();
+        // The balance, the total supply, the deactivated bid and the snapshot.
+        assert_eq!(update.0.len(), 4);
+        assert!(update
+            .0
+            .contains_key(&Key::Bid(public_key(11).to_account_hash())));
+        assert!(generated.diff.contains("set balance of"));
+        assert!(generated.diff.contains("rotate validator set to"));
+    }
+
+    #[test]
+    fn should_reject_unknown_operations() {
+        assert!(toml::from_str::<Operations>("[[set_stake]]\nvalidator = \"01\"").is_err());
+    }
+}
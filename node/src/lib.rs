@@ -29,6 +29,9 @@ mod config_migration;
 pub mod crypto;
 mod data_migration;
 pub mod effect;
+mod global_state_pruning;
+mod global_state_update_gen;
+mod local_network;
 pub mod logging;
 pub mod protocol;
 pub mod reactor;
@@ -53,6 +56,8 @@ use signal_hook::{
 };
 
 pub use components::{
+    admin_server::Config as AdminServerConfig,
+    block_proposer::Config as BlockProposerConfig,
     consensus::Config as ConsensusConfig,
     contract_runtime::Config as ContractRuntimeConfig,
     deploy_acceptor::Config as DeployAcceptorConfig,
@@ -66,6 +71,13 @@ pub use components::{
 };
 pub use config_migration::{migrate_config, Error as ConfigMigrationError};
 pub use data_migration::{migrate_data, Error as DataMigrationError};
+pub use global_state_pruning::{prune_global_state, Error as GlobalStatePruningError};
+pub use global_state_update_gen::{
+    generate_global_state_update, Error as GlobalStateUpdateGenError, GeneratedGlobalStateUpdate,
+};
+pub use local_network::{
+    Error as LocalNetworkError, LocalNetwork, LocalNetworkConfig, NodeAddresses, MAX_NODE_COUNT,
+};
 pub use types::NodeRng;
 pub use utils::OS_PAGE_SIZE;
 
@@ -0,0 +1,858 @@
+//! A self-contained local network of validator nodes, run as child processes.
+//!
+//! [`LocalNetwork::generate`] creates a directory per node holding a fresh secret key, a copy of
+//! the chainspec with an activation point shortly in the future and every node bonded as a
+//! validator, and a config with non-conflicting ports on `127.0.0.1` and `known_addresses`
+//! listing every node:
+//!
+//! ```text
+//! <dir>/.local-network
+//! <dir>/faucet/secret_key.pem
+//! <dir>/node-1/{accounts.toml, chainspec.toml, config.toml, secret_key.pem}
+//! <dir>/node-1/storage/
+//! <dir>/node-1/{stdout.log, stderr.log}
+//! ...
+//! ```
+//!
+//! The empty `.local-network` file marks `<dir>` as generated, so that a later `generate` may
+//! replace it.  A non-empty directory without it is never deleted.
+//!
+//! Node `n` uses port `base_port + n` for networking, and `base_port + 100 + n`,
+//! `base_port + 200 + n`, `base_port + 300 + n` and `base_port + 400 + n` for the JSON-RPC, REST,
+//! event stream and admin servers respectively.
+//!
+//! Individual nodes can then be started, stopped, restarted and have their storage wiped, either
+//! through the API or interactively via [`LocalNetwork::supervise`].
+
+use std::{
+    cmp,
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead},
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    process::{Child, Command, ExitStatus, Stdio},
+    str::FromStr,
+    sync::{atomic::Ordering, mpsc},
+    thread,
+    time::{Duration, Instant},
+};
+
+use thiserror::Error;
+use toml::{value::Table, Value};
+use tracing::{info, warn};
+
+use casper_types::{AsymmetricType, PublicKey, SecretKey};
+
+use crate::{
+    crypto::{self, AsymmetricKeyExt},
+    types::{TimeDiff, Timestamp},
+    utils::{self, ReadFileError, WriteFileError},
+    TERMINATION_REQUESTED,
+};
+
+const CHAINSPEC_TEMPLATE_FILENAME: &str = "chainspec.toml.in";
+const CHAINSPEC_FILENAME: &str = "chainspec.toml";
+const ACCOUNTS_FILENAME: &str = "accounts.toml";
+const CONFIG_FILENAME: &str = "config.toml";
+const SECRET_KEY_FILENAME: &str = "secret_key.pem";
+const STORAGE_DIR: &str = "storage";
+const STDOUT_FILENAME: &str = "stdout.log";
+const STDERR_FILENAME: &str = "stderr.log";
+const FAUCET_DIR: &str = "faucet";
+/// Marks a directory as holding a generated network, and hence safe to replace.
+const MARKER_FILENAME: &str = ".local-network";
+
+/// The maximum number of nodes, limited by the port ranges reserved for each service.
+pub const MAX_NODE_COUNT: usize = 99;
+
+const RPC_PORT_OFFSET: u16 = 100;
+const REST_PORT_OFFSET: u16 = 200;
+const EVENT_STREAM_PORT_OFFSET: u16 = 300;
+const ADMIN_PORT_OFFSET: u16 = 400;
+
+const FAUCET_BALANCE: &str = "1000000000000000000000000000";
+const VALIDATOR_BALANCE: &str = "1000000000000000000";
+const VALIDATOR_BONDED_AMOUNT: &str = "500000000000000";
+const VALIDATOR_DELEGATION_RATE: i64 = 10;
+
+/// How long a node is given to shut down after being sent `SIGTERM` before it is killed.
+const STOP_TIMEOUT: Duration = Duration::from_secs(10);
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Error returned by a [`LocalNetwork`].
+#[derive(Debug, Error)]
+pub enum Error {
+    /// The requested number of nodes is not supported.
+    #[error("node count must be between 1 and {}, got {}", MAX_NODE_COUNT, .0)]
+    InvalidNodeCount(usize),
+
+    /// The base port leaves no room for the ports of every node.
+    #[error("base port {0} is too high to allocate ports for every node")]
+    InvalidBasePort(u16),
+
+    /// There is no node with the given index.
+    #[error("no node {0}; nodes are numbered from 1")]
+    NoSuchNode(usize),
+
+    /// The node must be stopped for the operation.
+    #[error("node {0} is running")]
+    NodeRunning(usize),
+
+    /// Error reading a resource file.
+    #[error(transparent)]
+    ReadFile(#[from] ReadFileError),
+
+    /// Error writing a generated file.
+    #[error(transparent)]
+    WriteFile(#[from] WriteFileError),
+
+    /// The directory is not empty and does not hold a previously generated network.
+    #[error(
+        "'{}' is not empty and was not generated as a local network; refusing to delete it",
+        .0.display()
+    )]
+    DirectoryNotEmpty(PathBuf),
+
+    /// Error creating or removing a directory.
+    #[error("could not create or remove '{}': {error}", .path.display())]
+    Directory {
+        /// The directory.
+        path: PathBuf,
+        /// The underlying OS error.
+        #[source]
+        error: io::Error,
+    },
+
+    /// Error decoding a resource file as TOML.
+    #[error("error decoding '{}': {error}", .path.display())]
+    DecodeToml {
+        /// The file.
+        path: PathBuf,
+        /// The decoding error.
+        #[source]
+        error: toml::de::Error,
+    },
+
+    /// A resource file does not have the expected structure.
+    #[error("'{}' is malformed: {message}", .path.display())]
+    Malformed {
+        /// The file.
+        path: PathBuf,
+        /// Description of the problem.
+        message: String,
+    },
+
+    /// Error encoding a generated file as TOML.
+    #[error("error encoding TOML: {0}")]
+    EncodeToml(#[from] toml::ser::Error),
+
+    /// Error generating or writing a secret key.
+    #[error("error generating secret key: {0}")]
+    Crypto(#[from] crypto::Error),
+
+    /// Error spawning, signalling or waiting for a node process.
+    #[error("error controlling node {index}: {error}")]
+    Process {
+        /// The index of the node.
+        index: usize,
+        /// The underlying OS error.
+        #[source]
+        error: io::Error,
+    },
+}
+
+/// Configuration of a [`LocalNetwork`].
+#[derive(Clone, Debug)]
+pub struct LocalNetworkConfig {
+    /// The number of validator nodes.
+    pub node_count: usize,
+    /// The directory in which all files of the network are created.  It must be missing, empty or
+    /// hold a network previously generated there, which is then deleted.
+    pub dir: PathBuf,
+    /// The directory holding the `config.toml` and `chainspec.toml.in` (or `chainspec.toml`) on
+    /// which the nodes' files are based, e.g. `resources/local`.
+    pub resources_dir: PathBuf,
+    /// The `casper-node` binary used to run the nodes.
+    pub node_binary: PathBuf,
+    /// The delay between generating the chainspec and its activation point.
+    pub genesis_delay: Duration,
+    /// The port from which each node's ports are allocated.
+    pub base_port: u16,
+}
+
+/// The addresses on which a node listens.
+#[derive(Clone, Copy, Debug)]
+pub struct NodeAddresses {
+    /// The address used for networking with other nodes.
+    pub network: SocketAddr,
+    /// The address of the JSON-RPC server.
+    pub rpc: SocketAddr,
+    /// The address of the REST server.
+    pub rest: SocketAddr,
+    /// The address of the event stream server.
+    pub event_stream: SocketAddr,
+    /// The address of the admin server.
+    pub admin: SocketAddr,
+}
+
+impl NodeAddresses {
+    fn new(base_port: u16, index: usize) -> Self {
+        let port = base_port + index as u16;
+        let localhost = |port: u16| SocketAddr::from(([127, 0, 0, 1], port));
+        NodeAddresses {
+            network: localhost(port),
+            rpc: localhost(port + RPC_PORT_OFFSET),
+            rest: localhost(port + REST_PORT_OFFSET),
+            event_stream: localhost(port + EVENT_STREAM_PORT_OFFSET),
+            admin: localhost(port + ADMIN_PORT_OFFSET),
+        }
+    }
+}
+
+struct Node {
+    dir: PathBuf,
+    public_key: PublicKey,
+    addresses: NodeAddresses,
+    process: Option<Child>,
+}
+
+/// A local network of validator nodes, each run as a child process.
+///
+/// All running nodes are stopped when the network is dropped.
+pub struct LocalNetwork {
+    dir: PathBuf,
+    node_binary: PathBuf,
+    nodes: Vec<Node>,
+}
+
+impl LocalNetwork {
+    /// Generates the keys, chainspecs and configs of a new network in `config.dir`, replacing any
+    /// network previously generated there.  No nodes are started.
+    ///
+    /// Returns [`Error::DirectoryNotEmpty`] without modifying `config.dir` if it holds anything
+    /// other than a generated network.
+    pub fn generate(config: LocalNetworkConfig) -> Result<Self, Error> {
+        if config.node_count == 0 || config.node_count > MAX_NODE_COUNT {
+            return Err(Error::InvalidNodeCount(config.node_count));
+        }
+        if config
+            .base_port
+            .checked_add(ADMIN_PORT_OFFSET + MAX_NODE_COUNT as u16)
+            .is_none()
+        {
+            return Err(Error::InvalidBasePort(config.base_port));
+        }
+
+        if !is_replaceable(&config.dir)? {
+            return Err(Error::DirectoryNotEmpty(config.dir));
+        }
+        remove_dir(&config.dir)?;
+        create_dir(&config.dir)?;
+        utils::write_file(config.dir.join(MARKER_FILENAME), b"")?;
+
+        let faucet_dir = config.dir.join(FAUCET_DIR);
+        create_dir(&faucet_dir)?;
+        let faucet_key = SecretKey::generate_ed25519()?;
+        faucet_key.to_file(faucet_dir.join(SECRET_KEY_FILENAME))?;
+
+        let mut nodes = Vec::with_capacity(config.node_count);
+        for index in 1..=config.node_count {
+            let dir = config.dir.join(format!("node-{}", index));
+            create_dir(&dir)?;
+            let secret_key = SecretKey::generate_ed25519()?;
+            secret_key.to_file(dir.join(SECRET_KEY_FILENAME))?;
+            nodes.push(Node {
+                dir,
+                public_key: PublicKey::from(&secret_key),
+                addresses: NodeAddresses::new(config.base_port, index),
+                process: None,
+            });
+        }
+
+        let activation_point = Timestamp::now() + TimeDiff::from(config.genesis_delay);
+        let chainspec = generate_chainspec(&config.resources_dir, activation_point, nodes.len())?;
+        let accounts = generate_accounts(&PublicKey::from(&faucet_key), &nodes)?;
+        let known_addresses: Vec<Value> = nodes
+            .iter()
+            .map(|node| Value::String(node.addresses.network.to_string()))
+            .collect();
+        let base_config = read_toml(&config.resources_dir.join(CONFIG_FILENAME))?;
+
+        for node in &nodes {
+            let node_config = generate_config(
+                &config.resources_dir,
+                base_config.clone(),
+                &node.addresses,
+                known_addresses.clone(),
+            )?;
+            utils::write_file(node.dir.join(CHAINSPEC_FILENAME), &chainspec)?;
+            utils::write_file(node.dir.join(ACCOUNTS_FILENAME), &accounts)?;
+            utils::write_file(node.dir.join(CONFIG_FILENAME), node_config)?;
+        }
+
+        info!(
+            dir = %config.dir.display(),
+            node_count = nodes.len(),
+            %activation_point,
+            "generated local network"
+        );
+        Ok(LocalNetwork {
+            dir: config.dir,
+            node_binary: config.node_binary,
+            nodes,
+        })
+    }
+
+    /// Returns the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the directory holding the files of node `index`.
+    pub fn node_dir(&self, index: usize) -> Result<&Path, Error> {
+        Ok(&self.node(index)?.dir)
+    }
+
+    /// Returns the public key of node `index`.
+    pub fn public_key(&self, index: usize) -> Result<&PublicKey, Error> {
+        Ok(&self.node(index)?.public_key)
+    }
+
+    /// Returns the addresses on which node `index` listens.
+    pub fn addresses(&self, index: usize) -> Result<NodeAddresses, Error> {
+        Ok(self.node(index)?.addresses)
+    }
+
+    /// Returns the path of the secret key of the faucet account, funded at genesis.
+    pub fn faucet_secret_key_path(&self) -> PathBuf {
+        self.dir.join(FAUCET_DIR).join(SECRET_KEY_FILENAME)
+    }
+
+    /// Starts every node which isn't already running.
+    pub fn start_all(&mut self) -> Result<(), Error> {
+        for index in 1..=self.nodes.len() {
+            if !self.is_running(index)? {
+                self.start(index)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Stops every running node.
+    pub fn stop_all(&mut self) -> Result<(), Error> {
+        for index in 1..=self.nodes.len() {
+            self.stop(index)?;
+        }
+        Ok(())
+    }
+
+    /// Starts node `index`, appending its output to `stdout.log` and `stderr.log` in its
+    /// directory.
+    pub fn start(&mut self, index: usize) -> Result<(), Error> {
+        if self.is_running(index)? {
+            return Err(Error::NodeRunning(index));
+        }
+        let node_binary = self.node_binary.clone();
+        let node = self.node_mut(index)?;
+        let process_error = |error| Error::Process { index, error };
+        let stdout = open_log(&node.dir.join(STDOUT_FILENAME)).map_err(process_error)?;
+        let stderr = open_log(&node.dir.join(STDERR_FILENAME)).map_err(process_error)?;
+        let child = Command::new(node_binary)
+            .arg("validator")
+            .arg(node.dir.join(CONFIG_FILENAME))
+            .stdin(Stdio::null())
+            .stdout(stdout)
+            .stderr(stderr)
+            .spawn()
+            .map_err(process_error)?;
+        info!(%index, pid = child.id(), "started node");
+        node.process = Some(child);
+        Ok(())
+    }
+
+    /// Stops node `index` if it is running, giving it a few seconds to shut down gracefully
+    /// before killing it.
+    pub fn stop(&mut self, index: usize) -> Result<(), Error> {
+        let node = self.node_mut(index)?;
+        let mut child = match node.process.take() {
+            Some(child) => child,
+            None => return Ok(()),
+        };
+        let process_error = |error| Error::Process { index, error };
+        if child.try_wait().map_err(process_error)?.is_some() {
+            return Ok(());
+        }
+
+        // Safe as `kill` has no memory safety requirements; the PID belongs to our unreaped child.
+        if unsafe { libc::kill(child.id() as libc::pid_t, libc::SIGTERM) } != 0 {
+            return Err(process_error(io::Error::last_os_error()));
+        }
+        let deadline = Instant::now() + STOP_TIMEOUT;
+        while Instant::now() < deadline {
+            if let Some(status) = child.try_wait().map_err(process_error)? {
+                info!(%index, %status, "stopped node");
+                return Ok(());
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+
+        warn!(%index, "node did not shut down in time, killing it");
+        child.kill().map_err(process_error)?;
+        child.wait().map_err(process_error)?;
+        Ok(())
+    }
+
+    /// Stops and restarts node `index`.
+    pub fn restart(&mut self, index: usize) -> Result<(), Error> {
+        self.stop(index)?;
+        self.start(index)
+    }
+
+    /// Deletes the storage of node `index`, which must be stopped, so that it rejoins the network
+    /// from scratch when next started.
+    pub fn wipe(&mut self, index: usize) -> Result<(), Error> {
+        if self.is_running(index)? {
+            return Err(Error::NodeRunning(index));
+        }
+        let storage_dir = self.node(index)?.dir.join(STORAGE_DIR);
+        remove_dir(&storage_dir)?;
+        info!(%index, "wiped node storage");
+        Ok(())
+    }
+
+    /// Returns whether node `index` is running.
+    pub fn is_running(&mut self, index: usize) -> Result<bool, Error> {
+        Ok(self.try_wait(index)?.is_none() && self.node(index)?.process.is_some())
+    }
+
+    /// Returns the exit statuses of all nodes which exited since last checked, without being
+    /// stopped via [`stop`](Self::stop).
+    pub fn exited_nodes(&mut self) -> Result<Vec<(usize, ExitStatus)>, Error> {
+        let mut exited = vec![];
+        for index in 1..=self.nodes.len() {
+            if let Some(status) = self.try_wait(index)? {
+                exited.push((index, status));
+            }
+        }
+        Ok(exited)
+    }
+
+    /// Starts every node, then executes commands read line by line from stdin until `quit` is
+    /// entered or termination is requested via a signal.  Nodes exiting unexpectedly are
+    /// reported but not restarted.  All nodes are stopped before returning.
+    ///
+    /// Supported commands are `start <n>`, `stop <n>`, `restart <n>`, `wipe <n>`, `status` and
+    /// `quit`.
+    pub fn supervise(&mut self) -> Result<(), Error> {
+        self.start_all()?;
+        self.print_status()?;
+
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines() {
+                match line {
+                    Ok(line) => {
+                        if sender.send(line).is_err() {
+                            break;
+                        }
+                    }
+                    Err(error) => {
+                        warn!(%error, "error reading from stdin");
+                        break;
+                    }
+                }
+            }
+        });
+
+        while TERMINATION_REQUESTED.load(Ordering::SeqCst) == 0 {
+            for (index, status) in self.exited_nodes()? {
+                warn!(%index, %status, "node exited");
+            }
+            let line = match receiver.recv_timeout(POLL_INTERVAL) {
+                Ok(line) => line,
+                Err(_) => continue,
+            };
+            let result = match line.parse() {
+                Ok(SupervisorCommand::Quit) => break,
+                Ok(SupervisorCommand::Status) => self.print_status(),
+                Ok(SupervisorCommand::Start(index)) => self.start(index),
+                Ok(SupervisorCommand::Stop(index)) => self.stop(index),
+                Ok(SupervisorCommand::Restart(index)) => self.restart(index),
+                Ok(SupervisorCommand::Wipe(index)) => self.wipe(index),
+                Err(()) if line.trim().is_empty() => Ok(()),
+                Err(()) => {
+                    println!(
+                        "unknown command '{}'; expected start <n>, stop <n>, restart <n>, \
+                         wipe <n>, status or quit",
+                        line.trim()
+                    );
+                    Ok(())
+                }
+            };
+            if let Err(error) = result {
+                println!("error: {}", error);
+            }
+        }
+
+        self.stop_all()
+    }
+
+    fn print_status(&mut self) -> Result<(), Error> {
+        for index in 1..=self.nodes.len() {
+            let running = self.is_running(index)?;
+            let node = self.node(index)?;
+            println!(
+                "node-{}: {} rpc={} rest={} events={} dir={}",
+                index,
+                if running { "running" } else { "stopped" },
+                node.addresses.rpc,
+                node.addresses.rest,
+                node.addresses.event_stream,
+                node.dir.display()
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the exit status of node `index` if its process has exited, reaping it.
+    fn try_wait(&mut self, index: usize) -> Result<Option<ExitStatus>, Error> {
+        let node = self.node_mut(index)?;
+        let status = match node.process.as_mut() {
+            Some(child) => child
+                .try_wait()
+                .map_err(|error| Error::Process { index, error })?,
+            None => None,
+        };
+        if status.is_some() {
+            node.process = None;
+        }
+        Ok(status)
+    }
+
+    fn node(&self, index: usize) -> Result<&Node, Error> {
+        index
+            .checked_sub(1)
+            .and_then(|position| self.nodes.get(position))
+            .ok_or(Error::NoSuchNode(index))
+    }
+
+    fn node_mut(&mut self, index: usize) -> Result<&mut Node, Error> {
+        index
+            .checked_sub(1)
+            .and_then(move |position| self.nodes.get_mut(position))
+            .ok_or(Error::NoSuchNode(index))
+    }
+}
+
+impl Drop for LocalNetwork {
+    fn drop(&mut self) {
+        if let Err(error) = self.stop_all() {
+            warn!(%error, "failed to stop local network");
+        }
+    }
+}
+
+/// A command entered while supervising a [`LocalNetwork`].
+#[derive(Debug, PartialEq)]
+enum SupervisorCommand {
+    Start(usize),
+    Stop(usize),
+    Restart(usize),
+    Wipe(usize),
+    Status,
+    Quit,
+}
+
+impl FromStr for SupervisorCommand {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut words = input.split_whitespace();
+        let command = words.next().ok_or(())?;
+        let index = words.next().map(str::parse::<usize>).transpose();
+        if words.next().is_some() {
+            return Err(());
+        }
+        match (command, index) {
+            ("start", Ok(Some(index))) => Ok(SupervisorCommand::Start(index)),
+            ("stop", Ok(Some(index))) => Ok(SupervisorCommand::Stop(index)),
+            ("restart", Ok(Some(index))) => Ok(SupervisorCommand::Restart(index)),
+            ("wipe", Ok(Some(index))) => Ok(SupervisorCommand::Wipe(index)),
+            ("status", Ok(None)) => Ok(SupervisorCommand::Status),
+            ("quit", Ok(None)) => Ok(SupervisorCommand::Quit),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Returns the chainspec from `resources_dir`, activated at `activation_point` and with at least
+/// `node_count` validator slots.
+fn generate_chainspec(
+    resources_dir: &Path,
+    activation_point: Timestamp,
+    node_count: usize,
+) -> Result<String, Error> {
+    let template_path = resources_dir.join(CHAINSPEC_TEMPLATE_FILENAME);
+    let path = if template_path.exists() {
+        template_path
+    } else {
+        resources_dir.join(CHAINSPEC_FILENAME)
+    };
+    let mut chainspec = read_toml(&path)?;
+
+    let protocol = table_mut(&mut chainspec, "protocol", &path)?;
+    protocol.insert(
+        "activation_point".to_string(),
+        Value::String(activation_point.to_string()),
+    );
+
+    let core = table_mut(&mut chainspec, "core", &path)?;
+    let validator_slots = core
+        .get("validator_slots")
+        .and_then(Value::as_integer)
+        .unwrap_or_default();
+    core.insert(
+        "validator_slots".to_string(),
+        Value::Integer(cmp::max(validator_slots, node_count as i64)),
+    );
+
+    Ok(toml::to_string_pretty(&chainspec)?)
+}
+
+/// Returns an `accounts.toml` funding the faucet and bonding every node as a validator.
+fn generate_accounts(faucet: &PublicKey, nodes: &[Node]) -> Result<String, Error> {
+    let account = |public_key: &PublicKey, balance: &str| {
+        let mut account = Table::new();
+        account.insert("public_key".to_string(), Value::String(public_key.to_hex()));
+        account.insert("balance".to_string(), Value::String(balance.to_string()));
+        account
+    };
+
+    let mut accounts = vec![Value::Table(account(faucet, FAUCET_BALANCE))];
+    for node in nodes {
+        let mut validator = Table::new();
+        validator.insert(
+            "bonded_amount".to_string(),
+            Value::String(VALIDATOR_BONDED_AMOUNT.to_string()),
+        );
+        validator.insert(
+            "delegation_rate".to_string(),
+            Value::Integer(VALIDATOR_DELEGATION_RATE),
+        );
+        let mut account = account(&node.public_key, VALIDATOR_BALANCE);
+        account.insert("validator".to_string(), Value::Table(validator));
+        accounts.push(Value::Table(account));
+    }
+
+    let mut accounts_toml = Table::new();
+    accounts_toml.insert("accounts".to_string(), Value::Array(accounts));
+    Ok(toml::to_string_pretty(&Value::Table(accounts_toml))?)
+}
+
+/// Returns `base_config` with the node's secret key, storage and addresses filled in.
+fn generate_config(
+    resources_dir: &Path,
+    mut config: Value,
+    addresses: &NodeAddresses,
+    known_addresses: Vec<Value>,
+) -> Result<String, Error> {
+    let path = resources_dir.join(CONFIG_FILENAME);
+    let string = |value: &dyn ToString| Value::String(value.to_string());
+    let overrides = vec![
+        ("consensus", "secret_key_path", string(&SECRET_KEY_FILENAME)),
+        ("consensus", "unit_hashes_folder", string(&STORAGE_DIR)),
+        ("network", "bind_address", string(&addresses.network)),
+        ("network", "public_address", string(&addresses.network)),
+        ("network", "known_addresses", Value::Array(known_addresses)),
+        ("rpc_server", "address", string(&addresses.rpc)),
+        ("rest_server", "address", string(&addresses.rest)),
+        (
+            "event_stream_server",
+            "address",
+            string(&addresses.event_stream),
+        ),
+        (
+            "event_stream_server",
+            "event_index_path",
+            string(&format!("{}/sse", STORAGE_DIR)),
+        ),
+        ("admin_server", "address", string(&addresses.admin)),
+        ("storage", "path", string(&STORAGE_DIR)),
+    ];
+    for (section, key, value) in overrides {
+        table_mut(&mut config, section, &path)?.insert(key.to_string(), value);
+    }
+    Ok(toml::to_string_pretty(&config)?)
+}
+
+fn read_toml(path: &Path) -> Result<Value, Error> {
+    let bytes = utils::read_file(path)?;
+    let contents = String::from_utf8_lossy(&bytes);
+    toml::from_str(&contents).map_err(|error| Error::DecodeToml {
+        path: path.to_owned(),
+        error,
+    })
+}
+
+/// Returns the table `section` of `value`, inserting an empty one if missing.
+fn table_mut<'a>(value: &'a mut Value, section: &str, path: &Path) -> Result<&'a mut Table, Error> {
+    let malformed = |message: String| Error::Malformed {
+        path: path.to_owned(),
+        message,
+    };
+    value
+        .as_table_mut()
+        .ok_or_else(|| malformed("not a table".to_string()))?
+        .entry(section)
+        .or_insert_with(|| Value::Table(Table::new()))
+        .as_table_mut()
+        .ok_or_else(|| malformed(format!("[{}] is not a table", section)))
+}
+
+/// Returns whether `dir` can be deleted to generate a network: it is missing, empty or marked as
+/// holding a generated network.
+fn is_replaceable(dir: &Path) -> Result<bool, Error> {
+    match fs::read_dir(dir) {
+        Ok(mut entries) => Ok(entries.next().is_none() || dir.join(MARKER_FILENAME).is_file()),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(true),
+        Err(error) => Err(Error::Directory {
+            path: dir.to_owned(),
+            error,
+        }),
+    }
+}
+
+fn create_dir(path: &Path) -> Result<(), Error> {
+    fs::create_dir_all(path).map_err(|error| Error::Directory {
+        path: path.to_owned(),
+        error,
+    })
+}
+
+fn remove_dir(path: &Path) -> Result<(), Error> {
+    match fs::remove_dir_all(path) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => Err(Error::Directory {
+            path: path.to_owned(),
+            error,
+        }),
+        _ => Ok(()),
+    }
+}
+
+fn open_log(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        reactor::validator::Config,
+        types::Chainspec,
+        utils::{Loadable, RESOURCES_PATH},
+    };
+
+    #[test]
+    fn should_generate_loadable_network() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config = LocalNetworkConfig {
+            node_count: 3,
+            dir: temp_dir.path().join("net"),
+            resources_dir: RESOURCES_PATH.join("local"),
+            node_binary: PathBuf::from("casper-node"),
+            genesis_delay: Duration::from_secs(30),
+            base_port: 30000,
+        };
+        let network = LocalNetwork::generate(config).unwrap();
+        assert!(network.faucet_secret_key_path().exists());
+
+        let mut ports = vec![];
+        for index in 1..=3 {
+            let dir = network.node_dir(index).unwrap();
+            let chainspec = Chainspec::from_path(dir).unwrap();
+            assert_eq!(
+                chainspec.network_config.chainspec_validator_stakes().len(),
+                3
+            );
+            assert!(chainspec.core_config.validator_slots >= 3);
+
+            let encoded_config = fs::read_to_string(dir.join(CONFIG_FILENAME)).unwrap();
+            let config: Config = toml::from_str(&encoded_config).unwrap();
+            assert_eq!(config.network.known_addresses.len(), 3);
+
+            let addresses = network.addresses(index).unwrap();
+            ports.extend(vec![
+                addresses.network.port(),
+                addresses.rpc.port(),
+                addresses.rest.port(),
+                addresses.event_stream.port(),
+                addresses.admin.port(),
+            ]);
+        }
+        let port_count = ports.len();
+        ports.sort_unstable();
+        ports.dedup();
+        assert_eq!(ports.len(), port_count);
+
+        assert!(matches!(network.node_dir(0), Err(Error::NoSuchNode(0))));
+        assert!(matches!(network.node_dir(4), Err(Error::NoSuchNode(4))));
+    }
+
+    #[test]
+    fn should_only_replace_generated_network() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config = LocalNetworkConfig {
+            node_count: 1,
+            dir: temp_dir.path().to_owned(),
+            resources_dir: RESOURCES_PATH.join("local"),
+            node_binary: PathBuf::from("casper-node"),
+            genesis_delay: Duration::from_secs(30),
+            base_port: 30000,
+        };
+
+        let unrelated_file = temp_dir.path().join("unrelated.txt");
+        fs::write(&unrelated_file, "keep me").unwrap();
+        assert!(matches!(
+            LocalNetwork::generate(config.clone()),
+            Err(Error::DirectoryNotEmpty(_))
+        ));
+        assert_eq!(fs::read_to_string(&unrelated_file).unwrap(), "keep me");
+
+        fs::remove_file(&unrelated_file).unwrap();
+        drop(LocalNetwork::generate(config.clone()).unwrap());
+        let network = LocalNetwork::generate(config).unwrap();
+        assert!(network.faucet_secret_key_path().exists());
+    }
+
+    #[test]
+    fn should_reject_invalid_node_count() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config = LocalNetworkConfig {
+            node_count: 0,
+            dir: temp_dir.path().join("net"),
+            resources_dir: RESOURCES_PATH.join("local"),
+            node_binary: PathBuf::from("casper-node"),
+            genesis_delay: Duration::from_secs(30),
+            base_port: 30000,
+        };
+        assert!(matches!(
+            LocalNetwork::generate(config),
+            Err(Error::InvalidNodeCount(0))
+        ));
+    }
+
+    #[test]
+    fn should_parse_supervisor_commands() {
+        assert_eq!("start 2".parse(), Ok(SupervisorCommand::Start(2)));
+        assert_eq!(" stop 1 ".parse(), Ok(SupervisorCommand::Stop(1)));
+        assert_eq!("restart 3".parse(), Ok(SupervisorCommand::Restart(3)));
+        assert_eq!("wipe 4".parse(), Ok(SupervisorCommand::Wipe(4)));
+        assert_eq!("status".parse(), Ok(SupervisorCommand::Status));
+        assert_eq!("quit".parse(), Ok(SupervisorCommand::Quit));
+        assert_eq!("start".parse::<SupervisorCommand>(), Err(()));
+        assert_eq!("start x".parse::<SupervisorCommand>(), Err(()));
+        assert_eq!("status 1".parse::<SupervisorCommand>(), Err(()));
+        assert_eq!("start 1 2".parse::<SupervisorCommand>(), Err(()));
+        assert_eq!("".parse::<SupervisorCommand>(), Err(()));
+    }
+}
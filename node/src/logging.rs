@@ -5,6 +5,7 @@ use std::{env, fmt, io};
 use ansi_term::{Color, Style};
 use anyhow::anyhow;
 use datasize::DataSize;
+use once_cell::sync::OnceCell;
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 use tracing::{
@@ -18,7 +19,7 @@ use tracing_subscriber::{
         FmtContext, FormatEvent, FormatFields, FormattedFields,
     },
     registry::LookupSpan,
-    EnvFilter,
+    reload, EnvFilter,
 };
 
 const LOG_CONFIGURATION_ENVVAR: &str = "RUST_LOG";
@@ -29,6 +30,9 @@ const LOG_FIELD_MODULE: &str = "log.module_path";
 const LOG_FIELD_FILE: &str = "log.file";
 const LOG_FIELD_LINE: &str = "log.line";
 
+/// Handle to the filter of the global logger, set once the logger has been installed.
+static LOG_FILTER_HANDLE: OnceCell<LogFilterHandle> = OnceCell::new();
+
 /// Logging configuration.
 #[derive(DataSize, Debug, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -279,18 +283,73 @@ pub fn init_with_config(config: &LoggingConfig) -> anyhow::Result<()> {
 
     match config.format {
         // Setup a new tracing-subscriber writing to `stdout` for logging.
-        LoggingFormat::Text => tracing_subscriber::fmt()
-            .with_writer(io::stdout)
-            .with_env_filter(filter)
-            .fmt_fields(formatter)
-            .event_format(FmtEvent::new(config.color, config.abbreviate_modules))
-            .try_init(),
+        LoggingFormat::Text => {
+            let builder = tracing_subscriber::fmt()
+                .with_writer(io::stdout)
+                .with_env_filter(filter)
+                .fmt_fields(formatter)
+                .event_format(FmtEvent::new(config.color, config.abbreviate_modules))
+                .with_filter_reloading();
+            let handle = builder.reload_handle();
+            builder
+                .try_init()
+                .map(|()| LogFilterHandle::install(handle))
+        }
         // JSON logging writes to `stdout` as well but uses the JSON format.
-        LoggingFormat::Json => tracing_subscriber::fmt()
-            .with_writer(io::stdout)
-            .with_env_filter(filter)
-            .json()
-            .try_init(),
+        LoggingFormat::Json => {
+            let builder = tracing_subscriber::fmt()
+                .with_writer(io::stdout)
+                .with_env_filter(filter)
+                .json()
+                .with_filter_reloading();
+            let handle = builder.reload_handle();
+            builder
+                .try_init()
+                .map(|()| LogFilterHandle::install(handle))
+        }
     }
     .map_err(|error| anyhow!(error))
 }
+
+/// Replaces the filter of the global logger, e.g. with `warn,casper_node=debug`.
+///
+/// The directives use the same syntax as the `RUST_LOG` environment variable.
+pub fn reload_filter(directives: &str) -> anyhow::Result<()> {
+    let filter = EnvFilter::try_new(directives)?;
+    let handle = LOG_FILTER_HANDLE
+        .get()
+        .ok_or_else(|| anyhow!("logging has not been initialized"))?;
+    (handle.reload)(filter)?;
+    Ok(())
+}
+
+/// Returns the directives of the global logger's current filter.
+pub fn current_filter() -> anyhow::Result<String> {
+    let handle = LOG_FILTER_HANDLE
+        .get()
+        .ok_or_else(|| anyhow!("logging has not been initialized"))?;
+    Ok((handle.current)()?)
+}
+
+/// Type-erased access to the reloadable filter of the global logger.
+///
+/// The concrete `reload::Handle` type depends on the configured output format, hence the boxing.
+struct LogFilterHandle {
+    reload: Box<dyn Fn(EnvFilter) -> Result<(), reload::Error> + Send + Sync>,
+    current: Box<dyn Fn() -> Result<String, reload::Error> + Send + Sync>,
+}
+
+impl LogFilterHandle {
+    /// Stores `handle` as the handle to the global logger's filter.
+    fn install<S>(handle: reload::Handle<EnvFilter, S>)
+    where
+        S: Subscriber + 'static,
+        reload::Handle<EnvFilter, S>: Send + Sync,
+    {
+        let reload_handle = handle.clone();
+        let _ = LOG_FILTER_HANDLE.set(LogFilterHandle {
+            reload: Box::new(move |filter| reload_handle.reload(filter)),
+            current: Box::new(move || handle.with_current(ToString::to_string)),
+        });
+    }
+}
@@ -60,7 +60,7 @@ use utils::rlimit::{Limit, OpenFiles, ResourceLimit};
 
 use crate::{
     effect::{announcements::ControlAnnouncement, Effect, EffectBuilder, Effects},
-    types::{ExitCode, Timestamp},
+    types::{ExitCode, TimeDiff, Timestamp},
     unregister_metric,
     utils::{self, WeightedRoundRobin},
     NodeRng, QUEUE_DUMP_REQUESTED, TERMINATION_REQUESTED,
@@ -103,6 +103,14 @@ static DISPATCH_EVENT_THRESHOLD: Lazy<Duration> = Lazy::new(|| {
         .unwrap_or_else(|_| DEFAULT_DISPATCH_EVENT_THRESHOLD)
 });
 
+/// The time at which the first reactor of this process was created.
+static STARTUP_TIMESTAMP: Lazy<Timestamp> = Lazy::new(Timestamp::now);
+
+/// Returns the time elapsed since the first reactor of this process was created.
+pub(crate) fn uptime() -> TimeDiff {
+    Timestamp::now() - *STARTUP_TIMESTAMP
+}
+
 #[cfg(target_os = "linux")]
 /// The desired limit for open files.
 const TARGET_OPEN_FILES_LIMIT: Limit = 64_000;
@@ -206,6 +214,18 @@ impl<REv> EventQueueHandle<REv> {
     pub(crate) fn event_queues_counts(&self) -> HashMap<QueueKind, usize> {
         self.0.event_queues_counts()
     }
+
+    /// Serializes the contents of the scheduler's queues to JSON.
+    pub(crate) async fn queues_json(&self) -> Result<Vec<u8>, serde_json::Error>
+    where
+        REv: Serialize,
+    {
+        let mut buffer = Vec::new();
+        self.0
+            .snapshot(&mut serde_json::Serializer::new(&mut buffer))
+            .await?;
+        Ok(buffer)
+    }
 }
 
 /// Reactor core.
@@ -436,6 +456,7 @@ where
         rng: &mut NodeRng,
         registry: &Registry,
     ) -> Result<Self, R::Error> {
+        Lazy::force(&STARTUP_TIMESTAMP);
         adjust_open_files_limit();
 
         let event_size = mem::size_of::<R::Event>();
@@ -181,8 +181,14 @@ impl Reactor {
             chainspec_loader.chainspec().protocol_config.version.clone(),
         )?;
 
-        let contract_runtime =
-            ContractRuntime::new(storage_config, &config.value().contract_runtime, registry)?;
+        let contract_runtime = ContractRuntime::new(
+            storage_config,
+            &config.value().contract_runtime,
+            chainspec_loader
+                .chainspec()
+                .strict_entry_point_args_protocol_version(),
+            registry,
+        )?;
 
         if let Some(state_roots) = storage.get_state_root_hashes_for_trie_check() {
             let missing_trie_keys = contract_runtime.trie_store_check(state_roots.clone());
@@ -55,10 +55,10 @@ use crate::{
         requests::{
             BlockExecutorRequest, BlockProposerRequest, BlockValidationRequest,
             ChainspecLoaderRequest, ConsensusRequest, ContractRuntimeRequest, FetcherRequest,
-            LinearChainRequest, MetricsRequest, NetworkInfoRequest, NetworkRequest, RestRequest,
-            StateStoreRequest, StorageRequest,
+            LinearChainRequest, MetricsRequest, NetworkInfoRequest, NetworkRequest,
+            ReactorStatusRequest, RestRequest, StateStoreRequest, StorageRequest,
         },
-        EffectBuilder, Effects,
+        EffectBuilder, EffectExt, Effects,
     },
     protocol::Message,
     reactor::{
@@ -68,7 +68,10 @@ use crate::{
         validator::{self, Error, ValidatorInitConfig},
         EventQueueHandle, Finalize, ReactorExit,
     },
-    types::{Block, BlockByHeight, Deploy, ExitCode, NodeId, ProtoBlock, Tag, Timestamp},
+    types::{
+        Block, BlockByHeight, Deploy, ExitCode, NodeId, ProtoBlock, ReactorPhase, ReactorStatus,
+        Tag, Timestamp,
+    },
     utils::{Source, WithDir},
     NodeRng,
 };
@@ -103,6 +106,10 @@ pub enum Event {
     #[from]
     MetricsRequest(#[serde(skip_serializing)] MetricsRequest),
 
+    /// Reactor status request.
+    #[from]
+    ReactorStatusRequest(#[serde(skip_serializing)] ReactorStatusRequest),
+
     #[from]
     /// Chainspec Loader event.
     ChainspecLoader(#[serde(skip_serializing)] chainspec_loader::Event),
@@ -296,6 +303,7 @@ impl Display for Event {
             Event::RestServer(event) => write!(f, "rest server: {}", event),
             Event::EventStreamServer(event) => write!(f, "event stream server: {}", event),
             Event::MetricsRequest(req) => write!(f, "metrics request: {}", req),
+            Event::ReactorStatusRequest(req) => write!(f, "reactor status request: {}", req),
             Event::ChainspecLoader(event) => write!(f, "chainspec loader: {}", event),
             Event::ChainspecLoaderRequest(req) => write!(f, "chainspec loader request: {}", req),
             Event::NetworkInfoRequest(req) => write!(f, "network info request: {}", req),
@@ -482,8 +490,10 @@ impl reactor::Reactor for Reactor {
             protocol_version.clone(),
         )?;
 
-        let event_stream_server =
-            EventStreamServer::new(config.event_stream_server.clone(), protocol_version.clone())?;
+        let event_stream_server = EventStreamServer::new(
+            WithDir::new(root.clone(), config.event_stream_server.clone()),
+            protocol_version.clone(),
+        )?;
 
         let block_validator = BlockValidator::new(Arc::clone(&chainspec_loader.chainspec()));
 
@@ -898,6 +908,16 @@ impl reactor::Reactor for Reactor {
                 Event::MetricsRequest,
                 self.metrics.handle_event(effect_builder, rng, req),
             ),
+            Event::ReactorStatusRequest(ReactorStatusRequest::GetStatus { responder }) => {
+                let status = ReactorStatus {
+                    phase: ReactorPhase::Joiner,
+                    uptime: reactor::uptime(),
+                    sync_progress: Some(self.linear_chain_sync.sync_progress()),
+                    last_finalized_block_height: self.block_executor.last_finalized_height(),
+                    last_executed_block_height: self.block_executor.last_executed_height(),
+                };
+                responder.respond(status).ignore()
+            }
             Event::ChainspecLoader(event) => reactor::wrap_effects(
                 Event::ChainspecLoader,
                 self.chainspec_loader
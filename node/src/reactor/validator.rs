@@ -26,6 +26,7 @@ use tracing::{debug, error, warn};
 use crate::testing::network::NetworkedReactor;
 use crate::{
     components::{
+        admin_server::AdminServer,
         block_executor::{self, BlockExecutor},
         block_proposer::{self, BlockProposer},
         block_validator::{self, BlockValidator},
@@ -54,14 +55,18 @@ use crate::{
         requests::{
             BlockExecutorRequest, BlockProposerRequest, BlockValidationRequest,
             ChainspecLoaderRequest, ConsensusRequest, ContractRuntimeRequest, FetcherRequest,
-            LinearChainRequest, MetricsRequest, NetworkInfoRequest, NetworkRequest, RestRequest,
-            RpcRequest, StateStoreRequest, StorageRequest,
+            LinearChainRequest, MemoryReportRequest, MetricsRequest, NetworkInfoRequest,
+            NetworkRequest, ReactorStatusRequest, RestRequest, RpcRequest, StateStoreRequest,
+            StorageRequest,
         },
         EffectBuilder, EffectExt, Effects,
     },
     protocol::Message,
     reactor::{self, event_queue_metrics::EventQueueMetrics, EventQueueHandle, ReactorExit},
-    types::{Block, Deploy, ExitCode, NodeId, ProtoBlock, Tag, TimeDiff, Timestamp},
+    types::{
+        Block, Deploy, ExitCode, NodeId, ProtoBlock, ReactorPhase, ReactorStatus, Tag, TimeDiff,
+        Timestamp,
+    },
     utils::Source,
     NodeRng,
 };
@@ -150,6 +155,12 @@ pub enum Event {
     /// Metrics request.
     #[from]
     MetricsRequest(#[serde(skip_serializing)] MetricsRequest),
+    /// Memory report request.
+    #[from]
+    MemoryReportRequest(#[serde(skip_serializing)] MemoryReportRequest),
+    /// Reactor status request.
+    #[from]
+    ReactorStatusRequest(#[serde(skip_serializing)] ReactorStatusRequest),
     /// Chainspec info request
     #[from]
     ChainspecLoaderRequest(#[serde(skip_serializing)] ChainspecLoaderRequest),
@@ -281,6 +292,8 @@ impl Display for Event {
             Event::BlockExecutorRequest(req) => write!(f, "block executor request: {}", req),
             Event::ProtoBlockValidatorRequest(req) => write!(f, "block validator request: {}", req),
             Event::MetricsRequest(req) => write!(f, "metrics request: {}", req),
+            Event::MemoryReportRequest(req) => write!(f, "memory report request: {}", req),
+            Event::ReactorStatusRequest(req) => write!(f, "reactor status request: {}", req),
             Event::ControlAnnouncement(ctrl_ann) => write!(f, "control: {}", ctrl_ann),
             Event::NetworkAnnouncement(ann) => write!(f, "network announcement: {}", ann),
             Event::RpcServerAnnouncement(ann) => write!(f, "api server announcement: {}", ann),
@@ -347,6 +360,7 @@ pub struct Reactor {
     contract_runtime: ContractRuntime,
     rpc_server: RpcServer,
     rest_server: RestServer,
+    admin_server: Option<AdminServer>,
     event_stream_server: EventStreamServer,
     chainspec_loader: ChainspecLoader,
     consensus: EraSupervisor<NodeId>,
@@ -444,6 +458,14 @@ impl reactor::Reactor for Reactor {
             effect_builder,
             protocol_version.clone(),
         )?;
+        let admin_server = if config.admin_server.enable {
+            Some(AdminServer::new(
+                config.admin_server.clone(),
+                effect_builder,
+            )?)
+        } else {
+            None
+        };
 
         let deploy_acceptor =
             DeployAcceptor::new(config.deploy_acceptor, &*chainspec_loader.chainspec());
@@ -462,6 +484,7 @@ impl reactor::Reactor for Reactor {
                 .map(|block| block.height() + 1)
                 .unwrap_or(0),
             chainspec_loader.chainspec().as_ref(),
+            config.block_proposer,
         )?;
         let mut effects = reactor::wrap_effects(Event::BlockProposer, block_proposer_effects);
         let block_executor = BlockExecutor::new(
@@ -524,6 +547,7 @@ impl reactor::Reactor for Reactor {
                 contract_runtime,
                 rpc_server,
                 rest_server,
+                admin_server,
                 event_stream_server,
                 chainspec_loader,
                 consensus,
@@ -661,6 +685,21 @@ impl reactor::Reactor for Reactor {
                 Event::MetricsRequest,
                 self.metrics.handle_event(effect_builder, rng, req),
             ),
+            Event::MemoryReportRequest(MemoryReportRequest::EstimateHeapSizes { responder }) => {
+                responder
+                    .respond(self.memory_metrics.estimate(self))
+                    .ignore()
+            }
+            Event::ReactorStatusRequest(ReactorStatusRequest::GetStatus { responder }) => {
+                let status = ReactorStatus {
+                    phase: ReactorPhase::Validator,
+                    uptime: reactor::uptime(),
+                    sync_progress: None,
+                    last_finalized_block_height: self.block_executor.last_finalized_height(),
+                    last_executed_block_height: self.block_executor.last_executed_height(),
+                };
+                responder.respond(status).ignore()
+            }
             Event::ChainspecLoaderRequest(req) => {
                 self.dispatch_event(effect_builder, rng, Event::ChainspecLoader(req.into()))
             }
@@ -943,13 +982,18 @@ impl reactor::Reactor for Reactor {
                 self.dispatch_event(effect_builder, rng, reactor_event)
             }
             Event::LinearChainAnnouncement(LinearChainAnnouncement::BlockAdded(block)) => {
+                let reactor_event =
+                    Event::ContractRuntime(contract_runtime::Event::BlockAdded(block.clone()));
+                let mut effects = self.dispatch_event(effect_builder, rng, reactor_event);
+
                 let block_hash = *block.hash();
                 let reactor_event =
                     Event::EventStreamServer(event_stream_server::Event::BlockAdded {
                         block_hash,
                         block,
                     });
-                self.dispatch_event(effect_builder, rng, reactor_event)
+                effects.extend(self.dispatch_event(effect_builder, rng, reactor_event));
+                effects
             }
             Event::LinearChainAnnouncement(LinearChainAnnouncement::NewFinalitySignature(fs)) => {
                 let reactor_event =
@@ -2,9 +2,10 @@ use datasize::DataSize;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    logging::LoggingConfig, types::NodeConfig, ConsensusConfig, ContractRuntimeConfig,
-    DeployAcceptorConfig, EventStreamServerConfig, FetcherConfig, GossipConfig, RestServerConfig,
-    RpcServerConfig, SmallNetworkConfig, StorageConfig,
+    logging::LoggingConfig, types::NodeConfig, AdminServerConfig, BlockProposerConfig,
+    ConsensusConfig, ContractRuntimeConfig, DeployAcceptorConfig, EventStreamServerConfig,
+    FetcherConfig, GossipConfig, RestServerConfig, RpcServerConfig, SmallNetworkConfig,
+    StorageConfig,
 };
 
 /// Root configuration.
@@ -26,6 +27,8 @@ pub struct Config {
     pub rest_server: RestServerConfig,
     /// RPC API server configuration.
     pub rpc_server: RpcServerConfig,
+    /// Admin API server configuration.
+    pub admin_server: AdminServerConfig,
     /// On-disk storage configuration.
     pub storage: StorageConfig,
     /// Gossip protocol configuration.
@@ -36,4 +39,6 @@ pub struct Config {
     pub contract_runtime: ContractRuntimeConfig,
     /// Deploy acceptor configuration.
     pub deploy_acceptor: DeployAcceptorConfig,
+    /// Block proposer configuration.
+    pub block_proposer: BlockProposerConfig,
 }
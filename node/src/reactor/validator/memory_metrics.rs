@@ -1,4 +1,4 @@
-use std::env;
+use std::{collections::BTreeMap, env};
 
 use datasize::DataSize;
 use prometheus::{self, Histogram, HistogramOpts, IntGauge, Registry};
@@ -231,6 +231,29 @@ impl MemoryMetrics {
                %proto_block_validator,
                %linear_chain,
                "Collected new set of memory metrics.");
+
+        vec![
+            ("total", total),
+            ("metrics", metrics),
+            ("net", net),
+            ("address_gossiper", address_gossiper),
+            ("storage", storage),
+            ("contract_runtime", contract_runtime),
+            ("rpc_server", rpc_server),
+            ("rest_server", rest_server),
+            ("event_stream_server", event_stream_server),
+            ("chainspec_loader", chainspec_loader),
+            ("consensus", consensus),
+            ("deploy_fetcher", deploy_fetcher),
+            ("deploy_gossiper", deploy_gossiper),
+            ("block_proposer", block_proposer),
+            ("block_executor", block_executor),
+            ("proto_block_validator", proto_block_validator),
+            ("linear_chain", linear_chain),
+        ]
+        .into_iter()
+        .map(|(component, size)| (component.to_string(), size))
+        .collect()
     }
 }
 
@@ -16,9 +16,11 @@ use tracing_futures::Instrument;
 
 use super::ConditionCheckReactor;
 use crate::{
+    components::in_memory_network::{self, LinkFaults},
     effect::{EffectBuilder, Effects},
     reactor::{Finalize, Reactor, Runner},
     testing::TestRng,
+    types::NodeId,
     NodeRng,
 };
 
@@ -311,6 +313,50 @@ where
     }
 }
 
+/// Fault injection for networks whose nodes are connected via the active in-memory network.
+///
+/// All of these panic if there is no active `in_memory_network::NetworkController`.
+impl<R> Network<R>
+where
+    R: Reactor + NetworkedReactor<NodeId = NodeId>,
+{
+    /// Partitions the nodes in `side_a` from the nodes in `side_b` until `heal` is called.
+    pub fn partition(&mut self, side_a: &[NodeId], side_b: &[NodeId]) {
+        in_memory_network::with_active_faults(|faults| {
+            faults.partition(side_a.to_vec(), side_b.to_vec(), None)
+        })
+    }
+
+    /// Partitions the nodes in `side_a` from the nodes in `side_b` for `duration`.
+    pub fn partition_for(&mut self, side_a: &[NodeId], side_b: &[NodeId], duration: Duration) {
+        in_memory_network::with_active_faults(|faults| {
+            faults.partition(side_a.to_vec(), side_b.to_vec(), Some(duration))
+        })
+    }
+
+    /// Removes all partitions.
+    pub fn heal(&mut self) {
+        in_memory_network::with_active_faults(|faults| faults.heal())
+    }
+
+    /// Sets the faults applied to every link without a specific configuration.
+    pub fn set_default_link_faults(&mut self, link_faults: LinkFaults) {
+        in_memory_network::with_active_faults(|faults| faults.set_default_link_faults(link_faults))
+    }
+
+    /// Sets the faults applied to messages sent from `sender` to `recipient`.
+    pub fn set_link_faults(
+        &mut self,
+        sender: &NodeId,
+        recipient: &NodeId,
+        link_faults: LinkFaults,
+    ) {
+        in_memory_network::with_active_faults(|faults| {
+            faults.set_link_faults(sender.clone(), recipient.clone(), link_faults)
+        })
+    }
+}
+
 impl<R> Finalize for Network<R>
 where
     R: Finalize + NetworkedReactor + Reactor + Send + 'static,
@@ -18,22 +18,25 @@ use rand::{CryptoRng, RngCore};
 use rand_chacha::ChaCha20Rng;
 
 pub use block::{
-    json_compatibility::JsonBlock, Block, BlockBody, BlockHash, BlockHeader, BlockSignatures,
+    json_compatibility::{JsonBlock, JsonSignedBlockHeader},
+    Block, BlockBody, BlockHash, BlockHeader, BlockIdentifier, BlockSignatures,
     BlockValidationError, FinalitySignature,
 };
 pub(crate) use block::{BlockByHeight, BlockLike, FinalizedBlock, ProtoBlock};
 pub(crate) use chainspec::ActivationPoint;
 pub use chainspec::Chainspec;
 pub use deploy::{
-    Approval, Deploy, DeployHash, DeployHeader, DeployMetadata, DeployValidationFailure,
-    Error as DeployError, ExcessiveSizeError as ExcessiveSizeDeployError,
+    AccountDeploy, Approval, Deploy, DeployHash, DeployHeader, DeployMetadata,
+    DeployValidationFailure, Error as DeployError, ExcessiveSizeError as ExcessiveSizeDeployError,
 };
 pub use exit_code::ExitCode;
 pub use item::{Item, Tag};
 pub use node_config::NodeConfig;
 pub(crate) use node_id::NodeId;
-pub use peers_map::PeersMap;
-pub use status_feed::{ChainspecInfo, GetStatusResult, StatusFeed};
+pub use peers_map::{PeerConnectionState, PeersMap};
+pub use status_feed::{
+    ChainspecInfo, GetStatusResult, ReactorPhase, ReactorStatus, StatusFeed, SyncProgress,
+};
 pub use timestamp::{TimeDiff, Timestamp};
 
 /// An object-safe RNG trait that requires a cryptographically strong random number generator.
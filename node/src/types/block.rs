@@ -44,7 +44,7 @@ use crate::{
         AsymmetricKeyExt,
     },
     rpcs::docs::DocExample,
-    types::{Deploy, DeployHash, JsonBlock},
+    types::{Deploy, DeployHash, JsonBlock, JsonSignedBlockHeader},
     utils::DisplayIter,
 };
 
@@ -145,6 +145,18 @@ static JSON_BLOCK: Lazy<JsonBlock> = Lazy::new(|| {
 
     JsonBlock::new(block, block_signature)
 });
+static JSON_SIGNED_BLOCK_HEADER: Lazy<JsonSignedBlockHeader> = Lazy::new(|| {
+    let block = Block::doc_example();
+    let mut block_signatures = BlockSignatures::new(*block.hash(), block.header().era_id);
+
+    let secret_key = SecretKey::doc_example();
+    let public_key = PublicKey::from(secret_key);
+    let finality_signature =
+        FinalitySignature::new(*block.hash(), block.header().era_id, secret_key, public_key);
+    block_signatures.insert_proof(public_key, finality_signature.signature);
+
+    JsonSignedBlockHeader::new(block.header().clone(), block_signatures)
+});
 
 /// Error returned from constructing or validating a `Block`.
 #[derive(Debug, Error)]
@@ -418,6 +430,21 @@ impl FinalizedBlock {
         self.proposer
     }
 
+    /// Returns the accumulated seed of the block created from this finalized block, given the
+    /// accumulated seed of its parent.
+    pub(crate) fn accumulated_seed(&self, parent_seed: Digest) -> Digest {
+        let mut accumulated_seed = [0; Digest::LENGTH];
+
+        let mut hasher = VarBlake2b::new(Digest::LENGTH).expect("should create hasher");
+        hasher.update(parent_seed);
+        hasher.update([self.proto_block.random_bit as u8]);
+        hasher.finalize_variable(|slice| {
+            accumulated_seed.copy_from_slice(slice);
+        });
+
+        accumulated_seed.into()
+    }
+
     /// Generates a random instance using a `TestRng`.
     #[cfg(test)]
     pub fn random(rng: &mut TestRng) -> Self {
@@ -600,6 +627,16 @@ impl FromBytes for BlockHash {
     }
 }
 
+/// Identifier for possible ways to retrieve a block.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub enum BlockIdentifier {
+    /// Identify and retrieve the block with its hash.
+    Hash(BlockHash),
+    /// Identify and retrieve the block with its height.
+    Height(u64),
+}
+
 #[derive(Clone, DataSize, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize, Debug)]
 /// A struct to contain information related to the end of an era and validator weights for the
 /// following era.
@@ -1056,21 +1093,14 @@ impl Block {
             None => None,
         };
 
-        let mut accumulated_seed = [0; Digest::LENGTH];
-
-        let mut hasher = VarBlake2b::new(Digest::LENGTH).expect("should create hasher");
-        hasher.update(parent_seed);
-        hasher.update([finalized_block.proto_block.random_bit as u8]);
-        hasher.finalize_variable(|slice| {
-            accumulated_seed.copy_from_slice(slice);
-        });
+        let accumulated_seed = finalized_block.accumulated_seed(parent_seed);
 
         let header = BlockHeader {
             parent_hash,
             state_root_hash,
             body_hash,
             random_bit: finalized_block.proto_block.random_bit,
-            accumulated_seed: accumulated_seed.into(),
+            accumulated_seed,
             era_end,
             timestamp,
             era_id,
@@ -1557,6 +1587,49 @@ pub(crate) mod json_compatibility {
         }
     }
 
+    /// A JSON-friendly representation of a block header along with the finality signatures
+    /// collected for its block, allowing the header to be verified as part of the linear chain.
+    #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
+    #[serde(deny_unknown_fields)]
+    pub struct JsonSignedBlockHeader {
+        header: JsonBlockHeader,
+        proofs: Vec<JsonProof>,
+    }
+
+    impl JsonSignedBlockHeader {
+        /// Creates a new JSON signed block header from a block header and its block's signatures.
+        pub fn new(block_header: BlockHeader, signatures: BlockSignatures) -> Self {
+            let header = JsonBlockHeader::from(block_header);
+            let proofs = signatures.proofs.into_iter().map(JsonProof::from).collect();
+            JsonSignedBlockHeader { header, proofs }
+        }
+
+        /// Returns the block header.
+        pub fn header(&self) -> BlockHeader {
+            BlockHeader::from(self.header.clone())
+        }
+
+        /// Returns the finality signatures, bound to the hash and era of the block header.
+        pub fn finality_signatures(&self) -> Vec<FinalitySignature> {
+            let block_hash = self.header().hash();
+            self.proofs
+                .iter()
+                .map(|proof| FinalitySignature {
+                    block_hash,
+                    era_id: self.header.era_id,
+                    signature: proof.signature,
+                    public_key: proof.public_key,
+                })
+                .collect()
+        }
+    }
+
+    impl DocExample for JsonSignedBlockHeader {
+        fn doc_example() -> &'static Self {
+            &*JSON_SIGNED_BLOCK_HEADER
+        }
+    }
+
     /// A JSON-friendly representation of a proof, i.e. a block's finality signature.
     #[derive(Debug, Serialize, Deserialize, JsonSchema, Clone)]
     #[serde(deny_unknown_fields)]
@@ -1580,6 +1653,28 @@ pub(crate) mod json_compatibility {
         }
     }
 
+    #[test]
+    fn signed_block_header_should_yield_verifiable_finality_signatures() {
+        let mut rng = TestRng::new();
+        let block = Block::random(&mut rng);
+        let secret_key = SecretKey::random(&mut rng);
+        let public_key = PublicKey::from(&secret_key);
+        let finality_signature = FinalitySignature::new(
+            *block.hash(),
+            block.header().era_id,
+            &secret_key,
+            public_key,
+        );
+        let mut signatures = BlockSignatures::new(*block.hash(), block.header().era_id);
+        signatures.insert_proof(public_key, finality_signature.signature);
+
+        let signed_header = JsonSignedBlockHeader::new(block.header().clone(), signatures);
+        assert_eq!(signed_header.header(), *block.header());
+        let finality_signatures = signed_header.finality_signatures();
+        assert_eq!(finality_signatures, vec![finality_signature]);
+        assert!(finality_signatures[0].verify().is_ok());
+    }
+
     #[test]
     fn block_json_roundtrip() {
         let mut rng = TestRng::new();
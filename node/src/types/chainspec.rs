@@ -33,9 +33,14 @@ use casper_types::{
 pub(crate) use self::accounts_config::{AccountConfig, ValidatorConfig};
 pub use self::error::Error;
 pub(crate) use self::{
-    accounts_config::AccountsConfig, activation_point::ActivationPoint, core_config::CoreConfig,
-    deploy_config::DeployConfig, global_state_update::GlobalStateUpdate,
-    highway_config::HighwayConfig, network_config::NetworkConfig, protocol_config::ProtocolConfig,
+    accounts_config::AccountsConfig,
+    activation_point::ActivationPoint,
+    core_config::CoreConfig,
+    deploy_config::DeployConfig,
+    global_state_update::{GlobalStateUpdate, GlobalStateUpdateConfig},
+    highway_config::HighwayConfig,
+    network_config::NetworkConfig,
+    protocol_config::ProtocolConfig,
 };
 #[cfg(test)]
 use crate::testing::TestRng;
@@ -106,6 +111,21 @@ impl Chainspec {
             self.protocol_config.version.patch as u32,
         )
     }
+
+    /// Returns the protocol version from which installed contracts have their call args checked by
+    /// name against their entry points' parameters, if any.
+    pub(crate) fn strict_entry_point_args_protocol_version(&self) -> Option<ProtocolVersion> {
+        self.protocol_config
+            .strict_entry_point_args_version
+            .as_ref()
+            .map(|version| {
+                ProtocolVersion::from_parts(
+                    version.major as u32,
+                    version.minor as u32,
+                    version.patch as u32,
+                )
+            })
+    }
 }
 
 #[cfg(test)]
@@ -188,8 +208,9 @@ impl Loadable for Chainspec {
 
 impl From<&Chainspec> for ExecConfig {
     fn from(chainspec: &Chainspec) -> Self {
-        ExecConfig::new(
-            chainspec.network_config.accounts_config.clone().into(),
+        let accounts_config = &chainspec.network_config.accounts_config;
+        let mut exec_config = ExecConfig::new(
+            accounts_config.clone().into(),
             chainspec.wasm_config,
             chainspec.system_costs_config,
             chainspec.core_config.validator_slots,
@@ -197,12 +218,23 @@ impl From<&Chainspec> for ExecConfig {
             chainspec.core_config.locked_funds_period.millis(),
             chainspec.core_config.round_seigniorage_rate,
             chainspec.core_config.unbonding_delay,
+            chainspec.core_config.refund_ratio,
+            chainspec.core_config.fee_handling,
             chainspec
                 .protocol_config
                 .activation_point
                 .genesis_timestamp()
                 .map_or(0, |timestamp| timestamp.millis()),
-        )
+        );
+        for account_config in accounts_config.accounts() {
+            if let Some(account_setup) = account_config.genesis_account_setup() {
+                exec_config.push_account_setup(account_setup);
+            }
+        }
+        for contract_config in accounts_config.contracts() {
+            exec_config.push_contract(contract_config.clone().into());
+        }
+        exec_config
     }
 }
 
@@ -274,6 +306,8 @@ mod tests {
             remove_contract_user_group_urefs: HostFunction::new(131, [0, 1, 2, 3, 4, 5]),
             print: HostFunction::new(123, [0, 1]),
             blake2b: HostFunction::new(133, [0, 1, 2, 3]),
+            // Costs of host functions added after 1.0.0 default when absent from the chainspec.
+            ..HostFunctionCosts::default()
         });
     static EXPECTED_GENESIS_WASM_COSTS: Lazy<WasmConfig> = Lazy::new(|| {
         WasmConfig::new(
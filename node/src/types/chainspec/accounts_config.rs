@@ -1,7 +1,11 @@
 //! The accounts config is a set of configuration options that is used to create accounts at
-//! genesis, and set up auction contract with validators and delegators.
+//! genesis, set up auction contract with validators and delegators, and install contracts.
 mod account_config;
+mod action_thresholds_config;
+mod associated_key_config;
+mod contract_config;
 mod delegator_config;
+mod named_key_config;
 mod validator_config;
 
 use std::path::Path;
@@ -18,7 +22,11 @@ use crate::utils::{self, Loadable};
 
 use super::error::ChainspecAccountsLoadError;
 pub use account_config::AccountConfig;
+pub use action_thresholds_config::ActionThresholdsConfig;
+pub use associated_key_config::AssociatedKeyConfig;
+pub use contract_config::ContractConfig;
 pub use delegator_config::DelegatorConfig;
+pub use named_key_config::NamedKeyConfig;
 pub use validator_config::ValidatorConfig;
 
 const CHAINSPEC_ACCOUNTS_FILENAME: &str = "accounts.toml";
@@ -39,6 +47,9 @@ pub struct AccountsConfig {
     accounts: Vec<AccountConfig>,
     #[serde(default, deserialize_with = "sorted_vec_deserializer")]
     delegators: Vec<DelegatorConfig>,
+    /// Contracts to be installed at genesis, in the given order.
+    #[serde(default)]
+    contracts: Vec<ContractConfig>,
 }
 
 impl AccountsConfig {
@@ -46,6 +57,7 @@ impl AccountsConfig {
         Self {
             accounts,
             delegators,
+            contracts: vec![],
         }
     }
 
@@ -57,10 +69,15 @@ impl AccountsConfig {
         &self.delegators
     }
 
+    pub fn contracts(&self) -> &[ContractConfig] {
+        &self.contracts
+    }
+
     #[cfg(test)]
     /// Generates a random instance using a `TestRng`.
     pub fn random(rng: &mut TestRng) -> Self {
         let alpha = AccountConfig::random(rng);
+        let alpha_public_key = alpha.public_key;
         let accounts = vec![
             alpha,
             AccountConfig::random(rng),
@@ -69,13 +86,16 @@ impl AccountsConfig {
         ];
 
         let mut delegator = DelegatorConfig::random(rng);
-        delegator.validator_public_key = alpha.public_key;
+        delegator.validator_public_key = alpha_public_key;
 
         let delegators = vec![delegator];
 
+        let contracts = vec![ContractConfig::random(rng)];
+
         AccountsConfig {
             accounts,
             delegators,
+            contracts,
         }
     }
 }
@@ -85,11 +105,14 @@ impl ToBytes for AccountsConfig {
         let mut buffer = bytesrepr::allocate_buffer(self)?;
         buffer.extend(self.accounts.to_bytes()?);
         buffer.extend(self.delegators.to_bytes()?);
+        buffer.extend(self.contracts.to_bytes()?);
         Ok(buffer)
     }
 
     fn serialized_length(&self) -> usize {
-        self.accounts.serialized_length() + self.delegators.serialized_length()
+        self.accounts.serialized_length()
+            + self.delegators.serialized_length()
+            + self.contracts.serialized_length()
     }
 }
 
@@ -97,7 +120,12 @@ impl FromBytes for AccountsConfig {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (accounts, remainder) = FromBytes::from_bytes(bytes)?;
         let (delegators, remainder) = FromBytes::from_bytes(remainder)?;
-        let accounts_config = AccountsConfig::new(accounts, delegators);
+        let (contracts, remainder) = FromBytes::from_bytes(remainder)?;
+        let accounts_config = AccountsConfig {
+            accounts,
+            delegators,
+            contracts,
+        };
         Ok((accounts_config, remainder))
     }
 }
@@ -111,7 +139,13 @@ impl Loadable for AccountsConfig {
             return Ok(AccountsConfig::new(vec![], vec![]));
         }
         let bytes = utils::read_file(accounts_path)?;
-        let toml_chainspec: AccountsConfig = toml::from_slice(&bytes)?;
+        let mut toml_chainspec: AccountsConfig = toml::from_slice(&bytes)?;
+        // Contracts' Wasm paths are relative to the same directory as accounts.toml.
+        for contract_config in toml_chainspec.contracts.iter_mut() {
+            contract_config
+                .load_wasm(path.as_ref())
+                .map_err(ChainspecAccountsLoadError::LoadContractWasm)?;
+        }
         Ok(toml_chainspec)
     }
 }
@@ -134,7 +168,11 @@ impl From<AccountsConfig> for Vec<GenesisAccount> {
 
 #[cfg(test)]
 mod tests {
+    use casper_execution_engine::core::engine_state::GenesisContract;
+    use casper_types::{account::Weight, contracts::DEFAULT_ENTRY_POINT_NAME};
+
     use super::*;
+    use crate::utils::RESOURCES_PATH;
 
     #[test]
     fn serialization_roundtrip() {
@@ -142,4 +180,31 @@ mod tests {
         let accounts_config = AccountsConfig::random(&mut rng);
         bytesrepr::test_serialization_roundtrip(&accounts_config);
     }
+
+    #[test]
+    fn should_load_account_setups_and_contracts() {
+        let path = RESOURCES_PATH.join("test/valid/genesis_setup");
+        let accounts_config = AccountsConfig::from_path(path).expect("should load accounts");
+
+        let account_setups: Vec<_> = accounts_config
+            .accounts()
+            .iter()
+            .filter_map(AccountConfig::genesis_account_setup)
+            .collect();
+        assert_eq!(account_setups.len(), 1);
+        let account_setup = &account_setups[0];
+        assert_eq!(account_setup.associated_keys().len(), 2);
+        assert_eq!(account_setup.deployment_threshold(), Weight::new(2));
+        assert_eq!(account_setup.key_management_threshold(), Weight::new(3));
+        assert_eq!(account_setup.named_keys().len(), 2);
+
+        assert_eq!(accounts_config.contracts().len(), 1);
+        let genesis_contract = GenesisContract::from(accounts_config.contracts()[0].clone());
+        assert_eq!(genesis_contract.name(), "counter");
+        assert!(!genesis_contract.module_bytes().is_empty());
+        assert!(genesis_contract
+            .entry_points()
+            .has_entry_point(DEFAULT_ENTRY_POINT_NAME));
+        assert_eq!(genesis_contract.named_keys().len(), 1);
+    }
 }
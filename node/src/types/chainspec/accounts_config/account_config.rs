@@ -1,10 +1,15 @@
+use std::collections::BTreeMap;
+
 use datasize::DataSize;
 use num::Zero;
 #[cfg(test)]
 use rand::{distributions::Standard, prelude::*};
 use serde::{Deserialize, Serialize};
 
-use casper_execution_engine::{core::engine_state::GenesisAccount, shared::motes::Motes};
+use casper_execution_engine::{
+    core::engine_state::{GenesisAccount, GenesisAccountSetup},
+    shared::motes::Motes,
+};
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes},
     PublicKey,
@@ -15,13 +20,21 @@ use casper_types::{SecretKey, U512};
 #[cfg(test)]
 use crate::testing::TestRng;
 
-use super::ValidatorConfig;
+use super::{ActionThresholdsConfig, AssociatedKeyConfig, NamedKeyConfig, ValidatorConfig};
 
-#[derive(PartialEq, Ord, PartialOrd, Eq, Serialize, Deserialize, DataSize, Debug, Copy, Clone)]
+#[derive(PartialEq, Ord, PartialOrd, Eq, Serialize, Deserialize, DataSize, Debug, Clone)]
 pub struct AccountConfig {
     pub(super) public_key: PublicKey,
     balance: Motes,
     validator: Option<ValidatorConfig>,
+    /// Keys associated with the account in addition to its own key.
+    #[serde(default)]
+    associated_keys: Vec<AssociatedKeyConfig>,
+    /// If `None`, both thresholds default to 1.
+    #[serde(default)]
+    action_thresholds: Option<ActionThresholdsConfig>,
+    #[serde(default)]
+    named_keys: BTreeMap<String, NamedKeyConfig>,
 }
 
 impl AccountConfig {
@@ -30,6 +43,9 @@ impl AccountConfig {
             public_key,
             balance,
             validator,
+            associated_keys: Vec::new(),
+            action_thresholds: None,
+            named_keys: BTreeMap::new(),
         }
     }
 
@@ -52,17 +68,56 @@ impl AccountConfig {
         self.validator.is_some()
     }
 
+    /// Returns the setup of the account's associated keys, action thresholds and named keys, or
+    /// `None` if the account is a plain single-key account.
+    pub fn genesis_account_setup(&self) -> Option<GenesisAccountSetup> {
+        if self.associated_keys.is_empty()
+            && self.action_thresholds.is_none()
+            && self.named_keys.is_empty()
+        {
+            return None;
+        }
+
+        let associated_keys = self
+            .associated_keys
+            .iter()
+            .map(|associated_key| (associated_key.account_hash(), associated_key.weight()))
+            .collect();
+        let action_thresholds = self.action_thresholds.unwrap_or_default();
+        let named_keys = self
+            .named_keys
+            .iter()
+            .map(|(name, named_key)| (name.clone(), named_key.clone().into()))
+            .collect();
+
+        Some(GenesisAccountSetup::new(
+            self.public_key,
+            associated_keys,
+            action_thresholds.deployment(),
+            action_thresholds.key_management(),
+            named_keys,
+        ))
+    }
+
     #[cfg(test)]
     /// Generates a random instance using a `TestRng`.
     pub fn random(rng: &mut TestRng) -> Self {
         let public_key = PublicKey::from(&SecretKey::ed25519(rng.gen()));
         let balance = Motes::new(rng.gen());
         let validator = rng.gen();
+        let associated_keys = vec![rng.gen()];
+        let action_thresholds = rng.gen();
+        let named_keys = (0..rng.gen_range(0..3))
+            .map(|index| (format!("named_key_{}", index), rng.gen()))
+            .collect();
 
         AccountConfig {
             public_key,
             balance,
             validator,
+            associated_keys,
+            action_thresholds,
+            named_keys,
         }
     }
 }
@@ -88,6 +143,9 @@ impl ToBytes for AccountConfig {
         buffer.extend(self.public_key.to_bytes()?);
         buffer.extend(self.balance.to_bytes()?);
         buffer.extend(self.validator.to_bytes()?);
+        buffer.extend(self.associated_keys.to_bytes()?);
+        buffer.extend(self.action_thresholds.to_bytes()?);
+        buffer.extend(self.named_keys.to_bytes()?);
         Ok(buffer)
     }
 
@@ -95,6 +153,9 @@ impl ToBytes for AccountConfig {
         self.public_key.serialized_length()
             + self.balance.serialized_length()
             + self.validator.serialized_length()
+            + self.associated_keys.serialized_length()
+            + self.action_thresholds.serialized_length()
+            + self.named_keys.serialized_length()
     }
 }
 
@@ -103,10 +164,16 @@ impl FromBytes for AccountConfig {
         let (public_key, remainder) = FromBytes::from_bytes(bytes)?;
         let (balance, remainder) = FromBytes::from_bytes(remainder)?;
         let (validator, remainder) = FromBytes::from_bytes(remainder)?;
+        let (associated_keys, remainder) = FromBytes::from_bytes(remainder)?;
+        let (action_thresholds, remainder) = FromBytes::from_bytes(remainder)?;
+        let (named_keys, remainder) = FromBytes::from_bytes(remainder)?;
         let account_config = AccountConfig {
             public_key,
             balance,
             validator,
+            associated_keys,
+            action_thresholds,
+            named_keys,
         };
         Ok((account_config, remainder))
     }
@@ -0,0 +1,73 @@
+use datasize::DataSize;
+#[cfg(test)]
+use rand::{distributions::Standard, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use casper_types::{
+    account::Weight,
+    bytesrepr::{self, FromBytes, ToBytes},
+};
+
+/// The weights required to send deploys from and to manage the keys of a genesis account.
+#[derive(PartialEq, Ord, PartialOrd, Eq, Serialize, Deserialize, DataSize, Debug, Copy, Clone)]
+pub struct ActionThresholdsConfig {
+    #[data_size(skip)]
+    deployment: Weight,
+    #[data_size(skip)]
+    key_management: Weight,
+}
+
+impl ActionThresholdsConfig {
+    pub fn new(deployment: Weight, key_management: Weight) -> Self {
+        Self {
+            deployment,
+            key_management,
+        }
+    }
+
+    pub fn deployment(&self) -> Weight {
+        self.deployment
+    }
+
+    pub fn key_management(&self) -> Weight {
+        self.key_management
+    }
+}
+
+impl Default for ActionThresholdsConfig {
+    fn default() -> Self {
+        ActionThresholdsConfig::new(Weight::new(1), Weight::new(1))
+    }
+}
+
+#[cfg(test)]
+impl Distribution<ActionThresholdsConfig> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ActionThresholdsConfig {
+        ActionThresholdsConfig::new(Weight::new(rng.gen()), Weight::new(rng.gen()))
+    }
+}
+
+impl ToBytes for ActionThresholdsConfig {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.deployment.to_bytes()?);
+        buffer.extend(self.key_management.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.deployment.serialized_length() + self.key_management.serialized_length()
+    }
+}
+
+impl FromBytes for ActionThresholdsConfig {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (deployment, remainder) = FromBytes::from_bytes(bytes)?;
+        let (key_management, remainder) = FromBytes::from_bytes(remainder)?;
+        let action_thresholds_config = ActionThresholdsConfig {
+            deployment,
+            key_management,
+        };
+        Ok((action_thresholds_config, remainder))
+    }
+}
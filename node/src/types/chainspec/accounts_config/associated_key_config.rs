@@ -0,0 +1,66 @@
+use datasize::DataSize;
+#[cfg(test)]
+use rand::{distributions::Standard, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use casper_types::{
+    account::{AccountHash, Weight},
+    bytesrepr::{self, FromBytes, ToBytes},
+};
+
+/// A key associated with a genesis account, along with its weight.
+#[derive(PartialEq, Ord, PartialOrd, Eq, Serialize, Deserialize, DataSize, Debug, Copy, Clone)]
+pub struct AssociatedKeyConfig {
+    account_hash: AccountHash,
+    #[data_size(skip)]
+    weight: Weight,
+}
+
+impl AssociatedKeyConfig {
+    pub fn new(account_hash: AccountHash, weight: Weight) -> Self {
+        Self {
+            account_hash,
+            weight,
+        }
+    }
+
+    pub fn account_hash(&self) -> AccountHash {
+        self.account_hash
+    }
+
+    pub fn weight(&self) -> Weight {
+        self.weight
+    }
+}
+
+#[cfg(test)]
+impl Distribution<AssociatedKeyConfig> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> AssociatedKeyConfig {
+        AssociatedKeyConfig::new(AccountHash::new(rng.gen()), Weight::new(rng.gen()))
+    }
+}
+
+impl ToBytes for AssociatedKeyConfig {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.account_hash.to_bytes()?);
+        buffer.extend(self.weight.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.account_hash.serialized_length() + self.weight.serialized_length()
+    }
+}
+
+impl FromBytes for AssociatedKeyConfig {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (account_hash, remainder) = FromBytes::from_bytes(bytes)?;
+        let (weight, remainder) = FromBytes::from_bytes(remainder)?;
+        let associated_key_config = AssociatedKeyConfig {
+            account_hash,
+            weight,
+        };
+        Ok((associated_key_config, remainder))
+    }
+}
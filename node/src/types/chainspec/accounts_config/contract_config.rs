@@ -0,0 +1,136 @@
+use std::{collections::BTreeMap, path::Path};
+
+use datasize::DataSize;
+#[cfg(test)]
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use casper_execution_engine::core::engine_state::GenesisContract;
+#[cfg(test)]
+use casper_types::SecretKey;
+use casper_types::{
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
+    EntryPoint, EntryPoints, PublicKey,
+};
+
+#[cfg(test)]
+use crate::testing::TestRng;
+use crate::utils::{self, ReadFileError};
+
+use super::NamedKeyConfig;
+
+/// A Wasm contract to be installed at genesis.
+#[derive(PartialEq, Eq, Serialize, Deserialize, DataSize, Debug, Clone)]
+pub struct ContractConfig {
+    /// The name under which the contract hash is stored in the owner's named keys.
+    name: String,
+    /// The public key of the genesis account owning the contract.
+    owner: PublicKey,
+    /// The path to the Wasm file, relative to the directory containing the chainspec.
+    wasm_path: String,
+    /// The contents of the Wasm file, populated when the accounts config is loaded.
+    #[serde(skip)]
+    wasm: Bytes,
+    #[serde(default)]
+    #[data_size(skip)]
+    entry_points: Vec<EntryPoint>,
+    #[serde(default)]
+    named_keys: BTreeMap<String, NamedKeyConfig>,
+}
+
+impl ContractConfig {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn owner(&self) -> PublicKey {
+        self.owner
+    }
+
+    /// Reads the Wasm file, resolving its path relative to `root`.
+    pub(super) fn load_wasm(&mut self, root: &Path) -> Result<(), ReadFileError> {
+        self.wasm = utils::read_file(root.join(&self.wasm_path))?.into();
+        Ok(())
+    }
+
+    #[cfg(test)]
+    /// Generates a random instance using a `TestRng`.
+    pub fn random(rng: &mut TestRng) -> Self {
+        let name = format!("contract_{}", rng.gen::<u16>());
+        let owner = PublicKey::from(&SecretKey::ed25519(rng.gen()));
+        let wasm_path = format!("{}.wasm", name);
+        let wasm = rng.gen::<[u8; 32]>().to_vec().into();
+        let entry_points = vec![EntryPoint::default()];
+        let named_keys = (0..rng.gen_range(0..3))
+            .map(|index| (format!("named_key_{}", index), rng.gen()))
+            .collect();
+
+        ContractConfig {
+            name,
+            owner,
+            wasm_path,
+            wasm,
+            entry_points,
+            named_keys,
+        }
+    }
+}
+
+impl ToBytes for ContractConfig {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.name.to_bytes()?);
+        buffer.extend(self.owner.to_bytes()?);
+        buffer.extend(self.wasm_path.to_bytes()?);
+        buffer.extend(self.wasm.to_bytes()?);
+        buffer.extend(self.entry_points.to_bytes()?);
+        buffer.extend(self.named_keys.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.name.serialized_length()
+            + self.owner.serialized_length()
+            + self.wasm_path.serialized_length()
+            + self.wasm.serialized_length()
+            + self.entry_points.serialized_length()
+            + self.named_keys.serialized_length()
+    }
+}
+
+impl FromBytes for ContractConfig {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (name, remainder) = FromBytes::from_bytes(bytes)?;
+        let (owner, remainder) = FromBytes::from_bytes(remainder)?;
+        let (wasm_path, remainder) = FromBytes::from_bytes(remainder)?;
+        let (wasm, remainder) = FromBytes::from_bytes(remainder)?;
+        let (entry_points, remainder) = FromBytes::from_bytes(remainder)?;
+        let (named_keys, remainder) = FromBytes::from_bytes(remainder)?;
+        let contract_config = ContractConfig {
+            name,
+            owner,
+            wasm_path,
+            wasm,
+            entry_points,
+            named_keys,
+        };
+        Ok((contract_config, remainder))
+    }
+}
+
+impl From<ContractConfig> for GenesisContract {
+    fn from(contract_config: ContractConfig) -> Self {
+        let named_keys = contract_config
+            .named_keys
+            .into_iter()
+            .map(|(name, named_key)| (name, named_key.into()))
+            .collect();
+        GenesisContract::new(
+            contract_config.name,
+            contract_config.owner,
+            contract_config.wasm.into(),
+            EntryPoints::from(contract_config.entry_points),
+            named_keys,
+        )
+    }
+}
@@ -0,0 +1,86 @@
+use datasize::DataSize;
+#[cfg(test)]
+use rand::{distributions::Standard, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use casper_execution_engine::core::engine_state::GenesisNamedKeyValue;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
+    CLValue, Key,
+};
+
+const KEY_TAG: u8 = 0;
+const VALUE_TAG: u8 = 1;
+
+/// The value of a named key created at genesis.
+#[derive(PartialEq, Ord, PartialOrd, Eq, Serialize, Deserialize, DataSize, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum NamedKeyConfig {
+    /// An existing key, e.g. `{ key = { Hash = "hash-..." } }`.
+    Key(Key),
+    /// A value to be stored under a new `URef`, e.g.
+    /// `{ value = { cl_type = "U64", bytes = "0100000000000000" } }`.
+    Value(CLValue),
+}
+
+#[cfg(test)]
+impl Distribution<NamedKeyConfig> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NamedKeyConfig {
+        if rng.gen() {
+            NamedKeyConfig::Key(rng.gen())
+        } else {
+            NamedKeyConfig::Value(CLValue::from_t(rng.gen::<u64>()).expect("should create CLValue"))
+        }
+    }
+}
+
+impl ToBytes for NamedKeyConfig {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        match self {
+            NamedKeyConfig::Key(key) => {
+                buffer.push(KEY_TAG);
+                buffer.extend(key.to_bytes()?);
+            }
+            NamedKeyConfig::Value(cl_value) => {
+                buffer.push(VALUE_TAG);
+                buffer.extend(cl_value.to_bytes()?);
+            }
+        }
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                NamedKeyConfig::Key(key) => key.serialized_length(),
+                NamedKeyConfig::Value(cl_value) => cl_value.serialized_length(),
+            }
+    }
+}
+
+impl FromBytes for NamedKeyConfig {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            KEY_TAG => {
+                let (key, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((NamedKeyConfig::Key(key), remainder))
+            }
+            VALUE_TAG => {
+                let (cl_value, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((NamedKeyConfig::Value(cl_value), remainder))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+impl From<NamedKeyConfig> for GenesisNamedKeyValue {
+    fn from(named_key_config: NamedKeyConfig) -> Self {
+        match named_key_config {
+            NamedKeyConfig::Key(key) => GenesisNamedKeyValue::Key(key),
+            NamedKeyConfig::Value(cl_value) => GenesisNamedKeyValue::Value(cl_value),
+        }
+    }
+}
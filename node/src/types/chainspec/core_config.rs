@@ -4,8 +4,12 @@ use num::rational::Ratio;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    system::handle_payment::FeeHandling,
+};
 
+use super::Error;
 #[cfg(test)]
 use crate::testing::TestRng;
 use crate::types::TimeDiff;
@@ -28,6 +32,29 @@ pub struct CoreConfig {
     /// Round seigniorage rate represented as a fractional number.
     #[data_size(skip)]
     pub(crate) round_seigniorage_rate: Ratio<u64>,
+    /// The fraction of the unspent payment which is refunded to the deployer.
+    #[data_size(skip)]
+    #[serde(default = "default_refund_ratio")]
+    pub(crate) refund_ratio: Ratio<u64>,
+    /// What happens to the fees paid for deploys.
+    #[serde(default)]
+    pub(crate) fee_handling: FeeHandling,
+}
+
+/// Nothing is refunded unless the chainspec says otherwise, as was the case before the refund
+/// ratio was configurable.
+fn default_refund_ratio() -> Ratio<u64> {
+    Ratio::new(0, 1)
+}
+
+impl CoreConfig {
+    /// Checks whether the values set in the config make sense, returning an error if they don't.
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        if self.refund_ratio > Ratio::new(1, 1) {
+            return Err(Error::InvalidRefundRatio(self.refund_ratio));
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -44,6 +71,15 @@ impl CoreConfig {
             rng.gen_range(1..1_000_000_000),
             rng.gen_range(1..1_000_000_000),
         );
+        let refund_ratio = {
+            let denom = rng.gen_range(1..1_000_000_000);
+            Ratio::new(rng.gen_range(0..=denom), denom)
+        };
+        let fee_handling = match rng.gen_range(0..3) {
+            0 => FeeHandling::PayToProposer,
+            1 => FeeHandling::Accumulate,
+            _ => FeeHandling::Burn,
+        };
 
         CoreConfig {
             era_duration,
@@ -53,6 +89,8 @@ impl CoreConfig {
             locked_funds_period,
             unbonding_delay,
             round_seigniorage_rate,
+            refund_ratio,
+            fee_handling,
         }
     }
 }
@@ -67,6 +105,8 @@ impl ToBytes for CoreConfig {
         buffer.extend(self.locked_funds_period.to_bytes()?);
         buffer.extend(self.unbonding_delay.to_bytes()?);
         buffer.extend(self.round_seigniorage_rate.to_bytes()?);
+        buffer.extend(self.refund_ratio.to_bytes()?);
+        buffer.extend(self.fee_handling.to_bytes()?);
         Ok(buffer)
     }
 
@@ -78,6 +118,8 @@ impl ToBytes for CoreConfig {
             + self.locked_funds_period.serialized_length()
             + self.unbonding_delay.serialized_length()
             + self.round_seigniorage_rate.serialized_length()
+            + self.refund_ratio.serialized_length()
+            + self.fee_handling.serialized_length()
     }
 }
 
@@ -90,6 +132,8 @@ impl FromBytes for CoreConfig {
         let (locked_funds_period, remainder) = TimeDiff::from_bytes(remainder)?;
         let (unbonding_delay, remainder) = u64::from_bytes(remainder)?;
         let (round_seigniorage_rate, remainder) = Ratio::<u64>::from_bytes(remainder)?;
+        let (refund_ratio, remainder) = Ratio::<u64>::from_bytes(remainder)?;
+        let (fee_handling, remainder) = FeeHandling::from_bytes(remainder)?;
         let config = CoreConfig {
             era_duration,
             minimum_era_height,
@@ -98,6 +142,8 @@ impl FromBytes for CoreConfig {
             locked_funds_period,
             unbonding_delay,
             round_seigniorage_rate,
+            refund_ratio,
+            fee_handling,
         };
         Ok((config, remainder))
     }
@@ -114,6 +160,36 @@ mod tests {
         bytesrepr::test_serialization_roundtrip(&config);
     }
 
+    #[test]
+    fn should_default_refund_ratio_and_fee_handling() {
+        let mut rng = crate::new_rng();
+        let config = CoreConfig::random(&mut rng);
+        let mut encoded: toml::value::Table =
+            toml::from_str(&toml::to_string_pretty(&config).unwrap()).unwrap();
+        encoded.remove("refund_ratio");
+        encoded.remove("fee_handling");
+
+        let decoded: CoreConfig = toml::Value::Table(encoded).try_into().unwrap();
+        assert_eq!(decoded.refund_ratio, Ratio::new(0, 1));
+        assert_eq!(decoded.fee_handling, FeeHandling::PayToProposer);
+    }
+
+    #[test]
+    fn should_reject_refund_ratio_greater_than_one() {
+        let mut rng = crate::new_rng();
+        let mut config = CoreConfig::random(&mut rng);
+        assert!(config.validate().is_ok());
+
+        config.refund_ratio = Ratio::new(1, 1);
+        assert!(config.validate().is_ok());
+
+        config.refund_ratio = Ratio::new(3, 2);
+        assert!(matches!(
+            config.validate(),
+            Err(Error::InvalidRefundRatio(refund_ratio)) if refund_ratio == Ratio::new(3, 2)
+        ));
+    }
+
     #[test]
     fn toml_roundtrip() {
         let mut rng = crate::new_rng();
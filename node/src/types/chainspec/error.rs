@@ -1,5 +1,6 @@
 use std::{io, path::PathBuf};
 
+use num::rational::Ratio;
 use thiserror::Error;
 use uint::FromDecStrErr;
 
@@ -49,6 +50,10 @@ pub enum Error {
         /// The searched directory.
         dir: PathBuf,
     },
+
+    /// The refund ratio is greater than 1.
+    #[error("refund ratio is greater than 1: {0}")]
+    InvalidRefundRatio(Ratio<u64>),
 }
 
 /// Error loading chainspec accounts file.
@@ -62,6 +67,10 @@ pub enum ChainspecAccountsLoadError {
     #[error("decoding from TOML error: {0}")]
     DecodingFromToml(#[from] toml::de::Error),
 
+    /// Error loading the Wasm file of a genesis contract.
+    #[error("could not load contract wasm: {0}")]
+    LoadContractWasm(ReadFileError),
+
     /// Error while decoding a chainspec account's key hash from hex format.
     #[error("decoding from hex error: {0}")]
     DecodingFromHex(#[from] hex::FromHexError),
@@ -5,6 +5,7 @@ use datasize::DataSize;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
+use casper_execution_engine::shared::stored_value::StoredValue;
 use casper_types::{
     bytesrepr::{self, Bytes, FromBytes, ToBytes},
     Key,
@@ -29,6 +30,25 @@ pub struct GlobalStateUpdateConfig {
     entries: Vec<GlobalStateUpdateEntry>,
 }
 
+impl GlobalStateUpdateConfig {
+    /// Creates a config which writes each of the given `values` under its key.
+    pub(crate) fn from_values<'a, I>(values: I) -> Result<Self, bytesrepr::Error>
+    where
+        I: IntoIterator<Item = (&'a Key, &'a StoredValue)>,
+    {
+        let entries = values
+            .into_iter()
+            .map(|(key, value)| {
+                Ok(GlobalStateUpdateEntry {
+                    key: key.to_formatted_string(),
+                    value: base64::encode(value.to_bytes()?),
+                })
+            })
+            .collect::<Result<_, bytesrepr::Error>>()?;
+        Ok(GlobalStateUpdateConfig { entries })
+    }
+}
+
 impl Loadable for Option<GlobalStateUpdateConfig> {
     type Error = GlobalStateUpdateLoadError;
 
@@ -35,6 +35,8 @@ struct TomlProtocol {
     version: Version,
     hard_reset: bool,
     activation_point: ActivationPoint,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    strict_entry_point_args_version: Option<Version>,
 }
 
 /// A chainspec configuration as laid out in the TOML-encoded configuration file.
@@ -57,6 +59,10 @@ impl From<&Chainspec> for TomlChainspec {
             version: chainspec.protocol_config.version.clone(),
             hard_reset: chainspec.protocol_config.hard_reset,
             activation_point: chainspec.protocol_config.activation_point,
+            strict_entry_point_args_version: chainspec
+                .protocol_config
+                .strict_entry_point_args_version
+                .clone(),
         };
         let network = TomlNetwork {
             name: chainspec.network_config.name.clone(),
@@ -83,6 +89,7 @@ impl From<&Chainspec> for TomlChainspec {
 pub(super) fn parse_toml<P: AsRef<Path>>(chainspec_path: P) -> Result<Chainspec, Error> {
     let bytes = utils::read_file(chainspec_path.as_ref()).map_err(Error::LoadChainspec)?;
     let toml_chainspec: TomlChainspec = toml::from_slice(&bytes)?;
+    toml_chainspec.core.validate()?;
 
     let root = chainspec_path
         .as_ref()
@@ -107,6 +114,7 @@ pub(super) fn parse_toml<P: AsRef<Path>>(chainspec_path: P) -> Result<Chainspec,
         hard_reset: toml_chainspec.protocol.hard_reset,
         activation_point: toml_chainspec.protocol.activation_point,
         global_state_update,
+        strict_entry_point_args_version: toml_chainspec.protocol.strict_entry_point_args_version,
     };
 
     Ok(Chainspec {
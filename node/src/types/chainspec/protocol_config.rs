@@ -25,6 +25,10 @@ pub struct ProtocolConfig {
     /// Any arbitrary updates we might want to make to the global state at the start of the era
     /// specified in the activation point.
     pub(crate) global_state_update: Option<GlobalStateUpdate>,
+    /// The protocol version from which installed contracts have their call args checked by name
+    /// against their entry points' parameters, if any.
+    #[data_size(skip)]
+    pub(crate) strict_entry_point_args_version: Option<Version>,
 }
 
 #[cfg(test)]
@@ -37,12 +41,20 @@ impl ProtocolConfig {
             rng.gen::<u8>() as u64,
         );
         let activation_point = ActivationPoint::random(rng);
+        let strict_entry_point_args_version = rng.gen::<bool>().then(|| {
+            Version::new(
+                rng.gen_range(0..10),
+                rng.gen::<u8>() as u64,
+                rng.gen::<u8>() as u64,
+            )
+        });
 
         ProtocolConfig {
             version: protocol_version,
             hard_reset: rng.gen(),
             activation_point,
             global_state_update: None,
+            strict_entry_point_args_version,
         }
     }
 }
@@ -54,6 +66,12 @@ impl ToBytes for ProtocolConfig {
         buffer.extend(self.hard_reset.to_bytes()?);
         buffer.extend(self.activation_point.to_bytes()?);
         buffer.extend(self.global_state_update.to_bytes()?);
+        buffer.extend(
+            self.strict_entry_point_args_version
+                .as_ref()
+                .map(Version::to_string)
+                .to_bytes()?,
+        );
         Ok(buffer)
     }
 
@@ -62,6 +80,11 @@ impl ToBytes for ProtocolConfig {
             + self.hard_reset.serialized_length()
             + self.activation_point.serialized_length()
             + self.global_state_update.serialized_length()
+            + self
+                .strict_entry_point_args_version
+                .as_ref()
+                .map(Version::to_string)
+                .serialized_length()
     }
 }
 
@@ -73,11 +96,18 @@ impl FromBytes for ProtocolConfig {
         let (hard_reset, remainder) = bool::from_bytes(remainder)?;
         let (activation_point, remainder) = ActivationPoint::from_bytes(remainder)?;
         let (global_state_update, remainder) = Option::<GlobalStateUpdate>::from_bytes(remainder)?;
+        let (strict_entry_point_args_version_string, remainder) =
+            Option::<String>::from_bytes(remainder)?;
+        let strict_entry_point_args_version = strict_entry_point_args_version_string
+            .map(|version_string| Version::parse(&version_string))
+            .transpose()
+            .map_err(|_| bytesrepr::Error::Formatting)?;
         let protocol_config = ProtocolConfig {
             version: protocol_version,
             activation_point,
             global_state_update,
             hard_reset,
+            strict_entry_point_args_version,
         };
         Ok((protocol_config, remainder))
     }
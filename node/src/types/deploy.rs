@@ -21,14 +21,19 @@ use thiserror::Error;
 use tracing::{info, warn};
 
 use casper_execution_engine::{
-    core::engine_state::{executable_deploy_item::ExecutableDeployItem, DeployItem},
-    shared::motes::Motes,
+    core::{
+        engine_state::{executable_deploy_item::ExecutableDeployItem, DeployItem},
+        runtime,
+    },
+    shared::{motes::Motes, wasm_config::WasmConfig, wasm_prep::Preprocessor},
 };
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes},
+    contracts::DEFAULT_ENTRY_POINT_NAME,
     runtime_args,
     system::standard_payment::ARG_AMOUNT,
-    AsymmetricType, ExecutionResult, PublicKey, RuntimeArgs, SecretKey, Signature, U512,
+    AsymmetricType, ExecutionResult, ProtocolVersion, PublicKey, RuntimeArgs, SecretKey, Signature,
+    U512,
 };
 
 use super::{BlockHash, Item, Tag, TimeDiff, Timestamp};
@@ -181,6 +186,18 @@ pub enum DeployValidationFailure {
         /// The attempted transfer amount.
         attempted: U512,
     },
+
+    /// The payment code's Wasm would be rejected by the contract runtime.
+    #[error("invalid payment wasm: {0}")]
+    InvalidPaymentWasm(String),
+
+    /// The session code's Wasm would be rejected by the contract runtime.
+    #[error("invalid session wasm: {0}")]
+    InvalidSessionWasm(String),
+
+    /// The task validating the deploy's Wasm failed to complete.
+    #[error("failed to validate wasm: {0}")]
+    WasmValidationFailed(String),
 }
 
 /// Error returned when a Deploy is too large.
@@ -741,6 +758,40 @@ impl Deploy {
         self.is_valid()
     }
 
+    /// Returns `Ok` if the Wasm of the payment and session code passes the checks which the
+    /// contract runtime applies before executing it.
+    ///
+    /// Only `ModuleBytes` items are checked, and empty payment module bytes are skipped as they
+    /// denote the standard payment.  Preprocessing and instantiating the modules is expensive, so
+    /// this should not be called from within the reactor.
+    pub fn has_valid_wasm(
+        &self,
+        wasm_config: &WasmConfig,
+        protocol_version: ProtocolVersion,
+    ) -> Result<(), DeployValidationFailure> {
+        if let ExecutableDeployItem::ModuleBytes { module_bytes, .. } = self.payment() {
+            if !module_bytes.is_empty() {
+                validate_wasm(module_bytes.as_ref(), wasm_config, protocol_version).map_err(
+                    |error_msg| {
+                        info!(deploy_hash = %self.id(), %error_msg, "invalid payment wasm");
+                        DeployValidationFailure::InvalidPaymentWasm(error_msg)
+                    },
+                )?;
+            }
+        }
+
+        if let ExecutableDeployItem::ModuleBytes { module_bytes, .. } = self.session() {
+            validate_wasm(module_bytes.as_ref(), wasm_config, protocol_version).map_err(
+                |error_msg| {
+                    info!(deploy_hash = %self.id(), %error_msg, "invalid session wasm");
+                    DeployValidationFailure::InvalidSessionWasm(error_msg)
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+
     /// Generates a random instance using a `TestRng`.
     #[cfg(test)]
     pub fn random(rng: &mut TestRng) -> Self {
@@ -779,6 +830,35 @@ impl DocExample for Deploy {
     }
 }
 
+/// Checks that `module_bytes` can be preprocessed and instantiated in the same way as by the
+/// contract runtime, and that the module exports the function which is called to execute it.
+fn validate_wasm(
+    module_bytes: &[u8],
+    wasm_config: &WasmConfig,
+    protocol_version: ProtocolVersion,
+) -> Result<(), String> {
+    // Covers deserialization, the presence of a memory section and forbidden opcodes.
+    let module = Preprocessor::new(*wasm_config)
+        .preprocess(module_bytes)
+        .map_err(|error| error.to_string())?;
+
+    // Covers unresolvable imports, memory limits and the presence of a start function.
+    let (instance, _memory) = runtime::instance_and_memory(module, protocol_version, wasm_config)
+        .map_err(|error| error.to_string())?;
+
+    let exports_entry_point = instance
+        .export_by_name(DEFAULT_ENTRY_POINT_NAME)
+        .map_or(false, |export| export.as_func().is_some());
+    if !exports_entry_point {
+        return Err(format!(
+            "module doesn't export a function named \"{}\"",
+            DEFAULT_ENTRY_POINT_NAME
+        ));
+    }
+
+    Ok(())
+}
+
 fn serialize_header(header: &DeployHeader) -> Vec<u8> {
     header
         .to_bytes()
@@ -886,6 +966,42 @@ pub struct DeployMetadata {
     pub execution_results: HashMap<BlockHash, ExecutionResult>,
 }
 
+/// An entry in the per-account deploy history: a deploy sent by an account and the block it was
+/// executed in.
+#[derive(
+    Clone,
+    Copy,
+    DataSize,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Serialize,
+    Deserialize,
+    Debug,
+    JsonSchema,
+)]
+#[serde(deny_unknown_fields)]
+pub struct AccountDeploy {
+    /// The height of the block in which the deploy was executed.
+    pub block_height: u64,
+    /// The hash of the deploy.
+    pub deploy_hash: DeployHash,
+    /// The hash of the block in which the deploy was executed.
+    pub block_hash: BlockHash,
+}
+
+impl Display for AccountDeploy {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} in {} at height {}",
+            self.deploy_hash, self.block_hash, self.block_height
+        )
+    }
+}
+
 impl ToBytes for Deploy {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut buffer = bytesrepr::allocate_buffer(self)?;
@@ -929,7 +1045,7 @@ impl FromBytes for Deploy {
 mod tests {
     use std::{iter, time::Duration};
 
-    use casper_execution_engine::core::engine_state::MAX_PAYMENT_AMOUNT;
+    use casper_execution_engine::{core::engine_state::MAX_PAYMENT_AMOUNT, shared::wasm};
     use casper_types::{bytesrepr::Bytes, CLValue};
 
     use super::*;
@@ -1187,4 +1303,83 @@ mod tests {
             "deploy should not have run expensive `is_valid` call"
         );
     }
+
+    fn create_wasm_deploy(
+        rng: &mut TestRng,
+        payment_bytes: Vec<u8>,
+        session_bytes: Vec<u8>,
+    ) -> Deploy {
+        let secret_key = SecretKey::random(rng);
+        Deploy::new(
+            Timestamp::now(),
+            DeployConfig::default().max_ttl,
+            1,
+            vec![],
+            "net-1".to_string(),
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: Bytes::from(payment_bytes),
+                args: RuntimeArgs::new(),
+            },
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: Bytes::from(session_bytes),
+                args: RuntimeArgs::new(),
+            },
+            &secret_key,
+        )
+    }
+
+    #[test]
+    fn has_valid_wasm() {
+        let mut rng = crate::new_rng();
+        let wasm_config = WasmConfig::default();
+
+        let deploy = create_wasm_deploy(&mut rng, vec![], wasm::do_nothing_bytes());
+        assert_eq!(
+            deploy.has_valid_wasm(&wasm_config, ProtocolVersion::V1_0_0),
+            Ok(())
+        );
+
+        let deploy =
+            create_wasm_deploy(&mut rng, wasm::do_nothing_bytes(), wasm::do_nothing_bytes());
+        assert_eq!(
+            deploy.has_valid_wasm(&wasm_config, ProtocolVersion::V1_0_0),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn not_valid_wasm_due_to_undeserializable_module() {
+        let mut rng = crate::new_rng();
+        let wasm_config = WasmConfig::default();
+        let garbage = vec![0xde, 0xad, 0xbe, 0xef];
+
+        let deploy = create_wasm_deploy(&mut rng, garbage.clone(), wasm::do_nothing_bytes());
+        match deploy.has_valid_wasm(&wasm_config, ProtocolVersion::V1_0_0) {
+            Err(DeployValidationFailure::InvalidPaymentWasm(_)) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let deploy = create_wasm_deploy(&mut rng, vec![], garbage);
+        match deploy.has_valid_wasm(&wasm_config, ProtocolVersion::V1_0_0) {
+            Err(DeployValidationFailure::InvalidSessionWasm(_)) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn not_valid_wasm_due_to_missing_entry_point() {
+        let mut rng = crate::new_rng();
+
+        // A module with a single memory section and no exports.
+        let module_bytes = vec![
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x05, 0x03, 0x01, 0x00, 0x01,
+        ];
+        let deploy = create_wasm_deploy(&mut rng, vec![], module_bytes);
+        match deploy.has_valid_wasm(&WasmConfig::default(), ProtocolVersion::V1_0_0) {
+            Err(DeployValidationFailure::InvalidSessionWasm(error_msg)) => {
+                assert!(error_msg.contains(DEFAULT_ENTRY_POINT_NAME))
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
 }
@@ -32,3 +32,14 @@ impl From<BTreeMap<NodeId, String>> for PeersMap {
         PeersMap(ret)
     }
 }
+
+/// The state of the connections to a peer.
+#[derive(Clone, Serialize, Debug, PartialEq, Eq)]
+pub struct PeerConnectionState {
+    /// The peer's network address.
+    pub address: String,
+    /// Whether the peer has connected to us.
+    pub incoming: bool,
+    /// Whether we have connected to the peer.
+    pub outgoing: bool,
+}
@@ -33,6 +33,19 @@ static CHAINSPEC_INFO: Lazy<ChainspecInfo> = Lazy::new(|| {
     }
 });
 
+static REACTOR_STATUS: Lazy<ReactorStatus> = Lazy::new(|| ReactorStatus {
+    phase: ReactorPhase::Joiner,
+    uptime: TimeDiff::from(13 * 60 * 1000),
+    sync_progress: Some(SyncProgress {
+        trusted_hash: Some(*Block::doc_example().hash()),
+        target_height: Some(Block::doc_example().height() + 100),
+        current_height: Some(Block::doc_example().height()),
+        is_synced: false,
+    }),
+    last_finalized_block_height: Some(Block::doc_example().height()),
+    last_executed_block_height: Some(Block::doc_example().height()),
+});
+
 static GET_STATUS_RESULT: Lazy<GetStatusResult> = Lazy::new(|| {
     let node_id = NodeId::doc_example();
     let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 54321);
@@ -44,6 +57,7 @@ static GET_STATUS_RESULT: Lazy<GetStatusResult> = Lazy::new(|| {
         chainspec_info: ChainspecInfo::doc_example().clone(),
         our_public_signing_key: *PublicKey::doc_example(),
         round_length: Some(TimeDiff::from(1 << 16)),
+        reactor_status: ReactorStatus::doc_example().clone(),
         version: crate::VERSION_STRING.as_str(),
     };
     GetStatusResult::new(status_feed, DOCS_EXAMPLE_PROTOCOL_VERSION.clone())
@@ -81,6 +95,50 @@ impl ChainspecInfo {
     }
 }
 
+/// The reactor the node is currently running.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum ReactorPhase {
+    /// Joining the network by synchronizing the linear chain.
+    Joiner,
+    /// Fully joined and participating in consensus.
+    Validator,
+}
+
+/// Progress of the linear chain synchronization performed while joining.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SyncProgress {
+    /// The trusted block hash the synchronization started from, if any.
+    pub trusted_hash: Option<BlockHash>,
+    /// The highest block height seen while synchronizing.
+    pub target_height: Option<u64>,
+    /// The height of the most recent block being executed.
+    pub current_height: Option<u64>,
+    /// Whether the synchronization has finished.
+    pub is_synced: bool,
+}
+
+/// Status information provided by the currently running reactor itself.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReactorStatus {
+    /// The reactor the node is currently running.
+    pub phase: ReactorPhase,
+    /// Time elapsed since the node started up.
+    pub uptime: TimeDiff,
+    /// Progress of the linear chain synchronization, if the node is joining.
+    pub sync_progress: Option<SyncProgress>,
+    /// The height of the last finalized block handed to the block executor.
+    pub last_finalized_block_height: Option<u64>,
+    /// The height of the last block executed by the block executor.
+    pub last_executed_block_height: Option<u64>,
+}
+
+impl DocExample for ReactorStatus {
+    fn doc_example() -> &'static Self {
+        &*REACTOR_STATUS
+    }
+}
+
 /// Data feed for client "info_get_status" endpoint.
 #[derive(Debug, Serialize)]
 #[serde(bound = "I: Eq + Hash + Ord + Serialize")]
@@ -95,6 +153,8 @@ pub struct StatusFeed<I> {
     pub our_public_signing_key: PublicKey,
     /// The next round length if this node is a validator.
     pub round_length: Option<TimeDiff>,
+    /// The status of the currently running reactor.
+    pub reactor_status: ReactorStatus,
     /// The compiled node version.
     pub version: &'static str,
 }
@@ -105,6 +165,7 @@ impl<I> StatusFeed<I> {
         peers: BTreeMap<I, String>,
         chainspec_info: ChainspecInfo,
         (our_public_signing_key, round_length): (PublicKey, Option<TimeDiff>),
+        reactor_status: ReactorStatus,
     ) -> Self {
         StatusFeed {
             last_added_block,
@@ -112,6 +173,7 @@ impl<I> StatusFeed<I> {
             chainspec_info,
             our_public_signing_key,
             round_length,
+            reactor_status,
             version: crate::VERSION_STRING.as_str(),
         }
     }
@@ -163,6 +225,16 @@ pub struct GetStatusResult {
     pub round_length: Option<TimeDiff>,
     /// Information about the next scheduled upgrade.
     pub next_upgrade: Option<NextUpgrade>,
+    /// The reactor the node is currently running.
+    pub reactor_phase: ReactorPhase,
+    /// Time elapsed since the node started up.
+    pub uptime: TimeDiff,
+    /// Progress of the linear chain synchronization, if the node is joining.
+    pub sync_progress: Option<SyncProgress>,
+    /// The height of the last finalized block handed to the block executor.
+    pub last_finalized_block_height: Option<u64>,
+    /// The height of the last block executed by the block executor.
+    pub last_executed_block_height: Option<u64>,
     /// The compiled node version.
     pub build_version: String,
 }
@@ -181,6 +253,11 @@ impl GetStatusResult {
             our_public_signing_key: status_feed.our_public_signing_key,
             round_length: status_feed.round_length,
             next_upgrade: status_feed.chainspec_info.next_upgrade,
+            reactor_phase: status_feed.reactor_status.phase,
+            uptime: status_feed.reactor_status.uptime,
+            sync_progress: status_feed.reactor_status.sync_progress,
+            last_finalized_block_height: status_feed.reactor_status.last_finalized_block_height,
+            last_executed_block_height: status_feed.reactor_status.last_executed_block_height,
             build_version: crate::VERSION_STRING.clone(),
         }
     }
@@ -9,10 +9,11 @@ use core::mem::MaybeUninit;
 use casper_types::{
     account::AccountHash,
     api_error,
-    bytesrepr::{self, FromBytes},
+    bytesrepr::{self, FromBytes, ToBytes},
     contracts::{ContractVersion, NamedKeys},
     ApiError, BlockTime, CLTyped, CLValue, ContractHash, ContractPackageHash, Key, Phase,
-    RuntimeArgs, URef, BLAKE2B_DIGEST_LENGTH, BLOCKTIME_SERIALIZED_LENGTH, PHASE_SERIALIZED_LENGTH,
+    PublicKey, RuntimeArgs, Signature, URef, BLAKE2B_DIGEST_LENGTH, BLOCKTIME_SERIALIZED_LENGTH,
+    KECCAK256_DIGEST_LENGTH, PHASE_SERIALIZED_LENGTH, RANDOM_BYTES_COUNT, SHA256_DIGEST_LENGTH,
 };
 
 use crate::{contract_api, ext_ffi, unwrap_or_revert::UnwrapOrRevert};
@@ -220,6 +221,18 @@ pub fn get_phase() -> Phase {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+/// Emits an event named `name` carrying `payload`.
+///
+/// Events are recorded in the execution results of the current deploy, attributed to the
+/// currently-executing contract, and are discarded if the emitting code fails.
+pub fn emit_event<T: CLTyped + ToBytes>(name: &str, payload: T) {
+    let (name_ptr, name_size, _bytes1) = contract_api::to_ptr(name);
+    let cl_value = CLValue::from_t(payload).unwrap_or_revert();
+    let (payload_ptr, payload_size, _bytes2) = contract_api::to_ptr(cl_value);
+    let ret = unsafe { ext_ffi::casper_emit_event(name_ptr, name_size, payload_ptr, payload_size) };
+    api_error::result_from(ret).unwrap_or_revert();
+}
+
 /// Returns the requested named [`Key`] from the current context.
 ///
 /// The current context is either the caller's account or a stored contract depending on whether the
@@ -320,6 +333,90 @@ pub fn blake2b<T: AsRef<[u8]>>(input: T) -> [u8; BLAKE2B_DIGEST_LENGTH] {
     ret
 }
 
+/// Returns a 32-byte SHA-256 digest
+pub fn sha256<T: AsRef<[u8]>>(input: T) -> [u8; SHA256_DIGEST_LENGTH] {
+    let mut ret = [0; SHA256_DIGEST_LENGTH];
+    let result = unsafe {
+        ext_ffi::casper_sha256(
+            input.as_ref().as_ptr(),
+            input.as_ref().len(),
+            ret.as_mut_ptr(),
+            SHA256_DIGEST_LENGTH,
+        )
+    };
+    api_error::result_from(result).unwrap_or_revert();
+    ret
+}
+
+/// Returns a 32-byte Keccak-256 digest
+pub fn keccak256<T: AsRef<[u8]>>(input: T) -> [u8; KECCAK256_DIGEST_LENGTH] {
+    let mut ret = [0; KECCAK256_DIGEST_LENGTH];
+    let result = unsafe {
+        ext_ffi::casper_keccak256(
+            input.as_ref().as_ptr(),
+            input.as_ref().len(),
+            ret.as_mut_ptr(),
+            KECCAK256_DIGEST_LENGTH,
+        )
+    };
+    api_error::result_from(result).unwrap_or_revert();
+    ret
+}
+
+/// Returns `true` if `signature` is a valid Ed25519 or secp256k1 signature of `message` by the
+/// holder of the secret key corresponding to `public_key`.
+///
+/// System signatures are never valid, and a signature never verifies against a public key of a
+/// different algorithm.
+pub fn verify_signature<T: AsRef<[u8]>>(
+    message: T,
+    signature: &Signature,
+    public_key: &PublicKey,
+) -> bool {
+    let (signature_ptr, signature_size, _bytes1) = contract_api::to_ptr(*signature);
+    let (public_key_ptr, public_key_size, _bytes2) = contract_api::to_ptr(*public_key);
+    let result = unsafe {
+        ext_ffi::casper_verify_signature(
+            message.as_ref().as_ptr(),
+            message.as_ref().len(),
+            signature_ptr,
+            signature_size,
+            public_key_ptr,
+            public_key_size,
+        )
+    };
+    result != 0
+}
+
+/// Returns 32 random bytes provided by the protocol.
+///
+/// The bytes are derived from the accumulated seed of the block in which the deploy is executed,
+/// the deploy hash, the execution phase and the number of previous calls to this function made by
+/// any contract while executing the deploy.  Every call returns different bytes, and every
+/// validator executing the deploy gets the same ones.
+///
+/// # Security
+///
+/// The bytes are deterministic and are NOT secret or unbiased:
+///
+/// * they can be computed by anyone once the block has been proposed, so they must never be used as
+///   key material or other secrets;
+/// * the accumulated seed of a block only adds a single random bit chosen by its proposer to the
+///   seed of its parent, so the proposer can choose between two outcomes, or exclude the deploy
+///   from its block altogether;
+/// * as soon as the parent block is known, the sender of a deploy can compute both candidate seeds,
+///   and try out different deploys to find one with a favourable outcome, although they don't
+///   control which block the deploy will be included in.
+///
+/// This makes them suitable for low-stakes uses such as picking the traits of a minted NFT, but
+/// lotteries with significant prizes should prefer a commit-reveal scheme or an external oracle.
+pub fn random_bytes() -> [u8; RANDOM_BYTES_COUNT] {
+    let mut ret = [0; RANDOM_BYTES_COUNT];
+    let result = unsafe { ext_ffi::casper_random_bytes(ret.as_mut_ptr(), RANDOM_BYTES_COUNT) };
+    api_error::result_from(result).unwrap_or_revert();
+    ret
+}
+
 fn read_host_buffer_into(dest: &mut [u8]) -> Result<usize, ApiError> {
     let mut bytes_written = MaybeUninit::uninit();
     let ret = unsafe {
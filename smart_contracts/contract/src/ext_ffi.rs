@@ -700,6 +700,125 @@ extern "C" {
         out_ptr: *mut u8,
         out_size: usize,
     ) -> i32;
+    /// Creates a new dictionary and writes the serialized seed `URef` of the new dictionary to
+    /// the host buffer.  The seed `URef` has `READ_ADD_WRITE` access rights.
+    ///
+    /// # Arguments
+    ///
+    /// * `output_size_ptr` - pointer to a value where host will write size of bytes of the seed
+    ///   `URef`
+    pub fn casper_new_dictionary(output_size_ptr: *mut usize) -> i32;
+    /// Reads the value stored under `key_bytes_ptr` in the dictionary seeded by the `URef` at
+    /// `uref_ptr`, and writes it to the host buffer.  The seed `URef` must be known to the
+    /// current context and have `READ` access rights.
+    ///
+    /// # Arguments
+    ///
+    /// * `uref_ptr` - pointer to the serialized seed `URef`
+    /// * `uref_size` - size of the serialized seed `URef`
+    /// * `key_bytes_ptr` - pointer to the bytes of the dictionary item key
+    /// * `key_bytes_size` - size of the dictionary item key
+    /// * `output_size` - pointer to a value where host will write size of bytes read from the
+    ///   dictionary
+    pub fn casper_dictionary_get(
+        uref_ptr: *const u8,
+        uref_size: usize,
+        key_bytes_ptr: *const u8,
+        key_bytes_size: usize,
+        output_size: *mut usize,
+    ) -> i32;
+    /// Writes the serialized `CLValue` at `value_ptr` under `key_bytes_ptr` in the dictionary
+    /// seeded by the `URef` at `uref_ptr`.  The seed `URef` must be known to the current context
+    /// and have `WRITE` access rights.
+    ///
+    /// # Arguments
+    ///
+    /// * `uref_ptr` - pointer to the serialized seed `URef`
+    /// * `uref_size` - size of the serialized seed `URef`
+    /// * `key_bytes_ptr` - pointer to the bytes of the dictionary item key
+    /// * `key_bytes_size` - size of the dictionary item key
+    /// * `value_ptr` - pointer to the serialized `CLValue` to be stored
+    /// * `value_size` - size of the serialized `CLValue`
+    pub fn casper_dictionary_put(
+        uref_ptr: *const u8,
+        uref_size: usize,
+        key_bytes_ptr: *const u8,
+        key_bytes_size: usize,
+        value_ptr: *const u8,
+        value_size: usize,
+    ) -> i32;
+    /// Emits an event with the given name and serialized `CLValue` payload.  Events are recorded
+    /// in the execution results of the deploy and are discarded if the emitting code fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `name_ptr` - pointer to the serialized event name
+    /// * `name_size` - size of the serialized event name
+    /// * `payload_ptr` - pointer to the serialized `CLValue` payload
+    /// * `payload_size` - size of the serialized `CLValue` payload
+    pub fn casper_emit_event(
+        name_ptr: *const u8,
+        name_size: usize,
+        payload_ptr: *const u8,
+        payload_size: usize,
+    ) -> i32;
+    /// Returns a 32-byte SHA-256 hash digest from the given input bytes
+    ///
+    /// # Arguments
+    /// * `in_ptr` - pointer to bytes
+    /// * `in_size` - length of bytes
+    /// * `out_ptr` - pointer to the location where argument bytes will be copied from the host side
+    /// * `out_size` - size of output pointer
+    pub fn casper_sha256(
+        in_ptr: *const u8,
+        in_size: usize,
+        out_ptr: *mut u8,
+        out_size: usize,
+    ) -> i32;
+    /// Returns a 32-byte Keccak-256 hash digest from the given input bytes
+    ///
+    /// # Arguments
+    /// * `in_ptr` - pointer to bytes
+    /// * `in_size` - length of bytes
+    /// * `out_ptr` - pointer to the location where argument bytes will be copied from the host side
+    /// * `out_size` - size of output pointer
+    pub fn casper_keccak256(
+        in_ptr: *const u8,
+        in_size: usize,
+        out_ptr: *mut u8,
+        out_size: usize,
+    ) -> i32;
+    /// Verifies a signature of the given message against the given public key.  Returns `1` if the
+    /// signature is valid, or `0` otherwise.  This function causes a `Trap` if the signature or
+    /// public key cannot be deserialized.
+    ///
+    /// # Arguments
+    ///
+    /// * `message_ptr` - pointer to the message bytes
+    /// * `message_size` - size of the message bytes
+    /// * `signature_ptr` - pointer to the serialized `Signature`
+    /// * `signature_size` - size of the serialized `Signature`
+    /// * `public_key_ptr` - pointer to the serialized `PublicKey`
+    /// * `public_key_size` - size of the serialized `PublicKey`
+    pub fn casper_verify_signature(
+        message_ptr: *const u8,
+        message_size: usize,
+        signature_ptr: *const u8,
+        signature_size: usize,
+        public_key_ptr: *const u8,
+        public_key_size: usize,
+    ) -> i32;
+    /// Writes the next 32 random bytes of the currently executing deploy to the given buffer.
+    ///
+    /// The bytes are derived from the accumulated seed of the block being executed, the deploy
+    /// hash and the number of previous calls to this function during the deploy's execution.
+    ///
+    /// # Arguments
+    ///
+    /// * `out_ptr` - pointer to the location where the random bytes will be copied from the host
+    ///   side
+    /// * `out_size` - size of output pointer
+    pub fn casper_random_bytes(out_ptr: *mut u8, out_size: usize) -> i32;
     /// Prints data directly to stanadard output on the host.
     ///
     /// # Arguments
@@ -0,0 +1,15 @@
+#![no_std]
+#![no_main]
+
+use casper_contract::contract_api::{runtime, storage};
+
+const RANDOM_BYTES_1: &str = "random_bytes_1";
+const RANDOM_BYTES_2: &str = "random_bytes_2";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let random_bytes_1 = runtime::random_bytes();
+    let random_bytes_2 = runtime::random_bytes();
+    runtime::put_key(RANDOM_BYTES_1, storage::new_uref(random_bytes_1).into());
+    runtime::put_key(RANDOM_BYTES_2, storage::new_uref(random_bytes_2).into());
+}
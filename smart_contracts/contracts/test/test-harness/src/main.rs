@@ -0,0 +1,92 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints, NamedKeys},
+    ApiError, CLType, CLTyped, EntryPointAccess, EntryPointType, Parameter, URef,
+};
+
+const PACKAGE_HASH_KEY_NAME: &str = "test_harness_package_hash";
+const ACCESS_KEY_NAME: &str = "test_harness_access";
+const INSTALLED_KEY_NAME: &str = "test_harness_installed";
+const COUNT_KEY_NAME: &str = "count";
+const ENTRY_POINT_INCREMENT: &str = "increment";
+const ENTRY_POINT_SET: &str = "set";
+const ENTRY_POINT_FAIL: &str = "fail";
+const ARG_VALUE: &str = "value";
+const EVENT_COUNT_CHANGED: &str = "count_changed";
+
+fn count_uref() -> URef {
+    runtime::get_key(COUNT_KEY_NAME)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert()
+}
+
+fn set_count(value: u64) {
+    storage::write(count_uref(), value);
+    runtime::emit_event(EVENT_COUNT_CHANGED, value);
+}
+
+#[no_mangle]
+pub extern "C" fn increment() {
+    let count: u64 = storage::read(count_uref())
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    set_count(count + 1);
+}
+
+#[no_mangle]
+pub extern "C" fn set() {
+    let value: u64 = runtime::get_named_arg(ARG_VALUE);
+    set_count(value);
+}
+
+#[no_mangle]
+pub extern "C" fn fail() {
+    runtime::revert(ApiError::User(0))
+}
+
+fn entry_point(name: &str, params: Vec<Parameter>) -> EntryPoint {
+    EntryPoint::new(
+        name.to_string(),
+        params,
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        entry_points.add_entry_point(entry_point(ENTRY_POINT_INCREMENT, vec![]));
+        entry_points.add_entry_point(entry_point(
+            ENTRY_POINT_SET,
+            vec![Parameter::new(ARG_VALUE, u64::cl_type())],
+        ));
+        entry_points.add_entry_point(entry_point(ENTRY_POINT_FAIL, vec![]));
+        entry_points
+    };
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(COUNT_KEY_NAME.to_string(), storage::new_uref(0u64).into());
+
+    storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(PACKAGE_HASH_KEY_NAME.to_string()),
+        Some(ACCESS_KEY_NAME.to_string()),
+    );
+
+    runtime::put_key(INSTALLED_KEY_NAME, storage::new_uref(true).into());
+}
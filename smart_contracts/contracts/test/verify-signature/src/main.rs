@@ -0,0 +1,29 @@
+#![no_std]
+#![no_main]
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, Bytes},
+    PublicKey, Signature,
+};
+
+const VERIFY_RESULT: &str = "verify_result";
+
+const ARG_MESSAGE: &str = "message";
+const ARG_SIGNATURE: &str = "signature";
+const ARG_PUBLIC_KEY: &str = "public_key";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let message: Bytes = runtime::get_named_arg(ARG_MESSAGE);
+    // `Signature` isn't a `CLTyped` type, so it is passed in serialized form.
+    let signature_bytes: Bytes = runtime::get_named_arg(ARG_SIGNATURE);
+    let signature: Signature = bytesrepr::deserialize(signature_bytes.into()).unwrap_or_revert();
+    let public_key: PublicKey = runtime::get_named_arg(ARG_PUBLIC_KEY);
+    let is_valid = runtime::verify_signature(message, &signature, &public_key);
+    let uref = storage::new_uref(is_valid);
+    runtime::put_key(VERIFY_RESULT, uref.into())
+}
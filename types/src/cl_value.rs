@@ -1,7 +1,7 @@
 // TODO - remove once schemars stops causing warning.
 #![allow(clippy::field_reassign_with_default)]
 
-use alloc::{string::String, vec::Vec};
+use alloc::{format, string::String, vec::Vec};
 use core::fmt;
 
 use datasize::DataSize;
@@ -19,6 +19,8 @@ use crate::{
 
 mod jsonrepr;
 
+pub use jsonrepr::{cl_value_from_json, cl_value_to_json};
+
 /// Error while converting a [`CLValue`] into a given type.
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct CLTypeMismatch {
@@ -176,7 +178,8 @@ impl JsonSchema for CLValue {
 /// the underlying data as a separate member.
 ///
 /// The `parsed` field, representing the original value, is a convenience only available when a
-/// CLValue is encoded to JSON, and can always be set to null if preferred.
+/// CLValue is encoded to JSON, and can always be set to null if preferred.  When decoding from
+/// JSON, `bytes` may be omitted, in which case the value is parsed from the `parsed` field instead.
 #[derive(Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 #[cfg_attr(feature = "std", derive(JsonSchema))]
@@ -202,14 +205,37 @@ impl Serialize for CLValue {
     }
 }
 
+/// The JSON form of a `CLValue` accepted when decoding, where `bytes` is optional if `parsed` is
+/// provided.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CLValueJsonInput {
+    cl_type: CLType,
+    #[serde(default)]
+    bytes: Option<String>,
+    #[serde(default)]
+    parsed: Option<Value>,
+}
+
 impl<'de> Deserialize<'de> for CLValue {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let (cl_type, bytes) = if deserializer.is_human_readable() {
-            let json = CLValueJson::deserialize(deserializer)?;
-            (
-                json.cl_type.clone(),
-                base16::decode(&json.bytes).map_err(D::Error::custom)?,
-            )
+            let json = CLValueJsonInput::deserialize(deserializer)?;
+            match (json.bytes, json.parsed) {
+                (Some(bytes), _) => (
+                    json.cl_type,
+                    base16::decode(&bytes).map_err(D::Error::custom)?,
+                ),
+                (None, Some(parsed)) => {
+                    return jsonrepr::cl_value_from_json(&json.cl_type, &parsed).ok_or_else(|| {
+                        D::Error::custom(format!(
+                            "failed to parse {} as a value of type {:?}",
+                            parsed, json.cl_type
+                        ))
+                    })
+                }
+                (None, None) => return Err(D::Error::missing_field("bytes")),
+            }
         } else {
             <(CLType, Vec<u8>)>::deserialize(deserializer)?
         };
@@ -259,6 +285,20 @@ mod tests {
         assert_eq!(cl_value, decoded);
     }
 
+    #[test]
+    fn json_decode_from_parsed() {
+        let cl_value = CLValue::from_t(vec![(1u8, String::from("a"))]).unwrap();
+        let json_string = r#"{"cl_type":{"List":{"Tuple2":["U8","String"]}},"parsed":[[1,"a"]]}"#;
+        let decoded: CLValue = serde_json::from_str(json_string).unwrap();
+        assert_eq!(cl_value, decoded);
+
+        let mismatched = r#"{"cl_type":"U8","parsed":"a"}"#;
+        assert!(serde_json::from_str::<CLValue>(mismatched).is_err());
+
+        let missing_value = r#"{"cl_type":"U8"}"#;
+        assert!(serde_json::from_str::<CLValue>(missing_value).is_err());
+    }
+
     fn check_to_json<T: CLTyped + ToBytes + FromBytes>(value: T, expected: &str) {
         let cl_value = CLValue::from_t(value).unwrap();
         let cl_value_as_json = serde_json::to_string(&cl_value).unwrap();
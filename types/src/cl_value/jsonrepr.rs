@@ -1,10 +1,13 @@
-use alloc::{string::String, vec, vec::Vec};
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use core::convert::TryFrom;
 
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 use serde_json::{json, Value};
 
 use crate::{
-    bytesrepr::{self, FromBytes, OPTION_NONE_TAG, OPTION_SOME_TAG, RESULT_ERR_TAG, RESULT_OK_TAG},
+    bytesrepr::{
+        self, FromBytes, ToBytes, OPTION_NONE_TAG, OPTION_SOME_TAG, RESULT_ERR_TAG, RESULT_OK_TAG,
+    },
     CLType, CLValue, Key, PublicKey, URef, U128, U256, U512,
 };
 
@@ -106,6 +109,129 @@ fn simple_type_to_json<T: FromBytes + Serialize>(bytes: &[u8]) -> Option<(Value,
     Some((json!(value), remainder))
 }
 
+/// Returns a `CLValue` of the given type parsed from the JSON value, or `None` if the JSON value
+/// doesn't represent a value of that type.
+///
+/// This is the inverse of [`cl_value_to_json`], so the JSON representation of each type is as
+/// output by that function, e.g. `U512` values are decimal strings, `ByteArray`s are hex strings,
+/// `Map`s are lists of `{"key": ..., "value": ...}` objects and `Result`s are objects with a single
+/// `Ok` or `Err` field.  Note that since `Option::None` is represented as `null`, an `Option` of
+/// `Unit` or of another `Option` can't be unambiguously parsed; `null` is always taken as `None`.
+pub fn cl_value_from_json(cl_type: &CLType, json_value: &Value) -> Option<CLValue> {
+    let mut bytes = Vec::new();
+    from_json(cl_type, json_value, &mut bytes)?;
+    Some(CLValue {
+        cl_type: cl_type.clone(),
+        bytes: bytes.into(),
+    })
+}
+
+fn from_json(cl_type: &CLType, json_value: &Value, bytes: &mut Vec<u8>) -> Option<()> {
+    match cl_type {
+        CLType::Bool => simple_type_from_json::<bool>(json_value, bytes),
+        CLType::I32 => simple_type_from_json::<i32>(json_value, bytes),
+        CLType::I64 => simple_type_from_json::<i64>(json_value, bytes),
+        CLType::U8 => simple_type_from_json::<u8>(json_value, bytes),
+        CLType::U32 => simple_type_from_json::<u32>(json_value, bytes),
+        CLType::U64 => simple_type_from_json::<u64>(json_value, bytes),
+        CLType::U128 => simple_type_from_json::<U128>(json_value, bytes),
+        CLType::U256 => simple_type_from_json::<U256>(json_value, bytes),
+        CLType::U512 => simple_type_from_json::<U512>(json_value, bytes),
+        CLType::Unit => simple_type_from_json::<()>(json_value, bytes),
+        CLType::String => simple_type_from_json::<String>(json_value, bytes),
+        CLType::Key => simple_type_from_json::<Key>(json_value, bytes),
+        CLType::URef => simple_type_from_json::<URef>(json_value, bytes),
+        CLType::PublicKey => simple_type_from_json::<PublicKey>(json_value, bytes),
+        CLType::Option(inner_cl_type) => {
+            if json_value.is_null() {
+                bytes.push(OPTION_NONE_TAG);
+                Some(())
+            } else {
+                bytes.push(OPTION_SOME_TAG);
+                from_json(inner_cl_type, json_value, bytes)
+            }
+        }
+        CLType::List(inner_cl_type) => {
+            let items = json_value.as_array()?;
+            append_length(items.len(), bytes)?;
+            for item in items {
+                from_json(inner_cl_type, item, bytes)?;
+            }
+            Some(())
+        }
+        CLType::ByteArray(length) => {
+            let byte_array = hex::decode(json_value.as_str()?).ok()?;
+            if byte_array.len() != *length as usize {
+                return None;
+            }
+            bytes.extend(byte_array);
+            Some(())
+        }
+        CLType::Result { ok, err } => {
+            let object = json_value.as_object()?;
+            if object.len() != 1 {
+                return None;
+            }
+            if let Some(value) = object.get("Ok") {
+                bytes.push(RESULT_OK_TAG);
+                from_json(ok, value, bytes)
+            } else if let Some(value) = object.get("Err") {
+                bytes.push(RESULT_ERR_TAG);
+                from_json(err, value, bytes)
+            } else {
+                None
+            }
+        }
+        CLType::Map { key, value } => {
+            let entries = json_value.as_array()?;
+            append_length(entries.len(), bytes)?;
+            for entry in entries {
+                let entry = entry.as_object()?;
+                if entry.len() != 2 {
+                    return None;
+                }
+                from_json(key, entry.get("key")?, bytes)?;
+                from_json(value, entry.get("value")?, bytes)?;
+            }
+            Some(())
+        }
+        CLType::Tuple1(arr) => tuple_from_json(arr, json_value, bytes),
+        CLType::Tuple2(arr) => tuple_from_json(arr, json_value, bytes),
+        CLType::Tuple3(arr) => tuple_from_json(arr, json_value, bytes),
+        CLType::Any => None,
+    }
+}
+
+fn simple_type_from_json<T: DeserializeOwned + ToBytes>(
+    json_value: &Value,
+    bytes: &mut Vec<u8>,
+) -> Option<()> {
+    let value: T = serde_json::from_value(json_value.clone()).ok()?;
+    bytes.extend(value.into_bytes().ok()?);
+    Some(())
+}
+
+fn tuple_from_json(
+    cl_types: &[Box<CLType>],
+    json_value: &Value,
+    bytes: &mut Vec<u8>,
+) -> Option<()> {
+    let items = json_value.as_array()?;
+    if items.len() != cl_types.len() {
+        return None;
+    }
+    for (cl_type, item) in cl_types.iter().zip(items) {
+        from_json(cl_type, item, bytes)?;
+    }
+    Some(())
+}
+
+fn append_length(length: usize, bytes: &mut Vec<u8>) -> Option<()> {
+    let length = u32::try_from(length).ok()?;
+    bytes.extend(length.into_bytes().ok()?);
+    Some(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,6 +243,13 @@ mod tests {
         let cl_value_as_json: Value = cl_value_to_json(&cl_value).unwrap();
         let expected = json!(value);
         assert_eq!(cl_value_as_json, expected);
+        assert_roundtrip(&cl_value);
+    }
+
+    fn assert_roundtrip(cl_value: &CLValue) {
+        let cl_value_as_json = cl_value_to_json(cl_value).unwrap();
+        let decoded = cl_value_from_json(cl_value.cl_type(), &cl_value_as_json).unwrap();
+        assert_eq!(&decoded, cl_value);
     }
 
     #[test]
@@ -145,6 +278,7 @@ mod tests {
         let cl_value_as_json: Value = cl_value_to_json(&cl_value).unwrap();
         let expected = json!([a_hex, b_hex]);
         assert_eq!(cl_value_as_json, expected);
+        assert_roundtrip(&cl_value);
     }
 
     #[test]
@@ -159,6 +293,7 @@ mod tests {
         let cl_value_as_json: Value = cl_value_to_json(&cl_value).unwrap();
         let expected = json!([[a_hex, b_hex], [c_hex]]);
         assert_eq!(cl_value_as_json, expected);
+        assert_roundtrip(&cl_value);
     }
 
     #[test]
@@ -177,6 +312,7 @@ mod tests {
             { "key": key2, "value": value2 }
         ]);
         assert_eq!(cl_value_as_json, expected);
+        assert_roundtrip(&cl_value);
     }
 
     #[test]
@@ -196,6 +332,7 @@ mod tests {
         let cl_value_as_json = cl_value_to_json(&cl_value).unwrap();
         let expected = json!(hex::encode(&bytes));
         assert_eq!(cl_value_as_json, expected);
+        assert_roundtrip(&cl_value);
     }
 
     #[test]
@@ -218,4 +355,62 @@ mod tests {
         test_value((v1.clone(), v2.clone()));
         test_value((v1, v2, v3));
     }
+
+    #[test]
+    fn simple_types_roundtrip() {
+        test_value(true);
+        test_value(-1i32);
+        test_value(i64::min_value());
+        test_value(u8::max_value());
+        test_value(u32::max_value());
+        test_value(u64::max_value());
+        test_value(U128::max_value());
+        test_value(U256::max_value());
+        test_value(U512::max_value());
+        test_value(());
+        test_value(String::from("a value"));
+        test_value(Key::Hash([1; 32]));
+        test_value(URef::new([2; 32], crate::AccessRights::READ_ADD_WRITE));
+        test_value(PublicKey::from(SecretKey::ed25519(
+            [3; SecretKey::ED25519_LENGTH],
+        )));
+    }
+
+    #[test]
+    fn should_fail_to_parse_mismatched_json() {
+        let mismatched = [
+            (CLType::U8, json!(256)),
+            (CLType::U512, json!(true)),
+            (CLType::String, json!(1)),
+            (CLType::List(Box::new(CLType::U8)), json!([1, "a"])),
+            (CLType::ByteArray(3), json!("0102")),
+            (
+                CLType::Result {
+                    ok: Box::new(CLType::U8),
+                    err: Box::new(CLType::String),
+                },
+                json!({ "Ok": 1, "Err": "a" }),
+            ),
+            (
+                CLType::Map {
+                    key: Box::new(CLType::String),
+                    value: Box::new(CLType::U8),
+                },
+                json!([{ "key": "a" }]),
+            ),
+            (
+                CLType::Tuple2([Box::new(CLType::U8), Box::new(CLType::U8)]),
+                json!([1]),
+            ),
+            (CLType::Any, json!(null)),
+        ];
+        for (cl_type, json_value) in &mismatched {
+            assert!(
+                cl_value_from_json(cl_type, json_value).is_none(),
+                "should fail to parse {} as {:?}",
+                json_value,
+                cl_type
+            );
+        }
+    }
 }
@@ -26,7 +26,7 @@ use crate::{
     contract_wasm::ContractWasmHash,
     uref,
     uref::URef,
-    CLType, CLTyped, HashAddr, Key, ProtocolVersion, KEY_HASH_LENGTH,
+    CLType, CLTyped, CLValue, HashAddr, Key, ProtocolVersion, KEY_HASH_LENGTH,
 };
 
 /// Maximum number of distinct user groups.
@@ -1320,27 +1320,63 @@ impl FromBytes for EntryPointAccess {
     }
 }
 
+/// Prefix of the serialized form of a [`Parameter`] with a default value.
+///
+/// Parameters without a default keep their original serialized form, which starts with the length
+/// of the parameter's name and so can never start with this value.
+const PARAMETER_WITH_DEFAULT_MARKER: u32 = u32::MAX;
+
 /// Parameter to a method
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct Parameter {
     name: String,
     cl_type: CLType,
+    /// The value used when the caller doesn't provide this argument. A parameter without a default
+    /// value is required.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default: Option<CLValue>,
 }
 
 impl Parameter {
-    /// `Parameter` constructor.
+    /// `Parameter` constructor for a required parameter.
     pub fn new<T: Into<String>>(name: T, cl_type: CLType) -> Self {
         Parameter {
             name: name.into(),
             cl_type,
+            default: None,
+        }
+    }
+
+    /// `Parameter` constructor for an optional parameter, taking `default` when the caller doesn't
+    /// provide it.  The type of the parameter is the type of `default`.
+    pub fn optional<T: Into<String>>(name: T, default: CLValue) -> Self {
+        Parameter {
+            name: name.into(),
+            cl_type: default.cl_type().clone(),
+            default: Some(default),
         }
     }
 
+    /// Get the name of this argument.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
     /// Get the type of this argument.
     pub fn cl_type(&self) -> &CLType {
         &self.cl_type
     }
+
+    /// Get the default value of this argument, or `None` if it is required.
+    pub fn default_value(&self) -> Option<&CLValue> {
+        self.default.as_ref()
+    }
+
+    /// Returns `true` if the caller may omit this argument.
+    pub fn is_optional(&self) -> bool {
+        self.default.is_some()
+    }
 }
 
 impl From<Parameter> for (String, CLType) {
@@ -1351,23 +1387,59 @@ impl From<Parameter> for (String, CLType) {
 
 impl ToBytes for Parameter {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
-        let mut result = ToBytes::to_bytes(&self.name)?;
-        self.cl_type.append_bytes(&mut result)?;
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        if let Some(default) = &self.default {
+            result.append(&mut PARAMETER_WITH_DEFAULT_MARKER.to_bytes()?);
+            result.append(&mut self.name.to_bytes()?);
+            self.cl_type.append_bytes(&mut result)?;
+            result.append(&mut default.to_bytes()?);
+        } else {
+            result.append(&mut self.name.to_bytes()?);
+            self.cl_type.append_bytes(&mut result)?;
+        }
 
         Ok(result)
     }
 
     fn serialized_length(&self) -> usize {
-        ToBytes::serialized_length(&self.name) + self.cl_type.serialized_length()
+        let required_length =
+            ToBytes::serialized_length(&self.name) + self.cl_type.serialized_length();
+        match &self.default {
+            Some(default) => {
+                PARAMETER_WITH_DEFAULT_MARKER.serialized_length()
+                    + required_length
+                    + default.serialized_length()
+            }
+            None => required_length,
+        }
     }
 }
 
 impl FromBytes for Parameter {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (maybe_marker, remainder) = u32::from_bytes(bytes)?;
+        let (has_default, bytes) = if maybe_marker == PARAMETER_WITH_DEFAULT_MARKER {
+            (true, remainder)
+        } else {
+            (false, bytes)
+        };
         let (name, bytes) = String::from_bytes(bytes)?;
         let (cl_type, bytes) = CLType::from_bytes(bytes)?;
+        let (default, bytes) = if has_default {
+            let (default, bytes) = CLValue::from_bytes(bytes)?;
+            (Some(default), bytes)
+        } else {
+            (None, bytes)
+        };
 
-        Ok((Parameter { name, cl_type }, bytes))
+        Ok((
+            Parameter {
+                name,
+                cl_type,
+                default,
+            },
+            bytes,
+        ))
     }
 }
 
@@ -1471,6 +1543,64 @@ mod tests {
         assert_eq!(rem.len(), 0);
     }
 
+    #[test]
+    fn should_decode_original_contract_encoding() {
+        // A contract with a single entry point taking one `U512` parameter, serialized as it was
+        // before parameters could have default values.
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&[1; 32]); // contract package hash
+        bytes.extend_from_slice(&[2; 32]); // contract wasm hash
+        bytes.extend_from_slice(&[0, 0, 0, 0]); // no named keys
+        bytes.extend_from_slice(&[1, 0, 0, 0]); // one entry point
+        bytes.extend_from_slice(&[8, 0, 0, 0]);
+        bytes.extend_from_slice(b"delegate"); // entry point key
+        bytes.extend_from_slice(&[8, 0, 0, 0]);
+        bytes.extend_from_slice(b"delegate"); // entry point name
+        bytes.extend_from_slice(&[1, 0, 0, 0]); // one parameter
+        bytes.extend_from_slice(&[6, 0, 0, 0]);
+        bytes.extend_from_slice(b"param1"); // parameter name
+        bytes.push(8); // parameter type `U512`
+        bytes.push(9); // return type `Unit`
+        bytes.push(1); // public access
+        bytes.push(1); // contract entry point type
+        bytes.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]); // protocol version 1.0.0
+
+        let mut entry_points = EntryPoints::new();
+        entry_points.add_entry_point(EntryPoint::new(
+            "delegate",
+            vec![Parameter::new("param1", CLType::U512)],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        let contract = Contract::new(
+            ContractPackageHash::new([1; 32]),
+            ContractWasmHash::new([2; 32]),
+            NamedKeys::new(),
+            entry_points,
+            ProtocolVersion::V1_0_0,
+        );
+
+        let (decoded_contract, rem) = Contract::from_bytes(&bytes).expect("should deserialize");
+        assert_eq!(decoded_contract, contract);
+        assert!(rem.is_empty());
+        assert_eq!(contract.to_bytes().expect("should serialize"), bytes);
+    }
+
+    #[test]
+    fn parameter_roundtrip_serialization() {
+        let required = Parameter::new("required", CLType::U512);
+        bytesrepr::test_serialization_roundtrip(&required);
+
+        let default = CLValue::from_t(String::from("default")).expect("should create CLValue");
+        let optional = Parameter::optional("optional", default);
+        bytesrepr::test_serialization_roundtrip(&optional);
+        assert_eq!(
+            optional.to_bytes().expect("should serialize")[..4],
+            PARAMETER_WITH_DEFAULT_MARKER.to_le_bytes()
+        );
+    }
+
     #[test]
     fn should_remove_group() {
         let mut contract_package = make_contract_package();
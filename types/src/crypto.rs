@@ -6,7 +6,16 @@ mod error;
 #[cfg(any(feature = "gens", test))]
 pub use asymmetric_key::gens;
 pub use asymmetric_key::{
-    AsymmetricType, PublicKey, SecretKey, Signature, ED25519_TAG, SECP256K1_TAG, SYSTEM_ACCOUNT,
-    SYSTEM_TAG,
+    verify, AsymmetricType, PublicKey, SecretKey, Signature, ED25519_TAG, SECP256K1_TAG,
+    SYSTEM_ACCOUNT, SYSTEM_TAG,
 };
 pub use error::Error;
+
+/// The number of bytes in a SHA-256 digest.
+pub const SHA256_DIGEST_LENGTH: usize = 32;
+
+/// The number of bytes in a Keccak-256 digest.
+pub const KECCAK256_DIGEST_LENGTH: usize = 32;
+
+/// The number of bytes returned by each call to the `casper_random_bytes` host function.
+pub const RANDOM_BYTES_COUNT: usize = 32;
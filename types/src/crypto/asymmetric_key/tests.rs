@@ -1,4 +1,12 @@
-use crate::{crypto::SecretKey, PublicKey};
+use ed25519_dalek::ExpandedSecretKey;
+use k256::ecdsa::{signature::Signer, SigningKey as Secp256k1SecretKey};
+
+use crate::{
+    crypto::{self, SecretKey},
+    AsymmetricType, PublicKey, Signature,
+};
+
+const MESSAGE: &[u8] = b"message";
 
 #[test]
 fn can_construct_ed25519_keypair_from_zeroes() {
@@ -39,3 +47,40 @@ fn can_construct_system_public_key() {
 
     assert_ne!(public_key, secret_key.into())
 }
+
+#[test]
+fn should_verify_ed25519_signature() {
+    let secret_key = SecretKey::ed25519([1; SecretKey::ED25519_LENGTH]);
+    let public_key = PublicKey::from(&secret_key);
+    let signature = match (&secret_key, &public_key) {
+        (SecretKey::Ed25519(secret_key), PublicKey::Ed25519(public_key)) => Signature::Ed25519(
+            ExpandedSecretKey::from(secret_key)
+                .sign(MESSAGE, public_key)
+                .to_bytes(),
+        ),
+        _ => unreachable!(),
+    };
+
+    assert!(crypto::verify(MESSAGE, &signature, &public_key).is_ok());
+    assert!(crypto::verify(b"other message", &signature, &public_key).is_err());
+}
+
+#[test]
+fn should_verify_secp256k1_signature() {
+    let secret_key_bytes = [1; SecretKey::SECP256K1_LENGTH];
+    let public_key = PublicKey::from(SecretKey::secp256k1(secret_key_bytes));
+    let signer = Secp256k1SecretKey::from_bytes(&secret_key_bytes).unwrap();
+    let signature = Signature::Secp256k1(signer.sign(MESSAGE));
+
+    assert!(crypto::verify(MESSAGE, &signature, &public_key).is_ok());
+    assert!(crypto::verify(b"other message", &signature, &public_key).is_err());
+}
+
+#[test]
+fn should_not_verify_system_or_mismatched_signature() {
+    let public_key = PublicKey::from(SecretKey::ed25519([1; SecretKey::ED25519_LENGTH]));
+    let secp256k1_signature = Signature::secp256k1([1; Signature::SECP256K1_LENGTH]).unwrap();
+
+    assert!(crypto::verify(MESSAGE, &Signature::system(), &public_key).is_err());
+    assert!(crypto::verify(MESSAGE, &secp256k1_signature, &public_key).is_err());
+}
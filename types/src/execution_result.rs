@@ -32,12 +32,17 @@ use crate::{
     account::AccountHash,
     bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
     system::auction::{Bid, EraInfo, UnbondingPurse},
-    CLValue, DeployInfo, NamedKey, Transfer, TransferAddr, U128, U256, U512,
+    CLValue, ContractHash, DeployInfo, NamedKey, Transfer, TransferAddr, U128, U256, U512,
 };
 
 /// Constants to track ExecutionResult serialization.
+///
+/// Results without contract events use the original tags and layout, so that results stored before
+/// contract events were introduced remain readable and re-encode identically.
 const EXECUTION_RESULT_FAILURE_TAG: u8 = 0;
 const EXECUTION_RESULT_SUCCESS_TAG: u8 = 1;
+const EXECUTION_RESULT_FAILURE_WITH_EVENTS_TAG: u8 = 2;
+const EXECUTION_RESULT_SUCCESS_WITH_EVENTS_TAG: u8 = 3;
 
 /// Constants to track operation serialization.
 const OP_READ_TAG: u8 = 0;
@@ -89,6 +94,12 @@ static EXECUTION_RESULT: Lazy<ExecutionResult> = Lazy::new(|| {
         transform: Transform::Identity,
     });
 
+    let events = vec![ContractEvent {
+        contract_hash: Some(ContractHash::new([42; KEY_HASH_LENGTH])),
+        name: "token_minted".to_string(),
+        payload: CLValue::from_t(1_000u64).unwrap(),
+    }];
+
     let effect = ExecutionEffect {
         operations,
         transforms,
@@ -103,6 +114,7 @@ static EXECUTION_RESULT: Lazy<ExecutionResult> = Lazy::new(|| {
         effect,
         transfers,
         cost: U512::from(123_456),
+        events,
     }
 });
 
@@ -121,6 +133,9 @@ pub enum ExecutionResult {
         cost: U512,
         /// The error message associated with executing the deploy.
         error_message: String,
+        /// The events emitted by contracts, in the order they were emitted.
+        #[serde(default)]
+        events: Vec<ContractEvent>,
     },
     /// The result of a successful execution.
     Success {
@@ -130,10 +145,22 @@ pub enum ExecutionResult {
         transfers: Vec<TransferAddr>,
         /// The cost of executing the deploy.
         cost: U512,
+        /// The events emitted by contracts, in the order they were emitted.
+        #[serde(default)]
+        events: Vec<ContractEvent>,
     },
 }
 
 impl ExecutionResult {
+    /// Returns the events emitted by contracts, in the order they were emitted.
+    pub fn events(&self) -> &[ContractEvent] {
+        match self {
+            ExecutionResult::Failure { events, .. } | ExecutionResult::Success { events, .. } => {
+                events
+            }
+        }
+    }
+
     // This method is not intended to be used by third party crates.
     #[doc(hidden)]
     #[cfg(feature = "std")]
@@ -165,6 +192,12 @@ impl Distribution<ExecutionResult> for Standard {
             });
         }
 
+        let event_count = rng.gen_range(0..3);
+        let mut events = Vec::new();
+        for _ in 0..event_count {
+            events.push(rng.gen());
+        }
+
         let effect = ExecutionEffect {
             operations,
             transforms,
@@ -182,12 +215,14 @@ impl Distribution<ExecutionResult> for Standard {
                 transfers,
                 cost: rng.gen::<u64>().into(),
                 error_message: format!("Error message {}", rng.gen::<u64>()),
+                events,
             }
         } else {
             ExecutionResult::Success {
                 effect,
                 transfers,
                 cost: rng.gen::<u64>().into(),
+                events,
             }
         }
     }
@@ -202,22 +237,38 @@ impl ToBytes for ExecutionResult {
                 transfers,
                 cost,
                 error_message,
+                events,
             } => {
-                buffer.push(EXECUTION_RESULT_FAILURE_TAG);
+                if events.is_empty() {
+                    buffer.push(EXECUTION_RESULT_FAILURE_TAG);
+                } else {
+                    buffer.push(EXECUTION_RESULT_FAILURE_WITH_EVENTS_TAG);
+                }
                 buffer.extend(effect.to_bytes()?);
                 buffer.extend(transfers.to_bytes()?);
                 buffer.extend(cost.to_bytes()?);
                 buffer.extend(error_message.to_bytes()?);
+                if !events.is_empty() {
+                    buffer.extend(events.to_bytes()?);
+                }
             }
             ExecutionResult::Success {
                 effect,
                 transfers,
                 cost,
+                events,
             } => {
-                buffer.push(EXECUTION_RESULT_SUCCESS_TAG);
+                if events.is_empty() {
+                    buffer.push(EXECUTION_RESULT_SUCCESS_TAG);
+                } else {
+                    buffer.push(EXECUTION_RESULT_SUCCESS_WITH_EVENTS_TAG);
+                }
                 buffer.extend(effect.to_bytes()?);
                 buffer.extend(transfers.to_bytes()?);
                 buffer.extend(cost.to_bytes()?);
+                if !events.is_empty() {
+                    buffer.extend(events.to_bytes()?);
+                }
             }
         }
         Ok(buffer)
@@ -231,6 +282,7 @@ impl ToBytes for ExecutionResult {
                     transfers,
                     cost,
                     error_message,
+                    ..
                 } => {
                     effect.serialized_length()
                         + transfers.serialized_length()
@@ -241,12 +293,18 @@ impl ToBytes for ExecutionResult {
                     effect,
                     transfers,
                     cost,
+                    ..
                 } => {
                     effect.serialized_length()
                         + transfers.serialized_length()
                         + cost.serialized_length()
                 }
             }
+            + if self.events().is_empty() {
+                0
+            } else {
+                self.events().serialized_length()
+            }
     }
 }
 
@@ -254,27 +312,39 @@ impl FromBytes for ExecutionResult {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (tag, remainder) = u8::from_bytes(bytes)?;
         match tag {
-            EXECUTION_RESULT_FAILURE_TAG => {
+            EXECUTION_RESULT_FAILURE_TAG | EXECUTION_RESULT_FAILURE_WITH_EVENTS_TAG => {
                 let (effect, remainder) = ExecutionEffect::from_bytes(remainder)?;
                 let (transfers, remainder) = Vec::<TransferAddr>::from_bytes(remainder)?;
                 let (cost, remainder) = U512::from_bytes(remainder)?;
                 let (error_message, remainder) = String::from_bytes(remainder)?;
+                let (events, remainder) = if tag == EXECUTION_RESULT_FAILURE_WITH_EVENTS_TAG {
+                    Vec::<ContractEvent>::from_bytes(remainder)?
+                } else {
+                    (Vec::new(), remainder)
+                };
                 let execution_result = ExecutionResult::Failure {
                     effect,
                     transfers,
                     cost,
                     error_message,
+                    events,
                 };
                 Ok((execution_result, remainder))
             }
-            EXECUTION_RESULT_SUCCESS_TAG => {
+            EXECUTION_RESULT_SUCCESS_TAG | EXECUTION_RESULT_SUCCESS_WITH_EVENTS_TAG => {
                 let (effect, remainder) = ExecutionEffect::from_bytes(remainder)?;
                 let (transfers, remainder) = Vec::<TransferAddr>::from_bytes(remainder)?;
                 let (cost, remainder) = U512::from_bytes(remainder)?;
+                let (events, remainder) = if tag == EXECUTION_RESULT_SUCCESS_WITH_EVENTS_TAG {
+                    Vec::<ContractEvent>::from_bytes(remainder)?
+                } else {
+                    (Vec::new(), remainder)
+                };
                 let execution_result = ExecutionResult::Success {
                     effect,
                     transfers,
                     cost,
+                    events,
                 };
                 Ok((execution_result, remainder))
             }
@@ -319,6 +389,65 @@ impl FromBytes for ExecutionEffect {
     }
 }
 
+/// An event emitted by a contract while executing a deploy.
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct ContractEvent {
+    /// The hash of the contract which emitted the event, or `None` if it was emitted by session
+    /// code running in the context of an account.
+    pub contract_hash: Option<ContractHash>,
+    /// The name of the event.
+    pub name: String,
+    /// The payload of the event.
+    pub payload: CLValue,
+}
+
+impl ToBytes for ContractEvent {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.contract_hash.to_bytes()?);
+        buffer.extend(self.name.to_bytes()?);
+        buffer.extend(self.payload.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.contract_hash.serialized_length()
+            + self.name.serialized_length()
+            + self.payload.serialized_length()
+    }
+}
+
+impl FromBytes for ContractEvent {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (contract_hash, remainder) = Option::<ContractHash>::from_bytes(bytes)?;
+        let (name, remainder) = String::from_bytes(remainder)?;
+        let (payload, remainder) = CLValue::from_bytes(remainder)?;
+        let contract_event = ContractEvent {
+            contract_hash,
+            name,
+            payload,
+        };
+        Ok((contract_event, remainder))
+    }
+}
+
+impl Distribution<ContractEvent> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ContractEvent {
+        let contract_hash = if rng.gen() {
+            Some(ContractHash::new(rng.gen()))
+        } else {
+            None
+        };
+        ContractEvent {
+            contract_hash,
+            name: rng.gen::<u64>().to_string(),
+            payload: CLValue::from_t(rng.gen::<u64>()).unwrap(),
+        }
+    }
+}
+
 /// An operation performed while executing a deploy.
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug)]
 #[cfg_attr(feature = "std", derive(JsonSchema))]
@@ -673,10 +802,66 @@ mod tests {
         bytesrepr::test_serialization_roundtrip(&transform);
     }
 
+    #[test]
+    fn bytesrepr_test_contract_event() {
+        let mut rng = get_rng();
+        let contract_event: ContractEvent = rng.gen();
+        bytesrepr::test_serialization_roundtrip(&contract_event);
+    }
+
     #[test]
     fn bytesrepr_test_execution_result() {
         let mut rng = get_rng();
         let execution_result: ExecutionResult = rng.gen();
         bytesrepr::test_serialization_roundtrip(&execution_result);
     }
+
+    // An execution effect with a single read of a key "a", as encoded before contract events were
+    // introduced.
+    const ORIGINAL_EXECUTION_EFFECT_BYTES: [u8; 14] = [
+        1, 0, 0, 0, // number of operations
+        1, 0, 0, 0, 97, // operation key
+        0,  // operation kind
+        0, 0, 0, 0, // number of transforms
+    ];
+
+    fn original_execution_effect() -> ExecutionEffect {
+        ExecutionEffect {
+            operations: vec![Operation {
+                key: "a".to_string(),
+                kind: OpKind::Read,
+            }],
+            transforms: vec![],
+        }
+    }
+
+    #[test]
+    fn should_decode_original_execution_effect_encoding() {
+        let execution_effect: ExecutionEffect =
+            bytesrepr::deserialize(ORIGINAL_EXECUTION_EFFECT_BYTES.to_vec()).unwrap();
+        assert_eq!(execution_effect, original_execution_effect());
+        assert_eq!(
+            execution_effect.to_bytes().unwrap(),
+            ORIGINAL_EXECUTION_EFFECT_BYTES
+        );
+    }
+
+    #[test]
+    fn should_decode_original_execution_result_encoding() {
+        let mut original_bytes = vec![1]; // success tag
+        original_bytes.extend_from_slice(&ORIGINAL_EXECUTION_EFFECT_BYTES);
+        original_bytes.extend_from_slice(&[0, 0, 0, 0]); // number of transfers
+        original_bytes.extend_from_slice(&[1, 7]); // cost
+
+        let execution_result: ExecutionResult =
+            bytesrepr::deserialize(original_bytes.clone()).unwrap();
+        let expected_execution_result = ExecutionResult::Success {
+            effect: original_execution_effect(),
+            transfers: vec![],
+            cost: U512::from(7),
+            events: vec![],
+        };
+        assert_eq!(execution_result, expected_execution_result);
+        assert_eq!(execution_result.to_bytes().unwrap(), original_bytes);
+    }
 }
@@ -74,6 +74,7 @@ pub fn key_arb() -> impl Strategy<Value = Key> {
         uref_arb().prop_map(|uref| Key::Balance(uref.addr())),
         account_hash_arb().prop_map(Key::Bid),
         account_hash_arb().prop_map(Key::Withdraw),
+        u8_slice_32().prop_map(Key::Dictionary),
     ]
 }
 
@@ -252,7 +253,10 @@ pub fn entry_point_type_arb() -> impl Strategy<Value = EntryPointType> {
 }
 
 pub fn parameter_arb() -> impl Strategy<Value = Parameter> {
-    (".*", cl_type_arb()).prop_map(|(name, cl_type)| Parameter::new(name, cl_type))
+    prop_oneof![
+        (".*", cl_type_arb()).prop_map(|(name, cl_type)| Parameter::new(name, cl_type)),
+        (".*", cl_value_arb()).prop_map(|(name, default)| Parameter::optional(name, default)),
+    ]
 }
 
 pub fn parameters_arb() -> impl Strategy<Value = Parameters> {
@@ -6,6 +6,8 @@ pub const ARG_AMOUNT: &str = "amount";
 pub const ARG_ACCOUNT: &str = "account";
 /// Named constant for `target`.
 pub const ARG_TARGET: &str = "target";
+/// Named constant for `validator_public_keys`.
+pub const ARG_VALIDATOR_PUBLIC_KEYS: &str = "validator_public_keys";
 
 /// Named constant for method `get_payment_purse`.
 pub const METHOD_GET_PAYMENT_PURSE: &str = "get_payment_purse";
@@ -15,6 +17,8 @@ pub const METHOD_SET_REFUND_PURSE: &str = "set_refund_purse";
 pub const METHOD_GET_REFUND_PURSE: &str = "get_refund_purse";
 /// Named constant for method `finalize_payment`.
 pub const METHOD_FINALIZE_PAYMENT: &str = "finalize_payment";
+/// Named constant for method `distribute_accumulated_fees`.
+pub const METHOD_DISTRIBUTE_ACCUMULATED_FEES: &str = "distribute_accumulated_fees";
 
 /// Storage for handle payment contract hash.
 pub const CONTRACT_HASH_KEY: &str = "contract_hash";
@@ -28,3 +32,12 @@ pub const PAYMENT_PURSE_KEY: &str = "payment_purse";
 /// The uref name where the Handle Payment will refund unused payment back to the user. The uref
 /// this name corresponds to is set by the user.
 pub const REFUND_PURSE_KEY: &str = "refund_purse";
+
+/// The uref name where the Handle Payment accumulates fees when configured to do so.
+pub const ACCUMULATION_PURSE_KEY: &str = "accumulation_purse";
+
+/// Storage for the share of unspent payment refunded to the user, as a `Ratio<U512>`.
+pub const REFUND_RATIO_KEY: &str = "refund_ratio";
+
+/// Storage for the [`FeeHandling`](super::FeeHandling) policy applied to spent payment.
+pub const FEE_HANDLING_KEY: &str = "fee_handling";
@@ -121,11 +121,33 @@ pub enum Error {
     /// Failed to transfer funds.
     #[cfg_attr(feature = "std", error("Failed to transfer funds"))]
     Transfer,
-    // NOTE: These variants below will be removed once support for WASM system contracts will be
+    // NOTE: The variant below will be removed once support for WASM system contracts will be
     // dropped.
     #[doc(hidden)]
     #[cfg_attr(feature = "std", error("GasLimit"))]
     GasLimit,
+    /// Failed to read a value from the Handle Payment contract's storage.
+    #[cfg_attr(feature = "std", error("Storage error"))]
+    Storage,
+    /// The refund ratio or fee handling policy is stored under a key of an unexpected type.
+    #[cfg_attr(feature = "std", error("Fee configuration key has unexpected type"))]
+    FeeConfigKeyUnexpectedType,
+    /// Handle Payment contract's accumulation purse not found.
+    #[cfg_attr(feature = "std", error("Accumulation purse not found"))]
+    AccumulationPurseNotFound,
+    /// Handle Payment contract's accumulation purse key was not a URef.
+    #[cfg_attr(feature = "std", error("Accumulation purse has unexpected type"))]
+    AccumulationPurseKeyUnexpectedType,
+    /// Internal error: while finalizing payment, the amount spent could not be transferred to
+    /// the accumulation purse.
+    #[cfg_attr(feature = "std", error("Transfer to accumulation purse has failed"))]
+    FailedTransferToAccumulationPurse,
+    /// Internal error: while finalizing payment, the amount spent could not be burned.
+    #[cfg_attr(feature = "std", error("Burning fees has failed"))]
+    FailedBurn,
+    /// Internal error: the accumulated fees could not be transferred to a validator.
+    #[cfg_attr(feature = "std", error("Distribution of accumulated fees has failed"))]
+    FailedDistributeAccumulatedFees,
 }
 
 impl CLTyped for Error {
@@ -0,0 +1,87 @@
+use alloc::vec::Vec;
+
+use datasize::DataSize;
+#[cfg(feature = "std")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
+    CLType, CLTyped,
+};
+
+const PAY_TO_PROPOSER_TAG: u8 = 0;
+const ACCUMULATE_TAG: u8 = 1;
+const BURN_TAG: u8 = 2;
+
+/// Defines what happens to the fees paid for executing deploys.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, DataSize,
+)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum FeeHandling {
+    /// Fees are paid to the proposer of the block containing the deploy.
+    PayToProposer,
+    /// Fees are accumulated in the Handle Payment contract's accumulation purse and distributed
+    /// equally between the validators at the end of each era.
+    Accumulate,
+    /// Fees are burned, reducing the total supply.
+    Burn,
+}
+
+impl Default for FeeHandling {
+    fn default() -> Self {
+        FeeHandling::PayToProposer
+    }
+}
+
+impl CLTyped for FeeHandling {
+    fn cl_type() -> CLType {
+        CLType::U8
+    }
+}
+
+impl ToBytes for FeeHandling {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let tag = match self {
+            FeeHandling::PayToProposer => PAY_TO_PROPOSER_TAG,
+            FeeHandling::Accumulate => ACCUMULATE_TAG,
+            FeeHandling::Burn => BURN_TAG,
+        };
+        tag.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for FeeHandling {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        let fee_handling = match tag {
+            PAY_TO_PROPOSER_TAG => FeeHandling::PayToProposer,
+            ACCUMULATE_TAG => FeeHandling::Accumulate,
+            BURN_TAG => FeeHandling::Burn,
+            _ => return Err(bytesrepr::Error::Formatting),
+        };
+        Ok((fee_handling, remainder))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytesrepr_roundtrip() {
+        for fee_handling in &[
+            FeeHandling::PayToProposer,
+            FeeHandling::Accumulate,
+            FeeHandling::Burn,
+        ] {
+            bytesrepr::test_serialization_roundtrip(fee_handling);
+        }
+    }
+}
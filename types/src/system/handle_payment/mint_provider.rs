@@ -20,4 +20,7 @@ pub trait MintProvider {
 
     /// Checks balance of a `purse`. Returns `None` if given purse does not exist.
     fn balance(&mut self, purse: URef) -> Result<Option<U512>, Error>;
+
+    /// Removes `amount` from `purse` and reduces the total supply accordingly.
+    fn burn(&mut self, purse: URef, amount: U512) -> Result<(), Error>;
 }
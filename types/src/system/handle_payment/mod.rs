@@ -1,22 +1,23 @@
 //! Contains implementation of a Handle Payment contract functionality.
 mod constants;
 mod error;
+mod fee_handling;
 mod mint_provider;
 mod runtime_provider;
+mod storage_provider;
 
+use alloc::vec::Vec;
 use core::marker::Sized;
 
-use crate::{account::AccountHash, AccessRights, URef, U512};
+use crate::{account::AccountHash, AccessRights, PublicKey, URef, U512};
 
 pub use crate::system::handle_payment::{
-    constants::*, error::Error, mint_provider::MintProvider, runtime_provider::RuntimeProvider,
+    constants::*, error::Error, fee_handling::FeeHandling, mint_provider::MintProvider,
+    runtime_provider::RuntimeProvider, storage_provider::StorageProvider,
 };
 
-// A simplified representation of a refund percentage which is currently hardcoded to 0%.
-const REFUND_PERCENTAGE: U512 = U512::zero();
-
 /// Handle payment functionality implementation.
-pub trait HandlePayment: MintProvider + RuntimeProvider + Sized {
+pub trait HandlePayment: MintProvider + RuntimeProvider + StorageProvider + Sized {
     /// Get payment purse.
     fn get_payment_purse(&self) -> Result<URef, Error> {
         let purse = internal::get_payment_purse(self)?;
@@ -47,16 +48,32 @@ pub trait HandlePayment: MintProvider + RuntimeProvider + Sized {
     ) -> Result<(), Error> {
         internal::finalize_payment(self, amount_spent, account, target)
     }
+
+    /// Distributes the balance of the accumulation purse equally between `validators`.
+    fn distribute_accumulated_fees(&mut self, validators: Vec<PublicKey>) -> Result<(), Error> {
+        internal::distribute_accumulated_fees(self, validators)
+    }
 }
 
 mod internal {
+    use alloc::vec::Vec;
+    use core::cmp;
+
+    use num_rational::Ratio;
+
     use crate::{
         account::AccountHash,
-        system::handle_payment::{Error, MintProvider, RuntimeProvider},
-        Key, Phase, PublicKey, URef, U512,
+        bytesrepr::FromBytes,
+        system::handle_payment::{
+            Error, FeeHandling, MintProvider, RuntimeProvider, StorageProvider,
+        },
+        CLTyped, Key, Phase, PublicKey, URef, U512,
     };
 
-    use super::{PAYMENT_PURSE_KEY, REFUND_PERCENTAGE, REFUND_PURSE_KEY};
+    use super::{
+        ACCUMULATION_PURSE_KEY, FEE_HANDLING_KEY, PAYMENT_PURSE_KEY, REFUND_PURSE_KEY,
+        REFUND_RATIO_KEY,
+    };
 
     /// Returns the purse for accepting payment for transactions.
     pub fn get_payment_purse<R: RuntimeProvider>(runtime_provider: &R) -> Result<URef, Error> {
@@ -67,6 +84,36 @@ mod internal {
         }
     }
 
+    /// Returns the purse in which fees are accumulated.
+    pub fn get_accumulation_purse<R: RuntimeProvider>(runtime_provider: &R) -> Result<URef, Error> {
+        match runtime_provider.get_key(ACCUMULATION_PURSE_KEY) {
+            Some(Key::URef(uref)) => Ok(uref),
+            Some(_) => Err(Error::AccumulationPurseKeyUnexpectedType),
+            None => Err(Error::AccumulationPurseNotFound),
+        }
+    }
+
+    /// Reads the value stored under the named key `name`, or returns `None` if there is no such
+    /// named key.
+    ///
+    /// Networks upgraded from a protocol version predating the refund ratio and fee handling
+    /// settings have no such named keys until set by a later upgrade.
+    fn read_fee_config<P, T>(provider: &mut P, name: &str) -> Result<Option<T>, Error>
+    where
+        P: RuntimeProvider + StorageProvider,
+        T: CLTyped + FromBytes,
+    {
+        let uref = match provider.get_key(name) {
+            Some(Key::URef(uref)) => uref,
+            Some(_) => return Err(Error::FeeConfigKeyUnexpectedType),
+            None => return Ok(None),
+        };
+        match provider.read(uref)? {
+            Some(value) => Ok(Some(value)),
+            None => Err(Error::Storage),
+        }
+    }
+
     /// Sets the purse where refunds (excess funds not spent to pay for computation) will be sent.
     /// Note that if this function is never called, the default location is the main purse of the
     /// deployer's account.
@@ -92,11 +139,12 @@ mod internal {
         }
     }
 
-    /// Transfers funds from the payment purse to the validator rewards purse, as well as to the
-    /// refund purse, depending on how much was spent on the computation. This function maintains
-    /// the invariant that the balance of the payment purse is zero at the beginning and end of each
-    /// deploy and that the refund purse is unset at the beginning and end of each deploy.
-    pub fn finalize_payment<P: MintProvider + RuntimeProvider>(
+    /// Refunds the configured share of the unspent payment to the refund purse, and pays, burns
+    /// or accumulates the rest according to the configured [`FeeHandling`]. This function
+    /// maintains the invariant that the balance of the payment purse is zero at the beginning and
+    /// end of each deploy and that the refund purse is unset at the beginning and end of each
+    /// deploy.
+    pub fn finalize_payment<P: MintProvider + RuntimeProvider + StorageProvider>(
         provider: &mut P,
         amount_spent: U512,
         account: AccountHash,
@@ -117,23 +165,39 @@ mod internal {
             return Err(Error::InsufficientPaymentForAmountSpent);
         }
 
-        // User's part
-        let refund_amount = (total - amount_spent) * REFUND_PERCENTAGE;
+        let refund_ratio: Ratio<U512> = read_fee_config(provider, REFUND_RATIO_KEY)?
+            .unwrap_or_else(|| Ratio::from_integer(U512::zero()));
+        let fee_handling: FeeHandling =
+            read_fee_config(provider, FEE_HANDLING_KEY)?.unwrap_or_default();
+
+        // User's part, which can never exceed the unspent payment
+        let unspent = total - amount_spent;
+        let refund_amount = cmp::min((refund_ratio * Ratio::from(unspent)).to_integer(), unspent);
 
-        // Validator reward
-        let validator_reward = total - refund_amount;
+        // Fee
+        let fee = total - refund_amount;
 
-        // Makes sure both parts: for user, and for validator sums to the total amount in the
+        // Makes sure both parts: the user's refund and the fee sum to the total amount in the
         // payment's purse.
-        debug_assert_eq!(validator_reward + refund_amount, total);
+        debug_assert_eq!(fee + refund_amount, total);
 
         let refund_purse = get_refund_purse(provider)?;
         provider.remove_key(REFUND_PURSE_KEY)?; //unset refund purse after reading it
 
-        // pay target validator
-        provider
-            .transfer_purse_to_purse(payment_purse, target, validator_reward)
-            .map_err(|_| Error::FailedTransferToRewardsPurse)?;
+        match fee_handling {
+            FeeHandling::PayToProposer => provider
+                .transfer_purse_to_purse(payment_purse, target, fee)
+                .map_err(|_| Error::FailedTransferToRewardsPurse)?,
+            FeeHandling::Accumulate => {
+                let accumulation_purse = get_accumulation_purse(provider)?;
+                provider
+                    .transfer_purse_to_purse(payment_purse, accumulation_purse, fee)
+                    .map_err(|_| Error::FailedTransferToAccumulationPurse)?
+            }
+            FeeHandling::Burn => provider
+                .burn(payment_purse, fee)
+                .map_err(|_| Error::FailedBurn)?,
+        }
 
         if refund_amount.is_zero() {
             return Ok(());
@@ -156,6 +220,46 @@ mod internal {
         Ok(())
     }
 
+    /// Transfers the balance of the accumulation purse to the main purses of `validators` in
+    /// equal shares.  Any remainder stays in the accumulation purse.
+    pub fn distribute_accumulated_fees<P: MintProvider + RuntimeProvider>(
+        provider: &mut P,
+        validators: Vec<PublicKey>,
+    ) -> Result<(), Error> {
+        let caller = provider.get_caller();
+        if caller != PublicKey::System.to_account_hash() {
+            return Err(Error::SystemFunctionCalledByUserAccount);
+        }
+
+        if validators.is_empty() {
+            return Ok(());
+        }
+
+        // Networks which never accumulated fees have no accumulation purse.
+        let accumulation_purse = match get_accumulation_purse(provider) {
+            Ok(accumulation_purse) => accumulation_purse,
+            Err(Error::AccumulationPurseNotFound) => return Ok(()),
+            Err(error) => return Err(error),
+        };
+        let total = match provider.balance(accumulation_purse)? {
+            Some(balance) => balance,
+            None => return Err(Error::AccumulationPurseNotFound),
+        };
+
+        let share = total / U512::from(validators.len() as u64);
+        if share.is_zero() {
+            return Ok(());
+        }
+
+        for validator in validators {
+            provider
+                .transfer_purse_to_account(accumulation_purse, validator.to_account_hash(), share)
+                .map_err(|_| Error::FailedDistributeAccumulatedFees)?;
+        }
+
+        Ok(())
+    }
+
     pub fn refund_to_account<M: MintProvider>(
         mint_provider: &mut M,
         payment_purse: URef,
@@ -0,0 +1,7 @@
+use crate::{bytesrepr::FromBytes, system::handle_payment::Error, CLTyped, URef};
+
+/// Provides functionality of a contract storage.
+pub trait StorageProvider {
+    /// Read data from [`URef`].
+    fn read<T: CLTyped + FromBytes>(&mut self, uref: URef) -> Result<Option<T>, Error>;
+}
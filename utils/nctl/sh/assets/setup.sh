@@ -289,6 +289,7 @@ function _set_nodes()
             "cfg['rest_server']['address']='0.0.0.0:$(get_node_port_rest "$IDX")';"
             "cfg['rpc_server']['address']='0.0.0.0:$(get_node_port_rpc "$IDX")';"
             "cfg['event_stream_server']['address']='0.0.0.0:$(get_node_port_sse "$IDX")';"
+            "cfg['event_stream_server']['event_index_path']='../../storage/sse';"
             "toml.dump(cfg, open('$PATH_TO_FILE', 'w'));"
         )
         python3 -c "${SCRIPT[*]}"